@@ -0,0 +1,271 @@
+//! A multi-producer, multi-consumer channel where every receiver sees every
+//! sent value.
+//!
+//! The channel is backed by a bounded ring buffer of the last `capacity`
+//! values. Receivers that fall more than `capacity` values behind skip ahead
+//! to the oldest retained value and are told how many values they missed.
+
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Context, Poll, Waker};
+
+/// The transmission end of a broadcast channel.
+///
+/// This value is created by the [`channel`](channel) function and can be
+/// cloned to produce additional senders.
+#[derive(Debug)]
+pub struct Sender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+/// The receiving end of a broadcast channel.
+///
+/// This value is created by the [`channel`](channel) function or by
+/// [`Sender::subscribe`]. Every receiver observes every value sent after its
+/// creation, in order, unless it lags behind the ring buffer.
+#[derive(Debug)]
+pub struct Receiver<T> {
+    shared: Arc<Shared<T>>,
+    /// Sequence number of the next value this receiver will observe.
+    next: u64,
+}
+
+#[derive(Debug)]
+struct Shared<T> {
+    capacity: usize,
+    state: Mutex<State<T>>,
+}
+
+#[derive(Debug)]
+struct State<T> {
+    /// The last `capacity` (at most) sent values.
+    buffer: VecDeque<T>,
+    /// Sequence number of the first value in `buffer`; values with lower
+    /// sequence numbers have been overwritten.
+    head: u64,
+    /// Number of live `Sender` handles.
+    senders: usize,
+    /// Number of live `Receiver` handles.
+    receivers: usize,
+    /// Tasks waiting in [`Receiver::recv`] for the next value.
+    wakers: Vec<Waker>,
+}
+
+impl<T> State<T> {
+    /// Sequence number that the next sent value will receive.
+    fn tail(&self) -> u64 {
+        self.head + self.buffer.len() as u64
+    }
+
+    fn wake_all(&mut self) {
+        for waker in self.wakers.drain(..) {
+            waker.wake();
+        }
+    }
+}
+
+/// The error type returned from [`Sender::send`].
+///
+/// A send fails only when no receivers exist; the value that could not be
+/// delivered is handed back.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SendError<T>(pub T);
+
+impl<T> fmt::Display for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "send failed because all receivers are gone")
+    }
+}
+
+impl<T: fmt::Debug> Error for SendError<T> {}
+
+/// The error type returned from [`Receiver::recv`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RecvError {
+    /// All senders were dropped and every buffered value has been observed.
+    Closed,
+    /// The receiver fell behind the ring buffer and skipped ahead to the
+    /// oldest retained value; the payload is the number of missed values.
+    Lagged(u64),
+}
+
+impl fmt::Display for RecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Closed => write!(f, "recv failed because all senders are gone"),
+            Self::Lagged(n) => write!(f, "recv lagged behind by {} messages", n),
+        }
+    }
+}
+
+impl Error for RecvError {}
+
+/// Creates a new broadcast channel retaining at most `capacity` values,
+/// returning the sender/receiver halves.
+///
+/// Every value sent is observed by every receiver existing at the time of
+/// the send. A receiver that falls more than `capacity` values behind
+/// observes [`RecvError::Lagged`] once and resumes at the oldest value still
+/// in the buffer.
+///
+/// # Panics
+///
+/// Panics if `capacity` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use futures::channel::broadcast;
+/// use futures::executor::block_on;
+///
+/// let (tx, mut rx1) = broadcast::channel(16);
+/// let mut rx2 = tx.subscribe();
+///
+/// tx.send(3).unwrap();
+///
+/// assert_eq!(block_on(rx1.recv()), Ok(3));
+/// assert_eq!(block_on(rx2.recv()), Ok(3));
+/// ```
+pub fn channel<T: Clone>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    assert!(capacity > 0, "broadcast channel capacity cannot be zero");
+
+    let shared = Arc::new(Shared {
+        capacity,
+        state: Mutex::new(State {
+            buffer: VecDeque::with_capacity(capacity),
+            head: 0,
+            senders: 1,
+            receivers: 1,
+            wakers: Vec::new(),
+        }),
+    });
+    let receiver = Receiver { shared: shared.clone(), next: 0 };
+    let sender = Sender { shared };
+    (sender, receiver)
+}
+
+impl<T: Clone> Sender<T> {
+    /// Sends a value to all receivers, overwriting the oldest buffered value
+    /// if the ring buffer is full.
+    ///
+    /// An `Err` is returned, handing the value back, if no receivers exist.
+    pub fn send(&self, value: T) -> Result<(), SendError<T>> {
+        let mut state = self.shared.state.lock().unwrap();
+        if state.receivers == 0 {
+            return Err(SendError(value));
+        }
+
+        if state.buffer.len() == self.shared.capacity {
+            state.buffer.pop_front();
+            state.head += 1;
+        }
+        state.buffer.push_back(value);
+        state.wake_all();
+        Ok(())
+    }
+
+    /// Creates a new receiver that observes all values sent from this point
+    /// on.
+    pub fn subscribe(&self) -> Receiver<T> {
+        let mut state = self.shared.state.lock().unwrap();
+        state.receivers += 1;
+        let next = state.tail();
+        Receiver { shared: self.shared.clone(), next }
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.shared.state.lock().unwrap().senders += 1;
+        Self { shared: self.shared.clone() }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.state.lock().unwrap();
+        state.senders -= 1;
+        if state.senders == 0 {
+            // Receivers waiting for a value need to observe the close.
+            state.wake_all();
+        }
+    }
+}
+
+impl<T: Clone> Receiver<T> {
+    /// Waits for the next value.
+    ///
+    /// Values are yielded in the order they were sent. If this receiver fell
+    /// behind the ring buffer, the future resolves to
+    /// [`RecvError::Lagged`] and the receiver skips ahead to the oldest
+    /// retained value; the following `recv` yields that value. Once all
+    /// senders are gone and all buffered values have been observed, the
+    /// future resolves to [`RecvError::Closed`].
+    pub fn recv(&mut self) -> Recv<'_, T> {
+        Recv { receiver: Some(self) }
+    }
+
+    fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Result<T, RecvError>> {
+        let mut state = self.shared.state.lock().unwrap();
+
+        if self.next < state.head {
+            let missed = state.head - self.next;
+            self.next = state.head;
+            return Poll::Ready(Err(RecvError::Lagged(missed)));
+        }
+
+        if self.next < state.tail() {
+            let value = state.buffer[(self.next - state.head) as usize].clone();
+            self.next += 1;
+            return Poll::Ready(Ok(value));
+        }
+
+        if state.senders == 0 {
+            return Poll::Ready(Err(RecvError::Closed));
+        }
+
+        if !state.wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+            state.wakers.push(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.shared.state.lock().unwrap().receivers -= 1;
+    }
+}
+
+/// Future for the [`recv`](Receiver::recv) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Recv<'a, T> {
+    receiver: Option<&'a mut Receiver<T>>,
+}
+
+impl<T> Unpin for Recv<'_, T> {}
+
+impl<T: Clone> Future for Recv<'_, T> {
+    type Output = Result<T, RecvError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let receiver = self.receiver.as_mut().expect("polled Recv after completion");
+        let result = receiver.poll_recv(cx);
+        if result.is_ready() {
+            self.receiver = None;
+        }
+        result
+    }
+}
+
+impl<T: Clone> FusedFuture for Recv<'_, T> {
+    fn is_terminated(&self) -> bool {
+        self.receiver.is_none()
+    }
+}
@@ -7,6 +7,12 @@
 //! - [mpsc], a multi-producer, single-consumer channel for sending values
 //!   between tasks, analogous to the similarly-named structure in the standard
 //!   library.
+//! - [watch], a single-producer, multi-consumer channel that only retains
+//!   the last sent value, for watching changes to a shared value.
+//! - [broadcast], a multi-producer, multi-consumer channel where every
+//!   receiver sees every value, backed by a bounded ring buffer.
+//! - [priority], a bounded multi-producer, single-consumer channel that
+//!   yields buffered values in priority order rather than FIFO.
 //!
 //! All items are only available when the `std` or `alloc` feature of this
 //! library is activated, and it is activated by default.
@@ -31,6 +37,9 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "std")]
+pub mod broadcast;
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
 mod lock;
@@ -40,3 +49,9 @@ pub mod mpsc;
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
 pub mod oneshot;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "std")]
+pub mod priority;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "std")]
+pub mod watch;
@@ -78,6 +78,7 @@
 // happens-before semantics required for the acquire / release semantics used
 // by the queue structure.
 
+use futures_core::future::{FusedFuture, Future};
 use futures_core::stream::{FusedStream, Stream};
 use futures_core::task::__internal::AtomicWaker;
 use futures_core::task::{Context, Poll, Waker};
@@ -295,6 +296,11 @@ struct BoundedInner<T> {
     // Number of senders in existence
     num_senders: AtomicUsize,
 
+    // Number of slots claimed by outstanding permits. These count against
+    // `state`'s message count without a matching entry in `message_queue`,
+    // so the receiver must not wait on them when draining.
+    reserved: AtomicUsize,
+
     // Handle to the receiver's task.
     recv_task: AtomicWaker,
 }
@@ -366,6 +372,7 @@ pub fn channel<T>(buffer: usize) -> (Sender<T>, Receiver<T>) {
         message_queue: Queue::new(),
         parked_queue: Queue::new(),
         num_senders: AtomicUsize::new(1),
+        reserved: AtomicUsize::new(0),
         recv_task: AtomicWaker::new(),
     });
 
@@ -631,6 +638,76 @@ impl<T> BoundedSenderInner<T> {
         self.poll_unparked(Some(cx)).map(Ok)
     }
 
+    /// Polls the channel for capacity and, once available, claims a slot for
+    /// a later send. The claimed slot counts against the channel's capacity
+    /// until it is either used or released.
+    fn poll_reserve(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), SendError>> {
+        match self.poll_ready(cx) {
+            Poll::Ready(Ok(())) => {}
+            Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+            Poll::Pending => return Poll::Pending,
+        }
+
+        // Claim the slot by incrementing the message count without pushing a
+        // message, parking ourselves if the claim takes the channel to
+        // capacity, exactly as an actual send would.
+        let park_self = match self.inc_num_messages() {
+            Some(num_messages) => num_messages > self.inner.buffer,
+            None => return Poll::Ready(Err(SendError { kind: SendErrorKind::Disconnected })),
+        };
+
+        if park_self {
+            self.park();
+        }
+
+        self.inner.reserved.fetch_add(1, SeqCst);
+        Poll::Ready(Ok(()))
+    }
+
+    /// Attempts to claim a slot for a later send without waiting.
+    fn try_reserve(&mut self) -> Result<(), TrySendError<()>> {
+        // If the sender is currently blocked, there is no capacity for it.
+        if !self.poll_unparked(None).is_ready() {
+            return Err(TrySendError { err: SendError { kind: SendErrorKind::Full }, val: () });
+        }
+
+        let park_self = match self.inc_num_messages() {
+            Some(num_messages) => num_messages > self.inner.buffer,
+            None => {
+                return Err(TrySendError {
+                    err: SendError { kind: SendErrorKind::Disconnected },
+                    val: (),
+                })
+            }
+        };
+
+        if park_self {
+            self.park();
+        }
+
+        self.inner.reserved.fetch_add(1, SeqCst);
+        Ok(())
+    }
+
+    /// Returns a slot previously claimed by `poll_reserve` to the channel
+    /// without sending a message.
+    fn release_reserved(&self) {
+        // OPEN_MASK is the highest bit, so it's unaffected by the
+        // subtraction; the reserved slot guarantees num_messages > 0.
+        self.inner.state.fetch_sub(1, SeqCst);
+        self.inner.reserved.fetch_sub(1, SeqCst);
+
+        // The freed slot may let a parked sender make progress, just like a
+        // receive would.
+        if let Some(task) = unsafe { self.inner.parked_queue.pop_spin() } {
+            task.lock().unwrap().notify();
+        }
+
+        // A receiver parked on a closed channel may have been waiting only
+        // for this reservation to resolve.
+        self.inner.recv_task.wake();
+    }
+
     /// Returns whether the senders send to the same receiver.
     fn same_receiver(&self, other: &Self) -> bool {
         Arc::ptr_eq(&self.inner, &other.inner)
@@ -727,6 +804,42 @@ impl<T> Sender<T> {
         inner.poll_ready(cx)
     }
 
+    /// Reserves a slot in the channel for a later send.
+    ///
+    /// The returned future resolves to a [`Permit`] once the channel has
+    /// capacity for one more message. The reserved slot counts against the
+    /// channel's capacity while the permit is held, and sending through the
+    /// permit completes synchronously. Dropping an unused permit returns the
+    /// slot to the channel.
+    ///
+    /// This enables "check capacity, then commit" patterns where the item to
+    /// send is expensive to produce or should not be produced at all unless
+    /// it can be sent.
+    pub fn reserve(&mut self) -> Reserve<'_, T> {
+        Reserve { sender: Some(self) }
+    }
+
+    /// Attempts to reserve a slot in the channel without waiting.
+    ///
+    /// This is the non-blocking counterpart of [`reserve`](Sender::reserve),
+    /// usable from non-async contexts. It returns a [`Permit`] immediately
+    /// if the channel has capacity, or an error that reports via
+    /// [`is_full`](SendError::is_full) and
+    /// [`is_disconnected`](SendError::is_disconnected) whether the channel
+    /// was full or the receiver is gone.
+    pub fn try_reserve(&mut self) -> Result<Permit<'_, T>, TrySendError<()>> {
+        match &mut self.0 {
+            Some(inner) => inner.try_reserve()?,
+            None => {
+                return Err(TrySendError {
+                    err: SendError { kind: SendErrorKind::Disconnected },
+                    val: (),
+                })
+            }
+        }
+        Ok(Permit { sender: Some(self) })
+    }
+
     /// Returns whether this channel is closed without needing a context.
     pub fn is_closed(&self) -> bool {
         self.0.as_ref().map(BoundedSenderInner::is_closed).unwrap_or(true)
@@ -772,6 +885,79 @@ impl<T> Sender<T> {
     }
 }
 
+/// Future returned by [`Sender::reserve`].
+///
+/// Resolves to a [`Permit`] once the channel has capacity for one more
+/// message.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Reserve<'a, T> {
+    sender: Option<&'a mut Sender<T>>,
+}
+
+impl<T> Unpin for Reserve<'_, T> {}
+
+impl<'a, T> Future for Reserve<'a, T> {
+    type Output = Result<Permit<'a, T>, SendError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        {
+            let sender = self.sender.as_mut().expect("polled Reserve after completion");
+            let inner = match sender.0.as_mut() {
+                Some(inner) => inner,
+                None => return Poll::Ready(Err(SendError { kind: SendErrorKind::Disconnected })),
+            };
+            match inner.poll_reserve(cx) {
+                Poll::Ready(Ok(())) => {}
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+        let sender = self.sender.take().unwrap();
+        Poll::Ready(Ok(Permit { sender: Some(sender) }))
+    }
+}
+
+/// A reserved slot in a bounded channel, returned by [`Sender::reserve`].
+///
+/// The slot counts against the channel's capacity until the permit is
+/// consumed by [`send`](Permit::send) or dropped. Dropping an unused permit
+/// returns the slot to the channel.
+#[derive(Debug)]
+#[must_use = "permits hold a channel slot unless used or dropped"]
+pub struct Permit<'a, T> {
+    sender: Option<&'a mut Sender<T>>,
+}
+
+impl<T> Permit<'_, T> {
+    /// Sends a message using the reserved slot.
+    ///
+    /// This completes synchronously: the capacity check already happened
+    /// when the permit was acquired. If the receiver has been dropped in the
+    /// meantime the message is silently discarded along with the rest of the
+    /// queue.
+    pub fn send(mut self, msg: T) {
+        let sender = self.sender.take().unwrap();
+        if let Some(inner) = &sender.0 {
+            // The reservation becomes a queued message; update the count
+            // before the push so the receiver never waits on a slot that
+            // will not be filled.
+            inner.inner.reserved.fetch_sub(1, SeqCst);
+            inner.queue_push_and_signal(msg);
+        }
+    }
+}
+
+impl<T> Drop for Permit<'_, T> {
+    fn drop(&mut self) {
+        if let Some(sender) = self.sender.take() {
+            if let Some(inner) = &sender.0 {
+                inner.release_reserved();
+            }
+        }
+    }
+}
+
 impl<T> UnboundedSender<T> {
     /// Check if the channel is ready to receive a message.
     pub fn poll_ready(&self, _: &mut Context<'_>) -> Poll<Result<(), SendError>> {
@@ -987,6 +1173,20 @@ impl<T> Receiver<T> {
         }
     }
 
+    /// Receives up to `limit` messages in one batch, appending them to `buf`.
+    ///
+    /// The returned future waits until at least one message is available,
+    /// then moves every message that is already queued — at most `limit` —
+    /// into `buf` without waiting for more, and resolves to the number of
+    /// messages moved. Draining batches this way avoids the per-message
+    /// wakeup overhead of receiving items one at a time from a busy channel.
+    ///
+    /// The future resolves to zero only if the channel is closed and fully
+    /// drained, or if `limit` is zero.
+    pub fn recv_many<'a>(&'a mut self, buf: &'a mut Vec<T>, limit: usize) -> RecvMany<'a, T> {
+        RecvMany { receiver: Some(self), buf, limit }
+    }
+
     fn next_message(&mut self) -> Poll<Option<T>> {
         let inner = match self.inner.as_mut() {
             None => return Poll::Ready(None),
@@ -1087,13 +1287,21 @@ impl<T> Drop for Receiver<T> {
                     Poll::Ready(Some(_)) => {}
                     Poll::Ready(None) => break,
                     Poll::Pending => {
-                        let state = decode_state(self.inner.as_ref().unwrap().state.load(SeqCst));
+                        let inner = self.inner.as_ref().unwrap();
+                        let state = decode_state(inner.state.load(SeqCst));
 
                         // If the channel is closed, then there is no need to park.
                         if state.is_closed() {
                             break;
                         }
 
+                        // If every remaining slot belongs to an outstanding
+                        // permit, no message is on its way; anything sent
+                        // through a permit later is dropped with the queue.
+                        if state.num_messages <= inner.reserved.load(SeqCst) {
+                            break;
+                        }
+
                         // TODO: Spinning isn't ideal, it might be worth
                         // investigating using a condvar or some other strategy
                         // here. That said, if this case is hit, then another thread
@@ -1107,6 +1315,55 @@ impl<T> Drop for Receiver<T> {
     }
 }
 
+/// Future returned by [`Receiver::recv_many`].
+///
+/// Resolves to the number of messages moved into the provided buffer.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct RecvMany<'a, T> {
+    receiver: Option<&'a mut Receiver<T>>,
+    buf: &'a mut Vec<T>,
+    limit: usize,
+}
+
+impl<T> Unpin for RecvMany<'_, T> {}
+
+impl<T> Future for RecvMany<'_, T> {
+    type Output = usize;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let receiver = this.receiver.as_mut().expect("polled RecvMany after completion");
+
+        let mut count = 0;
+        while count < this.limit {
+            match Pin::new(&mut **receiver).poll_next(cx) {
+                Poll::Ready(Some(msg)) => {
+                    this.buf.push(msg);
+                    count += 1;
+                }
+                // Closed and drained; resolve with whatever was moved, even
+                // if that is nothing.
+                Poll::Ready(None) => break,
+                Poll::Pending => {
+                    if count == 0 {
+                        return Poll::Pending;
+                    }
+                    break;
+                }
+            }
+        }
+        this.receiver = None;
+        Poll::Ready(count)
+    }
+}
+
+impl<T> FusedFuture for RecvMany<'_, T> {
+    fn is_terminated(&self) -> bool {
+        self.receiver.is_none()
+    }
+}
+
 impl<T> UnboundedReceiver<T> {
     /// Closes the receiving half of a channel, without dropping it.
     ///
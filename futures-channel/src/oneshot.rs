@@ -342,6 +342,27 @@ impl<T> Sender<T> {
         self.inner.send(t)
     }
 
+    /// Completes this oneshot with a lazily produced value, but only if the
+    /// [`Receiver`](Receiver) has not been dropped yet.
+    ///
+    /// If the receiving end is known to be gone, `f` is never called and the
+    /// channel's cancellation is reported through `Err(())`. Otherwise the
+    /// value is produced and sent exactly like [`send`](Sender::send); if
+    /// the receiver goes away concurrently, the already produced value is
+    /// handed back as `Err(Some(t))` rather than silently dropped.
+    ///
+    /// This is useful when the value is expensive to compute and should not
+    /// be computed at all for a receiver that can no longer observe it.
+    pub fn send_with<F>(self, f: F) -> Result<(), Option<T>>
+    where
+        F: FnOnce() -> T,
+    {
+        if self.is_canceled() {
+            return Err(None);
+        }
+        self.send(f()).map_err(Some)
+    }
+
     /// Polls this `Sender` half to detect whether its associated
     /// [`Receiver`](Receiver) has been dropped.
     ///
@@ -0,0 +1,329 @@
+//! A bounded multi-producer, single-consumer channel that yields values in
+//! priority order.
+//!
+//! Unlike [`mpsc`](crate::mpsc), which is strictly FIFO, the receiver always
+//! dequeues the highest-priority value currently buffered, as determined by
+//! the value's [`Ord`] implementation. Values that compare equal are yielded
+//! in the order they were sent.
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::error::Error;
+use std::fmt;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll, Waker};
+
+/// The transmission end of a priority channel.
+///
+/// This value is created by the [`channel`](channel) function and can be
+/// cloned to produce additional senders.
+#[derive(Debug)]
+pub struct Sender<T: Ord> {
+    shared: Arc<Shared<T>>,
+}
+
+/// The receiving end of a priority channel.
+///
+/// This value is created by the [`channel`](channel) function. It implements
+/// [`Stream`] and yields the highest-priority buffered value first.
+#[derive(Debug)]
+pub struct Receiver<T: Ord> {
+    shared: Arc<Shared<T>>,
+}
+
+#[derive(Debug)]
+struct Shared<T: Ord> {
+    capacity: usize,
+    state: Mutex<State<T>>,
+}
+
+#[derive(Debug)]
+struct State<T: Ord> {
+    heap: BinaryHeap<Entry<T>>,
+    /// Bumped on every send; breaks priority ties in favor of older values.
+    next_seq: u64,
+    senders: usize,
+    receiver_gone: bool,
+    /// Task waiting for a value in the receiver.
+    recv_waker: Option<Waker>,
+    /// Tasks waiting for buffer space in [`Sender::send`].
+    send_wakers: Vec<Waker>,
+}
+
+/// A buffered value together with its insertion number. The insertion number
+/// orders equal-priority values first-in, first-out.
+#[derive(Debug)]
+struct Entry<T> {
+    value: T,
+    seq: u64,
+}
+
+impl<T: Ord> PartialEq for Entry<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Ord> Eq for Entry<T> {}
+
+impl<T: Ord> PartialOrd for Entry<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord> Ord for Entry<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // `BinaryHeap` is a max-heap: higher values dequeue first, and of
+        // two equal values the one with the *lower* insertion number wins.
+        self.value.cmp(&other.value).then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+impl<T: Ord> State<T> {
+    fn wake_recv(&mut self) {
+        if let Some(waker) = self.recv_waker.take() {
+            waker.wake();
+        }
+    }
+
+    fn wake_senders(&mut self) {
+        for waker in self.send_wakers.drain(..) {
+            waker.wake();
+        }
+    }
+}
+
+/// The error type returned from [`Sender::send`] and [`Sender::try_send`],
+/// handing back the value that could not be delivered.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SendError<T> {
+    /// The value that could not be sent.
+    pub value: T,
+    kind: SendErrorKind,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum SendErrorKind {
+    Full,
+    Disconnected,
+}
+
+impl<T> SendError<T> {
+    /// Returns `true` if this error is a result of the channel being full.
+    pub fn is_full(&self) -> bool {
+        match self.kind {
+            SendErrorKind::Full => true,
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if this error is a result of the receiver being dropped.
+    pub fn is_disconnected(&self) -> bool {
+        match self.kind {
+            SendErrorKind::Disconnected => true,
+            _ => false,
+        }
+    }
+}
+
+impl<T> fmt::Display for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_full() {
+            write!(f, "send failed because channel is full")
+        } else {
+            write!(f, "send failed because receiver is gone")
+        }
+    }
+}
+
+impl<T: fmt::Debug> Error for SendError<T> {}
+
+/// Creates a bounded priority channel, returning the sender/receiver halves.
+///
+/// The channel buffers at most `capacity` values; a send on a full channel
+/// waits for the receiver to make room. The receiver always dequeues the
+/// greatest buffered value according to `T`'s [`Ord`] implementation, with
+/// equal values dequeued in send order.
+///
+/// # Panics
+///
+/// Panics if `capacity` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use futures::channel::priority;
+/// use futures::executor::block_on;
+/// use futures::stream::StreamExt;
+///
+/// let (mut tx, rx) = priority::channel(8);
+///
+/// block_on(async {
+///     tx.send(1).await.unwrap();
+///     tx.send(3).await.unwrap();
+///     tx.send(2).await.unwrap();
+///     drop(tx);
+///
+///     assert_eq!(rx.collect::<Vec<_>>().await, vec![3, 2, 1]);
+/// });
+/// ```
+pub fn channel<T: Ord>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    assert!(capacity > 0, "priority channel capacity cannot be zero");
+
+    let shared = Arc::new(Shared {
+        capacity,
+        state: Mutex::new(State {
+            heap: BinaryHeap::with_capacity(capacity),
+            next_seq: 0,
+            senders: 1,
+            receiver_gone: false,
+            recv_waker: None,
+            send_wakers: Vec::new(),
+        }),
+    });
+    let receiver = Receiver { shared: shared.clone() };
+    let sender = Sender { shared };
+    (sender, receiver)
+}
+
+impl<T: Ord> State<T> {
+    fn push(&mut self, value: T) {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        self.heap.push(Entry { value, seq });
+        self.wake_recv();
+    }
+}
+
+impl<T: Ord> Sender<T> {
+    /// Sends a value, waiting for buffer space if the channel is full.
+    ///
+    /// The returned future resolves to an `Err` handing the value back if
+    /// the receiver is dropped before space becomes available.
+    pub fn send(&mut self, value: T) -> Send<'_, T> {
+        Send { sender: self, value: Some(value) }
+    }
+
+    /// Attempts to send a value without waiting.
+    ///
+    /// The value is handed back in the error if the channel is full or the
+    /// receiver has been dropped.
+    pub fn try_send(&mut self, value: T) -> Result<(), SendError<T>> {
+        let mut state = self.shared.state.lock().unwrap();
+        if state.receiver_gone {
+            return Err(SendError { value, kind: SendErrorKind::Disconnected });
+        }
+        if state.heap.len() >= self.shared.capacity {
+            return Err(SendError { value, kind: SendErrorKind::Full });
+        }
+        state.push(value);
+        Ok(())
+    }
+
+    fn poll_send(
+        &mut self,
+        cx: &mut Context<'_>,
+        value: &mut Option<T>,
+    ) -> Poll<Result<(), SendError<T>>> {
+        let mut state = self.shared.state.lock().unwrap();
+        if state.receiver_gone {
+            let value = value.take().unwrap();
+            return Poll::Ready(Err(SendError { value, kind: SendErrorKind::Disconnected }));
+        }
+        if state.heap.len() < self.shared.capacity {
+            state.push(value.take().unwrap());
+            return Poll::Ready(Ok(()));
+        }
+
+        if !state.send_wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+            state.send_wakers.push(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+}
+
+impl<T: Ord> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.shared.state.lock().unwrap().senders += 1;
+        Self { shared: self.shared.clone() }
+    }
+}
+
+impl<T: Ord> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.state.lock().unwrap();
+        state.senders -= 1;
+        if state.senders == 0 {
+            // The receiver needs to observe the end of the stream.
+            state.wake_recv();
+        }
+    }
+}
+
+impl<T: Ord> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.state.lock().unwrap();
+        state.receiver_gone = true;
+        state.heap.clear();
+        state.wake_senders();
+    }
+}
+
+// The channel does not ever project Pin to the inner T.
+impl<T: Ord> Unpin for Receiver<T> {}
+
+impl<T: Ord> Stream for Receiver<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let mut state = self.shared.state.lock().unwrap();
+        if let Some(entry) = state.heap.pop() {
+            // A freed slot may let a blocked sender make progress.
+            state.wake_senders();
+            return Poll::Ready(Some(entry.value));
+        }
+        if state.senders == 0 {
+            return Poll::Ready(None);
+        }
+        state.recv_waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl<T: Ord> FusedStream for Receiver<T> {
+    fn is_terminated(&self) -> bool {
+        let state = self.shared.state.lock().unwrap();
+        state.senders == 0 && state.heap.is_empty()
+    }
+}
+
+/// Future for the [`send`](Sender::send) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Send<'a, T: Ord> {
+    sender: &'a mut Sender<T>,
+    value: Option<T>,
+}
+
+impl<T: Ord> Unpin for Send<'_, T> {}
+
+impl<T: Ord> Future for Send<'_, T> {
+    type Output = Result<(), SendError<T>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        assert!(this.value.is_some(), "polled Send after completion");
+        this.sender.poll_send(cx, &mut this.value)
+    }
+}
+
+impl<T: Ord> FusedFuture for Send<'_, T> {
+    fn is_terminated(&self) -> bool {
+        self.value.is_none()
+    }
+}
@@ -0,0 +1,245 @@
+//! A single-producer, multi-consumer channel that only retains the *last*
+//! sent value.
+//!
+//! This channel is useful for watching a value that changes over time, such
+//! as configuration or application state. Receivers never observe
+//! intermediate values: if the sender stores several values between two
+//! inspections, only the most recent one is seen.
+
+use std::error::Error;
+use std::fmt;
+use std::ops::Deref;
+use std::pin::Pin;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering::SeqCst;
+use std::sync::{Arc, Mutex, RwLock, RwLockReadGuard};
+
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Context, Poll, Waker};
+
+/// The transmission end of a watch channel.
+///
+/// This value is created by the [`channel`](channel) function.
+#[derive(Debug)]
+pub struct Sender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+/// The receiving end of a watch channel.
+///
+/// This value is created by the [`channel`](channel) function and can be
+/// cloned to create additional receivers watching the same value.
+#[derive(Debug)]
+pub struct Receiver<T> {
+    shared: Arc<Shared<T>>,
+    /// Version of the stored value this receiver has already observed.
+    seen: usize,
+}
+
+/// Internal state shared between the sender and all receivers.
+#[derive(Debug)]
+struct Shared<T> {
+    /// The most recently sent value.
+    value: RwLock<T>,
+    /// Bumped by one on every `send`. Starts at zero, so a freshly created
+    /// receiver considers the initial value already observed.
+    version: AtomicUsize,
+    /// Number of `Sender` handles; the channel only ever has one, so this is
+    /// effectively a "sender alive" flag, but a count keeps `Drop` simple.
+    senders: AtomicUsize,
+    /// Tasks waiting in [`Receiver::changed`] for the next version.
+    wakers: Mutex<Vec<Waker>>,
+}
+
+/// Error returned by [`Receiver::changed`] when the [`Sender`] was dropped
+/// before a new value was sent.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Canceled;
+
+impl fmt::Display for Canceled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "watch channel canceled")
+    }
+}
+
+impl Error for Canceled {}
+
+/// A read guard for the value stored in the channel, returned by
+/// [`Receiver::borrow`].
+///
+/// Holding this guard blocks the sender from storing a new value, so it
+/// should only be held for short periods.
+#[derive(Debug)]
+pub struct Ref<'a, T> {
+    inner: RwLockReadGuard<'a, T>,
+}
+
+impl<T> Deref for Ref<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.inner
+    }
+}
+
+/// Creates a new watch channel, returning the sender/receiver halves.
+///
+/// The channel stores `initial` until the sender replaces it. All receivers
+/// start out having observed the initial value: [`Receiver::changed`] only
+/// resolves once a *new* value is sent.
+///
+/// # Examples
+///
+/// ```
+/// use futures::channel::watch;
+/// use futures::executor::block_on;
+///
+/// let (tx, mut rx) = watch::channel("hello");
+///
+/// assert_eq!(*rx.borrow(), "hello");
+///
+/// tx.send("world");
+/// block_on(rx.changed()).unwrap();
+/// assert_eq!(*rx.borrow(), "world");
+/// ```
+pub fn channel<T>(initial: T) -> (Sender<T>, Receiver<T>) {
+    let shared = Arc::new(Shared {
+        value: RwLock::new(initial),
+        version: AtomicUsize::new(0),
+        senders: AtomicUsize::new(1),
+        wakers: Mutex::new(Vec::new()),
+    });
+    let receiver = Receiver { shared: shared.clone(), seen: 0 };
+    let sender = Sender { shared };
+    (sender, receiver)
+}
+
+impl<T> Shared<T> {
+    fn wake_all(&self) {
+        let wakers = std::mem::replace(&mut *self.wakers.lock().unwrap(), Vec::new());
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Sender<T> {
+    /// Stores a new value in the channel, waking all receivers.
+    ///
+    /// The previous value is dropped. Receivers that have not yet observed
+    /// the previous value will never see it; they only ever see the latest
+    /// value. The value is stored even if no receivers currently exist.
+    pub fn send(&self, value: T) {
+        *self.shared.value.write().unwrap() = value;
+        self.shared.version.fetch_add(1, SeqCst);
+        self.shared.wake_all();
+    }
+
+    /// Returns a read guard to the most recently sent value.
+    pub fn borrow(&self) -> Ref<'_, T> {
+        Ref { inner: self.shared.value.read().unwrap() }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        self.shared.senders.fetch_sub(1, SeqCst);
+
+        // Receivers waiting in `changed` need to observe the cancellation.
+        self.shared.wake_all();
+    }
+}
+
+impl<T> Receiver<T> {
+    /// Returns a read guard to the most recently sent value.
+    ///
+    /// This does *not* mark the value as observed: a pending
+    /// [`changed`](Receiver::changed) call is unaffected.
+    pub fn borrow(&self) -> Ref<'_, T> {
+        Ref { inner: self.shared.value.read().unwrap() }
+    }
+
+    /// Waits for the channel to contain a value this receiver has not yet
+    /// observed.
+    ///
+    /// The returned future resolves to `Ok(())` once a new value is stored,
+    /// marking it as observed, or to `Err(`[`Canceled`]`)` if the sender is
+    /// dropped before that happens. A value sent before `changed` is called
+    /// but after the last observation still counts as new, so updates are
+    /// never missed — only coalesced.
+    pub fn changed(&mut self) -> Changed<'_, T> {
+        Changed { receiver: Some(self) }
+    }
+
+    /// Returns whether the sender has been dropped.
+    pub fn is_closed(&self) -> bool {
+        self.shared.senders.load(SeqCst) == 0
+    }
+
+    fn poll_changed(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Canceled>> {
+        let version = self.shared.version.load(SeqCst);
+        if version != self.seen {
+            self.seen = version;
+            return Poll::Ready(Ok(()));
+        }
+        if self.is_closed() {
+            return Poll::Ready(Err(Canceled));
+        }
+
+        {
+            let mut wakers = self.shared.wakers.lock().unwrap();
+            if !wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+                wakers.push(cx.waker().clone());
+            }
+        }
+
+        // Check again after registering the waker: a send or a sender drop
+        // in between would not have seen our waker.
+        let version = self.shared.version.load(SeqCst);
+        if version != self.seen {
+            self.seen = version;
+            Poll::Ready(Ok(()))
+        } else if self.is_closed() {
+            Poll::Ready(Err(Canceled))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<T> Clone for Receiver<T> {
+    /// Creates a new receiver with the same observation state: values the
+    /// original receiver has already observed count as observed for the
+    /// clone as well.
+    fn clone(&self) -> Self {
+        Self { shared: self.shared.clone(), seen: self.seen }
+    }
+}
+
+/// Future for the [`changed`](Receiver::changed) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Changed<'a, T> {
+    receiver: Option<&'a mut Receiver<T>>,
+}
+
+impl<T> Unpin for Changed<'_, T> {}
+
+impl<T> Future for Changed<'_, T> {
+    type Output = Result<(), Canceled>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let receiver = self.receiver.as_mut().expect("polled Changed after completion");
+        let result = receiver.poll_changed(cx);
+        if result.is_ready() {
+            self.receiver = None;
+        }
+        result
+    }
+}
+
+impl<T> FusedFuture for Changed<'_, T> {
+    fn is_terminated(&self) -> bool {
+        self.receiver.is_none()
+    }
+}
@@ -0,0 +1,143 @@
+use futures::channel::broadcast::{self, RecvError};
+use futures::executor::block_on;
+use futures::future::FutureExt;
+use futures_test::task::{new_count_waker, noop_context};
+use std::thread;
+
+#[test]
+fn fan_out() {
+    let (tx, mut rx1) = broadcast::channel(16);
+    let mut rx2 = tx.subscribe();
+
+    tx.send(1).unwrap();
+    tx.send(2).unwrap();
+
+    assert_eq!(block_on(rx1.recv()), Ok(1));
+    assert_eq!(block_on(rx1.recv()), Ok(2));
+    assert_eq!(block_on(rx2.recv()), Ok(1));
+    assert_eq!(block_on(rx2.recv()), Ok(2));
+}
+
+#[test]
+fn subscribe_only_sees_later_sends() {
+    let (tx, mut rx1) = broadcast::channel(16);
+    tx.send(1).unwrap();
+
+    let mut rx2 = tx.subscribe();
+    tx.send(2).unwrap();
+
+    assert_eq!(block_on(rx1.recv()), Ok(1));
+    assert_eq!(block_on(rx1.recv()), Ok(2));
+    assert_eq!(block_on(rx2.recv()), Ok(2));
+}
+
+#[test]
+fn lagging_receiver_skips_ahead() {
+    let (tx, mut rx) = broadcast::channel(2);
+
+    for i in 0..5 {
+        tx.send(i).unwrap();
+    }
+
+    // Capacity 2 retains values 3 and 4; values 0, 1 and 2 were missed.
+    assert_eq!(block_on(rx.recv()), Err(RecvError::Lagged(3)));
+    assert_eq!(block_on(rx.recv()), Ok(3));
+    assert_eq!(block_on(rx.recv()), Ok(4));
+
+    let mut cx = noop_context();
+    assert!(rx.recv().poll_unpin(&mut cx).is_pending());
+}
+
+#[test]
+fn send_fails_without_receivers() {
+    let (tx, rx) = broadcast::channel(4);
+    drop(rx);
+    assert_eq!(tx.send(1), Err(broadcast::SendError(1)));
+
+    // A new subscription makes sends succeed again.
+    let mut rx = tx.subscribe();
+    tx.send(2).unwrap();
+    assert_eq!(block_on(rx.recv()), Ok(2));
+}
+
+#[test]
+fn recv_drains_buffer_before_close() {
+    let (tx, mut rx) = broadcast::channel(4);
+    tx.send(1).unwrap();
+    tx.send(2).unwrap();
+    drop(tx);
+
+    assert_eq!(block_on(rx.recv()), Ok(1));
+    assert_eq!(block_on(rx.recv()), Ok(2));
+    assert_eq!(block_on(rx.recv()), Err(RecvError::Closed));
+}
+
+#[test]
+fn last_sender_drop_wakes_receiver() {
+    let (tx, mut rx) = broadcast::channel::<i32>(4);
+    let tx2 = tx.clone();
+
+    let (waker, count) = new_count_waker();
+    assert!(rx.recv().poll_unpin(&mut std::task::Context::from_waker(&waker)).is_pending());
+
+    drop(tx);
+    assert_eq!(count, 0);
+    drop(tx2);
+    assert_eq!(count, 1);
+    assert_eq!(block_on(rx.recv()), Err(RecvError::Closed));
+}
+
+#[test]
+fn send_wakes_waiting_receivers() {
+    let (tx, mut rx1) = broadcast::channel(4);
+    let mut rx2 = tx.subscribe();
+
+    let (waker1, count1) = new_count_waker();
+    let (waker2, count2) = new_count_waker();
+    assert!(rx1.recv().poll_unpin(&mut std::task::Context::from_waker(&waker1)).is_pending());
+    assert!(rx2.recv().poll_unpin(&mut std::task::Context::from_waker(&waker2)).is_pending());
+
+    tx.send(7).unwrap();
+    assert_eq!(count1, 1);
+    assert_eq!(count2, 1);
+    assert_eq!(block_on(rx1.recv()), Ok(7));
+    assert_eq!(block_on(rx2.recv()), Ok(7));
+}
+
+#[test]
+fn stress_fan_out_in_order() {
+    let (tx, rx) = broadcast::channel(1024);
+    let receivers: Vec<_> = (0..4).map(|_| tx.subscribe()).collect();
+    drop(rx);
+
+    let threads: Vec<_> = receivers
+        .into_iter()
+        .map(|mut rx| {
+            thread::spawn(move || {
+                block_on(async {
+                    let mut expected = 0;
+                    loop {
+                        match rx.recv().await {
+                            Ok(value) => {
+                                assert_eq!(value, expected);
+                                expected += 1;
+                            }
+                            Err(RecvError::Closed) => break,
+                            Err(RecvError::Lagged(_)) => panic!("receiver lagged"),
+                        }
+                    }
+                    assert_eq!(expected, 1000);
+                })
+            })
+        })
+        .collect();
+
+    for i in 0..1000 {
+        tx.send(i).unwrap();
+    }
+    drop(tx);
+
+    for t in threads {
+        t.join().unwrap();
+    }
+}
@@ -628,3 +628,211 @@ fn send_backpressure_multi_senders() {
     let item = block_on(rx.next()).unwrap();
     assert_eq!(item, 2);
 }
+
+#[test]
+fn reserve_then_send() {
+    let (mut tx, mut rx) = mpsc::channel::<i32>(1);
+
+    let permit = block_on(tx.reserve()).unwrap();
+    permit.send(1);
+    assert_eq!(block_on(rx.next()), Some(1));
+}
+
+#[test]
+fn reserved_slot_counts_against_capacity() {
+    // buffer = 0 means each sender has exactly one guaranteed slot.
+    let (mut tx, mut rx) = mpsc::channel::<i32>(0);
+    let mut tx2 = tx.clone();
+    let mut cx = noop_context();
+
+    let permit = {
+        let mut reserve = tx.reserve();
+        match reserve.poll_unpin(&mut cx) {
+            Poll::Ready(Ok(permit)) => permit,
+            other => panic!("reserve not ready: {:?}", other.map(|r| r.map(drop))),
+        }
+    };
+
+    // Sending through the permit works even though a plain send on the same
+    // channel sees it as full.
+    assert!(tx2.try_send(2).is_ok());
+    assert!(tx2.try_send(3).is_err());
+    permit.send(1);
+
+    assert_eq!(block_on(rx.next()), Some(2));
+    assert_eq!(block_on(rx.next()), Some(1));
+}
+
+#[test]
+fn dropping_permit_releases_slot() {
+    let (mut tx, rx) = mpsc::channel::<i32>(0);
+    let mut cx = noop_context();
+
+    {
+        let mut reserve = tx.reserve();
+        let permit = match reserve.poll_unpin(&mut cx) {
+            Poll::Ready(Ok(permit)) => permit,
+            other => panic!("reserve not ready: {:?}", other.map(|r| r.map(drop))),
+        };
+        drop(permit);
+    }
+
+    // The slot is back, so the next reserve (and send) succeeds immediately.
+    let permit = block_on(tx.reserve()).unwrap();
+    permit.send(7);
+
+    drop(tx);
+    let v: Vec<_> = block_on(rx.collect());
+    assert_eq!(v, vec![7]);
+}
+
+#[test]
+fn dropping_permit_wakes_parked_sender() {
+    let (mut tx, rx) = mpsc::channel::<i32>(1);
+    let mut tx2 = tx.clone();
+    let (waker, counter) = new_count_waker();
+    let mut cx = noop_context();
+
+    // The reservation takes the single buffer slot without parking `tx`.
+    let permit = match tx.reserve().poll_unpin(&mut cx) {
+        Poll::Ready(Ok(permit)) => permit,
+        other => panic!("reserve not ready: {:?}", other.map(|r| r.map(drop))),
+    };
+    // The second sender exhausts its guaranteed slot and parks.
+    tx2.try_send(2).unwrap();
+    let mut cx2 = Context::from_waker(&waker);
+    assert!(tx2.poll_ready(&mut cx2).is_pending());
+
+    drop(permit);
+    assert_eq!(counter, 1);
+
+    drop(rx);
+}
+
+#[test]
+fn reserve_on_closed_channel_errors() {
+    let (mut tx, rx) = mpsc::channel::<i32>(1);
+    drop(rx);
+
+    let err = block_on(tx.reserve()).unwrap_err();
+    assert!(err.is_disconnected());
+}
+
+#[test]
+fn try_reserve_success_and_full() {
+    let (mut tx, mut rx) = mpsc::channel::<i32>(0);
+
+    {
+        let permit = tx.try_reserve().unwrap();
+        permit.send(1);
+    }
+    // The guaranteed slot is occupied until the receiver drains it.
+    let err = tx.try_reserve().unwrap_err();
+    assert!(err.is_full());
+
+    assert_eq!(block_on(rx.next()), Some(1));
+    let permit = tx.try_reserve().unwrap();
+    permit.send(2);
+    assert_eq!(block_on(rx.next()), Some(2));
+}
+
+#[test]
+fn try_reserve_reduces_capacity() {
+    let (mut tx, rx) = mpsc::channel::<i32>(1);
+    let mut tx2 = tx.clone();
+
+    let _permit = tx.try_reserve().unwrap();
+    // The buffer slot is held by the permit; the second sender only has its
+    // guaranteed slot left.
+    tx2.try_send(2).unwrap();
+    assert!(tx2.try_send(3).unwrap_err().is_full());
+
+    drop(rx);
+}
+
+#[test]
+fn try_reserve_disconnected() {
+    let (mut tx, rx) = mpsc::channel::<i32>(1);
+    drop(rx);
+
+    let err = tx.try_reserve().unwrap_err();
+    assert!(err.is_disconnected());
+    assert!(!err.is_full());
+}
+
+#[test]
+fn recv_many_drains_ready_items() {
+    let (mut tx, mut rx) = mpsc::channel::<i32>(8);
+    for i in 0..5 {
+        tx.try_send(i).unwrap();
+    }
+
+    let mut buf = Vec::new();
+    assert_eq!(block_on(rx.recv_many(&mut buf, 10)), 5);
+    assert_eq!(buf, [0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn recv_many_respects_limit() {
+    let (mut tx, mut rx) = mpsc::channel::<i32>(8);
+    for i in 0..5 {
+        tx.try_send(i).unwrap();
+    }
+
+    let mut buf = Vec::new();
+    assert_eq!(block_on(rx.recv_many(&mut buf, 2)), 2);
+    assert_eq!(block_on(rx.recv_many(&mut buf, 2)), 2);
+    assert_eq!(block_on(rx.recv_many(&mut buf, 2)), 1);
+    assert_eq!(buf, [0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn recv_many_waits_for_first_item() {
+    let (mut tx, mut rx) = mpsc::channel::<i32>(8);
+
+    let mut buf = Vec::new();
+    {
+        let mut fut = rx.recv_many(&mut buf, 4);
+        let mut cx = noop_context();
+        assert!(fut.poll_unpin(&mut cx).is_pending());
+        drop(fut);
+    }
+
+    tx.try_send(1).unwrap();
+    assert_eq!(block_on(rx.recv_many(&mut buf, 4)), 1);
+    assert_eq!(buf, [1]);
+}
+
+#[test]
+fn recv_many_appends_to_buffer() {
+    let (mut tx, mut rx) = mpsc::channel::<i32>(8);
+    tx.try_send(2).unwrap();
+    tx.try_send(3).unwrap();
+
+    let mut buf = vec![1];
+    assert_eq!(block_on(rx.recv_many(&mut buf, 10)), 2);
+    assert_eq!(buf, [1, 2, 3]);
+}
+
+#[test]
+fn recv_many_closed_and_empty_returns_zero() {
+    let (mut tx, mut rx) = mpsc::channel::<i32>(8);
+    tx.try_send(1).unwrap();
+    drop(tx);
+
+    let mut buf = Vec::new();
+    assert_eq!(block_on(rx.recv_many(&mut buf, 10)), 1);
+    assert_eq!(block_on(rx.recv_many(&mut buf, 10)), 0);
+    assert!(buf == [1]);
+}
+
+#[test]
+fn recv_many_zero_limit() {
+    let (mut tx, mut rx) = mpsc::channel::<i32>(8);
+    tx.try_send(1).unwrap();
+
+    let mut buf = Vec::new();
+    assert_eq!(block_on(rx.recv_many(&mut buf, 0)), 0);
+    assert!(buf.is_empty());
+    assert_eq!(block_on(rx.next()), Some(1));
+}
@@ -250,3 +250,34 @@ fn cancel_sends() {
 //         },
 //     }
 // }
+
+#[test]
+fn send_with_alive() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    assert_eq!(tx.send_with(|| 42), Ok(()));
+    assert_eq!(block_on(rx), Ok(42));
+}
+
+#[test]
+fn send_with_dropped_receiver_skips_closure() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    drop(rx);
+
+    assert_eq!(tx.send_with(|| panic!("value computed for a dead receiver")), Err(None));
+}
+
+#[test]
+fn send_with_race() {
+    for _ in 0..100 {
+        let (tx, rx) = oneshot::channel::<i32>();
+        let t = thread::spawn(move || drop(rx));
+
+        // Whatever the interleaving, a produced value is either delivered
+        // or handed back; `Err(None)` means it was never produced at all.
+        match tx.send_with(|| 42) {
+            Ok(()) | Err(None) => {}
+            Err(Some(value)) => assert_eq!(value, 42),
+        }
+        t.join().unwrap();
+    }
+}
@@ -0,0 +1,117 @@
+use futures::channel::priority;
+use futures::executor::block_on;
+use futures::future::FutureExt;
+use futures::stream::{FusedStream, StreamExt};
+use futures_test::task::{new_count_waker, noop_context};
+use std::cmp::Ordering;
+
+#[test]
+fn dequeues_in_priority_order() {
+    let (mut tx, mut rx) = priority::channel(8);
+
+    for value in [2, 5, 1, 4, 3].iter() {
+        tx.try_send(*value).unwrap();
+    }
+    drop(tx);
+
+    assert_eq!(block_on(rx.by_ref().collect::<Vec<_>>()), vec![5, 4, 3, 2, 1]);
+    assert!(rx.is_terminated());
+}
+
+/// A value whose ordering only considers the priority, so equal-priority
+/// values are distinguishable by their payload.
+#[derive(PartialEq, Eq, Debug)]
+struct Job {
+    priority: u8,
+    id: u32,
+}
+
+impl PartialOrd for Job {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Job {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority.cmp(&other.priority)
+    }
+}
+
+#[test]
+fn equal_priorities_are_fifo() {
+    let (mut tx, rx) = priority::channel(8);
+
+    tx.try_send(Job { priority: 1, id: 0 }).unwrap();
+    tx.try_send(Job { priority: 2, id: 1 }).unwrap();
+    tx.try_send(Job { priority: 1, id: 2 }).unwrap();
+    tx.try_send(Job { priority: 2, id: 3 }).unwrap();
+    tx.try_send(Job { priority: 1, id: 4 }).unwrap();
+    drop(tx);
+
+    let ids: Vec<u32> = block_on(rx.map(|job| job.id).collect());
+    assert_eq!(ids, vec![1, 3, 0, 2, 4]);
+}
+
+#[test]
+fn full_channel_applies_backpressure() {
+    let (mut tx, mut rx) = priority::channel(2);
+
+    tx.try_send(1).unwrap();
+    tx.try_send(2).unwrap();
+    assert!(tx.try_send(3).unwrap_err().is_full());
+
+    let (waker, count) = new_count_waker();
+    let mut send = tx.send(3);
+    assert!(send.poll_unpin(&mut std::task::Context::from_waker(&waker)).is_pending());
+
+    // Receiving frees a slot and wakes the blocked sender.
+    assert_eq!(block_on(rx.next()), Some(2));
+    assert_eq!(count, 1);
+    assert!(send.poll_unpin(&mut noop_context()).is_ready());
+    drop(send);
+    drop(tx);
+
+    assert_eq!(block_on(rx.collect::<Vec<_>>()), vec![3, 1]);
+}
+
+#[test]
+fn recv_waits_for_send() {
+    let (mut tx, mut rx) = priority::channel(4);
+
+    let (waker, count) = new_count_waker();
+    assert!(rx.next().poll_unpin(&mut std::task::Context::from_waker(&waker)).is_pending());
+
+    tx.try_send(7).unwrap();
+    assert_eq!(count, 1);
+    assert_eq!(block_on(rx.next()), Some(7));
+}
+
+#[test]
+fn receiver_drop_fails_sends() {
+    let (mut tx, rx) = priority::channel(4);
+    drop(rx);
+
+    let err = tx.try_send(1).unwrap_err();
+    assert!(err.is_disconnected());
+    assert_eq!(err.value, 1);
+
+    let err = block_on(tx.send(2)).unwrap_err();
+    assert!(err.is_disconnected());
+    assert_eq!(err.value, 2);
+}
+
+#[test]
+fn all_senders_dropped_ends_stream() {
+    let (tx, mut rx) = priority::channel::<i32>(4);
+    let tx2 = tx.clone();
+
+    let (waker, count) = new_count_waker();
+    assert!(rx.next().poll_unpin(&mut std::task::Context::from_waker(&waker)).is_pending());
+
+    drop(tx);
+    assert_eq!(count, 0);
+    drop(tx2);
+    assert_eq!(count, 1);
+    assert_eq!(block_on(rx.next()), None);
+}
@@ -0,0 +1,122 @@
+use futures::channel::watch;
+use futures::executor::block_on;
+use futures::future::FutureExt;
+use futures::task::Poll;
+use futures_test::task::{new_count_waker, noop_context};
+use std::thread;
+
+#[test]
+fn smoke() {
+    let (tx, mut rx) = watch::channel(1);
+    assert_eq!(*rx.borrow(), 1);
+
+    tx.send(2);
+    assert_eq!(block_on(rx.changed()), Ok(()));
+    assert_eq!(*rx.borrow(), 2);
+}
+
+#[test]
+fn initial_value_counts_as_observed() {
+    let (_tx, mut rx) = watch::channel("initial");
+    let mut cx = noop_context();
+    let poll = rx.changed().poll_unpin(&mut cx);
+    assert!(poll.is_pending());
+}
+
+#[test]
+fn multiple_receivers_all_wake() {
+    let (tx, mut rx1) = watch::channel(0);
+    let mut rx2 = rx1.clone();
+
+    let (waker1, count1) = new_count_waker();
+    let (waker2, count2) = new_count_waker();
+    assert!(rx1.changed().poll_unpin(&mut std::task::Context::from_waker(&waker1)).is_pending());
+    assert!(rx2.changed().poll_unpin(&mut std::task::Context::from_waker(&waker2)).is_pending());
+
+    tx.send(7);
+    assert_eq!(count1, 1);
+    assert_eq!(count2, 1);
+
+    assert_eq!(block_on(rx1.changed()), Ok(()));
+    assert_eq!(block_on(rx2.changed()), Ok(()));
+    assert_eq!(*rx1.borrow(), 7);
+    assert_eq!(*rx2.borrow(), 7);
+}
+
+#[test]
+fn updates_are_coalesced() {
+    let (tx, mut rx) = watch::channel(0);
+
+    tx.send(1);
+    tx.send(2);
+    tx.send(3);
+
+    // Only the latest value is seen, and only one `changed` resolves for
+    // the whole burst.
+    assert_eq!(block_on(rx.changed()), Ok(()));
+    assert_eq!(*rx.borrow(), 3);
+
+    let mut cx = noop_context();
+    assert!(rx.changed().poll_unpin(&mut cx).is_pending());
+}
+
+#[test]
+fn unobserved_send_resolves_immediately() {
+    let (tx, mut rx) = watch::channel(0);
+    tx.send(1);
+
+    // The send happened before `changed` was called, but has not been
+    // observed by this receiver yet.
+    let mut cx = noop_context();
+    assert_eq!(rx.changed().poll_unpin(&mut cx), Poll::Ready(Ok(())));
+}
+
+#[test]
+fn sender_drop_cancels() {
+    let (tx, mut rx) = watch::channel(0);
+
+    let (waker, count) = new_count_waker();
+    assert!(rx.changed().poll_unpin(&mut std::task::Context::from_waker(&waker)).is_pending());
+    assert!(!rx.is_closed());
+
+    drop(tx);
+    assert_eq!(count, 1);
+    assert!(rx.is_closed());
+    assert_eq!(block_on(rx.changed()), Err(watch::Canceled));
+
+    // The last value remains readable after cancellation.
+    assert_eq!(*rx.borrow(), 0);
+}
+
+#[test]
+fn pending_value_beats_cancellation() {
+    let (tx, mut rx) = watch::channel(0);
+    tx.send(1);
+    drop(tx);
+
+    // A value sent before the sender was dropped is still delivered.
+    assert_eq!(block_on(rx.changed()), Ok(()));
+    assert_eq!(*rx.borrow(), 1);
+    assert_eq!(block_on(rx.changed()), Err(watch::Canceled));
+}
+
+#[test]
+fn stress_receiver_sees_final_value() {
+    let (tx, mut rx) = watch::channel(0);
+
+    let t = thread::spawn(move || {
+        for i in 1..=1000 {
+            tx.send(i);
+        }
+    });
+
+    block_on(async {
+        loop {
+            if rx.changed().await.is_err() {
+                break;
+            }
+        }
+    });
+    t.join().unwrap();
+    assert_eq!(*rx.borrow(), 1000);
+}
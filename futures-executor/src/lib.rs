@@ -68,7 +68,7 @@ mod unpark_mutex;
 #[cfg(feature = "thread-pool")]
 #[cfg_attr(docsrs, doc(cfg(feature = "thread-pool")))]
 #[cfg(feature = "std")]
-pub use crate::thread_pool::{ThreadPool, ThreadPoolBuilder};
+pub use crate::thread_pool::{ThreadPool, ThreadPoolBuilder, ThreadPoolMetrics};
 
 #[cfg(feature = "std")]
 mod enter;
@@ -256,10 +256,20 @@ impl LocalPool {
     /// remaining incomplete tasks in the pool can continue with further use of one
     /// of the pool's run or poll methods. While the function is running, all tasks
     /// in the pool will try to make progress.
-    pub fn run_until_stalled(&mut self) {
+    ///
+    /// The return value reports whether the pass made progress: it is `true`
+    /// if at least one task was polled, even if that task only stayed
+    /// pending, and `false` if the pool was empty or no task was ready to be
+    /// polled. This lets callers embedding the pool in an outer event loop
+    /// decide whether it is time to sleep.
+    pub fn run_until_stalled(&mut self) -> bool {
+        // The pool's `FuturesUnordered` counts every poll of a managed
+        // future, so a pass made progress exactly when the counter moved.
+        let polls_before = self.pool.poll_count();
         poll_executor(|ctx| {
             let _ = self.poll_pool(ctx);
         });
+        self.pool.poll_count() != polls_before
     }
 
     // Make maximal progress on the entire pool of spawned task, returning `Ready`
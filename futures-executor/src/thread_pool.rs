@@ -5,9 +5,11 @@ use futures_core::task::{Context, Poll};
 use futures_task::{waker_ref, ArcWake};
 use futures_task::{FutureObj, Spawn, SpawnError};
 use futures_util::future::FutureExt;
+use std::any::Any;
 use std::cmp;
 use std::fmt;
 use std::io;
+use std::panic::{self, AssertUnwindSafe};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::mpsc;
 use std::sync::{Arc, Mutex};
@@ -40,8 +42,11 @@ pub struct ThreadPoolBuilder {
     name_prefix: Option<String>,
     after_start: Option<Arc<dyn Fn(usize) + Send + Sync>>,
     before_stop: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+    panic_handler: Option<PanicHandler>,
 }
 
+type PanicHandler = Arc<dyn Fn(Box<dyn Any + Send>) + Send + Sync>;
+
 trait AssertSendSync: Send + Sync {}
 impl AssertSendSync for ThreadPool {}
 
@@ -50,6 +55,25 @@ struct PoolState {
     rx: Mutex<mpsc::Receiver<Message>>,
     cnt: AtomicUsize,
     size: usize,
+    spawned: AtomicUsize,
+    completed: AtomicUsize,
+}
+
+/// A point-in-time snapshot of a [`ThreadPool`]'s activity counters.
+///
+/// Returned by [`ThreadPool::metrics`]. The counters are tracked with relaxed
+/// atomics, so a snapshot taken while tasks are being spawned on other
+/// threads may be momentarily out of date, but `spawned` never decreases and
+/// `completed` never exceeds it by more than the in-flight task count.
+#[cfg_attr(docsrs, doc(cfg(feature = "thread-pool")))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ThreadPoolMetrics {
+    /// The number of tasks spawned onto the pool so far.
+    pub spawned: usize,
+    /// The number of spawned tasks that have run to completion.
+    ///
+    /// Tasks that panicked are not counted as completed.
+    pub completed: usize,
 }
 
 impl fmt::Debug for ThreadPool {
@@ -101,9 +125,18 @@ impl ThreadPool {
             wake_handle: Arc::new(WakeHandle { exec: self.clone(), mutex: UnparkMutex::new() }),
             exec: self.clone(),
         };
+        self.state.spawned.fetch_add(1, Ordering::Relaxed);
         self.state.send(Message::Run(task));
     }
 
+    /// Returns a snapshot of the pool's activity counters.
+    pub fn metrics(&self) -> ThreadPoolMetrics {
+        ThreadPoolMetrics {
+            spawned: self.state.spawned.load(Ordering::Relaxed),
+            completed: self.state.completed.load(Ordering::Relaxed),
+        }
+    }
+
     /// Spawns a task that polls the given future with output `()` to
     /// completion.
     ///
@@ -143,6 +176,7 @@ impl PoolState {
         idx: usize,
         after_start: Option<Arc<dyn Fn(usize) + Send + Sync>>,
         before_stop: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+        panic_handler: Option<PanicHandler>,
     ) {
         let _scope = enter().unwrap();
         if let Some(after_start) = after_start {
@@ -151,7 +185,17 @@ impl PoolState {
         loop {
             let msg = self.rx.lock().unwrap().recv().unwrap();
             match msg {
-                Message::Run(task) => task.run(),
+                Message::Run(task) => match panic::catch_unwind(AssertUnwindSafe(|| task.run())) {
+                    Ok(()) => {}
+                    Err(payload) => match &panic_handler {
+                        Some(handler) => {
+                            // The handler itself panicking must not take the
+                            // worker down with it.
+                            let _ = panic::catch_unwind(AssertUnwindSafe(|| handler(payload)));
+                        }
+                        None => panic::resume_unwind(payload),
+                    },
+                },
                 Message::Close => break,
             }
         }
@@ -189,6 +233,7 @@ impl ThreadPoolBuilder {
             name_prefix: None,
             after_start: None,
             before_stop: None,
+            panic_handler: None,
         }
     }
 
@@ -258,6 +303,22 @@ impl ThreadPoolBuilder {
         self
     }
 
+    /// Set a handler for panics escaping from the pool's tasks.
+    ///
+    /// When a spawned future panics, the panic payload is passed to `f` on
+    /// the worker thread that was polling it, and the worker then keeps
+    /// serving other tasks. A panic inside the handler itself is caught and
+    /// ignored.
+    ///
+    /// By default, a panicking task brings down its worker thread.
+    pub fn panic_handler<F>(&mut self, f: F) -> &mut Self
+    where
+        F: Fn(Box<dyn Any + Send>) + Send + Sync + 'static,
+    {
+        self.panic_handler = Some(Arc::new(f));
+        self
+    }
+
     /// Create a [`ThreadPool`](ThreadPool) with the given configuration.
     pub fn create(&mut self) -> Result<ThreadPool, io::Error> {
         let (tx, rx) = mpsc::channel();
@@ -267,6 +328,8 @@ impl ThreadPoolBuilder {
                 rx: Mutex::new(rx),
                 cnt: AtomicUsize::new(1),
                 size: self.pool_size,
+                spawned: AtomicUsize::new(0),
+                completed: AtomicUsize::new(0),
             }),
         };
 
@@ -274,6 +337,7 @@ impl ThreadPoolBuilder {
             let state = pool.state.clone();
             let after_start = self.after_start.clone();
             let before_stop = self.before_stop.clone();
+            let panic_handler = self.panic_handler.clone();
             let mut thread_builder = thread::Builder::new();
             if let Some(ref name_prefix) = self.name_prefix {
                 thread_builder = thread_builder.name(format!("{}{}", name_prefix, counter));
@@ -281,7 +345,8 @@ impl ThreadPoolBuilder {
             if self.stack_size > 0 {
                 thread_builder = thread_builder.stack_size(self.stack_size);
             }
-            thread_builder.spawn(move || state.work(counter, after_start, before_stop))?;
+            thread_builder
+                .spawn(move || state.work(counter, after_start, before_stop, panic_handler))?;
         }
         Ok(pool)
     }
@@ -322,7 +387,10 @@ impl Task {
                 let res = future.poll_unpin(&mut cx);
                 match res {
                     Poll::Pending => {}
-                    Poll::Ready(()) => return wake_handle.mutex.complete(),
+                    Poll::Ready(()) => {
+                        exec.state.completed.fetch_add(1, Ordering::Relaxed);
+                        return wake_handle.mutex.complete();
+                    }
                 }
                 let task = Self { future, wake_handle: wake_handle.clone(), exec };
                 match wake_handle.mutex.wait(task) {
@@ -372,4 +440,66 @@ mod tests {
         let count = rx.into_iter().count();
         assert_eq!(count, 2);
     }
+
+    #[test]
+    fn test_metrics() {
+        let pool = ThreadPoolBuilder::new().pool_size(2).create().unwrap();
+        assert_eq!(pool.metrics(), ThreadPoolMetrics { spawned: 0, completed: 0 });
+
+        let (tx, rx) = mpsc::channel();
+        for _ in 0..5 {
+            let tx = tx.clone();
+            pool.spawn_ok(async move { tx.send(()).unwrap() });
+        }
+        assert_eq!(pool.metrics().spawned, 5);
+
+        for _ in 0..5 {
+            rx.recv().unwrap();
+        }
+        // `completed` is bumped before the worker releases the task, so poll
+        // briefly rather than assuming the counter is already visible.
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+        while pool.metrics().completed != 5 {
+            assert!(std::time::Instant::now() < deadline);
+            thread::yield_now();
+        }
+        assert_eq!(pool.metrics(), ThreadPoolMetrics { spawned: 5, completed: 5 });
+    }
+
+    #[test]
+    fn test_panic_handler_invoked() {
+        let (tx, rx) = mpsc::channel();
+        let pool = ThreadPoolBuilder::new()
+            .pool_size(1)
+            .panic_handler(move |payload| {
+                let msg = payload.downcast_ref::<&str>().copied().unwrap_or("<non-str payload>");
+                tx.send(msg.to_string()).unwrap();
+            })
+            .create()
+            .unwrap();
+
+        pool.spawn_ok(async { panic!("oops") });
+        assert_eq!(rx.recv().unwrap(), "oops");
+
+        // The worker that caught the panic keeps serving tasks.
+        let (done_tx, done_rx) = mpsc::channel();
+        pool.spawn_ok(async move { done_tx.send(()).unwrap() });
+        done_rx.recv().unwrap();
+    }
+
+    #[test]
+    fn test_panic_in_panic_handler() {
+        let pool = ThreadPoolBuilder::new()
+            .pool_size(1)
+            .panic_handler(|_| panic!("handler panic"))
+            .create()
+            .unwrap();
+
+        pool.spawn_ok(async { panic!("oops") });
+
+        // A panicking handler must not take the worker down either.
+        let (done_tx, done_rx) = mpsc::channel();
+        pool.spawn_ok(async move { done_tx.send(()).unwrap() });
+        done_rx.recv().unwrap();
+    }
 }
@@ -432,3 +432,91 @@ fn park_unpark_independence() {
 
     futures::executor::block_on(future)
 }
+
+#[test]
+fn run_until_stalled_reports_progress() {
+    let mut pool = LocalPool::new();
+    let spawn = pool.spawner();
+
+    // An empty pool makes no progress.
+    assert!(!pool.run_until_stalled());
+
+    // A task that completes counts as progress.
+    spawn.spawn_local_obj(Box::pin(future::ready(())).into()).unwrap();
+    assert!(pool.run_until_stalled());
+    assert!(!pool.run_until_stalled());
+
+    // A task that is polled but stays pending still counts as progress the
+    // first time; once it has stalled without being woken, later passes
+    // don't poll it and report no progress.
+    spawn.spawn_local_obj(Box::pin(future::pending::<()>()).into()).unwrap();
+    assert!(pool.run_until_stalled());
+    assert!(!pool.run_until_stalled());
+
+    // Waking the stalled task makes the next pass count as progress again.
+    let waker: Rc<RefCell<Option<Waker>>> = Rc::new(RefCell::new(None));
+    let waker2 = waker.clone();
+    let mut resolved = false;
+    spawn
+        .spawn_local_obj(
+            Box::pin(poll_fn(move |cx| {
+                if resolved {
+                    Poll::Ready(())
+                } else {
+                    resolved = true;
+                    *waker2.borrow_mut() = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+            }))
+            .into(),
+        )
+        .unwrap();
+    assert!(pool.run_until_stalled());
+    assert!(!pool.run_until_stalled());
+    waker.borrow_mut().take().unwrap().wake();
+    assert!(pool.run_until_stalled());
+    assert!(!pool.run_until_stalled());
+}
+
+#[test]
+fn spawn_local_with_handle_resolves_to_output() {
+    use futures::task::LocalSpawnExt;
+
+    let mut pool = LocalPool::new();
+    let spawner = pool.spawner();
+
+    let handle = spawner.spawn_local_with_handle(future::ready(42)).unwrap();
+    assert_eq!(pool.run_until(handle), 42);
+
+    // The handle resolves even when the task completed long before it is
+    // awaited.
+    let handle = spawner.spawn_local_with_handle(future::ready(7)).unwrap();
+    pool.run_until_stalled();
+    assert_eq!(pool.run_until(handle), 7);
+}
+
+#[test]
+fn spawn_local_with_handle_drop_cancels_task() {
+    use futures::task::LocalSpawnExt;
+
+    let mut pool = LocalPool::new();
+    let spawner = pool.spawner();
+
+    let polls = Rc::new(Cell::new(0));
+    let polls2 = polls.clone();
+    let handle = spawner
+        .spawn_local_with_handle(poll_fn(move |_| {
+            polls2.set(polls2.get() + 1);
+            Poll::<()>::Pending
+        }))
+        .unwrap();
+
+    pool.run_until_stalled();
+    assert_eq!(polls.get(), 1);
+
+    // Dropping the handle stops the task from being driven any further.
+    drop(handle);
+    pool.run_until_stalled();
+    pool.run_until_stalled();
+    assert_eq!(polls.get(), 1);
+}
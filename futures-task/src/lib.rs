@@ -40,10 +40,16 @@ mod waker_ref;
 #[cfg(feature = "alloc")]
 pub use crate::waker_ref::{waker_ref, WakerRef};
 
+#[cfg(feature = "alloc")]
+mod local_waker;
+#[cfg(feature = "alloc")]
+pub use crate::local_waker::{local_waker, LocalWake, LocalWaker};
+
 mod future_obj;
 pub use crate::future_obj::{FutureObj, LocalFutureObj, UnsafeFutureObj};
 
 mod noop_waker;
+pub use crate::noop_waker::noop_context;
 pub use crate::noop_waker::noop_waker;
 pub use crate::noop_waker::noop_waker_ref;
 
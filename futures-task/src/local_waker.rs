@@ -0,0 +1,131 @@
+use alloc::rc::Rc;
+use core::fmt;
+use core::marker::PhantomData;
+use core::mem;
+use core::ops::Deref;
+use core::task::{RawWaker, RawWakerVTable, Waker};
+
+/// A way of waking up a specific task on its own thread.
+///
+/// This is a single-threaded analogue of [`ArcWake`](crate::ArcWake): types
+/// that are expected to be wrapped in an [`Rc`] can be converted into
+/// [`LocalWaker`] objects with [`local_waker`](local_waker()), without the
+/// atomic reference counting that `Arc`-based wakers pay for.
+///
+/// As the name implies, the resulting wakers are only usable from the thread
+/// they were created on, which makes this suitable for executors that run
+/// `!Send` tasks.
+pub trait LocalWake {
+    /// Indicates that the associated task is ready to make progress and should
+    /// be `poll`ed.
+    ///
+    /// Executors generally maintain a queue of "ready" tasks; `wake` should
+    /// place the associated task onto this queue.
+    fn wake(self: Rc<Self>) {
+        Self::wake_by_ref(&self)
+    }
+
+    /// Indicates that the associated task is ready to make progress and should
+    /// be `poll`ed.
+    ///
+    /// This function is similar to [`wake`](LocalWake::wake), but must not
+    /// consume the provided data pointer.
+    fn wake_by_ref(rc_self: &Rc<Self>);
+}
+
+fn local_waker_vtable<W: LocalWake>() -> &'static RawWakerVTable {
+    &RawWakerVTable::new(
+        clone_rc_raw::<W>,
+        wake_rc_raw::<W>,
+        wake_by_ref_rc_raw::<W>,
+        drop_rc_raw::<W>,
+    )
+}
+
+/// Creates a [`LocalWaker`] from an `Rc<impl LocalWake>`.
+///
+/// The returned [`LocalWaker`] will call
+/// [`LocalWake.wake()`](LocalWake::wake) if awoken.
+pub fn local_waker<W>(wake: Rc<W>) -> LocalWaker
+where
+    W: LocalWake + 'static,
+{
+    let ptr = Rc::into_raw(wake) as *const ();
+
+    LocalWaker {
+        waker: unsafe { Waker::from_raw(RawWaker::new(ptr, local_waker_vtable::<W>())) },
+        _marker: PhantomData,
+    }
+}
+
+/// A [`Waker`] that is bound to the thread it was created on.
+///
+/// This wraps a [`Waker`] whose wake functions manipulate a non-atomic
+/// reference count, so unlike `Waker` it deliberately implements neither
+/// [`Send`] nor [`Sync`]. It derefs to [`Waker`], so a
+/// [`Context`](core::task::Context) can be built from it with
+/// [`Context::from_waker`](core::task::Context::from_waker).
+///
+/// The `Waker` it derefs to must not be cloned out of it and moved to
+/// another thread.
+#[derive(Clone)]
+pub struct LocalWaker {
+    waker: Waker,
+    // `Rc` keeps this (and, at one remove, the inner waker) on its thread.
+    _marker: PhantomData<Rc<()>>,
+}
+
+impl Deref for LocalWaker {
+    type Target = Waker;
+
+    fn deref(&self) -> &Waker {
+        &self.waker
+    }
+}
+
+impl LocalWaker {
+    /// Wakes up the task associated with this `LocalWaker`.
+    pub fn wake(self) {
+        self.waker.wake()
+    }
+
+    /// Wakes up the task associated with this `LocalWaker` without consuming
+    /// it.
+    pub fn wake_by_ref(&self) {
+        self.waker.wake_by_ref()
+    }
+}
+
+impl fmt::Debug for LocalWaker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocalWaker").finish()
+    }
+}
+
+#[allow(clippy::redundant_clone)] // The clone here isn't actually redundant.
+unsafe fn increase_refcount<T: LocalWake>(data: *const ()) {
+    // Retain Rc, but don't touch refcount by wrapping in ManuallyDrop
+    let rc = mem::ManuallyDrop::new(Rc::<T>::from_raw(data as *const T));
+    // Now increase refcount, but don't drop new refcount either
+    let _rc_clone: mem::ManuallyDrop<_> = rc.clone();
+}
+
+unsafe fn clone_rc_raw<T: LocalWake>(data: *const ()) -> RawWaker {
+    increase_refcount::<T>(data);
+    RawWaker::new(data, local_waker_vtable::<T>())
+}
+
+unsafe fn wake_rc_raw<T: LocalWake>(data: *const ()) {
+    let rc: Rc<T> = Rc::from_raw(data as *const T);
+    LocalWake::wake(rc);
+}
+
+unsafe fn wake_by_ref_rc_raw<T: LocalWake>(data: *const ()) {
+    // Retain Rc, but don't touch refcount by wrapping in ManuallyDrop
+    let rc = mem::ManuallyDrop::new(Rc::<T>::from_raw(data as *const T));
+    LocalWake::wake_by_ref(&rc);
+}
+
+unsafe fn drop_rc_raw<T: LocalWake>(data: *const ()) {
+    drop(Rc::<T>::from_raw(data as *const T))
+}
@@ -1,7 +1,7 @@
 //! Utilities for creating zero-cost wakers that don't do anything.
 
 use core::ptr::null;
-use core::task::{RawWaker, RawWakerVTable, Waker};
+use core::task::{Context, RawWaker, RawWakerVTable, Waker};
 
 unsafe fn noop_clone(_data: *const ()) -> RawWaker {
     noop_raw_waker()
@@ -18,6 +18,9 @@ const fn noop_raw_waker() -> RawWaker {
 /// Create a new [`Waker`] which does
 /// nothing when `wake()` is called on it.
 ///
+/// The waker is allocation-free: constructing, cloning, waking and dropping
+/// it are all guaranteed no-ops.
+///
 /// # Examples
 ///
 /// ```
@@ -52,6 +55,28 @@ pub fn noop_waker_ref() -> &'static Waker {
     unsafe { &*(&NOOP_WAKER_INSTANCE.0 as *const RawWaker as *const Waker) }
 }
 
+/// Create a new [`Context`] whose waker does nothing when `wake()` is called
+/// on it.
+///
+/// This is useful for polling futures by hand when no real waker is needed,
+/// e.g. in benchmarks. The context borrows the static waker behind
+/// [`noop_waker_ref`](noop_waker_ref()), so creating it does not allocate and
+/// it can be recreated freely.
+///
+/// # Examples
+///
+/// ```
+/// use futures::future::FutureExt;
+/// use futures::task::{noop_context, Poll};
+///
+/// let mut cx = noop_context();
+/// assert_eq!(futures::future::ready(1).poll_unpin(&mut cx), Poll::Ready(1));
+/// ```
+#[inline]
+pub fn noop_context() -> Context<'static> {
+    Context::from_waker(noop_waker_ref())
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -1,8 +1,10 @@
 use crate::task::AtomicWaker;
+use crate::time::Timer;
 use alloc::sync::Arc;
 use core::fmt;
 use core::pin::Pin;
 use core::sync::atomic::{AtomicBool, Ordering};
+use core::time::Duration;
 use futures_core::future::Future;
 use futures_core::task::{Context, Poll};
 use futures_core::Stream;
@@ -68,6 +70,45 @@ impl<T> Abortable<T> {
     pub fn is_aborted(&self) -> bool {
         self.inner.aborted.load(Ordering::Relaxed)
     }
+
+    /// Creates a new `Abortable` future/stream that is additionally aborted
+    /// automatically once `duration` has elapsed.
+    ///
+    /// The deadline is tracked by a delay future obtained from `timer`, so any
+    /// timer implementation (e.g. `tokio::time::sleep` or `async_io::Timer`)
+    /// can be plugged in. The delay is dropped as soon as the task completes,
+    /// cancelling the underlying timer. Aborting through the handle tied to
+    /// `reg` works as with [`Abortable::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use std::time::Duration;
+    /// use futures::future::{self, AbortHandle, Abortable, Aborted};
+    ///
+    /// let (_handle, reg) = AbortHandle::new_pair();
+    /// let task = Abortable::with_deadline(
+    ///     future::pending::<()>(),
+    ///     reg,
+    ///     Duration::from_millis(10),
+    ///     |_| future::ready(()),
+    /// );
+    /// assert_eq!(task.await, Err(Aborted));
+    /// # });
+    /// ```
+    pub fn with_deadline<T2>(
+        task: T,
+        reg: AbortRegistration,
+        duration: Duration,
+        mut timer: T2,
+    ) -> DeadlineAbortable<T, T2::Sleep>
+    where
+        T2: Timer,
+    {
+        let handle = AbortHandle { inner: reg.inner.clone() };
+        DeadlineAbortable { task: Self::new(task, reg), sleep: Some(timer.sleep(duration)), handle }
+    }
 }
 
 /// A registration handle for an `Abortable` task.
@@ -182,4 +223,97 @@ impl AbortHandle {
         self.inner.aborted.store(true, Ordering::Relaxed);
         self.inner.waker.wake();
     }
+
+    /// Checks whether [`AbortHandle::abort`] was *called* on any associated
+    /// [`AbortHandle`].
+    ///
+    /// This function returns `true` if `abort` was called, even if the task
+    /// has not been polled since, had already completed, or is still running.
+    pub fn is_aborted(&self) -> bool {
+        self.inner.aborted.load(Ordering::Relaxed)
+    }
+}
+
+pin_project! {
+    /// A future/stream which aborts automatically once a deadline elapses.
+    ///
+    /// Created by [`Abortable::with_deadline`].
+    #[derive(Debug)]
+    #[must_use = "futures/streams do nothing unless you poll them"]
+    pub struct DeadlineAbortable<T, Sl> {
+        #[pin]
+        task: Abortable<T>,
+        #[pin]
+        sleep: Option<Sl>,
+        handle: AbortHandle,
+    }
+}
+
+impl<T, Sl> DeadlineAbortable<T, Sl> {
+    /// Checks whether the task has been aborted, either through an
+    /// [`AbortHandle`] or by the deadline elapsing.
+    ///
+    /// See [`Abortable::is_aborted`] for the exact semantics.
+    pub fn is_aborted(&self) -> bool {
+        self.task.is_aborted()
+    }
+}
+
+impl<T, Sl> DeadlineAbortable<T, Sl>
+where
+    Sl: Future<Output = ()>,
+{
+    fn poll_deadline(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut this = self.project();
+        if let Some(sleep) = this.sleep.as_mut().as_pin_mut() {
+            if sleep.poll(cx).is_ready() {
+                this.sleep.set(None);
+                this.handle.abort();
+                return Poll::Ready(());
+            }
+        }
+        Poll::Pending
+    }
+}
+
+impl<Fut, Sl> Future for DeadlineAbortable<Fut, Sl>
+where
+    Fut: Future,
+    Sl: Future<Output = ()>,
+{
+    type Output = Result<Fut::Output, Aborted>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // The wrapped task is polled first so that its value is preferred
+        // when it completes on the same poll as the deadline elapsing.
+        if let Poll::Ready(res) = self.as_mut().project().task.poll(cx) {
+            // Drop the delay future, cancelling the underlying timer.
+            self.as_mut().project().sleep.set(None);
+            return Poll::Ready(res);
+        }
+
+        self.poll_deadline(cx).map(|()| Err(Aborted))
+    }
+}
+
+impl<St, Sl> Stream for DeadlineAbortable<St, Sl>
+where
+    St: Stream,
+    Sl: Future<Output = ()>,
+{
+    type Item = St::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.as_mut().project().task.poll_next(cx) {
+            Poll::Ready(Some(item)) => return Poll::Ready(Some(item)),
+            Poll::Ready(None) => {
+                // Drop the delay future, cancelling the underlying timer.
+                self.as_mut().project().sleep.set(None);
+                return Poll::Ready(None);
+            }
+            Poll::Pending => {}
+        }
+
+        self.poll_deadline(cx).map(|()| None)
+    }
 }
@@ -239,6 +239,23 @@ pub(crate) fn map_err_fn<F>(f: F) -> MapErrFn<F> {
     MapErrFn(f)
 }
 
+#[derive(Debug, Copy, Clone)]
+pub struct MapErrWithFn<C, F>(C, F);
+
+impl<C, F, T, E, E2> FnOnce1<Result<T, E>> for MapErrWithFn<C, F>
+where
+    F: FnOnce(C, E) -> E2,
+{
+    type Output = Result<T, E2>;
+    fn call_once(self, arg: Result<T, E>) -> Self::Output {
+        let Self(context, f) = self;
+        arg.map_err(|e| f(context, e))
+    }
+}
+pub(crate) fn map_err_with_fn<C, F>(context: C, f: F) -> MapErrWithFn<C, F> {
+    MapErrWithFn(context, f)
+}
+
 #[derive(Debug, Copy, Clone)]
 pub struct InspectOkFn<F>(F);
 
@@ -11,6 +11,8 @@ use core::pin::Pin;
 use crate::fns::{inspect_fn, into_fn, ok_fn, InspectFn, IntoFn, OkFn};
 use crate::future::{assert_future, Either};
 use crate::stream::assert_stream;
+use crate::time::Timer;
+use core::time::Duration;
 #[cfg(feature = "alloc")]
 use futures_core::future::{BoxFuture, LocalBoxFuture};
 use futures_core::{
@@ -94,6 +96,14 @@ delegate_all!(
     ): Debug + Future + FusedFuture + New[|x: Fut| Map::new(x, ok_fn())]
 );
 
+mod on_drop;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::on_drop::OnDrop;
+
+mod timeout;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::timeout::{Elapsed, Timeout};
+
 #[cfg(feature = "std")]
 mod catch_unwind;
 #[cfg(feature = "std")]
@@ -156,6 +166,18 @@ pub trait FutureExt: Future {
     ///
     /// This function is equivalent to calling `map(Into::into)` but allows naming
     /// the return type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::future::FutureExt;
+    ///
+    /// let future = async { 1u8 };
+    /// let future = future.map_into::<u32>();
+    /// assert_eq!(future.await, 1u32);
+    /// # });
+    /// ```
     fn map_into<U>(self) -> MapInto<Self, U>
     where
         Self::Output: Into<U>,
@@ -396,6 +418,85 @@ pub trait FutureExt: Future {
         assert_future::<Self::Output, _>(Inspect::new(self, f))
     }
 
+    /// Runs a closure if this future is dropped before it completes.
+    ///
+    /// This makes cancellation observable: the closure fires if and only if
+    /// the future is dropped while still pending, and never on normal
+    /// completion. A typical use is decrementing an in-flight counter or
+    /// recording a cancellation metric.
+    ///
+    /// Polling the returned future after it has completed is a no-op and
+    /// resolves to [`Poll::Pending`](core::task::Poll::Pending).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::future::{self, FutureExt};
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// static CANCELLED: AtomicUsize = AtomicUsize::new(0);
+    ///
+    /// // Completed normally: the closure does not run.
+    /// let answer = future::ready(42)
+    ///     .on_drop(|| { CANCELLED.fetch_add(1, Ordering::Relaxed); })
+    ///     .await;
+    /// assert_eq!(answer, 42);
+    /// assert_eq!(CANCELLED.load(Ordering::Relaxed), 0);
+    ///
+    /// // Dropped while pending: the closure runs.
+    /// let pending = future::pending::<()>()
+    ///     .on_drop(|| { CANCELLED.fetch_add(1, Ordering::Relaxed); });
+    /// drop(pending);
+    /// assert_eq!(CANCELLED.load(Ordering::Relaxed), 1);
+    /// # });
+    /// ```
+    fn on_drop<F>(self, f: F) -> OnDrop<Self, F>
+    where
+        F: FnOnce(),
+        Self: Sized,
+    {
+        assert_future::<Self::Output, _>(OnDrop::new(self, f))
+    }
+
+    /// Requires this future to complete before `duration` elapses on the
+    /// given timer.
+    ///
+    /// The returned future resolves to `Ok` with this future's output if it
+    /// completes in time, and to [`Err(Elapsed)`](Elapsed) otherwise. If both
+    /// complete on the same poll, this future's value is preferred. Dropping
+    /// the returned future cancels the underlying sleep.
+    ///
+    /// The `timer` is anything implementing [`Timer`](crate::time::Timer),
+    /// including any closure of type `FnMut(Duration) -> impl Future<Output =
+    /// ()>`, so a runtime's sleep function can be passed in directly. A
+    /// thread-based [`ThreadTimer`](crate::time::ThreadTimer) is available
+    /// behind the `std` feature when no runtime timer is at hand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use std::time::Duration;
+    ///
+    /// use futures::future::{self, FutureExt};
+    /// use futures::time::ThreadTimer;
+    ///
+    /// let result = future::ready(1).timeout(Duration::from_secs(60), ThreadTimer).await;
+    /// assert_eq!(result, Ok(1));
+    ///
+    /// let result = future::pending::<i32>().timeout(Duration::from_millis(10), ThreadTimer).await;
+    /// assert!(result.is_err());
+    /// # });
+    /// ```
+    fn timeout<T>(self, duration: Duration, mut timer: T) -> Timeout<Self, T::Sleep>
+    where
+        T: Timer,
+        Self: Sized,
+    {
+        assert_future::<Result<Self::Output, Elapsed>, _>(Timeout::new(self, timer.sleep(duration)))
+    }
+
     /// Catches unwinding panics while polling the future.
     ///
     /// In general, panics within a future can propagate all the way out to the
@@ -607,4 +708,41 @@ pub trait FutureExt: Future {
             _ => None,
         }
     }
+
+    /// Polls the future exactly once with a noop waker, consuming it only if
+    /// it is ready.
+    ///
+    /// Unlike [`now_or_never`](FutureExt::now_or_never), which drops a
+    /// pending future, this hands the future back in the `Err` variant so it
+    /// can be retried later. Since the future is never polled after
+    /// completing, futures that panic on being polled twice are safe to use
+    /// with this method.
+    ///
+    /// Note that polling with a noop waker means nothing will be scheduled
+    /// to wake anyone up; this is for opportunistic polling in loops that
+    /// retry on their own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use futures::future::{self, FutureExt};
+    /// assert_eq!(future::ready(5).poll_once().ok(), Some(5));
+    ///
+    /// let pending = future::pending::<i32>();
+    /// let pending = pending.poll_once().unwrap_err();
+    /// // Still pending; try again some other time.
+    /// assert!(pending.poll_once().is_err());
+    /// ```
+    fn poll_once(mut self) -> Result<Self::Output, Self>
+    where
+        Self: Sized + Unpin,
+    {
+        let noop_waker = crate::task::noop_waker();
+        let mut cx = Context::from_waker(&noop_waker);
+
+        match Pin::new(&mut self).poll(&mut cx) {
+            Poll::Ready(x) => Ok(x),
+            Poll::Pending => Err(self),
+        }
+    }
 }
@@ -0,0 +1,70 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+/// Runs the closure when dropped, unless it has been disarmed.
+struct Guard<F: FnOnce()> {
+    f: Option<F>,
+}
+
+impl<F: FnOnce()> Drop for Guard<F> {
+    fn drop(&mut self) {
+        if let Some(f) = self.f.take() {
+            f()
+        }
+    }
+}
+
+pin_project! {
+    /// Future for the [`on_drop`](super::FutureExt::on_drop) method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct OnDrop<Fut, F: FnOnce()> {
+        #[pin]
+        future: Fut,
+        guard: Guard<F>,
+    }
+}
+
+impl<Fut, F> fmt::Debug for OnDrop<Fut, F>
+where
+    Fut: fmt::Debug,
+    F: FnOnce(),
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OnDrop")
+            .field("future", &self.future)
+            .field("armed", &self.guard.f.is_some())
+            .finish()
+    }
+}
+
+impl<Fut: Future, F: FnOnce()> OnDrop<Fut, F> {
+    pub(super) fn new(future: Fut, f: F) -> Self {
+        Self { future, guard: Guard { f: Some(f) } }
+    }
+}
+
+impl<Fut: Future, F: FnOnce()> FusedFuture for OnDrop<Fut, F> {
+    fn is_terminated(&self) -> bool {
+        self.guard.f.is_none()
+    }
+}
+
+impl<Fut: Future, F: FnOnce()> Future for OnDrop<Fut, F> {
+    type Output = Fut::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        if this.guard.f.is_none() {
+            // Already completed; the inner future must not be polled again.
+            return Poll::Pending;
+        }
+        let output = ready!(this.future.poll(cx));
+        // Normal completion: disarm the guard so the closure never runs.
+        this.guard.f = None;
+        Poll::Ready(output)
+    }
+}
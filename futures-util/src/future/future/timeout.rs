@@ -0,0 +1,77 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+/// Error returned by [`timeout`](super::FutureExt::timeout) when the delay
+/// elapses before the wrapped future completes.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Elapsed;
+
+impl fmt::Display for Elapsed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "deadline has elapsed")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Elapsed {}
+
+pin_project! {
+    /// Future for the [`timeout`](super::FutureExt::timeout) method.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Timeout<Fut, Sl> {
+        #[pin]
+        future: Fut,
+        #[pin]
+        sleep: Option<Sl>,
+    }
+}
+
+impl<Fut, Sl> Timeout<Fut, Sl> {
+    pub(super) fn new(future: Fut, sleep: Sl) -> Self {
+        Self { future, sleep: Some(sleep) }
+    }
+}
+
+impl<Fut, Sl> Future for Timeout<Fut, Sl>
+where
+    Fut: Future,
+    Sl: Future<Output = ()>,
+{
+    type Output = Result<Fut::Output, Elapsed>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        // The wrapped future is polled first so that its value is preferred
+        // when it completes on the same poll as the delay elapsing.
+        if let Poll::Ready(output) = this.future.poll(cx) {
+            this.sleep.set(None);
+            return Poll::Ready(Ok(output));
+        }
+
+        match this.sleep.as_mut().as_pin_mut() {
+            Some(sleep) => match sleep.poll(cx) {
+                Poll::Ready(()) => {
+                    this.sleep.set(None);
+                    Poll::Ready(Err(Elapsed))
+                }
+                Poll::Pending => Poll::Pending,
+            },
+            None => panic!("Timeout polled after completion"),
+        }
+    }
+}
+
+impl<Fut, Sl> FusedFuture for Timeout<Fut, Sl>
+where
+    Fut: Future,
+    Sl: Future<Output = ()>,
+{
+    fn is_terminated(&self) -> bool {
+        self.sleep.is_none()
+    }
+}
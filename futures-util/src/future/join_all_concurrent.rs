@@ -0,0 +1,122 @@
+//! Definition of the `JoinAllConcurrent` combinator, waiting for all of a
+//! list of futures to finish with bounded concurrency.
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::future::Future;
+use core::mem;
+use core::num::NonZeroUsize;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use super::assert_future;
+use crate::stream::{FuturesOrdered, StreamExt};
+
+/// Future for the [`join_all_concurrent`] function.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct JoinAllConcurrent<F>
+where
+    F: Future,
+{
+    // Futures not yet started, in reverse input order so the next one to
+    // start can be popped off the end.
+    pending: Vec<F>,
+    in_progress: FuturesOrdered<F>,
+    outputs: Vec<F::Output>,
+    max: Option<NonZeroUsize>,
+}
+
+// No field is ever pinned: not-yet-started futures are only moved before
+// their first poll, and `FuturesOrdered` is itself `Unpin`.
+impl<F: Future> Unpin for JoinAllConcurrent<F> {}
+
+impl<F> fmt::Debug for JoinAllConcurrent<F>
+where
+    F: Future + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JoinAllConcurrent")
+            .field("pending", &self.pending)
+            .field("in_progress", &self.in_progress)
+            .field("max", &self.max)
+            .finish()
+    }
+}
+
+/// Creates a future which represents a collection of the outputs of the
+/// futures given, driving at most `limit` of them at a time.
+///
+/// Like [`join_all`](super::join_all()), the results are collected into a
+/// `Vec<T>` in the same order as the futures were provided, but no more than
+/// `limit` futures are in flight at any point in time; the rest are not
+/// started until a slot frees up. A `limit` larger than the input behaves
+/// exactly like `join_all`.
+///
+/// The limit argument is of type `Into<Option<usize>>`, and so can be
+/// provided as either `None`, `Some(10)`, or just `10`. Note: a limit of zero is
+/// interpreted as no limit at all, and will have the same result as passing in `None`.
+///
+/// This function is only available when the `std` or `alloc` feature of this
+/// library is activated, and it is activated by default.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::future::join_all_concurrent;
+///
+/// async fn foo(i: u32) -> u32 { i }
+///
+/// let futures = vec![foo(1), foo(2), foo(3)];
+///
+/// assert_eq!(join_all_concurrent(futures, 2).await, [1, 2, 3]);
+/// # });
+/// ```
+pub fn join_all_concurrent<I>(
+    iter: I,
+    limit: impl Into<Option<usize>>,
+) -> JoinAllConcurrent<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Future,
+{
+    let mut pending: Vec<_> = iter.into_iter().collect();
+    pending.reverse();
+    assert_future::<Vec<<I::Item as Future>::Output>, _>(JoinAllConcurrent {
+        pending,
+        in_progress: FuturesOrdered::new(),
+        outputs: Vec::new(),
+        max: limit.into().and_then(NonZeroUsize::new),
+    })
+}
+
+impl<F> Future for JoinAllConcurrent<F>
+where
+    F: Future,
+{
+    type Output = Vec<F::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+
+        loop {
+            // Top up the set of running futures, as long as we're below the
+            // concurrency limit.
+            while this.max.map(|max| this.in_progress.len() < max.get()).unwrap_or(true) {
+                match this.pending.pop() {
+                    Some(fut) => this.in_progress.push_back(fut),
+                    None => break,
+                }
+            }
+
+            match this.in_progress.poll_next_unpin(cx) {
+                Poll::Ready(Some(output)) => this.outputs.push(output),
+                Poll::Ready(None) => {
+                    debug_assert!(this.pending.is_empty());
+                    return Poll::Ready(mem::replace(&mut this.outputs, Vec::new()));
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
@@ -21,7 +21,8 @@ pub use futures_task::{FutureObj, LocalFutureObj, UnsafeFutureObj};
 #[allow(clippy::module_inception)]
 mod future;
 pub use self::future::{
-    Flatten, Fuse, FutureExt, Inspect, IntoStream, Map, MapInto, NeverError, Then, UnitError,
+    Elapsed, Flatten, Fuse, FutureExt, Inspect, IntoStream, Map, MapInto, NeverError, OnDrop, Then,
+    Timeout, UnitError,
 };
 
 #[deprecated(note = "This is now an alias for [Flatten](Flatten)")]
@@ -40,8 +41,8 @@ pub use self::future::{Shared, WeakShared};
 
 mod try_future;
 pub use self::try_future::{
-    AndThen, ErrInto, InspectErr, InspectOk, IntoFuture, MapErr, MapOk, MapOkOrElse, OkInto,
-    OrElse, TryFlatten, TryFlattenStream, TryFutureExt, UnwrapOrElse,
+    AndThen, ErrInto, InspectErr, InspectOk, IntoFuture, MapErr, MapErrWith, MapOk, MapOkOrElse,
+    OkInto, OrElse, TryFlatten, TryFlattenStream, TryFutureExt, UnwrapOrElse,
 };
 
 #[cfg(feature = "sink")]
@@ -82,6 +83,21 @@ mod join_all;
 #[cfg(feature = "alloc")]
 pub use self::join_all::{join_all, JoinAll};
 
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+mod join_all_concurrent;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+pub use self::join_all_concurrent::{join_all_concurrent, JoinAllConcurrent};
+
+mod race;
+pub use self::race::{race, Race};
+
+#[cfg(feature = "alloc")]
+mod race_all;
+#[cfg(feature = "alloc")]
+pub use self::race_all::{race_all, RaceAll};
+
 mod select;
 pub use self::select::{select, Select};
 
@@ -98,6 +114,13 @@ mod try_join_all;
 #[cfg(feature = "alloc")]
 pub use self::try_join_all::{try_join_all, TryJoinAll};
 
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+mod try_join_all_concurrent;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+pub use self::try_join_all_concurrent::{try_join_all_concurrent, TryJoinAllConcurrent};
+
 mod try_select;
 pub use self::try_select::{try_select, TrySelect};
 
@@ -106,6 +129,9 @@ mod select_ok;
 #[cfg(feature = "alloc")]
 pub use self::select_ok::{select_ok, SelectOk};
 
+mod retry;
+pub use self::retry::{retry, Retry, RetryPolicy};
+
 mod either;
 pub use self::either::Either;
 
@@ -114,7 +140,7 @@ pub use self::either::Either;
 mod abortable;
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
-pub use crate::abortable::{AbortHandle, AbortRegistration, Abortable, Aborted};
+pub use crate::abortable::{AbortHandle, AbortRegistration, Abortable, Aborted, DeadlineAbortable};
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
 pub use abortable::abortable;
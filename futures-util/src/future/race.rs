@@ -0,0 +1,84 @@
+use super::assert_future;
+use crate::future::FutureExt;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Context, Poll};
+
+/// Future for the [`race()`] function.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[derive(Debug)]
+pub struct Race<A, B> {
+    inner: Option<(A, B)>,
+}
+
+impl<A: Unpin, B: Unpin> Unpin for Race<A, B> {}
+
+/// Waits for either one of two futures with the same output to complete,
+/// returning its output and dropping the other future.
+///
+/// Unlike [`select`](super::select()), the loser is not handed back: it is
+/// dropped — and thereby cancelled — as soon as the winner resolves. Use this
+/// when only the first result matters.
+///
+/// The race is left-biased: both futures are polled in order, so if both are
+/// ready at the same time, `future1`'s output wins.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::future::{self, race};
+/// use futures::pin_mut;
+///
+/// let future1 = async {
+///     future::pending::<()>().await; // will never finish
+///     1
+/// };
+/// let future2 = async { 2 };
+///
+/// // `race` requires `Future + Unpin` bounds
+/// pin_mut!(future1);
+/// pin_mut!(future2);
+///
+/// assert_eq!(race(future1, future2).await, 2);
+/// # });
+/// ```
+pub fn race<A, B>(future1: A, future2: B) -> Race<A, B>
+where
+    A: Future + Unpin,
+    B: Future<Output = A::Output> + Unpin,
+{
+    assert_future::<A::Output, _>(Race { inner: Some((future1, future2)) })
+}
+
+impl<A, B> Future for Race<A, B>
+where
+    A: Future + Unpin,
+    B: Future<Output = A::Output> + Unpin,
+{
+    type Output = A::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let (mut a, mut b) = self.inner.take().expect("cannot poll Race twice");
+        match a.poll_unpin(cx) {
+            Poll::Ready(x) => Poll::Ready(x),
+            Poll::Pending => match b.poll_unpin(cx) {
+                Poll::Ready(x) => Poll::Ready(x),
+                Poll::Pending => {
+                    self.inner = Some((a, b));
+                    Poll::Pending
+                }
+            },
+        }
+    }
+}
+
+impl<A, B> FusedFuture for Race<A, B>
+where
+    A: Future + Unpin,
+    B: Future<Output = A::Output> + Unpin,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_none()
+    }
+}
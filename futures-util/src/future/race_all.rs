@@ -0,0 +1,84 @@
+use super::assert_future;
+use crate::future::FutureExt;
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Context, Poll};
+
+/// Future for the [`race_all`] function.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct RaceAll<Fut> {
+    inner: Vec<Fut>,
+}
+
+impl<Fut: Unpin> Unpin for RaceAll<Fut> {}
+
+/// Creates a new future which will race a list of futures, returning the
+/// output of whichever completes first and dropping the rest.
+///
+/// Unlike [`select_all`](super::select_all()), neither the winning index nor
+/// the remaining futures are handed back: the losers are dropped — and
+/// thereby cancelled — as soon as the winner resolves.
+///
+/// The race is biased towards earlier futures: they are polled in iteration
+/// order, so if several are ready at the same time, the first one's output
+/// wins.
+///
+/// This function is only available when the `std` or `alloc` feature of this
+/// library is activated, and it is activated by default.
+///
+/// # Panics
+///
+/// This function will panic if the iterator specified contains no items.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::future::{self, race_all, FutureExt};
+///
+/// let futures = vec![future::pending().boxed(), future::ready(2).boxed()];
+/// assert_eq!(race_all(futures).await, 2);
+/// # });
+/// ```
+pub fn race_all<I>(iter: I) -> RaceAll<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Future + Unpin,
+{
+    let ret = RaceAll { inner: iter.into_iter().collect() };
+    assert!(!ret.inner.is_empty());
+    assert_future::<<I::Item as Future>::Output, _>(ret)
+}
+
+impl<Fut: Future + Unpin> Future for RaceAll<Fut> {
+    type Output = Fut::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let item = self.inner.iter_mut().find_map(|f| match f.poll_unpin(cx) {
+            Poll::Pending => None,
+            Poll::Ready(e) => Some(e),
+        });
+        match item {
+            Some(res) => {
+                self.inner.clear();
+                Poll::Ready(res)
+            }
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl<Fut: Future + Unpin> FusedFuture for RaceAll<Fut> {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl<Fut: Future + Unpin> FromIterator<Fut> for RaceAll<Fut> {
+    fn from_iter<T: IntoIterator<Item = Fut>>(iter: T) -> Self {
+        race_all(iter)
+    }
+}
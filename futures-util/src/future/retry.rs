@@ -0,0 +1,163 @@
+use crate::time::Timer;
+use core::fmt;
+use core::pin::Pin;
+use core::time::Duration;
+use futures_core::future::{Future, TryFuture};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+/// A policy deciding whether and when a failed attempt should be retried.
+///
+/// Any closure of type `FnMut(usize, &E) -> Option<Duration>` is a
+/// `RetryPolicy`, so simple policies can be written inline.
+pub trait RetryPolicy<E> {
+    /// Returns how long to wait before the next attempt, or `None` to give
+    /// up and surface the error.
+    ///
+    /// `attempt` is the number of attempts that have failed so far, starting
+    /// at `1` for the first failure. `error` is the error the last attempt
+    /// failed with.
+    fn next_delay(&mut self, attempt: usize, error: &E) -> Option<Duration>;
+}
+
+impl<F, E> RetryPolicy<E> for F
+where
+    F: FnMut(usize, &E) -> Option<Duration>,
+{
+    fn next_delay(&mut self, attempt: usize, error: &E) -> Option<Duration> {
+        self(attempt, error)
+    }
+}
+
+/// Creates a future that drives futures produced by `factory` to completion,
+/// retrying failed attempts according to `policy`.
+///
+/// Each attempt calls `factory` for a fresh future. When an attempt resolves
+/// to `Ok`, that value is returned immediately — a first-try success involves
+/// no delay at all. When an attempt resolves to `Err`, the `policy` is asked
+/// for the next delay: `Some(duration)` sleeps on the injected `timer` and
+/// then tries again, while `None` gives up and returns the last error.
+///
+/// The `timer` is anything implementing [`Timer`](crate::time::Timer),
+/// including any closure of type `FnMut(Duration) -> impl Future<Output =
+/// ()>`.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use std::time::Duration;
+///
+/// use futures::future::{self, retry};
+///
+/// let mut attempts = 0;
+/// let result = retry(
+///     || {
+///         attempts += 1;
+///         if attempts < 3 { future::err(attempts) } else { future::ok("done") }
+///     },
+///     // Retry up to twice, without waiting between attempts.
+///     |attempt, _: &i32| if attempt <= 2 { Some(Duration::from_secs(0)) } else { None },
+///     |_| future::ready(()),
+/// )
+/// .await;
+/// assert_eq!(result, Ok("done"));
+/// # });
+/// ```
+pub fn retry<Fut, F, P, T>(mut factory: F, policy: P, timer: T) -> Retry<Fut, F, P, T>
+where
+    Fut: TryFuture,
+    F: FnMut() -> Fut,
+    P: RetryPolicy<Fut::Error>,
+    T: Timer,
+{
+    let future = factory();
+    super::assert_future::<Result<Fut::Ok, Fut::Error>, _>(Retry {
+        state: RetryState::Running { future },
+        factory,
+        policy,
+        timer,
+        attempt: 0,
+    })
+}
+
+pin_project! {
+    #[project = RetryStateProj]
+    enum RetryState<Fut, Sl> {
+        Running {
+            #[pin]
+            future: Fut,
+        },
+        Waiting {
+            #[pin]
+            sleep: Sl,
+        },
+    }
+}
+
+pin_project! {
+    /// Future for the [`retry`] function.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Retry<Fut, F, P, T>
+    where
+        T: Timer,
+    {
+        #[pin]
+        state: RetryState<Fut, T::Sleep>,
+        factory: F,
+        policy: P,
+        timer: T,
+        attempt: usize,
+    }
+}
+
+impl<Fut, F, P, T> fmt::Debug for Retry<Fut, F, P, T>
+where
+    Fut: fmt::Debug,
+    T: Timer,
+    T::Sleep: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state: &dyn fmt::Debug = match &self.state {
+            RetryState::Running { future } => future,
+            RetryState::Waiting { sleep } => sleep,
+        };
+        f.debug_struct("Retry").field("state", state).field("attempt", &self.attempt).finish()
+    }
+}
+
+impl<Fut, F, P, T> Future for Retry<Fut, F, P, T>
+where
+    Fut: TryFuture,
+    F: FnMut() -> Fut,
+    P: RetryPolicy<Fut::Error>,
+    T: Timer,
+{
+    type Output = Result<Fut::Ok, Fut::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        loop {
+            match this.state.as_mut().project() {
+                RetryStateProj::Running { future } => {
+                    let error = match futures_core::ready!(future.try_poll(cx)) {
+                        Ok(output) => return Poll::Ready(Ok(output)),
+                        Err(error) => error,
+                    };
+                    *this.attempt += 1;
+                    match this.policy.next_delay(*this.attempt, &error) {
+                        Some(duration) => this
+                            .state
+                            .set(RetryState::Waiting { sleep: this.timer.sleep(duration) }),
+                        None => return Poll::Ready(Err(error)),
+                    }
+                }
+                RetryStateProj::Waiting { sleep } => {
+                    futures_core::ready!(sleep.poll(cx));
+                    this.state.set(RetryState::Running { future: (this.factory)() });
+                }
+            }
+        }
+    }
+}
@@ -15,9 +15,9 @@ use futures_core::{
 use futures_sink::Sink;
 
 use crate::fns::{
-    inspect_err_fn, inspect_ok_fn, into_fn, map_err_fn, map_ok_fn, map_ok_or_else_fn,
-    unwrap_or_else_fn, InspectErrFn, InspectOkFn, IntoFn, MapErrFn, MapOkFn, MapOkOrElseFn,
-    UnwrapOrElseFn,
+    inspect_err_fn, inspect_ok_fn, into_fn, map_err_fn, map_err_with_fn, map_ok_fn,
+    map_ok_or_else_fn, unwrap_or_else_fn, InspectErrFn, InspectOkFn, IntoFn, MapErrFn,
+    MapErrWithFn, MapOkFn, MapOkOrElseFn, UnwrapOrElseFn,
 };
 use crate::future::{assert_future, Inspect, Map};
 use crate::stream::assert_stream;
@@ -117,6 +117,13 @@ delegate_all!(
     ): Debug + Future + FusedFuture + New[|x: Fut, f: F| Map::new(IntoFuture::new(x), map_err_fn(f))]
 );
 
+delegate_all!(
+    /// Future for the [`map_err_with`](TryFutureExt::map_err_with) method.
+    MapErrWith<Fut, C, F>(
+        Map<IntoFuture<Fut>, MapErrWithFn<C, F>>
+    ): Debug + Future + FusedFuture + New[|x: Fut, c: C, f: F| Map::new(IntoFuture::new(x), map_err_with_fn(c, f))]
+);
+
 delegate_all!(
     /// Future for the [`map_ok_or_else`](TryFutureExt::map_ok_or_else) method.
     MapOkOrElse<Fut, F, G>(
@@ -310,6 +317,37 @@ pub trait TryFutureExt: TryFuture {
         assert_future::<Result<Self::Ok, E>, _>(MapErr::new(self, f))
     }
 
+    /// Maps this future's error value to a different value, handing a captured
+    /// `context` value to the mapping closure.
+    ///
+    /// This is a convenience over [`map_err`](TryFutureExt::map_err) for
+    /// annotating errors with context (e.g. which request failed) without
+    /// writing a capturing closure by hand: `context` is moved into the
+    /// returned future and passed to `f` by value alongside the error.
+    ///
+    /// The provided closure `f` will only be called if this future is resolved
+    /// to an [`Err`]. If it resolves to an [`Ok`], the context is dropped and
+    /// the closure is never invoked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::future::TryFutureExt;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let future = async { Err::<i32, &str>("oh no") };
+    /// let future = future.map_err_with("request 7", |ctx, e| format!("{}: {}", ctx, e));
+    /// assert_eq!(future.await, Err("request 7: oh no".to_string()));
+    /// # });
+    /// ```
+    fn map_err_with<C, E, F>(self, context: C, f: F) -> MapErrWith<Self, C, F>
+    where
+        F: FnOnce(C, Self::Error) -> E,
+        Self: Sized,
+    {
+        assert_future::<Result<Self::Ok, E>, _>(MapErrWith::new(self, context, f))
+    }
+
     /// Maps this future's [`Error`](TryFuture::Error) to a new error type
     /// using the [`Into`](std::convert::Into) trait.
     ///
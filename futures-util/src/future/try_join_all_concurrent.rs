@@ -0,0 +1,170 @@
+//! Definition of the `TryJoinAllConcurrent` combinator, waiting for all of a
+//! list of futures to finish with either success or error, with bounded
+//! concurrency.
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::future::Future;
+use core::num::NonZeroUsize;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+use super::{assert_future, TryFuture};
+use crate::stream::{FuturesUnordered, StreamExt};
+
+pin_project! {
+    /// Future tagging its output with the input position, so results can be
+    /// put back in order no matter when they complete.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    #[derive(Debug)]
+    struct IndexedFut<F> {
+        #[pin]
+        future: F,
+        index: usize,
+    }
+}
+
+impl<F: TryFuture> Future for IndexedFut<F> {
+    type Output = (usize, Result<F::Ok, F::Error>);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let output = futures_core::ready!(this.future.try_poll(cx));
+        Poll::Ready((*this.index, output))
+    }
+}
+
+/// Future for the [`try_join_all_concurrent`] function.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TryJoinAllConcurrent<F>
+where
+    F: TryFuture,
+{
+    // Futures not yet started, in reverse input order so the next one to
+    // start can be popped off the end.
+    pending: Vec<IndexedFut<F>>,
+    in_progress: FuturesUnordered<IndexedFut<F>>,
+    outputs: Vec<Option<F::Ok>>,
+    max: Option<NonZeroUsize>,
+}
+
+// No field is ever pinned: not-yet-started futures are only moved before
+// their first poll, and `FuturesUnordered` is itself `Unpin`.
+impl<F: TryFuture> Unpin for TryJoinAllConcurrent<F> {}
+
+impl<F> fmt::Debug for TryJoinAllConcurrent<F>
+where
+    F: TryFuture + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryJoinAllConcurrent")
+            .field("pending", &self.pending)
+            .field("in_progress", &self.in_progress)
+            .field("max", &self.max)
+            .finish()
+    }
+}
+
+/// Creates a future which represents either a collection of the results of
+/// the futures given or an error, driving at most `limit` of them at a time.
+///
+/// Like [`try_join_all`](super::try_join_all()), successful results are
+/// collected into a `Vec<T>` in the same order as the futures were provided,
+/// and the first error short-circuits, cancelling every outstanding and
+/// not-yet-started future. No more than `limit` futures are in flight at any
+/// point in time; a `limit` larger than the input behaves exactly like
+/// `try_join_all`.
+///
+/// The limit argument is of type `Into<Option<usize>>`, and so can be
+/// provided as either `None`, `Some(10)`, or just `10`. Note: a limit of zero is
+/// interpreted as no limit at all, and will have the same result as passing in `None`.
+///
+/// This function is only available when the `std` or `alloc` feature of this
+/// library is activated, and it is activated by default.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::future::{self, try_join_all_concurrent};
+///
+/// let futures = vec![
+///     future::ok::<u32, u32>(1),
+///     future::ok::<u32, u32>(2),
+///     future::ok::<u32, u32>(3),
+/// ];
+///
+/// assert_eq!(try_join_all_concurrent(futures, 2).await, Ok(vec![1, 2, 3]));
+///
+/// let futures = vec![
+///     future::ok::<u32, u32>(1),
+///     future::err::<u32, u32>(2),
+///     future::ok::<u32, u32>(3),
+/// ];
+///
+/// assert_eq!(try_join_all_concurrent(futures, 2).await, Err(2));
+/// # });
+/// ```
+pub fn try_join_all_concurrent<I>(
+    iter: I,
+    limit: impl Into<Option<usize>>,
+) -> TryJoinAllConcurrent<I::Item>
+where
+    I: IntoIterator,
+    I::Item: TryFuture,
+{
+    let mut pending: Vec<_> =
+        iter.into_iter().enumerate().map(|(index, future)| IndexedFut { future, index }).collect();
+    let mut outputs = Vec::with_capacity(pending.len());
+    outputs.resize_with(pending.len(), || None);
+    pending.reverse();
+    assert_future::<Result<Vec<<I::Item as TryFuture>::Ok>, <I::Item as TryFuture>::Error>, _>(
+        TryJoinAllConcurrent {
+            pending,
+            in_progress: FuturesUnordered::new(),
+            outputs,
+            max: limit.into().and_then(NonZeroUsize::new),
+        },
+    )
+}
+
+impl<F> Future for TryJoinAllConcurrent<F>
+where
+    F: TryFuture,
+{
+    type Output = Result<Vec<F::Ok>, F::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+
+        loop {
+            // Top up the set of running futures, as long as we're below the
+            // concurrency limit.
+            while this.max.map(|max| this.in_progress.len() < max.get()).unwrap_or(true) {
+                match this.pending.pop() {
+                    Some(fut) => this.in_progress.push(fut),
+                    None => break,
+                }
+            }
+
+            match this.in_progress.poll_next_unpin(cx) {
+                Poll::Ready(Some((index, Ok(output)))) => this.outputs[index] = Some(output),
+                Poll::Ready(Some((_, Err(e)))) => {
+                    // Short-circuit: cancel everything still outstanding and
+                    // everything not yet started.
+                    this.in_progress = FuturesUnordered::new();
+                    this.pending.clear();
+                    this.outputs.clear();
+                    return Poll::Ready(Err(e));
+                }
+                Poll::Ready(None) => {
+                    debug_assert!(this.pending.is_empty());
+                    let outputs = this.outputs.drain(..).map(|output| output.unwrap()).collect();
+                    return Poll::Ready(Ok(outputs));
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
@@ -60,6 +60,15 @@ impl<R: AsyncRead> BufReader<R> {
         &self.buffer[self.pos..self.cap]
     }
 
+    /// Returns the capacity of the internal buffer.
+    ///
+    /// Note that a capacity of zero is allowed but makes the `AsyncBufRead`
+    /// side of this reader useless: `poll_fill_buf` can only ever return an
+    /// empty slice. Plain reads bypass the buffer in that case.
+    pub fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
     /// Invalidates all data in the internal buffer.
     #[inline]
     fn discard_buffer(self: Pin<&mut Self>) {
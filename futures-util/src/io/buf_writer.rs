@@ -85,8 +85,9 @@ impl<W: AsyncWrite> BufWriter<W> {
         &self.buf
     }
 
-    /// Capacity of `buf`. how many chars can be held in buffer
-    pub(super) fn capacity(&self) -> usize {
+    /// Returns the capacity of the internal buffer: how many bytes can be
+    /// held before the writer has to flush to the underlying object.
+    pub fn capacity(&self) -> usize {
         self.buf.capacity()
     }
 
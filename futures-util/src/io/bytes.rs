@@ -0,0 +1,49 @@
+use futures_core::ready;
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+use futures_io::AsyncRead;
+use pin_project_lite::pin_project;
+use std::io;
+use std::pin::Pin;
+
+pin_project! {
+    /// Stream for the [`bytes`](super::AsyncReadExt::bytes) method.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Bytes<R> {
+        #[pin]
+        reader: R,
+        done: bool,
+    }
+}
+
+impl<R: AsyncRead> Bytes<R> {
+    pub(super) fn new(reader: R) -> Self {
+        Self { reader, done: false }
+    }
+}
+
+impl<R: AsyncRead> Stream for Bytes<R> {
+    type Item = io::Result<u8>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+        let mut buf = [0];
+        match ready!(this.reader.poll_read(cx, &mut buf)) {
+            Ok(0) => {
+                *this.done = true;
+                Poll::Ready(None)
+            }
+            Ok(..) => Poll::Ready(Some(Ok(buf[0]))),
+            Err(e) => {
+                // An error is yielded as the final item; the stream ends
+                // afterwards.
+                *this.done = true;
+                Poll::Ready(Some(Err(e)))
+            }
+        }
+    }
+}
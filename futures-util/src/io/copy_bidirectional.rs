@@ -0,0 +1,165 @@
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_io::{AsyncRead, AsyncWrite};
+use std::io;
+use std::pin::Pin;
+
+const BUF_SIZE: usize = 8 * 1024;
+
+/// State of one copy direction.
+#[derive(Debug)]
+struct TransferState {
+    buf: Box<[u8]>,
+    pos: usize,
+    cap: usize,
+    read_done: bool,
+    done: bool,
+    amt: u64,
+}
+
+impl TransferState {
+    fn new() -> Self {
+        Self {
+            buf: vec![0; BUF_SIZE].into_boxed_slice(),
+            pos: 0,
+            cap: 0,
+            read_done: false,
+            done: false,
+            amt: 0,
+        }
+    }
+
+    fn poll_transfer<R, W>(
+        &mut self,
+        cx: &mut Context<'_>,
+        mut reader: Pin<&mut R>,
+        mut writer: Pin<&mut W>,
+    ) -> Poll<io::Result<u64>>
+    where
+        R: AsyncRead + ?Sized,
+        W: AsyncWrite + ?Sized,
+    {
+        loop {
+            // If our buffer is empty, try to refill it from the reader.
+            if self.pos == self.cap && !self.read_done {
+                match reader.as_mut().poll_read(cx, &mut self.buf) {
+                    Poll::Ready(Ok(0)) => self.read_done = true,
+                    Poll::Ready(Ok(n)) => {
+                        self.pos = 0;
+                        self.cap = n;
+                    }
+                    Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                    Poll::Pending => {
+                        // Nothing new to write; make sure anything already
+                        // written makes it to the peer before going to sleep.
+                        ready!(writer.as_mut().poll_flush(cx))?;
+                        return Poll::Pending;
+                    }
+                }
+            }
+
+            // Write out whatever the buffer holds.
+            while self.pos < self.cap {
+                let n = ready!(writer.as_mut().poll_write(cx, &self.buf[self.pos..self.cap]))?;
+                if n == 0 {
+                    return Poll::Ready(Err(io::Error::new(
+                        io::ErrorKind::WriteZero,
+                        "write zero byte into writer",
+                    )));
+                }
+                self.pos += n;
+                self.amt += n as u64;
+            }
+
+            // This direction hit EOF and everything was written; propagate
+            // the half-close to the peer.
+            if self.read_done {
+                ready!(writer.as_mut().poll_close(cx))?;
+                self.done = true;
+                return Poll::Ready(Ok(self.amt));
+            }
+        }
+    }
+}
+
+/// Copies data in both directions between `a` and `b`.
+///
+/// The returned future drives both copy directions concurrently: bytes read
+/// from `a` are written to `b` and vice versa. A direction that hits EOF has
+/// its remaining bytes flushed and the write side of its peer closed, while
+/// the other direction keeps flowing, so half-closed connections are handled
+/// properly. The future completes once both directions have hit EOF, yielding
+/// the number of bytes copied from `a` to `b` and from `b` to `a`.
+///
+/// An error in either direction causes the future to resolve to that error
+/// immediately, abandoning the transfer in the other direction.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::io::{self, Cursor};
+///
+/// let mut a = Cursor::new(Vec::from(&b"hello"[..]));
+/// let mut b = Cursor::new(Vec::new());
+///
+/// let (a_to_b, b_to_a) = io::copy_bidirectional(&mut a, &mut b).await?;
+///
+/// assert_eq!(a_to_b, 5);
+/// assert_eq!(b_to_a, 0);
+/// assert_eq!(b.into_inner(), b"hello");
+/// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+/// ```
+pub fn copy_bidirectional<'a, A, B>(a: &'a mut A, b: &'a mut B) -> CopyBidirectional<'a, A, B>
+where
+    A: AsyncRead + AsyncWrite + Unpin + ?Sized,
+    B: AsyncRead + AsyncWrite + Unpin + ?Sized,
+{
+    CopyBidirectional { a, b, a_to_b: TransferState::new(), b_to_a: TransferState::new() }
+}
+
+/// Future for the [`copy_bidirectional()`] function.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct CopyBidirectional<'a, A: ?Sized, B: ?Sized> {
+    a: &'a mut A,
+    b: &'a mut B,
+    a_to_b: TransferState,
+    b_to_a: TransferState,
+}
+
+impl<A, B> Future for CopyBidirectional<'_, A, B>
+where
+    A: AsyncRead + AsyncWrite + Unpin + ?Sized,
+    B: AsyncRead + AsyncWrite + Unpin + ?Sized,
+{
+    type Output = io::Result<(u64, u64)>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let Self { a, b, a_to_b, b_to_a } = this;
+
+        let mut ready = true;
+        if !a_to_b.done {
+            match a_to_b.poll_transfer(cx, Pin::new(&mut **a), Pin::new(&mut **b)) {
+                Poll::Ready(Ok(_)) => {}
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending => ready = false,
+            }
+        }
+        if !b_to_a.done {
+            match b_to_a.poll_transfer(cx, Pin::new(&mut **b), Pin::new(&mut **a)) {
+                Poll::Ready(Ok(_)) => {}
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending => ready = false,
+            }
+        }
+
+        if ready {
+            Poll::Ready(Ok((a_to_b.amt, b_to_a.amt)))
+        } else {
+            Poll::Pending
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use super::BufReader;
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_io::{AsyncBufRead, AsyncRead, AsyncWrite};
+use pin_project_lite::pin_project;
+use std::io;
+use std::pin::Pin;
+
+/// Creates a future which copies all the bytes from one object to another,
+/// reporting progress along the way.
+///
+/// This behaves like [`copy`](super::copy()), except that `on_progress` is
+/// invoked with the cumulative number of bytes copied after each successful
+/// write. The callback frequency follows internal buffer fills, not
+/// individual bytes. A zero-length source never invokes the callback. If an
+/// error occurs mid-copy, the last value the callback observed is the number
+/// of bytes that were copied before the error.
+///
+/// On success the number of bytes is returned.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::io::{self, AsyncWriteExt, Cursor};
+///
+/// let reader = Cursor::new([1, 2, 3, 4]);
+/// let mut writer = Cursor::new(vec![0u8; 5]);
+/// let mut progress = 0;
+///
+/// let bytes = io::copy_with_progress(reader, &mut writer, |copied| progress = copied).await?;
+/// writer.close().await?;
+///
+/// assert_eq!(bytes, 4);
+/// assert_eq!(progress, 4);
+/// assert_eq!(writer.into_inner(), [1, 2, 3, 4, 0]);
+/// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+/// ```
+pub fn copy_with_progress<R, W, F>(
+    reader: R,
+    writer: &mut W,
+    on_progress: F,
+) -> CopyWithProgress<'_, R, W, F>
+where
+    R: AsyncRead,
+    W: AsyncWrite + Unpin + ?Sized,
+    F: FnMut(u64),
+{
+    CopyWithProgress { reader: BufReader::new(reader), writer, amt: 0, on_progress }
+}
+
+pin_project! {
+    /// Future for the [`copy_with_progress()`] function.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct CopyWithProgress<'a, R, W: ?Sized, F> {
+        #[pin]
+        reader: BufReader<R>,
+        writer: &'a mut W,
+        amt: u64,
+        on_progress: F,
+    }
+}
+
+impl<R, W, F> Future for CopyWithProgress<'_, R, W, F>
+where
+    R: AsyncRead,
+    W: AsyncWrite + Unpin + ?Sized,
+    F: FnMut(u64),
+{
+    type Output = io::Result<u64>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            let buffer = ready!(this.reader.as_mut().poll_fill_buf(cx))?;
+            if buffer.is_empty() {
+                ready!(Pin::new(&mut this.writer).poll_flush(cx))?;
+                return Poll::Ready(Ok(*this.amt));
+            }
+
+            let i = ready!(Pin::new(&mut this.writer).poll_write(cx, buffer))?;
+            if i == 0 {
+                return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+            }
+            *this.amt += i as u64;
+            this.reader.as_mut().consume(i);
+            (this.on_progress)(*this.amt);
+        }
+    }
+}
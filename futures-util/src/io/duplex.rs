@@ -0,0 +1,165 @@
+use futures_core::task::{Context, Poll, Waker};
+use futures_io::{AsyncRead, AsyncWrite};
+use std::collections::VecDeque;
+use std::io;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+/// Creates a new pair of connected, in-memory endpoints.
+///
+/// Data written to one endpoint can be read from the other, and vice versa,
+/// making the pair act like a bidirectional pipe; this is mainly useful for
+/// testing IO code without touching the network. Each direction buffers at
+/// most `max_buf_size` bytes: writing to a full buffer waits until the peer
+/// reads, providing backpressure.
+///
+/// Closing an endpoint (or dropping it) makes the peer's reads return EOF
+/// once the remaining buffered bytes are drained. Dropping an endpoint
+/// additionally makes the peer's writes fail with
+/// [`BrokenPipe`](std::io::ErrorKind::BrokenPipe).
+///
+/// # Panics
+///
+/// Panics if `max_buf_size` is zero, as no data could ever be transferred.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::io::{self, AsyncReadExt, AsyncWriteExt};
+///
+/// let (mut a, mut b) = io::duplex(64);
+///
+/// a.write_all(b"ping").await?;
+/// a.close().await?;
+///
+/// let mut buf = Vec::new();
+/// b.read_to_end(&mut buf).await?;
+/// assert_eq!(buf, b"ping");
+/// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+/// ```
+pub fn duplex(max_buf_size: usize) -> (DuplexStream, DuplexStream) {
+    assert!(max_buf_size > 0, "`max_buf_size` must be non-zero");
+    let a_to_b = Arc::new(Mutex::new(Pipe::new(max_buf_size)));
+    let b_to_a = Arc::new(Mutex::new(Pipe::new(max_buf_size)));
+    (
+        DuplexStream { read: b_to_a.clone(), write: a_to_b.clone() },
+        DuplexStream { read: a_to_b, write: b_to_a },
+    )
+}
+
+/// One endpoint of the bidirectional in-memory pipe created by [`duplex`].
+#[derive(Debug)]
+pub struct DuplexStream {
+    read: Arc<Mutex<Pipe>>,
+    write: Arc<Mutex<Pipe>>,
+}
+
+/// One direction of the pipe: a bounded byte buffer shared by the writing
+/// endpoint and the reading endpoint.
+#[derive(Debug)]
+struct Pipe {
+    buffer: VecDeque<u8>,
+    max_buf_size: usize,
+    is_closed: bool,
+    read_waker: Option<Waker>,
+    write_waker: Option<Waker>,
+}
+
+impl Pipe {
+    fn new(max_buf_size: usize) -> Self {
+        Self {
+            buffer: VecDeque::new(),
+            max_buf_size,
+            is_closed: false,
+            read_waker: None,
+            write_waker: None,
+        }
+    }
+
+    fn close(&mut self) {
+        self.is_closed = true;
+        if let Some(waker) = self.read_waker.take() {
+            waker.wake();
+        }
+        if let Some(waker) = self.write_waker.take() {
+            waker.wake();
+        }
+    }
+
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        if self.buffer.is_empty() {
+            if self.is_closed {
+                return Poll::Ready(Ok(0));
+            }
+            self.read_waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+        let n = self.buffer.len().min(buf.len());
+        for slot in buf.iter_mut().take(n) {
+            *slot = self.buffer.pop_front().unwrap();
+        }
+        // Space was freed; let a writer blocked on a full buffer continue.
+        if let Some(waker) = self.write_waker.take() {
+            waker.wake();
+        }
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_write(&mut self, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        if self.is_closed {
+            return Poll::Ready(Err(io::ErrorKind::BrokenPipe.into()));
+        }
+        let space = self.max_buf_size - self.buffer.len();
+        if space == 0 {
+            self.write_waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+        let n = space.min(buf.len());
+        self.buffer.extend(&buf[..n]);
+        if let Some(waker) = self.read_waker.take() {
+            waker.wake();
+        }
+        Poll::Ready(Ok(n))
+    }
+}
+
+impl AsyncRead for DuplexStream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        self.read.lock().unwrap().poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for DuplexStream {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.write.lock().unwrap().poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        // Written bytes are immediately visible to the peer; there is no
+        // intermediate buffer to flush.
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.write.lock().unwrap().close();
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl Drop for DuplexStream {
+    fn drop(&mut self) {
+        // Both directions shut down: the peer's reads hit EOF after draining
+        // what is buffered, and its writes fail with `BrokenPipe`.
+        self.read.lock().unwrap().close();
+        self.write.lock().unwrap().close();
+    }
+}
@@ -51,6 +51,9 @@ pub use self::buf_reader::{BufReader, SeeKRelative};
 mod buf_writer;
 pub use self::buf_writer::BufWriter;
 
+mod bytes;
+pub use self::bytes::Bytes;
+
 mod line_writer;
 pub use self::line_writer::LineWriter;
 
@@ -63,12 +66,21 @@ pub use self::close::Close;
 mod copy;
 pub use self::copy::{copy, Copy};
 
+mod copy_bidirectional;
+pub use self::copy_bidirectional::{copy_bidirectional, CopyBidirectional};
+
 mod copy_buf;
 pub use self::copy_buf::{copy_buf, CopyBuf};
 
+mod copy_with_progress;
+pub use self::copy_with_progress::{copy_with_progress, CopyWithProgress};
+
 mod cursor;
 pub use self::cursor::Cursor;
 
+mod duplex;
+pub use self::duplex::{duplex, DuplexStream};
+
 mod empty;
 pub use self::empty::{empty, Empty};
 
@@ -97,18 +109,27 @@ pub use self::read_vectored::ReadVectored;
 mod read_exact;
 pub use self::read_exact::ReadExact;
 
+mod read_exact_or_eof;
+pub use self::read_exact_or_eof::ReadExactOrEof;
+
 mod read_line;
 pub use self::read_line::ReadLine;
 
 mod read_to_end;
 pub use self::read_to_end::ReadToEnd;
 
+mod read_to_end_limited;
+pub use self::read_to_end_limited::ReadToEndLimited;
+
 mod read_to_string;
 pub use self::read_to_string::ReadToString;
 
 mod read_until;
 pub use self::read_until::ReadUntil;
 
+mod read_until_bytes;
+pub use self::read_until_bytes::ReadUntilBytes;
+
 mod repeat;
 pub use self::repeat::{repeat, Repeat};
 
@@ -174,6 +195,34 @@ pub trait AsyncReadExt: AsyncRead {
         assert_read(Chain::new(self, next))
     }
 
+    /// Transforms this `AsyncRead` instance into a [`Stream`](futures_core::stream::Stream)
+    /// over its bytes.
+    ///
+    /// The returned stream yields one `Result<u8, io::Error>` per byte read
+    /// from this object and ends when the underlying reader hits EOF. If the
+    /// reader returns an error, that error is yielded as the final item and
+    /// the stream ends afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncReadExt, Cursor};
+    /// use futures::stream::TryStreamExt;
+    ///
+    /// let reader = Cursor::new([1, 2, 3, 4]);
+    ///
+    /// let bytes: Vec<u8> = reader.bytes().try_collect().await?;
+    /// assert_eq!(bytes, [1, 2, 3, 4]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn bytes(self) -> Bytes<Self>
+    where
+        Self: Sized,
+    {
+        assert_stream::<Result<u8>, _>(Bytes::new(self))
+    }
+
     /// Tries to read some bytes directly into the given `buf` in asynchronous
     /// manner, returning a future type.
     ///
@@ -264,6 +313,38 @@ pub trait AsyncReadExt: AsyncRead {
         assert_future::<Result<()>, _>(ReadExact::new(self, buf))
     }
 
+    /// Creates a future which will read as much of `buf` as the source can
+    /// fill, returning the number of bytes actually read.
+    ///
+    /// Unlike [`read_exact`](AsyncReadExt::read_exact), reaching EOF before
+    /// the buffer is full is not an error: the future keeps reading until
+    /// either `buf` is full or EOF is reached, and then resolves to the
+    /// count. A return value of `0` therefore means a clean EOF before any
+    /// data, while a value smaller than `buf.len()` indicates a final
+    /// partial record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncReadExt, Cursor};
+    ///
+    /// let mut reader = Cursor::new([1, 2, 3]);
+    /// let mut output = [0u8; 5];
+    ///
+    /// let bytes = reader.read_exact_or_eof(&mut output).await?;
+    ///
+    /// assert_eq!(bytes, 3);
+    /// assert_eq!(output, [1, 2, 3, 0, 0]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn read_exact_or_eof<'a>(&'a mut self, buf: &'a mut [u8]) -> ReadExactOrEof<'a, Self>
+    where
+        Self: Unpin,
+    {
+        assert_future::<Result<usize>, _>(ReadExactOrEof::new(self, buf))
+    }
+
     /// Creates a future which will read all the bytes from this `AsyncRead`.
     ///
     /// On success the total number of bytes read is returned.
@@ -290,6 +371,50 @@ pub trait AsyncReadExt: AsyncRead {
         assert_future::<Result<usize>, _>(ReadToEnd::new(self, buf))
     }
 
+    /// Creates a future which will read all the bytes from this `AsyncRead`,
+    /// failing if more than `max` bytes would be buffered.
+    ///
+    /// This is the tool to reach for when reading from an untrusted source,
+    /// where plain [`read_to_end`](AsyncReadExt::read_to_end) could grow the
+    /// buffer without bound.
+    ///
+    /// On success the total number of bytes read is returned. A source with
+    /// exactly `max` bytes succeeds. If the source yields more than `max`
+    /// bytes, an error of kind [`std::io::ErrorKind::Other`] is returned and
+    /// `buf` contains exactly `max` bytes read from the source: the data
+    /// already buffered is kept, but nothing past the limit is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncReadExt, Cursor};
+    ///
+    /// let mut reader = Cursor::new([1, 2, 3, 4]);
+    /// let mut output = Vec::new();
+    ///
+    /// let bytes = reader.read_to_end_limited(&mut output, 8).await?;
+    ///
+    /// assert_eq!(bytes, 4);
+    /// assert_eq!(output, vec![1, 2, 3, 4]);
+    ///
+    /// let mut reader = Cursor::new([1, 2, 3, 4]);
+    /// let mut output = Vec::new();
+    ///
+    /// assert!(reader.read_to_end_limited(&mut output, 3).await.is_err());
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn read_to_end_limited<'a>(
+        &'a mut self,
+        buf: &'a mut Vec<u8>,
+        max: usize,
+    ) -> ReadToEndLimited<'a, Self>
+    where
+        Self: Unpin,
+    {
+        assert_future::<Result<usize>, _>(ReadToEndLimited::new(self, buf, max))
+    }
+
     /// Creates a future which will read all the bytes from this `AsyncRead`.
     ///
     /// On success the total number of bytes read is returned.
@@ -723,6 +848,51 @@ pub trait AsyncBufReadExt: AsyncBufRead {
         assert_future::<Result<usize>, _>(ReadUntil::new(self, byte, buf))
     }
 
+    /// Creates a future which will read all the bytes associated with this I/O
+    /// object into `buf` until the byte sequence `delim` or EOF is reached.
+    ///
+    /// This is the multi-byte counterpart of
+    /// [`read_until`](AsyncBufReadExt::read_until), intended for protocols
+    /// delimited by sequences like `\r\n` or custom markers. All bytes up to,
+    /// and including, the delimiter (if found) will be appended to `buf`,
+    /// even when the delimiter straddles internal buffer boundaries. If EOF
+    /// is reached before the delimiter is found, everything read up to that
+    /// point has been appended to `buf`.
+    ///
+    /// The returned future will resolve to the number of bytes read once the
+    /// read operation is completed.
+    ///
+    /// # Errors
+    ///
+    /// An empty `delim` yields an error of kind
+    /// [`std::io::ErrorKind::InvalidInput`]. I/O errors are returned as with
+    /// [`read_until`](AsyncBufReadExt::read_until).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncBufReadExt, Cursor};
+    ///
+    /// let mut cursor = Cursor::new(b"header\r\nbody");
+    /// let mut buf = vec![];
+    ///
+    /// let num_bytes = cursor.read_until_bytes(b"\r\n", &mut buf).await?;
+    /// assert_eq!(num_bytes, 8);
+    /// assert_eq!(buf, b"header\r\n");
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn read_until_bytes<'a>(
+        &'a mut self,
+        delim: &'a [u8],
+        buf: &'a mut Vec<u8>,
+    ) -> ReadUntilBytes<'a, Self>
+    where
+        Self: Unpin,
+    {
+        assert_future::<Result<usize>, _>(ReadUntilBytes::new(self, delim, buf))
+    }
+
     /// Creates a future which will read all the bytes associated with this I/O
     /// object into `buf` until a newline (the 0xA byte) or EOF is reached,
     /// This method is the async equivalent to [`BufRead::read_line`](std::io::BufRead::read_line).
@@ -0,0 +1,45 @@
+use crate::io::AsyncRead;
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use std::io;
+use std::mem;
+use std::pin::Pin;
+
+/// Future for the [`read_exact_or_eof`](super::AsyncReadExt::read_exact_or_eof) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ReadExactOrEof<'a, R: ?Sized> {
+    reader: &'a mut R,
+    buf: &'a mut [u8],
+    read: usize,
+}
+
+impl<R: ?Sized + Unpin> Unpin for ReadExactOrEof<'_, R> {}
+
+impl<'a, R: AsyncRead + ?Sized + Unpin> ReadExactOrEof<'a, R> {
+    pub(super) fn new(reader: &'a mut R, buf: &'a mut [u8]) -> Self {
+        Self { reader, buf, read: 0 }
+    }
+}
+
+impl<R: AsyncRead + ?Sized + Unpin> Future for ReadExactOrEof<'_, R> {
+    type Output = io::Result<usize>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        while !this.buf.is_empty() {
+            let n = ready!(Pin::new(&mut this.reader).poll_read(cx, this.buf))?;
+            {
+                let (_, rest) = mem::replace(&mut this.buf, &mut []).split_at_mut(n);
+                this.buf = rest;
+            }
+            if n == 0 {
+                // EOF: report the short count instead of erroring.
+                break;
+            }
+            this.read += n;
+        }
+        Poll::Ready(Ok(this.read))
+    }
+}
@@ -24,9 +24,9 @@ impl<'a, R: AsyncRead + ?Sized + Unpin> ReadToEnd<'a, R> {
     }
 }
 
-struct Guard<'a> {
-    buf: &'a mut Vec<u8>,
-    len: usize,
+pub(super) struct Guard<'a> {
+    pub(super) buf: &'a mut Vec<u8>,
+    pub(super) len: usize,
 }
 
 impl Drop for Guard<'_> {
@@ -0,0 +1,92 @@
+use super::read_to_end::Guard;
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_io::AsyncRead;
+use std::io;
+use std::pin::Pin;
+use std::vec::Vec;
+
+/// Future for the [`read_to_end_limited`](super::AsyncReadExt::read_to_end_limited) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ReadToEndLimited<'a, R: ?Sized> {
+    reader: &'a mut R,
+    buf: &'a mut Vec<u8>,
+    start_len: usize,
+    max: usize,
+}
+
+impl<R: ?Sized + Unpin> Unpin for ReadToEndLimited<'_, R> {}
+
+impl<'a, R: AsyncRead + ?Sized + Unpin> ReadToEndLimited<'a, R> {
+    pub(super) fn new(reader: &'a mut R, buf: &'a mut Vec<u8>, max: usize) -> Self {
+        let start_len = buf.len();
+        Self { reader, buf, start_len, max }
+    }
+}
+
+fn read_to_end_limited_internal<R: AsyncRead + ?Sized>(
+    mut rd: Pin<&mut R>,
+    cx: &mut Context<'_>,
+    buf: &mut Vec<u8>,
+    start_len: usize,
+    max: usize,
+) -> Poll<io::Result<usize>> {
+    let mut g = Guard { len: buf.len(), buf };
+    loop {
+        if g.len == g.buf.len() {
+            unsafe {
+                g.buf.reserve(32);
+                let capacity = g.buf.capacity();
+                g.buf.set_len(capacity);
+                super::initialize(&rd, &mut g.buf[g.len..]);
+            }
+        }
+
+        // Never hand the reader a window larger than what the limit still
+        // allows, plus one probe byte so that a source with exactly `max`
+        // bytes left can still report EOF successfully. A probe byte that
+        // does get filled in is discarded by the guard on return.
+        let remaining = max - (g.len - start_len);
+        let window = (g.buf.len() - g.len).min(remaining + 1);
+        let buf = &mut g.buf[g.len..g.len + window];
+        match ready!(rd.as_mut().poll_read(cx, buf)) {
+            Ok(0) => return Poll::Ready(Ok(g.len - start_len)),
+            Ok(n) => {
+                // See the comment in `read_to_end_internal` on why bogus
+                // values from read cannot be allowed.
+                assert!(n <= buf.len());
+                if n > remaining {
+                    // Keep the data up to the limit so the caller ends up
+                    // with exactly `max` new bytes, and discard the rest.
+                    g.len += remaining;
+                    return Poll::Ready(Err(io::Error::new(
+                        io::ErrorKind::Other,
+                        "read limit exceeded",
+                    )));
+                }
+                g.len += n;
+            }
+            Err(e) => return Poll::Ready(Err(e)),
+        }
+    }
+}
+
+impl<A> Future for ReadToEndLimited<'_, A>
+where
+    A: AsyncRead + ?Sized + Unpin,
+{
+    type Output = io::Result<usize>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        read_to_end_limited_internal(
+            Pin::new(&mut this.reader),
+            cx,
+            this.buf,
+            this.start_len,
+            this.max,
+        )
+    }
+}
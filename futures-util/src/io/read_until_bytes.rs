@@ -0,0 +1,93 @@
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_io::AsyncBufRead;
+use std::io;
+use std::mem;
+use std::pin::Pin;
+
+/// Future for the [`read_until_bytes`](super::AsyncBufReadExt::read_until_bytes) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ReadUntilBytes<'a, R: ?Sized> {
+    reader: &'a mut R,
+    delim: &'a [u8],
+    buf: &'a mut Vec<u8>,
+    read: usize,
+}
+
+impl<R: ?Sized + Unpin> Unpin for ReadUntilBytes<'_, R> {}
+
+impl<'a, R: AsyncBufRead + ?Sized + Unpin> ReadUntilBytes<'a, R> {
+    pub(super) fn new(reader: &'a mut R, delim: &'a [u8], buf: &'a mut Vec<u8>) -> Self {
+        Self { reader, delim, buf, read: 0 }
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|window| window == needle)
+}
+
+fn read_until_bytes_internal<R: AsyncBufRead + ?Sized>(
+    mut reader: Pin<&mut R>,
+    cx: &mut Context<'_>,
+    delim: &[u8],
+    buf: &mut Vec<u8>,
+    read: &mut usize,
+) -> Poll<io::Result<usize>> {
+    if delim.is_empty() {
+        return Poll::Ready(Err(io::Error::new(io::ErrorKind::InvalidInput, "empty delimiter")));
+    }
+
+    loop {
+        let (done, used) = {
+            let available = ready!(reader.as_mut().poll_fill_buf(cx))?;
+            if available.is_empty() {
+                // EOF before the delimiter: return what was read so far.
+                return Poll::Ready(Ok(mem::replace(read, 0)));
+            }
+
+            // The delimiter may straddle the boundary between what we've
+            // already buffered and the fresh data, so first check a small
+            // window combining the tail of `buf` with the head of
+            // `available`. A match here necessarily starts in the tail:
+            // matches entirely within already-buffered data were ruled out
+            // on earlier iterations, and the head alone is too short to
+            // hold one.
+            let tail_len = (delim.len() - 1).min(*read);
+            let overlap = if tail_len > 0 {
+                let mut window = Vec::with_capacity(tail_len + delim.len() - 1);
+                window.extend_from_slice(&buf[buf.len() - tail_len..]);
+                window.extend_from_slice(&available[..available.len().min(delim.len() - 1)]);
+                find(&window, delim).map(|i| i + delim.len() - tail_len)
+            } else {
+                None
+            };
+
+            if let Some(used) = overlap {
+                buf.extend_from_slice(&available[..used]);
+                (true, used)
+            } else if let Some(i) = find(available, delim) {
+                buf.extend_from_slice(&available[..i + delim.len()]);
+                (true, i + delim.len())
+            } else {
+                buf.extend_from_slice(available);
+                (false, available.len())
+            }
+        };
+        reader.as_mut().consume(used);
+        *read += used;
+        if done {
+            return Poll::Ready(Ok(mem::replace(read, 0)));
+        }
+    }
+}
+
+impl<R: AsyncBufRead + ?Sized + Unpin> Future for ReadUntilBytes<'_, R> {
+    type Output = io::Result<usize>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let Self { reader, delim, buf, read } = &mut *self;
+        read_until_bytes_internal(Pin::new(reader), cx, delim, buf, read)
+    }
+}
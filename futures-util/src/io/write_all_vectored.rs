@@ -176,6 +176,8 @@ mod tests {
             (vec![IoSlice::new(&[1]), IoSlice::new(&[2]), IoSlice::new(&[3])], &[1, 2, 3]),
             (vec![IoSlice::new(&[1, 1]), IoSlice::new(&[2, 2]), IoSlice::new(&[3, 3])], &[1, 1, 2, 2, 3, 3]),
             (vec![IoSlice::new(&[1, 1, 1]), IoSlice::new(&[2, 2, 2]), IoSlice::new(&[3, 3, 3])], &[1, 1, 1, 2, 2, 2, 3, 3, 3]),
+            (vec![IoSlice::new(&[]), IoSlice::new(&[1, 1]), IoSlice::new(&[])], &[1, 1]),
+            (vec![IoSlice::new(&[1, 1, 1]), IoSlice::new(&[]), IoSlice::new(&[2, 2])], &[1, 1, 1, 2, 2]),
         ];
 
         for (mut input, wanted) in tests {
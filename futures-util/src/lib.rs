@@ -309,6 +309,8 @@ pub use crate::sink::{Sink, SinkExt};
 
 pub mod task;
 
+pub mod time;
+
 #[cfg(feature = "compat")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compat")))]
 pub mod compat;
@@ -0,0 +1,438 @@
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Context, Poll, Waker};
+use std::cell::UnsafeCell;
+use std::collections::VecDeque;
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
+use std::sync::Mutex as StdMutex;
+
+/// A futures-aware reader-writer lock.
+///
+/// Any number of readers can hold the lock at the same time, while a writer
+/// gets exclusive access.
+///
+/// # Fairness
+///
+/// Waiters are served in FIFO order, and a pending writer blocks readers that
+/// arrive after it, so a steady stream of readers cannot starve a writer.
+pub struct RwLock<T: ?Sized> {
+    state: StdMutex<State>,
+    value: UnsafeCell<T>,
+}
+
+struct State {
+    readers: usize,
+    writer: bool,
+    next_id: usize,
+    waiters: VecDeque<Waiter>,
+}
+
+struct Waiter {
+    id: usize,
+    write: bool,
+    granted: bool,
+    waker: Option<Waker>,
+}
+
+impl<T: ?Sized> fmt::Debug for RwLock<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.state.lock().unwrap();
+        f.debug_struct("RwLock")
+            .field("readers", &state.readers)
+            .field("is_write_locked", &state.writer)
+            .field("has_waiters", &!state.waiters.is_empty())
+            .finish()
+    }
+}
+
+impl<T> From<T> for RwLock<T> {
+    fn from(t: T) -> Self {
+        Self::new(t)
+    }
+}
+
+impl<T: Default> Default for RwLock<T> {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+impl<T> RwLock<T> {
+    /// Creates a new futures-aware reader-writer lock.
+    pub fn new(t: T) -> Self {
+        Self {
+            state: StdMutex::new(State {
+                readers: 0,
+                writer: false,
+                next_id: 0,
+                waiters: VecDeque::new(),
+            }),
+            value: UnsafeCell::new(t),
+        }
+    }
+
+    /// Consumes this lock, returning the underlying data.
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+impl<T: ?Sized> RwLock<T> {
+    /// Attempt to acquire a shared lock immediately.
+    ///
+    /// If the lock is write-locked, or a writer is waiting for it, this will
+    /// return `None`.
+    pub fn try_read(&self) -> Option<RwLockReadGuard<'_, T>> {
+        let mut state = self.state.lock().unwrap();
+        if !state.writer && state.waiters.is_empty() {
+            state.readers += 1;
+            Some(RwLockReadGuard { rwlock: self })
+        } else {
+            None
+        }
+    }
+
+    /// Attempt to acquire an exclusive lock immediately.
+    ///
+    /// If the lock is held in any way, this will return `None`.
+    pub fn try_write(&self) -> Option<RwLockWriteGuard<'_, T>> {
+        let mut state = self.state.lock().unwrap();
+        if !state.writer && state.readers == 0 && state.waiters.is_empty() {
+            state.writer = true;
+            Some(RwLockWriteGuard { rwlock: self })
+        } else {
+            None
+        }
+    }
+
+    /// Acquire a shared lock asynchronously.
+    ///
+    /// This method returns a future that will resolve once all writers that
+    /// arrived before it have released the lock.
+    pub fn read(&self) -> RwLockReadFuture<'_, T> {
+        RwLockReadFuture { rwlock: Some(self), id: None }
+    }
+
+    /// Acquire an exclusive lock asynchronously.
+    ///
+    /// This method returns a future that will resolve once all other lock
+    /// holders have released the lock.
+    pub fn write(&self) -> RwLockWriteFuture<'_, T> {
+        RwLockWriteFuture { rwlock: Some(self), id: None }
+    }
+
+    /// Returns a mutable reference to the underlying data.
+    ///
+    /// Since this call borrows the `RwLock` mutably, no actual locking needs
+    /// to take place -- the mutable borrow statically guarantees no locks
+    /// exist.
+    pub fn get_mut(&mut self) -> &mut T {
+        // We know statically that there are no other references to `self`, so
+        // there's no need to lock the inner mutex.
+        unsafe { &mut *self.value.get() }
+    }
+
+    // Releases a shared lock. Called by `RwLockReadGuard` when it is dropped.
+    fn unlock_read(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.readers -= 1;
+        grant(&mut state);
+    }
+
+    // Releases an exclusive lock. Called by `RwLockWriteGuard` when it is
+    // dropped.
+    fn unlock_write(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.writer = false;
+        grant(&mut state);
+    }
+}
+
+// Hands the lock to waiters in FIFO order: consecutive readers at the front
+// of the queue are granted together, while a writer is granted alone once all
+// readers are gone. Granted waiters stay queued until their future is polled
+// (or dropped).
+fn grant(state: &mut State) {
+    let mut readers = state.readers;
+    let mut writer = state.writer;
+    for waiter in &mut state.waiters {
+        if waiter.granted {
+            if waiter.write {
+                break;
+            }
+            continue;
+        }
+        if waiter.write {
+            if !writer && readers == 0 {
+                writer = true;
+                waiter.granted = true;
+                if let Some(waker) = waiter.waker.take() {
+                    waker.wake();
+                }
+            }
+            break;
+        }
+        if writer {
+            break;
+        }
+        readers += 1;
+        waiter.granted = true;
+        if let Some(waker) = waiter.waker.take() {
+            waker.wake();
+        }
+    }
+    state.readers = readers;
+    state.writer = writer;
+}
+
+// Removes the waiter for a future that completed or was dropped, undoing its
+// grant if it never yielded a guard.
+fn remove_waiter(state: &mut State, id: usize, undo_grant: bool) {
+    if let Some(pos) = state.waiters.iter().position(|waiter| waiter.id == id) {
+        let waiter = state.waiters.remove(pos).unwrap();
+        if undo_grant && waiter.granted {
+            if waiter.write {
+                state.writer = false;
+            } else {
+                state.readers -= 1;
+            }
+            grant(state);
+        }
+    }
+}
+
+/// A future which resolves when a shared lock has been successfully acquired.
+pub struct RwLockReadFuture<'a, T: ?Sized> {
+    // `None` indicates that the lock was successfully acquired.
+    rwlock: Option<&'a RwLock<T>>,
+    id: Option<usize>,
+}
+
+impl<T: ?Sized> fmt::Debug for RwLockReadFuture<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RwLockReadFuture").field("was_acquired", &self.rwlock.is_none()).finish()
+    }
+}
+
+impl<T: ?Sized> FusedFuture for RwLockReadFuture<'_, T> {
+    fn is_terminated(&self) -> bool {
+        self.rwlock.is_none()
+    }
+}
+
+impl<'a, T: ?Sized> Future for RwLockReadFuture<'a, T> {
+    type Output = RwLockReadGuard<'a, T>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let rwlock = this.rwlock.expect("polled RwLockReadFuture after completion");
+        let mut state = rwlock.state.lock().unwrap();
+
+        if let Some(id) = this.id {
+            let waiter = state
+                .waiters
+                .iter_mut()
+                .find(|waiter| waiter.id == id)
+                .expect("rwlock waiter disappeared");
+            if waiter.granted {
+                remove_waiter(&mut state, id, false);
+                drop(state);
+                this.rwlock = None;
+                return Poll::Ready(RwLockReadGuard { rwlock });
+            }
+            waiter.waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+
+        if !state.writer && state.waiters.is_empty() {
+            state.readers += 1;
+            drop(state);
+            this.rwlock = None;
+            return Poll::Ready(RwLockReadGuard { rwlock });
+        }
+
+        let id = state.next_id;
+        state.next_id += 1;
+        state.waiters.push_back(Waiter {
+            id,
+            write: false,
+            granted: false,
+            waker: Some(cx.waker().clone()),
+        });
+        this.id = Some(id);
+        Poll::Pending
+    }
+}
+
+impl<T: ?Sized> Drop for RwLockReadFuture<'_, T> {
+    fn drop(&mut self) {
+        if let (Some(rwlock), Some(id)) = (self.rwlock, self.id) {
+            // This future was dropped before it yielded a guard; if the lock
+            // had already been granted, pass it on.
+            let mut state = rwlock.state.lock().unwrap();
+            remove_waiter(&mut state, id, true);
+        }
+    }
+}
+
+/// A future which resolves when an exclusive lock has been successfully
+/// acquired.
+pub struct RwLockWriteFuture<'a, T: ?Sized> {
+    // `None` indicates that the lock was successfully acquired.
+    rwlock: Option<&'a RwLock<T>>,
+    id: Option<usize>,
+}
+
+impl<T: ?Sized> fmt::Debug for RwLockWriteFuture<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RwLockWriteFuture").field("was_acquired", &self.rwlock.is_none()).finish()
+    }
+}
+
+impl<T: ?Sized> FusedFuture for RwLockWriteFuture<'_, T> {
+    fn is_terminated(&self) -> bool {
+        self.rwlock.is_none()
+    }
+}
+
+impl<'a, T: ?Sized> Future for RwLockWriteFuture<'a, T> {
+    type Output = RwLockWriteGuard<'a, T>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let rwlock = this.rwlock.expect("polled RwLockWriteFuture after completion");
+        let mut state = rwlock.state.lock().unwrap();
+
+        if let Some(id) = this.id {
+            let waiter = state
+                .waiters
+                .iter_mut()
+                .find(|waiter| waiter.id == id)
+                .expect("rwlock waiter disappeared");
+            if waiter.granted {
+                remove_waiter(&mut state, id, false);
+                drop(state);
+                this.rwlock = None;
+                return Poll::Ready(RwLockWriteGuard { rwlock });
+            }
+            waiter.waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+
+        if !state.writer && state.readers == 0 && state.waiters.is_empty() {
+            state.writer = true;
+            drop(state);
+            this.rwlock = None;
+            return Poll::Ready(RwLockWriteGuard { rwlock });
+        }
+
+        let id = state.next_id;
+        state.next_id += 1;
+        state.waiters.push_back(Waiter {
+            id,
+            write: true,
+            granted: false,
+            waker: Some(cx.waker().clone()),
+        });
+        this.id = Some(id);
+        Poll::Pending
+    }
+}
+
+impl<T: ?Sized> Drop for RwLockWriteFuture<'_, T> {
+    fn drop(&mut self) {
+        if let (Some(rwlock), Some(id)) = (self.rwlock, self.id) {
+            // This future was dropped before it yielded a guard; if the lock
+            // had already been granted, pass it on.
+            let mut state = rwlock.state.lock().unwrap();
+            remove_waiter(&mut state, id, true);
+        }
+    }
+}
+
+/// An RAII guard returned by the `read` and `try_read` methods.
+/// When this structure is dropped (falls out of scope), the shared lock will
+/// be released.
+pub struct RwLockReadGuard<'a, T: ?Sized> {
+    rwlock: &'a RwLock<T>,
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for RwLockReadGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RwLockReadGuard")
+            .field("value", &&**self)
+            .field("rwlock", &self.rwlock)
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Drop for RwLockReadGuard<'_, T> {
+    fn drop(&mut self) {
+        self.rwlock.unlock_read()
+    }
+}
+
+impl<T: ?Sized> Deref for RwLockReadGuard<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        unsafe { &*self.rwlock.value.get() }
+    }
+}
+
+/// An RAII guard returned by the `write` and `try_write` methods.
+/// When this structure is dropped (falls out of scope), the exclusive lock
+/// will be released.
+pub struct RwLockWriteGuard<'a, T: ?Sized> {
+    rwlock: &'a RwLock<T>,
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for RwLockWriteGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RwLockWriteGuard")
+            .field("value", &&**self)
+            .field("rwlock", &self.rwlock)
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Drop for RwLockWriteGuard<'_, T> {
+    fn drop(&mut self) {
+        self.rwlock.unlock_write()
+    }
+}
+
+impl<T: ?Sized> Deref for RwLockWriteGuard<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        unsafe { &*self.rwlock.value.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for RwLockWriteGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.rwlock.value.get() }
+    }
+}
+
+// The lock can be moved freely between threads and acquired on any thread so
+// long as the inner value can be safely sent between threads; sharing it
+// additionally hands out `&T` to concurrent readers.
+unsafe impl<T: ?Sized + Send> Send for RwLock<T> {}
+unsafe impl<T: ?Sized + Send + Sync> Sync for RwLock<T> {}
+
+// It's safe to switch which thread the acquire is being attempted on so long
+// as the eventual guard can be used there.
+unsafe impl<T: ?Sized + Send + Sync> Send for RwLockReadFuture<'_, T> {}
+// doesn't have any interesting `&self` methods (only Debug)
+unsafe impl<T: ?Sized> Sync for RwLockReadFuture<'_, T> {}
+unsafe impl<T: ?Sized + Send + Sync> Send for RwLockWriteFuture<'_, T> {}
+// doesn't have any interesting `&self` methods (only Debug)
+unsafe impl<T: ?Sized> Sync for RwLockWriteFuture<'_, T> {}
+
+// The read guard only ever hands out `&T`; the write guard additionally
+// hands out `&mut T` from its owning thread.
+unsafe impl<T: ?Sized + Sync> Send for RwLockReadGuard<'_, T> {}
+unsafe impl<T: ?Sized + Sync> Sync for RwLockReadGuard<'_, T> {}
+unsafe impl<T: ?Sized + Send> Send for RwLockWriteGuard<'_, T> {}
+unsafe impl<T: ?Sized + Sync> Sync for RwLockWriteGuard<'_, T> {}
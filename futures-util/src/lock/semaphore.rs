@@ -0,0 +1,341 @@
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Context, Poll, Waker};
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::pin::Pin;
+use std::sync::Mutex as StdMutex;
+
+/// A futures-aware counting semaphore.
+///
+/// A semaphore starts out with a fixed number of permits. [`acquire`] waits
+/// for a permit to become available, and the permit is returned to the
+/// semaphore when the [`SemaphorePermit`] guard is dropped.
+///
+/// # Fairness
+///
+/// Waiters are served in FIFO order: a task waiting for many permits blocks
+/// later tasks waiting for fewer, so it cannot be starved by a steady stream
+/// of small acquisitions.
+///
+/// [`acquire`]: Semaphore::acquire
+pub struct Semaphore {
+    state: StdMutex<State>,
+    total: usize,
+}
+
+struct State {
+    permits: usize,
+    closed: bool,
+    next_id: usize,
+    waiters: VecDeque<Waiter>,
+}
+
+struct Waiter {
+    id: usize,
+    needed: usize,
+    granted: bool,
+    waker: Option<Waker>,
+}
+
+impl fmt::Debug for Semaphore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.state.lock().unwrap();
+        f.debug_struct("Semaphore")
+            .field("permits", &state.permits)
+            .field("closed", &state.closed)
+            .field("has_waiters", &!state.waiters.is_empty())
+            .finish()
+    }
+}
+
+impl Semaphore {
+    /// Creates a new semaphore with the given number of permits.
+    pub fn new(permits: usize) -> Self {
+        Self {
+            state: StdMutex::new(State {
+                permits,
+                closed: false,
+                next_id: 0,
+                waiters: VecDeque::new(),
+            }),
+            total: permits,
+        }
+    }
+
+    /// Returns the number of permits currently available.
+    pub fn available_permits(&self) -> usize {
+        self.state.lock().unwrap().permits
+    }
+
+    /// Attempts to acquire a single permit immediately.
+    ///
+    /// If no permit is available, or the semaphore has been closed, this
+    /// returns `None`.
+    pub fn try_acquire(&self) -> Option<SemaphorePermit<'_>> {
+        self.try_acquire_many(1)
+    }
+
+    /// Attempts to acquire `n` permits immediately.
+    ///
+    /// If fewer than `n` permits are available, or the semaphore has been
+    /// closed, this returns `None`.
+    pub fn try_acquire_many(&self, n: usize) -> Option<SemaphorePermit<'_>> {
+        let mut state = self.state.lock().unwrap();
+        if !state.closed && state.permits >= n {
+            state.permits -= n;
+            Some(SemaphorePermit { semaphore: self, permits: n })
+        } else {
+            None
+        }
+    }
+
+    /// Acquires a single permit asynchronously.
+    ///
+    /// This method returns a future that resolves to a permit once one
+    /// becomes available, or to an [`AcquireError`] if the semaphore is
+    /// closed in the meantime.
+    pub fn acquire(&self) -> SemaphoreAcquireFuture<'_> {
+        self.acquire_many(1)
+    }
+
+    /// Acquires `n` permits asynchronously.
+    ///
+    /// The permits are all released together when the returned guard is
+    /// dropped. Asking for more permits than the semaphore was created with
+    /// can never succeed, and fails immediately with an [`AcquireError`].
+    pub fn acquire_many(&self, n: usize) -> SemaphoreAcquireFuture<'_> {
+        SemaphoreAcquireFuture { semaphore: Some(self), needed: n, id: None }
+    }
+
+    /// Closes the semaphore.
+    ///
+    /// All pending and future acquisitions fail with an [`AcquireError`].
+    /// Permits that have already been handed out remain valid, and are still
+    /// returned on guard drop.
+    pub fn close(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.closed = true;
+        for waiter in &mut state.waiters {
+            if let Some(waker) = waiter.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+
+    // Returns `n` permits to the semaphore, handing them to waiters in FIFO
+    // order. Called by `SemaphorePermit` when it is dropped.
+    fn release(&self, n: usize) {
+        let mut state = self.state.lock().unwrap();
+        state.permits += n;
+        grant(&mut state);
+    }
+}
+
+// Hands available permits to waiters strictly in queue order. Waiters that
+// have already been granted their permits stay queued until their future is
+// polled (or dropped), but no longer count towards the available permits.
+fn grant(state: &mut State) {
+    // Once the semaphore is closed, every pending acquisition fails, so
+    // returned permits must not be handed to waiters: they would resolve
+    // `Ok` even though `close` already failed them. The permits simply
+    // accumulate.
+    if state.closed {
+        return;
+    }
+    let mut permits = state.permits;
+    for waiter in &mut state.waiters {
+        if waiter.granted {
+            continue;
+        }
+        if permits < waiter.needed {
+            break;
+        }
+        permits -= waiter.needed;
+        waiter.granted = true;
+        if let Some(waker) = waiter.waker.take() {
+            waker.wake();
+        }
+    }
+    state.permits = permits;
+}
+
+/// An error returned when permits can not be acquired from a [`Semaphore`].
+#[derive(Clone, PartialEq, Eq)]
+pub struct AcquireError {
+    kind: AcquireErrorKind,
+}
+
+#[derive(Clone, PartialEq, Eq)]
+enum AcquireErrorKind {
+    Closed,
+    TooManyPermits,
+}
+
+impl AcquireError {
+    /// Returns `true` if the acquisition failed because the semaphore was
+    /// closed.
+    pub fn is_closed(&self) -> bool {
+        self.kind == AcquireErrorKind::Closed
+    }
+
+    /// Returns `true` if more permits were requested than the semaphore was
+    /// created with.
+    pub fn is_too_many_permits(&self) -> bool {
+        self.kind == AcquireErrorKind::TooManyPermits
+    }
+}
+
+impl fmt::Debug for AcquireError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            AcquireErrorKind::Closed => write!(f, "AcquireError {{ kind: Closed }}"),
+            AcquireErrorKind::TooManyPermits => {
+                write!(f, "AcquireError {{ kind: TooManyPermits }}")
+            }
+        }
+    }
+}
+
+impl fmt::Display for AcquireError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            AcquireErrorKind::Closed => write!(f, "semaphore closed"),
+            AcquireErrorKind::TooManyPermits => {
+                write!(f, "requested more permits than the semaphore holds")
+            }
+        }
+    }
+}
+
+impl Error for AcquireError {}
+
+/// A future which resolves when the requested number of permits has been
+/// acquired.
+pub struct SemaphoreAcquireFuture<'a> {
+    // `None` indicates that the future has completed.
+    semaphore: Option<&'a Semaphore>,
+    needed: usize,
+    id: Option<usize>,
+}
+
+impl fmt::Debug for SemaphoreAcquireFuture<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SemaphoreAcquireFuture")
+            .field("was_acquired", &self.semaphore.is_none())
+            .field("needed", &self.needed)
+            .finish()
+    }
+}
+
+impl FusedFuture for SemaphoreAcquireFuture<'_> {
+    fn is_terminated(&self) -> bool {
+        self.semaphore.is_none()
+    }
+}
+
+impl<'a> Future for SemaphoreAcquireFuture<'a> {
+    type Output = Result<SemaphorePermit<'a>, AcquireError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let semaphore = this.semaphore.expect("polled SemaphoreAcquireFuture after completion");
+        let mut state = semaphore.state.lock().unwrap();
+
+        if let Some(id) = this.id {
+            let pos = state
+                .waiters
+                .iter()
+                .position(|waiter| waiter.id == id)
+                .expect("semaphore waiter disappeared");
+            if state.waiters[pos].granted {
+                state.waiters.remove(pos);
+                drop(state);
+                this.semaphore = None;
+                return Poll::Ready(Ok(SemaphorePermit { semaphore, permits: this.needed }));
+            }
+            if state.closed {
+                state.waiters.remove(pos);
+                drop(state);
+                this.semaphore = None;
+                return Poll::Ready(Err(AcquireError { kind: AcquireErrorKind::Closed }));
+            }
+            state.waiters[pos].waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+
+        if state.closed {
+            drop(state);
+            this.semaphore = None;
+            return Poll::Ready(Err(AcquireError { kind: AcquireErrorKind::Closed }));
+        }
+        if this.needed > semaphore.total {
+            drop(state);
+            this.semaphore = None;
+            return Poll::Ready(Err(AcquireError { kind: AcquireErrorKind::TooManyPermits }));
+        }
+        // Only take permits directly when no one is queued ahead, so that
+        // acquisitions are granted in FIFO order.
+        if state.waiters.is_empty() && state.permits >= this.needed {
+            state.permits -= this.needed;
+            drop(state);
+            this.semaphore = None;
+            return Poll::Ready(Ok(SemaphorePermit { semaphore, permits: this.needed }));
+        }
+
+        let id = state.next_id;
+        state.next_id += 1;
+        state.waiters.push_back(Waiter {
+            id,
+            needed: this.needed,
+            granted: false,
+            waker: Some(cx.waker().clone()),
+        });
+        this.id = Some(id);
+        Poll::Pending
+    }
+}
+
+impl Drop for SemaphoreAcquireFuture<'_> {
+    fn drop(&mut self) {
+        if let (Some(semaphore), Some(id)) = (self.semaphore, self.id) {
+            // This future was dropped before it yielded its permits. If they
+            // had already been granted, pass them on to the next waiters.
+            let mut state = semaphore.state.lock().unwrap();
+            if let Some(pos) = state.waiters.iter().position(|waiter| waiter.id == id) {
+                let waiter = state.waiters.remove(pos).unwrap();
+                if waiter.granted {
+                    state.permits += waiter.needed;
+                    grant(&mut state);
+                }
+            }
+        }
+    }
+}
+
+/// An RAII guard returned by [`acquire`](Semaphore::acquire) and friends.
+/// When this structure is dropped (falls out of scope), the permits are
+/// returned to the semaphore.
+pub struct SemaphorePermit<'a> {
+    semaphore: &'a Semaphore,
+    permits: usize,
+}
+
+impl SemaphorePermit<'_> {
+    /// Returns the number of permits held by this guard.
+    pub fn permits(&self) -> usize {
+        self.permits
+    }
+}
+
+impl fmt::Debug for SemaphorePermit<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SemaphorePermit").field("permits", &self.permits).finish()
+    }
+}
+
+impl Drop for SemaphorePermit<'_> {
+    fn drop(&mut self) {
+        self.semaphore.release(self.permits);
+    }
+}
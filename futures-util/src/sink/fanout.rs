@@ -9,6 +9,12 @@ pin_project! {
     ///
     /// Backpressure from any downstream sink propagates up, which means that this sink
     /// can only process items as fast as its _slowest_ downstream sink.
+    ///
+    /// Errors from either sink are surfaced as soon as they are observed. The
+    /// first sink is always driven before the second, so if the first sink
+    /// fails in `start_send` the item is not delivered to the second sink,
+    /// while an error from the second sink leaves the first sink's copy of
+    /// the item in flight.
     #[must_use = "sinks do nothing unless polled"]
     pub struct Fanout<Si1, Si2> {
         #[pin]
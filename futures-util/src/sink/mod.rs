@@ -44,6 +44,9 @@ pub use self::send::Send;
 mod send_all;
 pub use self::send_all::SendAll;
 
+mod send_all_buffered;
+pub use self::send_all_buffered::SendAllBuffered;
+
 mod unfold;
 pub use self::unfold::{unfold, Unfold};
 
@@ -257,6 +260,29 @@ pub trait SinkExt<Item>: Sink<Item> {
         assert_future::<Result<(), Self::Error>, _>(SendAll::new(self, stream))
     }
 
+    /// A future that completes after the given stream has been fully processed
+    /// into the sink, flushing in batches rather than after every item.
+    ///
+    /// As long as the stream keeps producing items and the sink keeps
+    /// accepting them, items are handed to the sink without flushing in
+    /// between. The sink is only flushed when no further progress can be
+    /// made: when the sink reports that it is not ready, when the stream has
+    /// no item available yet, and once more after the stream is exhausted.
+    /// This batching can significantly reduce the number of flushes for
+    /// sinks where flushing is expensive, such as buffered IO sinks.
+    ///
+    /// Like [`send_all`](SinkExt::send_all), the returned future completes
+    /// once the stream is exhausted and the sink has been flushed, without
+    /// closing the sink. If the stream produces an error, that error is
+    /// returned without flushing the sink.
+    fn send_all_buffered<St>(&mut self, stream: St) -> SendAllBuffered<'_, Self, St>
+    where
+        St: Stream<Item = Result<Item, Self::Error>>,
+        Self: Unpin,
+    {
+        assert_future::<Result<(), Self::Error>, _>(SendAllBuffered::new(self, stream))
+    }
+
     /// Wrap this sink in an `Either` sink, making it the left-hand variant
     /// of that `Either`.
     ///
@@ -0,0 +1,110 @@
+use crate::stream::{Fuse, StreamExt};
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::stream::{Stream, TryStream};
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the [`send_all_buffered`](super::SinkExt::send_all_buffered) method.
+    #[allow(explicit_outlives_requirements)] // https://github.com/rust-lang/rust/issues/60993
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct SendAllBuffered<'a, Si, St>
+    where
+        Si: ?Sized,
+        St: TryStream,
+    {
+        sink: &'a mut Si,
+        #[pin]
+        stream: Fuse<St>,
+        buffered: Option<St::Ok>,
+    }
+}
+
+impl<Si, St> fmt::Debug for SendAllBuffered<'_, Si, St>
+where
+    Si: fmt::Debug + ?Sized,
+    St: fmt::Debug + TryStream,
+    St::Ok: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SendAllBuffered")
+            .field("sink", &self.sink)
+            .field("stream", &self.stream)
+            .field("buffered", &self.buffered)
+            .finish()
+    }
+}
+
+impl<'a, Si, St, Ok, Error> SendAllBuffered<'a, Si, St>
+where
+    Si: Sink<Ok, Error = Error> + Unpin + ?Sized,
+    St: TryStream<Ok = Ok, Error = Error> + Stream,
+{
+    pub(super) fn new(sink: &'a mut Si, stream: St) -> Self {
+        Self { sink, stream: stream.fuse(), buffered: None }
+    }
+}
+
+impl<Si, St, Ok, Error> Future for SendAllBuffered<'_, Si, St>
+where
+    Si: Sink<Ok, Error = Error> + Unpin + ?Sized,
+    St: Stream<Item = Result<Ok, Error>>,
+{
+    type Output = Result<(), Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        loop {
+            let this = self.as_mut().project();
+
+            // If the sink stalled mid-batch, an item is parked here. The sink
+            // has to accept it before anything else can happen.
+            if this.buffered.is_some() {
+                let mut ready = Pin::new(&mut *this.sink).poll_ready(cx)?;
+                if ready.is_pending() {
+                    // Flushing the batch is usually what makes room in the
+                    // sink, so flush and give `poll_ready` another chance.
+                    ready!(Pin::new(&mut *this.sink).poll_flush(cx))?;
+                    ready = Pin::new(&mut *this.sink).poll_ready(cx)?;
+                }
+                match ready {
+                    Poll::Ready(()) => {
+                        let item = this.buffered.take().unwrap();
+                        Pin::new(&mut *this.sink).start_send(item)?;
+                    }
+                    Poll::Pending => return Poll::Pending,
+                }
+                continue;
+            }
+
+            match this.stream.try_poll_next(cx)? {
+                Poll::Ready(Some(item)) => {
+                    // Batch phase: hand items over without flushing, as long
+                    // as both the source and the sink keep up.
+                    match Pin::new(&mut *this.sink).poll_ready(cx)? {
+                        Poll::Ready(()) => Pin::new(&mut *this.sink).start_send(item)?,
+                        Poll::Pending => {
+                            // Park the item; the branch above flushes and
+                            // retries before yielding.
+                            *this.buffered = Some(item);
+                        }
+                    }
+                }
+                Poll::Ready(None) => {
+                    // Source exhausted: flush the batch once and finish.
+                    ready!(Pin::new(&mut *this.sink).poll_flush(cx))?;
+                    return Poll::Ready(Ok(()));
+                }
+                Poll::Pending => {
+                    // No more items available right now: flush what we have
+                    // batched so far instead of sitting on it.
+                    ready!(Pin::new(&mut *this.sink).poll_flush(cx))?;
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+
+/// A stream able to yield items from both ends, analogously to
+/// [`DoubleEndedIterator`].
+///
+/// Both ends consume the same shared sequence: once the front and the back
+/// meet, the stream is exhausted and each end returns `None` without ever
+/// yielding an item twice.
+pub trait DoubleEndedStream: Stream {
+    /// Attempt to pull out the next value from the back of this stream,
+    /// registering the current task for wakeup if the value is not yet
+    /// available, and returning `None` if the stream is exhausted.
+    fn poll_next_back(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>>;
+
+    /// Creates a future that resolves to the next item from the back of the
+    /// stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, DoubleEndedStream};
+    ///
+    /// let mut stream = stream::iter(1..=3);
+    ///
+    /// assert_eq!(stream.next_back().await, Some(3));
+    /// assert_eq!(stream.next_back().await, Some(2));
+    /// assert_eq!(stream.next_back().await, Some(1));
+    /// assert_eq!(stream.next_back().await, None);
+    /// # });
+    /// ```
+    fn next_back(&mut self) -> NextBack<'_, Self>
+    where
+        Self: Unpin,
+    {
+        NextBack::new(self)
+    }
+}
+
+impl<S: ?Sized + DoubleEndedStream + Unpin> DoubleEndedStream for &mut S {
+    fn poll_next_back(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+        Pin::new(&mut **self).poll_next_back(cx)
+    }
+}
+
+impl<S: ?Sized + DoubleEndedStream> DoubleEndedStream for Pin<&mut S> {
+    fn poll_next_back(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+        S::poll_next_back((*self).as_mut(), cx)
+    }
+}
+
+/// Future for the [`next_back`](DoubleEndedStream::next_back) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct NextBack<'a, St: ?Sized> {
+    stream: &'a mut St,
+}
+
+impl<St: ?Sized + Unpin> Unpin for NextBack<'_, St> {}
+
+impl<'a, St: ?Sized + DoubleEndedStream + Unpin> NextBack<'a, St> {
+    pub(super) fn new(stream: &'a mut St) -> Self {
+        Self { stream }
+    }
+}
+
+impl<St: ?Sized + DoubleEndedStream + FusedStream + Unpin> FusedFuture for NextBack<'_, St> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St: ?Sized + DoubleEndedStream + Unpin> Future for NextBack<'_, St> {
+    type Output = Option<St::Item>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut *self.stream).poll_next_back(cx)
+    }
+}
@@ -19,7 +19,9 @@ pin_project! {
     struct OrderWrapper<T> {
         #[pin]
         data: T, // A future or a future's output
-        index: usize,
+        // Use a signed index to make `push_front` possible, which needs an
+        // index smaller than all existing ones.
+        index: isize,
     }
 }
 
@@ -95,8 +97,8 @@ where
 pub struct FuturesOrdered<T: Future> {
     in_progress_queue: FuturesUnordered<OrderWrapper<T>>,
     queued_outputs: BinaryHeap<OrderWrapper<T::Output>>,
-    next_incoming_index: usize,
-    next_outgoing_index: usize,
+    next_incoming_index: isize,
+    next_outgoing_index: isize,
 }
 
 impl<T: Future> Unpin for FuturesOrdered<T> {}
@@ -131,15 +133,35 @@ impl<Fut: Future> FuturesOrdered<Fut> {
 
     /// Push a future into the queue.
     ///
+    /// This method is an alias for [`push_back`](Self::push_back).
+    pub fn push(&mut self, future: Fut) {
+        self.push_back(future)
+    }
+
+    /// Pushes a future to the back of the queue.
+    ///
     /// This function submits the given future to the internal set for managing.
     /// This function will not call `poll` on the submitted future. The caller
     /// must ensure that `FuturesOrdered::poll` is called in order to receive
     /// task notifications.
-    pub fn push(&mut self, future: Fut) {
+    pub fn push_back(&mut self, future: Fut) {
         let wrapped = OrderWrapper { data: future, index: self.next_incoming_index };
         self.next_incoming_index += 1;
         self.in_progress_queue.push(wrapped);
     }
+
+    /// Pushes a future to the front of the queue.
+    ///
+    /// This function submits the given future to the internal set for
+    /// managing. This function will not call `poll` on the submitted future.
+    /// The caller must ensure that `FuturesOrdered::poll` is called in order
+    /// to receive task notifications. This future will be the next future to
+    /// be returned complete.
+    pub fn push_front(&mut self, future: Fut) {
+        let wrapped = OrderWrapper { data: future, index: self.next_outgoing_index - 1 };
+        self.next_outgoing_index -= 1;
+        self.in_progress_queue.push(wrapped);
+    }
 }
 
 impl<Fut: Future> Default for FuturesOrdered<Fut> {
@@ -4,6 +4,7 @@
 //! library is activated, and it is activated by default.
 
 use crate::task::AtomicWaker;
+use alloc::collections::VecDeque;
 use alloc::sync::{Arc, Weak};
 use core::cell::UnsafeCell;
 use core::cmp;
@@ -14,7 +15,7 @@ use core::mem;
 use core::pin::Pin;
 use core::ptr;
 use core::sync::atomic::Ordering::{AcqRel, Acquire, Relaxed, Release, SeqCst};
-use core::sync::atomic::{AtomicBool, AtomicPtr};
+use core::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize};
 use futures_core::future::Future;
 use futures_core::stream::{FusedStream, Stream};
 use futures_core::task::{Context, Poll};
@@ -83,6 +84,22 @@ pub struct FuturesUnordered<Fut> {
     ready_to_run_queue: Arc<ReadyToRunQueue<Fut>>,
     head_all: AtomicPtr<Task<Fut>>,
     is_terminated: AtomicBool,
+    // Maximum number of futures that may be polled concurrently, or zero if
+    // the set is unbounded.
+    max_concurrent: usize,
+    // Number of futures that have been polled at least once and have not yet
+    // completed. Only accessed by the thread that owns the set.
+    active_count: usize,
+    // Tasks that were dequeued from the ready to run queue but held back by
+    // the concurrency limit. They are still linked into `head_all` and their
+    // `queued` flag is still set, so they are re-enqueued rather than
+    // re-woken once a slot frees up. Only accessed by the thread that owns
+    // the set.
+    dormant: VecDeque<*const Task<Fut>>,
+    // Number of times `poll` has been called on futures in the set.
+    poll_count: AtomicUsize,
+    // The largest number of futures the set has contained so far.
+    peak_len: AtomicUsize,
 }
 
 unsafe impl<Fut: Send> Send for FuturesUnordered<Fut> {}
@@ -148,6 +165,7 @@ impl<Fut> FuturesUnordered<Fut> {
             len_all: UnsafeCell::new(0),
             next_ready_to_run: AtomicPtr::new(ptr::null_mut()),
             queued: AtomicBool::new(true),
+            started: AtomicBool::new(false),
             ready_to_run_queue: Weak::new(),
         });
         let stub_ptr = &*stub as *const Task<Fut>;
@@ -162,6 +180,82 @@ impl<Fut> FuturesUnordered<Fut> {
             head_all: AtomicPtr::new(ptr::null_mut()),
             ready_to_run_queue,
             is_terminated: AtomicBool::new(false),
+            max_concurrent: 0,
+            active_count: 0,
+            dormant: VecDeque::new(),
+            poll_count: AtomicUsize::new(0),
+            peak_len: AtomicUsize::new(0),
+        }
+    }
+
+    /// Constructs a new, empty [`FuturesUnordered`] which will poll at most
+    /// `limit` futures concurrently.
+    ///
+    /// Futures beyond the limit are held back and will not be polled for the
+    /// first time until one of the actively polled futures completes or is
+    /// removed from the set. A `limit` of zero means no limit, making this
+    /// equivalent to [`FuturesUnordered::new`].
+    pub fn with_max_concurrent(limit: usize) -> Self {
+        let mut this = Self::new();
+        this.max_concurrent = limit;
+        this
+    }
+
+    /// Returns the maximum number of futures that may be polled concurrently,
+    /// or zero if the set is unbounded.
+    pub fn max_concurrent(&self) -> usize {
+        self.max_concurrent
+    }
+
+    /// Changes the maximum number of futures that may be polled concurrently.
+    ///
+    /// A `limit` of zero removes the limit. Raising or removing the limit
+    /// schedules held-back futures for polling as slots become available.
+    /// Lowering the limit does not affect futures that have already been
+    /// polled; they are allowed to finish, and no new futures are started
+    /// until the number of active futures drops below the new limit.
+    pub fn set_max_concurrent(&mut self, limit: usize) {
+        self.max_concurrent = limit;
+        self.promote_dormant();
+    }
+
+    /// Moves futures that were held back by the concurrency limit into the
+    /// ready to run queue, up to the number of free slots.
+    ///
+    /// Tasks in `dormant` are still linked into `head_all` and their `queued`
+    /// flag is still set from the dequeue that deferred them, so the ready to
+    /// run queue can simply take them back.
+    fn promote_dormant(&mut self) {
+        let mut free = if self.max_concurrent == 0 {
+            usize::max_value()
+        } else {
+            self.max_concurrent.saturating_sub(self.active_count)
+        };
+        let mut promoted = false;
+        while free > 0 {
+            let task = match self.dormant.pop_front() {
+                Some(task) => task,
+                None => break,
+            };
+            // A task removed from the set while dormant (e.g. by `retain`)
+            // has had its future released already. Discard it here instead of
+            // spending a free slot on it, which would leave a live dormant
+            // future behind it unstarted.
+            //
+            // Safety: we have `&mut self`, so no other thread is accessing
+            // the `UnsafeCell` that contains the future.
+            if unsafe { (*(*task).future.get()).is_none() } {
+                drop(unsafe { Arc::from_raw(task) });
+                continue;
+            }
+            self.ready_to_run_queue.enqueue(task);
+            free -= 1;
+            promoted = true;
+        }
+        if promoted {
+            // Notify the task using this set so the promoted futures get
+            // polled even if no other wake-up is pending.
+            self.ready_to_run_queue.waker.wake();
         }
     }
 
@@ -173,6 +267,40 @@ impl<Fut> FuturesUnordered<Fut> {
         len
     }
 
+    /// Returns the number of times futures in this set have been polled.
+    ///
+    /// The counter covers every call to [`poll`](core::future::Future::poll)
+    /// on the managed futures, including polls that returned
+    /// [`Poll::Pending`], and is never reset. It is tracked with relaxed
+    /// atomics and is therefore cheap, but a snapshot taken while other
+    /// threads are pushing futures may be momentarily out of date.
+    pub fn poll_count(&self) -> usize {
+        self.poll_count.load(Relaxed)
+    }
+
+    /// Returns the largest number of futures this set has contained at any
+    /// point so far.
+    ///
+    /// The value is monotonic: it never decreases as futures complete or are
+    /// removed from the set.
+    pub fn peak_len(&self) -> usize {
+        self.peak_len.load(Relaxed)
+    }
+
+    /// Records a new list length, raising the peak if it has been exceeded.
+    fn update_peak_len(&self, len: usize) {
+        // `fetch_max` stabilized in Rust 1.45, but `.clippy.toml` still pins
+        // the MSRV at 1.36, so emulate it to keep `incompatible_msrv` quiet.
+        // The loop keeps the peak monotonic under concurrent pushes.
+        let mut peak = self.peak_len.load(Relaxed);
+        while len > peak {
+            match self.peak_len.compare_exchange_weak(peak, len, Relaxed, Relaxed) {
+                Ok(_) => break,
+                Err(actual) => peak = actual,
+            }
+        }
+    }
+
     /// Returns `true` if the set contains no futures.
     pub fn is_empty(&self) -> bool {
         // Relaxed ordering can be used here since we don't need to read from
@@ -194,6 +322,7 @@ impl<Fut> FuturesUnordered<Fut> {
             len_all: UnsafeCell::new(0),
             next_ready_to_run: AtomicPtr::new(ptr::null_mut()),
             queued: AtomicBool::new(true),
+            started: AtomicBool::new(false),
             ready_to_run_queue: Arc::downgrade(&self.ready_to_run_queue),
         });
 
@@ -206,6 +335,11 @@ impl<Fut> FuturesUnordered<Fut> {
         // and we'll reclaim ownership through the `unlink` method below.
         let ptr = self.link(task);
 
+        // Safety: `link` wrote the new list length to the node it returned,
+        // and no other thread mutates `len_all` on that node until the set's
+        // owner unlinks it.
+        self.update_peak_len(unsafe { *(*ptr).len_all.get() });
+
         // We'll need to get the future "into the system" to start tracking it,
         // e.g. getting its wake-up notifications going to us tracking which
         // futures are ready. To do that we unconditionally enqueue it for
@@ -274,6 +408,12 @@ impl<Fut> FuturesUnordered<Fut> {
             debug_assert!((*task.prev_all.get()).is_null());
         }
 
+        // If the future was polled at least once it occupied one of the
+        // concurrency slots; give that slot back.
+        if task.started.load(Relaxed) {
+            self.active_count -= 1;
+        }
+
         // The future is done, try to reset the queued flag. This will prevent
         // `wake` from doing any work in the future
         let prev = task.queued.swap(true, SeqCst);
@@ -473,10 +613,29 @@ impl<Fut: Future> Stream for FuturesUnordered<Fut> {
                     unsafe {
                         debug_assert!((*task.prev_all.get()).is_null());
                     }
+
+                    // Releasing the task may have freed a concurrency slot
+                    // since the last promotion; give it to a held-back future
+                    // rather than only promoting on the completion path.
+                    self.promote_dormant();
                     continue;
                 }
             };
 
+            // Enforce the concurrency limit, if any: futures that have not
+            // been polled yet stay dormant while the limit is reached. They
+            // remain linked in `head_all` with their `queued` flag set, and
+            // are re-enqueued by `promote_dormant` once a slot frees up.
+            let started = unsafe { (*task).started.load(Relaxed) };
+            if !started {
+                if self.max_concurrent != 0 && self.active_count >= self.max_concurrent {
+                    self.dormant.push_back(task);
+                    continue;
+                }
+                unsafe { (*task).started.store(true, Relaxed) };
+                self.active_count += 1;
+            }
+
             // Safety: `task` is a valid pointer
             let task = unsafe { self.unlink(task) };
 
@@ -509,6 +668,9 @@ impl<Fut: Future> Stream for FuturesUnordered<Fut> {
                 fn drop(&mut self) {
                     if let Some(task) = self.task.take() {
                         self.queue.release_task(task);
+                        // The released future freed a concurrency slot; let a
+                        // held-back future take it.
+                        self.queue.promote_dormant();
                     }
                 }
             }
@@ -536,6 +698,7 @@ impl<Fut: Future> Stream for FuturesUnordered<Fut> {
                 future.poll(&mut cx)
             };
             polled += 1;
+            bomb.queue.poll_count.fetch_add(1, Relaxed);
 
             match res {
                 Poll::Pending => {
@@ -569,9 +732,80 @@ impl<Fut> Debug for FuturesUnordered<Fut> {
 }
 
 impl<Fut> FuturesUnordered<Fut> {
+    /// Retains only the futures specified by the predicate.
+    ///
+    /// In other words, remove all futures `fut` for which `f(fut)` returns
+    /// `false`. Removed futures are dropped immediately; any wake-up
+    /// notifications they have already scheduled are cleaned out lazily by
+    /// later calls to [`poll_next`](Stream::poll_next).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::future;
+    /// use futures::stream::FuturesUnordered;
+    ///
+    /// let mut futures = (0..10).map(future::ready).collect::<FuturesUnordered<_>>();
+    /// assert_eq!(futures.len(), 10);
+    ///
+    /// let mut i = 0;
+    /// futures.retain(|_| {
+    ///     i += 1;
+    ///     i % 2 == 0
+    /// });
+    /// assert_eq!(futures.len(), 5);
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(Pin<&mut Fut>) -> bool,
+    {
+        let mut cur = *self.head_all.get_mut();
+
+        while !cur.is_null() {
+            // Read the next pointer before a potential unlink resets it to
+            // the pending state. We have exclusive access to the set, so
+            // `next_all` is stable and no spinning is needed.
+            let next = unsafe { (*cur).next_all.load(Relaxed) };
+
+            // Safety: `cur` is a valid pointer and we are the only thread
+            // accessing the `UnsafeCell` that contains the future. Futures in
+            // the set are never moved, so pinning is preserved.
+            let keep = match unsafe { &mut *(*cur).future.get() } {
+                Some(future) => f(unsafe { Pin::new_unchecked(future) }),
+                // Already-released tasks are not linked into `head_all`, but
+                // keep this defensive arm rather than assuming.
+                None => true,
+            };
+
+            if !keep {
+                // Safety: `cur` is a valid, linked task pointer.
+                let task = unsafe { self.unlink(cur) };
+                self.release_task(task);
+
+                // If the task was held back by the concurrency limit, its
+                // pointer is also stored in `dormant` and now owns the
+                // reference count `release_task` transferred to the `queued`
+                // holder. Purge it so `promote_dormant` does not later spend
+                // a free slot on a future that no longer exists.
+                if let Some(pos) = self.dormant.iter().position(|&t| ptr::eq(t, cur)) {
+                    self.dormant.remove(pos);
+                    // Safety: the pointer in `dormant` owns a reference.
+                    drop(unsafe { Arc::from_raw(cur) });
+                }
+            }
+
+            cur = next;
+        }
+
+        // Removed futures may have freed concurrency slots.
+        self.promote_dormant();
+    }
+
     /// Clears the set, removing all futures.
     pub fn clear(&mut self) {
         self.clear_head_all();
+        self.clear_dormant();
+        self.active_count = 0;
 
         // we just cleared all the tasks, and we have &mut self, so this is safe.
         unsafe { self.ready_to_run_queue.clear() };
@@ -579,6 +813,15 @@ impl<Fut> FuturesUnordered<Fut> {
         self.is_terminated.store(false, Relaxed);
     }
 
+    fn clear_dormant(&mut self) {
+        // Tasks that were held back by the concurrency limit are not in the
+        // ready to run queue. `clear_head_all` transferred their reference
+        // counts to us (their `queued` flag was still set), so drop them here.
+        while let Some(task) = self.dormant.pop_front() {
+            drop(unsafe { Arc::from_raw(task) });
+        }
+    }
+
     fn clear_head_all(&mut self) {
         while !self.head_all.get_mut().is_null() {
             let head = *self.head_all.get_mut();
@@ -595,6 +838,7 @@ impl<Fut> Drop for FuturesUnordered<Fut> {
         // wakers flying around which contain `Task<Fut>` references
         // inside them. We'll let those naturally get deallocated.
         self.clear_head_all();
+        self.clear_dormant();
 
         // Note that at this point we could still have a bunch of tasks in the
         // ready to run queue. None of those tasks, however, have futures
@@ -31,6 +31,11 @@ pub(super) struct Task<Fut> {
 
     // Whether or not this task is currently in the ready to run queue
     pub(super) queued: AtomicBool,
+
+    // Whether or not the future in this task has been polled at least once.
+    // Only accessed by the thread that owns the `FuturesUnordered`, and used
+    // to enforce an optional concurrency limit.
+    pub(super) started: AtomicBool,
 }
 
 // `Task` can be sent across threads safely because it ensures that
@@ -1,4 +1,5 @@
 use super::assert_stream;
+use crate::stream::DoubleEndedStream;
 use core::pin::Pin;
 use futures_core::stream::Stream;
 use futures_core::task::{Context, Poll};
@@ -47,3 +48,12 @@ where
         self.iter.size_hint()
     }
 }
+
+impl<I> DoubleEndedStream for Iter<I>
+where
+    I: DoubleEndedIterator,
+{
+    fn poll_next_back(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<I::Item>> {
+        Poll::Ready(self.iter.next_back())
+    }
+}
@@ -15,31 +15,71 @@ pub use futures_core::stream::{FusedStream, Stream, TryStream};
 
 // Extension traits and combinators
 
+mod double_ended;
+pub use self::double_ended::{DoubleEndedStream, NextBack};
+
 #[allow(clippy::module_inception)]
 mod stream;
 pub use self::stream::{
-    Chain, Collect, Concat, Cycle, Enumerate, Filter, FilterMap, FlatMap, Flatten, Fold, ForEach,
-    Fuse, Inspect, Map, Next, NextIf, NextIfEq, Peek, PeekMut, Peekable, Scan, SelectNextSome,
-    Skip, SkipWhile, StreamExt, StreamFuture, SwitchMap, Take, TakeUntil, TakeWhile, Then, TryFold,
-    TryForEach, Unzip, Zip,
+    Chain, Coalesce, Collect, Concat, Cycle, CycleN, Debounce, Dedup, DedupBy, DedupByKey,
+    EitherOrBoth, Enumerate, Filter, FilterMap, Find, FindMap, FindPosition, FlatMap, Flatten,
+    Fold, FoldStep, FoldWhile, ForEach, Fuse, Inspect, InspectDone, Interleave, Intersperse,
+    IntersperseWith, Last, Map, MaxByKey, Merge, MinByKey, Next, NextIf, NextIfEq, Nth, Partition,
+    Peek, PeekMut, Peekable, Position, Product, Rev, Sample, Scan, SelectNextSome, Skip, SkipWhile,
+    StepBy, StreamExt, StreamFuture, Sum, SwitchMap, Take, TakeUntil, TakeUntilRemainder,
+    TakeWhile, Then, Throttle, Timeout, TryFold, TryForEach, Unzip, WithPosition, Zip, ZipLongest,
 };
 
 #[cfg(feature = "std")]
 pub use self::stream::CatchUnwind;
 
+#[cfg(feature = "std")]
+pub use self::stream::RoundRobin;
+
+#[cfg(feature = "std")]
+pub use self::stream::{Counts, CountsBy};
+
+#[cfg(feature = "std")]
+pub use self::stream::{Unique, UniqueBy};
+
+#[cfg(feature = "std")]
+pub use self::stream::{GroupBy, GroupStream};
+
 #[cfg(feature = "alloc")]
 pub use self::stream::Chunks;
 
+#[cfg(feature = "alloc")]
+pub use self::stream::ChunksBy;
+
+#[cfg(feature = "alloc")]
+pub use self::stream::ChunksTimeout;
+
+#[cfg(feature = "alloc")]
+pub use self::stream::PeekN;
+
 #[cfg(feature = "alloc")]
 pub use self::stream::ReadyChunks;
 
+#[cfg(feature = "alloc")]
+pub use self::stream::Reverse;
+
+#[cfg(feature = "alloc")]
+pub use self::stream::{Sorted, SortedBy};
+
 #[cfg(feature = "sink")]
 #[cfg_attr(docsrs, doc(cfg(feature = "sink")))]
-pub use self::stream::Forward;
+pub use self::stream::{Forward, UnzipInto};
 
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
-pub use self::stream::{BufferUnordered, Buffered, ForEachConcurrent, TryForEachConcurrent};
+pub use self::stream::{
+    BufferUnordered, BufferUnorderedWeighted, Buffered, FlatMapUnordered, FlattenUnordered,
+    ForEachConcurrent, RateLimit, TryForEachConcurrent,
+};
+
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "std")]
+pub use self::stream::BufferedByKey;
 
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "sink")]
@@ -50,8 +90,8 @@ pub use self::stream::{ReuniteError, SplitSink, SplitStream};
 mod try_stream;
 pub use self::try_stream::{
     try_unfold, AndThen, ErrInto, InspectErr, InspectOk, IntoStream, MapErr, MapOk, OrElse,
-    TryCollect, TryConcat, TryFilter, TryFilterMap, TryFlatten, TryNext, TrySkipWhile,
-    TryStreamExt, TryTakeWhile, TryUnfold,
+    TryCollect, TryCollectInto, TryConcat, TryFilter, TryFilterMap, TryFlatten, TryNext,
+    TrySkipWhile, TryStreamExt, TryTakeWhile, TryUnfold,
 };
 
 #[cfg(feature = "io")]
@@ -61,7 +101,7 @@ pub use self::try_stream::IntoAsyncRead;
 
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
-pub use self::try_stream::{TryBufferUnordered, TryBuffered};
+pub use self::try_stream::{TryBufferUnordered, TryBuffered, TryFlattenUnordered};
 
 #[cfg(feature = "sink")]
 #[cfg_attr(docsrs, doc(cfg(feature = "sink")))]
@@ -70,6 +110,12 @@ pub use self::try_stream::TryForward;
 #[cfg(feature = "alloc")]
 pub use self::try_stream::{TryChunks, TryChunksError};
 
+#[cfg(feature = "alloc")]
+pub use self::try_stream::{TryChunksTimeout, TryChunksTimeoutError};
+
+#[cfg(feature = "alloc")]
+pub use self::try_stream::{TryReadyChunks, TryReadyChunksError};
+
 // Primitive streams
 
 mod iter;
@@ -105,6 +151,9 @@ pub use self::select_with_strategy::{select_with_strategy, PollNext, SelectWithS
 mod unfold;
 pub use self::unfold::{unfold, Unfold};
 
+mod unfold_with_state;
+pub use self::unfold_with_state::{unfold_with_state, StatefulUnfold};
+
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
 mod futures_ordered;
@@ -133,7 +182,7 @@ pub use self::select_all::{select_all, SelectAll};
 mod abortable;
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
-pub use crate::abortable::{AbortHandle, AbortRegistration, Abortable, Aborted};
+pub use crate::abortable::{AbortHandle, AbortRegistration, Abortable, Aborted, DeadlineAbortable};
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
 pub use abortable::abortable;
@@ -0,0 +1,172 @@
+use crate::stream::{Fuse, FuturesUnordered, StreamExt};
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the
+    /// [`buffer_unordered_weighted`](super::StreamExt::buffer_unordered_weighted)
+    /// method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct BufferUnorderedWeighted<St, W>
+    where
+        St: Stream,
+    {
+        #[pin]
+        stream: Fuse<St>,
+        in_progress_queue: FuturesUnordered<WeightedFuture<St::Item>>,
+        pending: Option<(St::Item, usize)>,
+        weight_fn: W,
+        capacity: usize,
+        in_flight: usize,
+    }
+}
+
+pin_project! {
+    /// A future tagged with its weight, so that the weight can be released
+    /// once the output arrives.
+    struct WeightedFuture<Fut> {
+        #[pin]
+        future: Fut,
+        weight: usize,
+    }
+}
+
+impl<Fut: Future> Future for WeightedFuture<Fut> {
+    type Output = (Fut::Output, usize);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let weight = *this.weight;
+        this.future.poll(cx).map(|output| (output, weight))
+    }
+}
+
+impl<St, W> fmt::Debug for BufferUnorderedWeighted<St, W>
+where
+    St: Stream + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BufferUnorderedWeighted")
+            .field("stream", &self.stream)
+            .field("in_progress_queue", &self.in_progress_queue)
+            .field("capacity", &self.capacity)
+            .field("in_flight", &self.in_flight)
+            .finish()
+    }
+}
+
+impl<St, W> BufferUnorderedWeighted<St, W>
+where
+    St: Stream,
+    St::Item: Future,
+    W: FnMut(&St::Item) -> usize,
+{
+    pub(super) fn new(stream: St, capacity: usize, weight_fn: W) -> Self {
+        Self {
+            stream: super::Fuse::new(stream),
+            in_progress_queue: FuturesUnordered::new(),
+            pending: None,
+            weight_fn,
+            capacity,
+            in_flight: 0,
+        }
+    }
+
+    delegate_access_inner!(stream, St, (.));
+}
+
+impl<St, W> Stream for BufferUnorderedWeighted<St, W>
+where
+    St: Stream,
+    St::Item: Future,
+    W: FnMut(&St::Item) -> usize,
+{
+    type Item = <St::Item as Future>::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        // First up, admit as many futures as the weight budget allows. An
+        // item that does not fit right now is parked in `pending` until
+        // enough weight is released; one that is too heavy to ever fit is
+        // run once the queue is otherwise empty, so it cannot deadlock.
+        loop {
+            let (fut, weight) = match this.pending.take() {
+                Some(parked) => parked,
+                None => match this.stream.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(fut)) => {
+                        let weight = (this.weight_fn)(&fut);
+                        (fut, weight)
+                    }
+                    Poll::Ready(None) | Poll::Pending => break,
+                },
+            };
+            if *this.in_flight + weight <= *this.capacity || this.in_progress_queue.is_empty() {
+                *this.in_flight += weight;
+                this.in_progress_queue.push(WeightedFuture { future: fut, weight });
+            } else {
+                *this.pending = Some((fut, weight));
+                break;
+            }
+        }
+
+        // Attempt to pull the next value from the in_progress_queue
+        match this.in_progress_queue.poll_next_unpin(cx) {
+            Poll::Ready(Some((output, weight))) => {
+                *this.in_flight -= weight;
+                return Poll::Ready(Some(output));
+            }
+            Poll::Pending => return Poll::Pending,
+            Poll::Ready(None) => {}
+        }
+
+        // If more values are still coming from the stream, we're not done yet
+        if this.stream.is_done() && this.pending.is_none() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let queue_len = self.in_progress_queue.len() + usize::from(self.pending.is_some());
+        let (lower, upper) = self.stream.size_hint();
+        let lower = lower.saturating_add(queue_len);
+        let upper = match upper {
+            Some(x) => x.checked_add(queue_len),
+            None => None,
+        };
+        (lower, upper)
+    }
+}
+
+impl<St, W> FusedStream for BufferUnorderedWeighted<St, W>
+where
+    St: Stream,
+    St::Item: Future,
+    W: FnMut(&St::Item) -> usize,
+{
+    fn is_terminated(&self) -> bool {
+        self.in_progress_queue.is_terminated()
+            && self.stream.is_terminated()
+            && self.pending.is_none()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, W, Item> Sink<Item> for BufferUnorderedWeighted<S, W>
+where
+    S: Stream + Sink<Item>,
+    S::Item: Future,
+{
+    type Error = S::Error;
+
+    delegate_sink!(stream, Item);
+}
@@ -0,0 +1,224 @@
+use crate::stream::{Fuse, FuturesUnordered, StreamExt};
+use alloc::collections::{BTreeMap, VecDeque};
+use core::fmt;
+use core::hash::Hash;
+use core::num::NonZeroUsize;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+use std::collections::HashMap;
+
+pin_project! {
+    /// Future wrapping a buffered item's future together with its key and
+    /// per-key sequence number, so completions can be routed back to the
+    /// right ordering queue.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    #[derive(Debug)]
+    struct KeyedFut<K, Fut> {
+        #[pin]
+        future: Fut,
+        key: Option<K>,
+        seq: usize,
+    }
+}
+
+impl<K, Fut: Future> Future for KeyedFut<K, Fut> {
+    type Output = (K, usize, Fut::Output);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let output = futures_core::ready!(this.future.poll(cx));
+        let key = this.key.take().expect("polled KeyedFut after completion");
+        Poll::Ready((key, *this.seq, output))
+    }
+}
+
+/// Per-key bookkeeping: the next sequence number to hand out, the next one
+/// to emit, and completions that arrived ahead of their turn.
+#[derive(Debug)]
+struct KeyState<T> {
+    next_seq: usize,
+    next_emit: usize,
+    buffered: BTreeMap<usize, T>,
+}
+
+impl<T> Default for KeyState<T> {
+    fn default() -> Self {
+        Self { next_seq: 0, next_emit: 0, buffered: BTreeMap::new() }
+    }
+}
+
+pin_project! {
+    /// Stream for the [`buffered_by_key`](super::StreamExt::buffered_by_key)
+    /// method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct BufferedByKey<St, Fut, K, KF, F>
+    where
+        St: Stream,
+        Fut: Future,
+    {
+        #[pin]
+        stream: Fuse<St>,
+        in_progress_queue: FuturesUnordered<KeyedFut<K, Fut>>,
+        keys: HashMap<K, KeyState<Fut::Output>>,
+        ready: VecDeque<Fut::Output>,
+        max: Option<NonZeroUsize>,
+        key_fn: KF,
+        f: F,
+    }
+}
+
+impl<St, Fut, K, KF, F> fmt::Debug for BufferedByKey<St, Fut, K, KF, F>
+where
+    St: Stream + fmt::Debug,
+    Fut: Future + fmt::Debug,
+    Fut::Output: fmt::Debug,
+    K: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BufferedByKey")
+            .field("stream", &self.stream)
+            .field("in_progress_queue", &self.in_progress_queue)
+            .field("keys", &self.keys)
+            .field("ready", &self.ready)
+            .field("max", &self.max)
+            .finish()
+    }
+}
+
+impl<St, Fut, K, KF, F> BufferedByKey<St, Fut, K, KF, F>
+where
+    St: Stream,
+    Fut: Future,
+    K: Eq + Hash + Clone,
+    KF: FnMut(&St::Item) -> K,
+    F: FnMut(St::Item) -> Fut,
+{
+    pub(super) fn new(stream: St, n: Option<usize>, key_fn: KF, f: F) -> Self {
+        Self {
+            stream: super::Fuse::new(stream),
+            in_progress_queue: FuturesUnordered::new(),
+            keys: HashMap::new(),
+            ready: VecDeque::new(),
+            max: n.and_then(NonZeroUsize::new),
+            key_fn,
+            f,
+        }
+    }
+
+    delegate_access_inner!(stream, St, (.));
+}
+
+impl<St, Fut, K, KF, F> Stream for BufferedByKey<St, Fut, K, KF, F>
+where
+    St: Stream,
+    Fut: Future,
+    K: Eq + Hash + Clone,
+    KF: FnMut(&St::Item) -> K,
+    F: FnMut(St::Item) -> Fut,
+{
+    type Item = Fut::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            // Completions released by an earlier in-order completion are
+            // emitted first, in the order they were unblocked.
+            if let Some(output) = this.ready.pop_front() {
+                return Poll::Ready(Some(output));
+            }
+
+            // Fill up the queue of futures from the underlying stream, as
+            // long as we're below the concurrency limit.
+            while this.max.map(|max| this.in_progress_queue.len() < max.get()).unwrap_or(true) {
+                match this.stream.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(item)) => {
+                        let key = (this.key_fn)(&item);
+                        let state = this.keys.entry(key.clone()).or_default();
+                        let seq = state.next_seq;
+                        state.next_seq += 1;
+                        this.in_progress_queue.push(KeyedFut {
+                            future: (this.f)(item),
+                            key: Some(key),
+                            seq,
+                        });
+                    }
+                    Poll::Ready(None) | Poll::Pending => break,
+                }
+            }
+
+            match this.in_progress_queue.poll_next_unpin(cx) {
+                Poll::Ready(Some((key, seq, output))) => {
+                    let state = this.keys.get_mut(&key).expect("completion for untracked key");
+                    if seq == state.next_emit {
+                        // In order for its key: emit it now, and release any
+                        // buffered successors that are now unblocked.
+                        state.next_emit += 1;
+                        while let Some(next) = state.buffered.remove(&state.next_emit) {
+                            this.ready.push_back(next);
+                            state.next_emit += 1;
+                        }
+                        if state.buffered.is_empty() && state.next_emit == state.next_seq {
+                            this.keys.remove(&key);
+                        }
+                        return Poll::Ready(Some(output));
+                    }
+                    // An earlier item of the same key is still running; park
+                    // the result and keep going, this also freed up a slot.
+                    state.buffered.insert(seq, output);
+                }
+                Poll::Ready(None) => {
+                    if this.stream.is_done() {
+                        debug_assert!(this.ready.is_empty());
+                        return Poll::Ready(None);
+                    }
+                    return Poll::Pending;
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let queue_len = self.in_progress_queue.len()
+            + self.ready.len()
+            + self.keys.values().map(|state| state.buffered.len()).sum::<usize>();
+        let (lower, upper) = self.stream.size_hint();
+        let lower = lower.saturating_add(queue_len);
+        let upper = match upper {
+            Some(x) => x.checked_add(queue_len),
+            None => None,
+        };
+        (lower, upper)
+    }
+}
+
+impl<St, Fut, K, KF, F> FusedStream for BufferedByKey<St, Fut, K, KF, F>
+where
+    St: Stream,
+    Fut: Future,
+    K: Eq + Hash + Clone,
+    KF: FnMut(&St::Item) -> K,
+    F: FnMut(St::Item) -> Fut,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated() && self.in_progress_queue.is_empty() && self.ready.is_empty()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<St, Fut, K, KF, F, Item> Sink<Item> for BufferedByKey<St, Fut, K, KF, F>
+where
+    St: Stream + Sink<Item>,
+    Fut: Future,
+{
+    type Error = St::Error;
+
+    delegate_sink!(stream, Item);
+}
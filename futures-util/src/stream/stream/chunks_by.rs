@@ -0,0 +1,119 @@
+use crate::stream::Fuse;
+use alloc::vec::Vec;
+use core::fmt;
+use core::mem;
+use core::pin::Pin;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`chunks_by`](super::StreamExt::chunks_by) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct ChunksBy<St: Stream, F> {
+        #[pin]
+        stream: Fuse<St>,
+        f: F,
+        items: Vec<St::Item>,
+    }
+}
+
+impl<St, F> fmt::Debug for ChunksBy<St, F>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChunksBy")
+            .field("stream", &self.stream)
+            .field("items", &self.items)
+            .finish()
+    }
+}
+
+impl<St, F> ChunksBy<St, F>
+where
+    St: Stream,
+    F: FnMut(&St::Item) -> bool,
+{
+    pub(super) fn new(stream: St, f: F) -> Self {
+        Self { stream: super::Fuse::new(stream), f, items: Vec::new() }
+    }
+
+    delegate_access_inner!(stream, St, (.));
+}
+
+impl<St, F> Stream for ChunksBy<St, F>
+where
+    St: Stream,
+    F: FnMut(&St::Item) -> bool,
+{
+    type Item = Vec<St::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                // A boundary item closes the current chunk and starts the next
+                // one. The first item of the stream always begins the first
+                // chunk, so an initial boundary yields nothing by itself.
+                Some(item) => {
+                    if (this.f)(&item) && !this.items.is_empty() {
+                        let full_buf = mem::replace(this.items, Vec::new());
+                        this.items.push(item);
+                        return Poll::Ready(Some(full_buf));
+                    }
+                    this.items.push(item);
+                }
+
+                // Since the underlying stream ran out of values, return what we
+                // have buffered, if we have anything.
+                None => {
+                    let last = if this.items.is_empty() {
+                        None
+                    } else {
+                        let full_buf = mem::replace(this.items, Vec::new());
+                        Some(full_buf)
+                    };
+
+                    return Poll::Ready(last);
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let chunk_len = if self.items.is_empty() { 0 } else { 1 };
+        let (_, upper) = self.stream.size_hint();
+        let lower = chunk_len;
+        let upper = match upper {
+            Some(x) => x.checked_add(chunk_len),
+            None => None,
+        };
+        (lower, upper)
+    }
+}
+
+impl<St, F> FusedStream for ChunksBy<St, F>
+where
+    St: FusedStream,
+    F: FnMut(&St::Item) -> bool,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated() && self.items.is_empty()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, F, Item> Sink<Item> for ChunksBy<S, F>
+where
+    S: Stream + Sink<Item>,
+{
+    type Error = S::Error;
+
+    delegate_sink!(stream, Item);
+}
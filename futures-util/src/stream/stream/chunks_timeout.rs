@@ -0,0 +1,155 @@
+use crate::stream::Fuse;
+use alloc::vec::Vec;
+use core::fmt;
+use core::mem;
+use core::pin::Pin;
+use core::time::Duration;
+use futures_core::future::Future;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`chunks_timeout`](super::StreamExt::chunks_timeout) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct ChunksTimeout<St, Fut, F>
+        where St: Stream,
+    {
+        #[pin]
+        stream: Fuse<St>,
+        #[pin]
+        delay: Option<Fut>,
+        items: Vec<St::Item>,
+        cap: usize, // https://github.com/rust-lang/futures-rs/issues/1475
+        duration: Duration,
+        f: F,
+    }
+}
+
+impl<St, Fut, F> fmt::Debug for ChunksTimeout<St, Fut, F>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+    Fut: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChunksTimeout")
+            .field("stream", &self.stream)
+            .field("delay", &self.delay)
+            .field("items", &self.items)
+            .field("cap", &self.cap)
+            .field("duration", &self.duration)
+            .finish()
+    }
+}
+
+impl<St, Fut, F> ChunksTimeout<St, Fut, F>
+where
+    St: Stream,
+    F: FnMut(Duration) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    pub(super) fn new(stream: St, capacity: usize, duration: Duration, f: F) -> Self {
+        assert!(capacity > 0);
+
+        Self {
+            stream: super::Fuse::new(stream),
+            delay: None,
+            items: Vec::with_capacity(capacity),
+            cap: capacity,
+            duration,
+            f,
+        }
+    }
+
+    fn take(self: Pin<&mut Self>) -> Vec<St::Item> {
+        let cap = self.cap;
+        mem::replace(self.project().items, Vec::with_capacity(cap))
+    }
+
+    delegate_access_inner!(stream, St, (.));
+}
+
+impl<St, Fut, F> Stream for ChunksTimeout<St, Fut, F>
+where
+    St: Stream,
+    F: FnMut(Duration) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    type Item = Vec<St::Item>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.as_mut().project();
+
+        // Buffer every item that is already available. The delay is armed
+        // when the first item of a fresh chunk arrives and cleared whenever
+        // the chunk is flushed, so a capacity-triggered flush resets it.
+        while !this.stream.is_done() {
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    if this.items.is_empty() {
+                        this.delay.set(Some((this.f)(*this.duration)));
+                    }
+                    this.items.push(item);
+                    if this.items.len() >= *this.cap {
+                        this.delay.set(None);
+                        return Poll::Ready(Some(self.take()));
+                    }
+                }
+                Poll::Ready(None) | Poll::Pending => break,
+            }
+        }
+
+        // The underlying stream ended: flush the partial chunk, if any.
+        if this.stream.is_done() {
+            this.delay.set(None);
+            let last = if this.items.is_empty() { None } else { Some(self.take()) };
+            return Poll::Ready(last);
+        }
+
+        if let Some(delay) = this.delay.as_mut().as_pin_mut() {
+            if delay.poll(cx).is_ready() {
+                this.delay.set(None);
+                debug_assert!(!this.items.is_empty());
+                return Poll::Ready(Some(self.take()));
+            }
+        }
+
+        Poll::Pending
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let chunk_len = if self.items.is_empty() { 0 } else { 1 };
+        let (lower, upper) = self.stream.size_hint();
+        let lower = lower.saturating_add(chunk_len);
+        let upper = match upper {
+            Some(x) => x.checked_add(chunk_len),
+            None => None,
+        };
+        (lower, upper)
+    }
+}
+
+impl<St, Fut, F> FusedStream for ChunksTimeout<St, Fut, F>
+where
+    St: Stream,
+    F: FnMut(Duration) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_done() && self.items.is_empty()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, Fut, F, Item> Sink<Item> for ChunksTimeout<S, Fut, F>
+where
+    S: Stream + Sink<Item>,
+{
+    type Error = S::Error;
+
+    delegate_sink!(stream, Item);
+}
@@ -0,0 +1,110 @@
+use crate::stream::Fuse;
+use core::fmt;
+use core::pin::Pin;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`coalesce`](super::StreamExt::coalesce) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Coalesce<St, F>
+        where St: Stream,
+    {
+        #[pin]
+        stream: Fuse<St>,
+        f: F,
+        pending: Option<St::Item>,
+    }
+}
+
+impl<St, F> fmt::Debug for Coalesce<St, F>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Coalesce")
+            .field("stream", &self.stream)
+            .field("pending", &self.pending)
+            .finish()
+    }
+}
+
+impl<St, F> Coalesce<St, F>
+where
+    St: Stream,
+    F: FnMut(St::Item, St::Item) -> Result<St::Item, (St::Item, St::Item)>,
+{
+    pub(super) fn new(stream: St, f: F) -> Self {
+        Self { stream: super::Fuse::new(stream), f, pending: None }
+    }
+
+    delegate_access_inner!(stream, St, (.));
+}
+
+impl<St, F> Stream for Coalesce<St, F>
+where
+    St: Stream,
+    F: FnMut(St::Item, St::Item) -> Result<St::Item, (St::Item, St::Item)>,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => match this.pending.take() {
+                    // Try to merge the incoming item into the held one; on a
+                    // failed merge the held item is emitted and the incoming
+                    // item takes its place.
+                    Some(pending) => match (this.f)(pending, item) {
+                        Ok(merged) => *this.pending = Some(merged),
+                        Err((unmerged, item)) => {
+                            *this.pending = Some(item);
+                            return Poll::Ready(Some(unmerged));
+                        }
+                    },
+                    None => *this.pending = Some(item),
+                },
+                // The final held item is emitted once the stream ends.
+                None => return Poll::Ready(this.pending.take()),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending_len = if self.pending.is_some() { 1 } else { 0 };
+        let (_, upper) = self.stream.size_hint();
+        let upper = match upper {
+            Some(x) => x.checked_add(pending_len),
+            None => None,
+        };
+        (0, upper)
+    }
+}
+
+impl<St, F> FusedStream for Coalesce<St, F>
+where
+    St: Stream,
+    F: FnMut(St::Item, St::Item) -> Result<St::Item, (St::Item, St::Item)>,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated() && self.pending.is_none()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, F, Item> Sink<Item> for Coalesce<S, F>
+where
+    S: Stream + Sink<Item>,
+{
+    type Error = S::Error;
+
+    delegate_sink!(stream, Item);
+}
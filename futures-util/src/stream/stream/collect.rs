@@ -27,6 +27,12 @@ impl<St: Stream, C: Default> Collect<St, C> {
     }
 }
 
+impl<St: Stream, C> Collect<St, C> {
+    pub(super) fn with_collection(stream: St, collection: C) -> Self {
+        Self { stream, collection }
+    }
+}
+
 impl<St, C> FusedFuture for Collect<St, C>
 where
     St: FusedStream,
@@ -0,0 +1,137 @@
+use core::fmt;
+use core::hash::Hash;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+use std::collections::HashMap;
+
+pin_project! {
+    /// Future for the [`counts`](super::StreamExt::counts) method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Counts<St: Stream> {
+        #[pin]
+        stream: St,
+        counts: HashMap<St::Item, usize>,
+    }
+}
+
+impl<St> fmt::Debug for Counts<St>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Counts")
+            .field("stream", &self.stream)
+            .field("counts", &self.counts)
+            .finish()
+    }
+}
+
+impl<St> Counts<St>
+where
+    St: Stream,
+    St::Item: Hash + Eq,
+{
+    pub(super) fn new(stream: St) -> Self {
+        Self { stream, counts: HashMap::new() }
+    }
+}
+
+impl<St> FusedFuture for Counts<St>
+where
+    St: FusedStream,
+    St::Item: Hash + Eq,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St> Future for Counts<St>
+where
+    St: Stream,
+    St::Item: Hash + Eq,
+{
+    type Output = HashMap<St::Item, usize>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => *this.counts.entry(item).or_insert(0) += 1,
+                None => return Poll::Ready(core::mem::replace(this.counts, HashMap::new())),
+            }
+        }
+    }
+}
+
+pin_project! {
+    /// Future for the [`counts_by`](super::StreamExt::counts_by) method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct CountsBy<St, F, K> {
+        #[pin]
+        stream: St,
+        f: F,
+        counts: HashMap<K, usize>,
+    }
+}
+
+impl<St, F, K> fmt::Debug for CountsBy<St, F, K>
+where
+    St: fmt::Debug,
+    K: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CountsBy")
+            .field("stream", &self.stream)
+            .field("counts", &self.counts)
+            .finish()
+    }
+}
+
+impl<St, F, K> CountsBy<St, F, K>
+where
+    St: Stream,
+    F: FnMut(St::Item) -> K,
+    K: Hash + Eq,
+{
+    pub(super) fn new(stream: St, f: F) -> Self {
+        Self { stream, f, counts: HashMap::new() }
+    }
+}
+
+impl<St, F, K> FusedFuture for CountsBy<St, F, K>
+where
+    St: FusedStream,
+    F: FnMut(St::Item) -> K,
+    K: Hash + Eq,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, F, K> Future for CountsBy<St, F, K>
+where
+    St: Stream,
+    F: FnMut(St::Item) -> K,
+    K: Hash + Eq,
+{
+    type Output = HashMap<K, usize>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => *this.counts.entry((this.f)(item)).or_insert(0) += 1,
+                None => return Poll::Ready(core::mem::replace(this.counts, HashMap::new())),
+            }
+        }
+    }
+}
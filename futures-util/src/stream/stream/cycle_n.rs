@@ -0,0 +1,77 @@
+use core::pin::Pin;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`cycle_n`](super::StreamExt::cycle_n) method.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct CycleN<St> {
+        orig: St,
+        #[pin]
+        stream: St,
+        remaining: usize,
+    }
+}
+
+impl<St> CycleN<St>
+where
+    St: Clone + Stream,
+{
+    pub(super) fn new(stream: St, n: usize) -> Self {
+        Self { orig: stream.clone(), stream, remaining: n }
+    }
+}
+
+impl<St> Stream for CycleN<St>
+where
+    St: Clone + Stream,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            if *this.remaining == 0 {
+                return Poll::Ready(None);
+            }
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                None => {
+                    *this.remaining -= 1;
+                    if *this.remaining == 0 {
+                        return Poll::Ready(None);
+                    }
+                    this.stream.set(this.orig.clone());
+                }
+                item => return Poll::Ready(item),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.remaining == 0 {
+            return (0, Some(0));
+        }
+        let (cur_lower, cur_upper) = self.stream.size_hint();
+        let (orig_lower, orig_upper) = self.orig.size_hint();
+        let rest = self.remaining - 1;
+        let lower = cur_lower.saturating_add(orig_lower.saturating_mul(rest));
+        let upper = match (cur_upper, orig_upper) {
+            (Some(cur), Some(orig)) => orig.checked_mul(rest).and_then(|n| cur.checked_add(n)),
+            _ => None,
+        };
+        (lower, upper)
+    }
+}
+
+impl<St> FusedStream for CycleN<St>
+where
+    St: Clone + Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.remaining == 0
+    }
+}
@@ -0,0 +1,131 @@
+use crate::stream::Fuse;
+use core::fmt;
+use core::pin::Pin;
+use core::time::Duration;
+use futures_core::future::Future;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`debounce`](super::StreamExt::debounce) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Debounce<St, Fut, F>
+        where St: Stream,
+    {
+        #[pin]
+        stream: Fuse<St>,
+        #[pin]
+        delay: Option<Fut>,
+        pending: Option<St::Item>,
+        duration: Duration,
+        f: F,
+    }
+}
+
+impl<St, Fut, F> fmt::Debug for Debounce<St, Fut, F>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+    Fut: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Debounce")
+            .field("stream", &self.stream)
+            .field("delay", &self.delay)
+            .field("pending", &self.pending)
+            .field("duration", &self.duration)
+            .finish()
+    }
+}
+
+impl<St, Fut, F> Debounce<St, Fut, F>
+where
+    St: Stream,
+    F: FnMut(Duration) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    pub(super) fn new(stream: St, duration: Duration, f: F) -> Self {
+        Self { stream: super::Fuse::new(stream), delay: None, pending: None, duration, f }
+    }
+
+    delegate_access_inner!(stream, St, (.));
+}
+
+impl<St, Fut, F> Stream for Debounce<St, Fut, F>
+where
+    St: Stream,
+    F: FnMut(Duration) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        // Pull every item that is already available, keeping only the most
+        // recent one and re-arming the delay each time.
+        while !this.stream.is_done() {
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    *this.pending = Some(item);
+                    this.delay.set(Some((this.f)(*this.duration)));
+                }
+                Poll::Ready(None) | Poll::Pending => break,
+            }
+        }
+
+        // Once the underlying stream has ended there is nothing left to
+        // coalesce with, so flush the buffered item without waiting for the
+        // delay to elapse.
+        if this.stream.is_done() {
+            this.delay.set(None);
+            return Poll::Ready(this.pending.take());
+        }
+
+        if let Some(delay) = this.delay.as_mut().as_pin_mut() {
+            if delay.poll(cx).is_ready() {
+                this.delay.set(None);
+                return Poll::Ready(Some(
+                    this.pending.take().expect("polled Debounce with armed delay but no item"),
+                ));
+            }
+        }
+
+        Poll::Pending
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending_len = if self.pending.is_some() { 1 } else { 0 };
+        let (_, upper) = self.stream.size_hint();
+        let upper = match upper {
+            Some(x) => x.checked_add(pending_len),
+            None => None,
+        };
+        (pending_len, upper)
+    }
+}
+
+impl<St, Fut, F> FusedStream for Debounce<St, Fut, F>
+where
+    St: Stream,
+    F: FnMut(Duration) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_done() && self.pending.is_none()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, Fut, F, Item> Sink<Item> for Debounce<S, Fut, F>
+where
+    S: Stream + Sink<Item>,
+{
+    type Error = S::Error;
+
+    delegate_sink!(stream, Item);
+}
@@ -0,0 +1,266 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`dedup`](super::StreamExt::dedup) method.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Dedup<St>
+        where St: Stream,
+    {
+        #[pin]
+        stream: St,
+        last: Option<St::Item>,
+    }
+}
+
+impl<St> Dedup<St>
+where
+    St: Stream,
+    St::Item: PartialEq + Clone,
+{
+    pub(super) fn new(stream: St) -> Self {
+        Self { stream, last: None }
+    }
+
+    delegate_access_inner!(stream, St, ());
+}
+
+impl<St> Stream for Dedup<St>
+where
+    St: Stream,
+    St::Item: PartialEq + Clone,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => {
+                    if this.last.as_ref() != Some(&item) {
+                        *this.last = Some(item.clone());
+                        return Poll::Ready(Some(item));
+                    }
+                }
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.stream.size_hint();
+        (0, upper)
+    }
+}
+
+impl<St> FusedStream for Dedup<St>
+where
+    St: FusedStream,
+    St::Item: PartialEq + Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, Item> Sink<Item> for Dedup<S>
+where
+    S: Stream + Sink<Item>,
+    S::Item: PartialEq + Clone,
+{
+    type Error = S::Error;
+
+    delegate_sink!(stream, Item);
+}
+
+pin_project! {
+    /// Stream for the [`dedup_by`](super::StreamExt::dedup_by) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct DedupBy<St, F>
+        where St: Stream,
+    {
+        #[pin]
+        stream: St,
+        f: F,
+        last: Option<St::Item>,
+    }
+}
+
+impl<St, F> fmt::Debug for DedupBy<St, F>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DedupBy").field("stream", &self.stream).field("last", &self.last).finish()
+    }
+}
+
+impl<St, F> DedupBy<St, F>
+where
+    St: Stream,
+    St::Item: Clone,
+    F: FnMut(&St::Item, &St::Item) -> bool,
+{
+    pub(super) fn new(stream: St, f: F) -> Self {
+        Self { stream, f, last: None }
+    }
+
+    delegate_access_inner!(stream, St, ());
+}
+
+impl<St, F> Stream for DedupBy<St, F>
+where
+    St: Stream,
+    St::Item: Clone,
+    F: FnMut(&St::Item, &St::Item) -> bool,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => {
+                    let is_dup = match this.last.as_ref() {
+                        Some(last) => (this.f)(last, &item),
+                        None => false,
+                    };
+                    if !is_dup {
+                        *this.last = Some(item.clone());
+                        return Poll::Ready(Some(item));
+                    }
+                }
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.stream.size_hint();
+        (0, upper)
+    }
+}
+
+impl<St, F> FusedStream for DedupBy<St, F>
+where
+    St: FusedStream,
+    St::Item: Clone,
+    F: FnMut(&St::Item, &St::Item) -> bool,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, F, Item> Sink<Item> for DedupBy<S, F>
+where
+    S: Stream + Sink<Item>,
+{
+    type Error = S::Error;
+
+    delegate_sink!(stream, Item);
+}
+
+pin_project! {
+    /// Stream for the [`dedup_by_key`](super::StreamExt::dedup_by_key) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct DedupByKey<St, F, K> {
+        #[pin]
+        stream: St,
+        f: F,
+        last_key: Option<K>,
+    }
+}
+
+impl<St, F, K> fmt::Debug for DedupByKey<St, F, K>
+where
+    St: fmt::Debug,
+    K: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DedupByKey")
+            .field("stream", &self.stream)
+            .field("last_key", &self.last_key)
+            .finish()
+    }
+}
+
+impl<St, F, K> DedupByKey<St, F, K>
+where
+    St: Stream,
+    F: FnMut(&St::Item) -> K,
+    K: PartialEq,
+{
+    pub(super) fn new(stream: St, f: F) -> Self {
+        Self { stream, f, last_key: None }
+    }
+
+    delegate_access_inner!(stream, St, ());
+}
+
+impl<St, F, K> Stream for DedupByKey<St, F, K>
+where
+    St: Stream,
+    F: FnMut(&St::Item) -> K,
+    K: PartialEq,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => {
+                    let key = (this.f)(&item);
+                    if this.last_key.as_ref() != Some(&key) {
+                        *this.last_key = Some(key);
+                        return Poll::Ready(Some(item));
+                    }
+                }
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.stream.size_hint();
+        (0, upper)
+    }
+}
+
+impl<St, F, K> FusedStream for DedupByKey<St, F, K>
+where
+    St: FusedStream,
+    F: FnMut(&St::Item) -> K,
+    K: PartialEq,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, F, K, Item> Sink<Item> for DedupByKey<S, F, K>
+where
+    S: Stream + Sink<Item>,
+{
+    type Error = S::Error;
+
+    delegate_sink!(stream, Item);
+}
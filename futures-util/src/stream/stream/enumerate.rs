@@ -19,7 +19,11 @@ pin_project! {
 
 impl<St: Stream> Enumerate<St> {
     pub(super) fn new(stream: St) -> Self {
-        Self { stream, count: 0 }
+        Self::new_from(stream, 0)
+    }
+
+    pub(super) fn new_from(stream: St, start: usize) -> Self {
+        Self { stream, count: start }
     }
 
     delegate_access_inner!(stream, St, ());
@@ -0,0 +1,73 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the [`find_map`](super::StreamExt::find_map) method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct FindMap<St, F> {
+        #[pin]
+        stream: St,
+        f: F,
+        done: bool,
+    }
+}
+
+impl<St, F> fmt::Debug for FindMap<St, F>
+where
+    St: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FindMap").field("stream", &self.stream).field("done", &self.done).finish()
+    }
+}
+
+impl<St, F, B> FindMap<St, F>
+where
+    St: Stream,
+    F: FnMut(St::Item) -> Option<B>,
+{
+    pub(super) fn new(stream: St, f: F) -> Self {
+        Self { stream, f, done: false }
+    }
+}
+
+impl<St, F, B> FusedFuture for FindMap<St, F>
+where
+    St: FusedStream,
+    F: FnMut(St::Item) -> Option<B>,
+{
+    fn is_terminated(&self) -> bool {
+        self.done || self.stream.is_terminated()
+    }
+}
+
+impl<St, F, B> Future for FindMap<St, F>
+where
+    St: Stream,
+    F: FnMut(St::Item) -> Option<B>,
+{
+    type Output = Option<B>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => {
+                    if let Some(mapped) = (this.f)(item) {
+                        *this.done = true;
+                        return Poll::Ready(Some(mapped));
+                    }
+                }
+                None => {
+                    *this.done = true;
+                    return Poll::Ready(None);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use crate::stream::{Fuse, FuturesUnordered, StreamExt};
+use core::fmt;
+use core::num::NonZeroUsize;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+/// Future which polls a sub-stream for its next item, resolving to the item
+/// together with the stream so that the stream can be resubmitted to the
+/// [`FuturesUnordered`] set afterwards.
+///
+/// The stream is moved in and out between polls, which is why sub-streams
+/// must be [`Unpin`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[derive(Debug)]
+pub(super) struct PollStreamFut<St> {
+    stream: Option<St>,
+}
+
+impl<St> PollStreamFut<St> {
+    pub(super) fn new(stream: St) -> Self {
+        Self { stream: Some(stream) }
+    }
+}
+
+impl<St: Stream + Unpin> Future for PollStreamFut<St> {
+    type Output = Option<(St::Item, St)>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        let item = match &mut this.stream {
+            Some(stream) => ready!(stream.poll_next_unpin(cx)),
+            None => None,
+        };
+        let stream = this.stream.take();
+
+        Poll::Ready(item.map(|item| (item, stream.unwrap())))
+    }
+}
+
+pin_project! {
+    /// Stream for the [`flat_map_unordered`](super::StreamExt::flat_map_unordered)
+    /// method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct FlatMapUnordered<St, U, F>
+    where
+        U: Stream,
+        U: Unpin,
+    {
+        #[pin]
+        stream: Fuse<St>,
+        in_progress_queue: FuturesUnordered<PollStreamFut<U>>,
+        limit: Option<NonZeroUsize>,
+        f: F,
+    }
+}
+
+impl<St, U, F> fmt::Debug for FlatMapUnordered<St, U, F>
+where
+    St: fmt::Debug,
+    U: Stream + Unpin + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FlatMapUnordered")
+            .field("stream", &self.stream)
+            .field("in_progress_queue", &self.in_progress_queue)
+            .field("limit", &self.limit)
+            .finish()
+    }
+}
+
+impl<St, U, F> FlatMapUnordered<St, U, F>
+where
+    St: Stream,
+    U: Stream + Unpin,
+    F: FnMut(St::Item) -> U,
+{
+    pub(super) fn new(stream: St, limit: Option<usize>, f: F) -> Self {
+        Self {
+            stream: super::Fuse::new(stream),
+            in_progress_queue: FuturesUnordered::new(),
+            // Note: `limit` = 0 gets ignored.
+            limit: limit.and_then(NonZeroUsize::new),
+            f,
+        }
+    }
+
+    delegate_access_inner!(stream, St, (.));
+}
+
+impl<St, U, F> Stream for FlatMapUnordered<St, U, F>
+where
+    St: Stream,
+    U: Stream + Unpin,
+    F: FnMut(St::Item) -> U,
+{
+    type Item = U::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            let mut made_progress_this_iter = false;
+
+            // First up, try to spawn off as many sub-streams as possible by
+            // pulling items from the underlying stream, as long as we're below
+            // the concurrency limit.
+            while this.limit.map(|limit| this.in_progress_queue.len() < limit.get()).unwrap_or(true)
+            {
+                match this.stream.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(item)) => {
+                        made_progress_this_iter = true;
+                        this.in_progress_queue.push(PollStreamFut::new((this.f)(item)));
+                    }
+                    Poll::Ready(None) | Poll::Pending => break,
+                }
+            }
+
+            // Attempt to pull the next value from any of the active
+            // sub-streams.
+            match this.in_progress_queue.poll_next_unpin(cx) {
+                Poll::Ready(Some(Some((item, stream)))) => {
+                    // The sub-stream produced an item; resubmit it so it keeps
+                    // being polled alongside the others.
+                    this.in_progress_queue.push(PollStreamFut::new(stream));
+                    return Poll::Ready(Some(item));
+                }
+                Poll::Ready(Some(None)) => {
+                    // A sub-stream finished, freeing up a slot for a new one.
+                    made_progress_this_iter = true;
+                }
+                Poll::Ready(None) => {
+                    if this.stream.is_done() {
+                        return Poll::Ready(None);
+                    }
+                }
+                Poll::Pending => {}
+            }
+
+            if !made_progress_this_iter {
+                return Poll::Pending;
+            }
+        }
+    }
+}
+
+impl<St, U, F> FusedStream for FlatMapUnordered<St, U, F>
+where
+    St: Stream,
+    U: Stream + Unpin,
+    F: FnMut(St::Item) -> U,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated() && self.in_progress_queue.is_empty()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<St, U, F, Item> Sink<Item> for FlatMapUnordered<St, U, F>
+where
+    St: Stream + Sink<Item>,
+    U: Stream + Unpin,
+{
+    type Error = St::Error;
+
+    delegate_sink!(stream, Item);
+}
@@ -0,0 +1,136 @@
+use super::flat_map_unordered::PollStreamFut;
+use crate::stream::{Fuse, FuturesUnordered, StreamExt};
+use core::fmt;
+use core::num::NonZeroUsize;
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`flatten_unordered`](super::StreamExt::flatten_unordered)
+    /// method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct FlattenUnordered<St>
+    where
+        St: Stream,
+        St::Item: Stream,
+        St::Item: Unpin,
+    {
+        #[pin]
+        stream: Fuse<St>,
+        in_progress_queue: FuturesUnordered<PollStreamFut<St::Item>>,
+        limit: Option<NonZeroUsize>,
+    }
+}
+
+impl<St> fmt::Debug for FlattenUnordered<St>
+where
+    St: Stream + fmt::Debug,
+    St::Item: Stream + Unpin + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FlattenUnordered")
+            .field("stream", &self.stream)
+            .field("in_progress_queue", &self.in_progress_queue)
+            .field("limit", &self.limit)
+            .finish()
+    }
+}
+
+impl<St> FlattenUnordered<St>
+where
+    St: Stream,
+    St::Item: Stream + Unpin,
+{
+    pub(super) fn new(stream: St, limit: Option<usize>) -> Self {
+        Self {
+            stream: super::Fuse::new(stream),
+            in_progress_queue: FuturesUnordered::new(),
+            // Note: `limit` = 0 gets ignored.
+            limit: limit.and_then(NonZeroUsize::new),
+        }
+    }
+
+    delegate_access_inner!(stream, St, (.));
+}
+
+impl<St> Stream for FlattenUnordered<St>
+where
+    St: Stream,
+    St::Item: Stream + Unpin,
+{
+    type Item = <St::Item as Stream>::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            let mut made_progress_this_iter = false;
+
+            // First up, try to pull as many inner streams as possible out of
+            // the underlying stream, as long as we're below the concurrency
+            // limit.
+            while this.limit.map(|limit| this.in_progress_queue.len() < limit.get()).unwrap_or(true)
+            {
+                match this.stream.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(inner)) => {
+                        made_progress_this_iter = true;
+                        this.in_progress_queue.push(PollStreamFut::new(inner));
+                    }
+                    Poll::Ready(None) | Poll::Pending => break,
+                }
+            }
+
+            // Attempt to pull the next value from any of the active inner
+            // streams.
+            match this.in_progress_queue.poll_next_unpin(cx) {
+                Poll::Ready(Some(Some((item, stream)))) => {
+                    // The inner stream produced an item; resubmit it so it
+                    // keeps being polled alongside the others.
+                    this.in_progress_queue.push(PollStreamFut::new(stream));
+                    return Poll::Ready(Some(item));
+                }
+                Poll::Ready(Some(None)) => {
+                    // An inner stream finished, freeing up a slot for a new
+                    // one.
+                    made_progress_this_iter = true;
+                }
+                Poll::Ready(None) => {
+                    if this.stream.is_done() {
+                        return Poll::Ready(None);
+                    }
+                }
+                Poll::Pending => {}
+            }
+
+            if !made_progress_this_iter {
+                return Poll::Pending;
+            }
+        }
+    }
+}
+
+impl<St> FusedStream for FlattenUnordered<St>
+where
+    St: Stream,
+    St::Item: Stream + Unpin,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated() && self.in_progress_queue.is_empty()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<St, Item> Sink<Item> for FlattenUnordered<St>
+where
+    St: Stream + Sink<Item>,
+    St::Item: Stream + Unpin,
+{
+    type Error = St::Error;
+
+    delegate_sink!(stream, Item);
+}
@@ -0,0 +1,231 @@
+use crate::stream::{Fuse, StreamExt};
+use core::fmt;
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll, Waker};
+use std::boxed::Box;
+use std::sync::{Arc, Mutex};
+
+/// State shared between a [`GroupBy`] stream and its active [`GroupStream`].
+struct Shared<St: Stream, K, F> {
+    stream: Pin<Box<Fuse<St>>>,
+    f: F,
+    /// The next item, along with its key, pulled from the underlying stream
+    /// but not yet claimed by a group.
+    peeked: Option<(K, St::Item)>,
+    /// Identifier of the most recently yielded group.
+    generation: usize,
+    /// Whether the most recently yielded group is still being consumed.
+    active: bool,
+    /// Key of the most recently yielded group, kept so that an early drop of
+    /// the group can request skipping without access to the key itself.
+    current_key: Option<K>,
+    /// Key of a group that was dropped early; the parent discards the
+    /// remaining items of that group before yielding the next one.
+    skip_key: Option<K>,
+    /// Waker of the parent task, registered while a group is active.
+    parent_waker: Option<Waker>,
+}
+
+/// Stream for the [`group_by`](super::StreamExt::group_by) method.
+#[must_use = "streams do nothing unless polled"]
+pub struct GroupBy<St: Stream, K, F> {
+    shared: Arc<Mutex<Shared<St, K, F>>>,
+}
+
+impl<St: Stream, K, F> fmt::Debug for GroupBy<St, K, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GroupBy").finish()
+    }
+}
+
+/// Stream of the items of one group, yielded by [`GroupBy`].
+#[must_use = "streams do nothing unless polled"]
+pub struct GroupStream<St: Stream, K, F> {
+    shared: Arc<Mutex<Shared<St, K, F>>>,
+    key: K,
+    generation: usize,
+    done: bool,
+}
+
+impl<St: Stream, K, F> fmt::Debug for GroupStream<St, K, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GroupStream").field("done", &self.done).finish()
+    }
+}
+
+// The underlying stream is boxed behind the shared state, so moving a
+// `GroupStream` never moves pinned data.
+impl<St: Stream, K, F> Unpin for GroupStream<St, K, F> {}
+
+impl<St, K, F> GroupBy<St, K, F>
+where
+    St: Stream,
+    F: FnMut(&St::Item) -> K,
+    K: PartialEq + Clone,
+{
+    pub(super) fn new(stream: St, f: F) -> Self {
+        Self {
+            shared: Arc::new(Mutex::new(Shared {
+                stream: Box::pin(stream.fuse()),
+                f,
+                peeked: None,
+                generation: 0,
+                active: false,
+                current_key: None,
+                skip_key: None,
+                parent_waker: None,
+            })),
+        }
+    }
+}
+
+impl<St, K, F> Stream for GroupBy<St, K, F>
+where
+    St: Stream,
+    F: FnMut(&St::Item) -> K,
+    K: PartialEq + Clone,
+{
+    type Item = (K, GroupStream<St, K, F>);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut s = self.shared.lock().unwrap();
+
+        // While a group is being consumed the parent must wait for it to
+        // finish (or be dropped) so that items are not pulled out from under
+        // it.
+        if s.active {
+            s.parent_waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+
+        loop {
+            if s.peeked.is_none() {
+                match s.stream.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(item)) => {
+                        let key = (s.f)(&item);
+                        s.peeked = Some((key, item));
+                    }
+                    Poll::Ready(None) => return Poll::Ready(None),
+                    Poll::Pending => return Poll::Pending,
+                }
+            }
+
+            // Discard leftovers of a group that was dropped early.
+            let skipped = match (&s.skip_key, &s.peeked) {
+                (Some(skip), Some((key, _))) => skip == key,
+                _ => false,
+            };
+            if skipped {
+                s.peeked = None;
+                continue;
+            }
+            s.skip_key = None;
+
+            let key = s.peeked.as_ref().expect("peeked item checked above").0.clone();
+            s.generation += 1;
+            s.active = true;
+            s.current_key = Some(key.clone());
+            let generation = s.generation;
+            drop(s);
+
+            let group = GroupStream {
+                shared: self.shared.clone(),
+                key: key.clone(),
+                generation,
+                done: false,
+            };
+            return Poll::Ready(Some((key, group)));
+        }
+    }
+}
+
+impl<St, K, F> FusedStream for GroupBy<St, K, F>
+where
+    St: Stream,
+    F: FnMut(&St::Item) -> K,
+    K: PartialEq + Clone,
+{
+    fn is_terminated(&self) -> bool {
+        let s = self.shared.lock().unwrap();
+        !s.active && s.peeked.is_none() && s.stream.is_done()
+    }
+}
+
+impl<St, K, F> Stream for GroupStream<St, K, F>
+where
+    St: Stream,
+    F: FnMut(&St::Item) -> K,
+    K: PartialEq + Clone,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.done {
+            return Poll::Ready(None);
+        }
+
+        let mut s = this.shared.lock().unwrap();
+        debug_assert_eq!(this.generation, s.generation);
+
+        if s.peeked.is_none() {
+            match s.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    let key = (s.f)(&item);
+                    s.peeked = Some((key, item));
+                }
+                Poll::Ready(None) => {
+                    this.done = true;
+                    s.active = false;
+                    if let Some(waker) = s.parent_waker.take() {
+                        waker.wake();
+                    }
+                    return Poll::Ready(None);
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+
+        let matches = matches!(&s.peeked, Some((key, _)) if *key == this.key);
+        if matches {
+            let (_, item) = s.peeked.take().expect("peeked item checked above");
+            Poll::Ready(Some(item))
+        } else {
+            // A new key starts the next group; this one is finished.
+            this.done = true;
+            s.active = false;
+            if let Some(waker) = s.parent_waker.take() {
+                waker.wake();
+            }
+            Poll::Ready(None)
+        }
+    }
+}
+
+impl<St, K, F> FusedStream for GroupStream<St, K, F>
+where
+    St: Stream,
+    F: FnMut(&St::Item) -> K,
+    K: PartialEq + Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<St: Stream, K, F> Drop for GroupStream<St, K, F> {
+    fn drop(&mut self) {
+        if self.done {
+            return;
+        }
+        let mut s = self.shared.lock().unwrap();
+        if s.generation == self.generation && s.active {
+            s.active = false;
+            s.skip_key = s.current_key.take();
+            if let Some(waker) = s.parent_waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use crate::stream::{Fuse, StreamExt};
+use core::fmt;
+use core::pin::Pin;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`inspect_done`](super::StreamExt::inspect_done) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct InspectDone<St, F> {
+        #[pin]
+        stream: Fuse<St>,
+        f: Option<F>,
+    }
+}
+
+impl<St, F> fmt::Debug for InspectDone<St, F>
+where
+    St: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InspectDone").field("stream", &self.stream).finish()
+    }
+}
+
+impl<St, F> InspectDone<St, F>
+where
+    St: Stream,
+    F: FnOnce(),
+{
+    pub(super) fn new(stream: St, f: F) -> Self {
+        Self { stream: stream.fuse(), f: Some(f) }
+    }
+
+    delegate_access_inner!(stream, St, (.));
+}
+
+impl<St, F> Stream for InspectDone<St, F>
+where
+    St: Stream,
+    F: FnOnce(),
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        match ready!(this.stream.poll_next(cx)) {
+            Some(item) => Poll::Ready(Some(item)),
+            None => {
+                // The closure fires only the first time the inner stream
+                // completes; later polls just keep yielding `None`.
+                if let Some(f) = this.f.take() {
+                    f();
+                }
+                Poll::Ready(None)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<St, F> FusedStream for InspectDone<St, F>
+where
+    St: Stream,
+    F: FnOnce(),
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_done()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, F, Item> Sink<Item> for InspectDone<S, F>
+where
+    S: Stream + Sink<Item>,
+{
+    type Error = S::Error;
+
+    delegate_sink!(stream, Item);
+}
@@ -0,0 +1,107 @@
+use crate::stream::Fuse;
+use core::pin::Pin;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`interleave`](super::StreamExt::interleave) method.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Interleave<St1, St2> {
+        #[pin]
+        stream1: Fuse<St1>,
+        #[pin]
+        stream2: Fuse<St2>,
+        second_next: bool,
+    }
+}
+
+impl<St1, St2> Interleave<St1, St2>
+where
+    St1: Stream,
+    St2: Stream<Item = St1::Item>,
+{
+    pub(super) fn new(stream1: St1, stream2: St2) -> Self {
+        Self { stream1: Fuse::new(stream1), stream2: Fuse::new(stream2), second_next: false }
+    }
+
+    /// Acquires a reference to the underlying streams that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> (&St1, &St2) {
+        (self.stream1.get_ref(), self.stream2.get_ref())
+    }
+
+    /// Acquires a mutable reference to the underlying streams that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// streams which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> (&mut St1, &mut St2) {
+        (self.stream1.get_mut(), self.stream2.get_mut())
+    }
+
+    /// Consumes this combinator, returning the underlying streams.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> (St1, St2) {
+        (self.stream1.into_inner(), self.stream2.into_inner())
+    }
+}
+
+impl<St1, St2> Stream for Interleave<St1, St2>
+where
+    St1: Stream,
+    St2: Stream<Item = St1::Item>,
+{
+    type Item = St1::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        // If it is the second stream's turn, wait for it rather than
+        // switching to the other side; deterministic ordering is the point
+        // of this combinator.
+        if *this.second_next && !this.stream2.is_done() {
+            if let Some(item) = ready!(this.stream2.as_mut().poll_next(cx)) {
+                *this.second_next = false;
+                return Poll::Ready(Some(item));
+            }
+            // The second stream is exhausted: fall through and drain the
+            // first one.
+        }
+
+        if !this.stream1.is_done() {
+            if let Some(item) = ready!(this.stream1.as_mut().poll_next(cx)) {
+                *this.second_next = true;
+                return Poll::Ready(Some(item));
+            }
+        }
+
+        // The first stream is exhausted: drain the remainder of the second.
+        this.stream2.poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower1, upper1) = self.stream1.size_hint();
+        let (lower2, upper2) = self.stream2.size_hint();
+        let lower = lower1.saturating_add(lower2);
+        let upper = match (upper1, upper2) {
+            (Some(x), Some(y)) => x.checked_add(y),
+            _ => None,
+        };
+        (lower, upper)
+    }
+}
+
+impl<St1, St2> FusedStream for Interleave<St1, St2>
+where
+    St1: Stream,
+    St2: Stream<Item = St1::Item>,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream1.is_done() && self.stream2.is_done()
+    }
+}
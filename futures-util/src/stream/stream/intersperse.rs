@@ -0,0 +1,207 @@
+use crate::stream::Fuse;
+use core::fmt;
+use core::mem;
+use core::pin::Pin;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`intersperse`](super::StreamExt::intersperse) method.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Intersperse<St>
+        where St: Stream,
+    {
+        #[pin]
+        stream: Fuse<St>,
+        separator: St::Item,
+        next_item: Option<St::Item>,
+        started: bool,
+    }
+}
+
+impl<St> Intersperse<St>
+where
+    St: Stream,
+    St::Item: Clone,
+{
+    pub(super) fn new(stream: St, separator: St::Item) -> Self {
+        Self { stream: super::Fuse::new(stream), separator, next_item: None, started: false }
+    }
+
+    delegate_access_inner!(stream, St, (.));
+}
+
+impl<St> Stream for Intersperse<St>
+where
+    St: Stream,
+    St::Item: Clone,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        // An item fetched while deciding to emit a separator is owed next.
+        if let Some(item) = this.next_item.take() {
+            return Poll::Ready(Some(item));
+        }
+
+        match ready!(this.stream.as_mut().poll_next(cx)) {
+            Some(item) => {
+                if mem::replace(this.started, true) {
+                    // A successor exists, so a separator goes out first.
+                    *this.next_item = Some(item);
+                    Poll::Ready(Some(this.separator.clone()))
+                } else {
+                    Poll::Ready(Some(item))
+                }
+            }
+            None => Poll::Ready(None),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        intersperse_size_hint(&self.stream, self.next_item.is_some(), self.started)
+    }
+}
+
+impl<St> FusedStream for Intersperse<St>
+where
+    St: Stream,
+    St::Item: Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_done() && self.next_item.is_none()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, Item> Sink<Item> for Intersperse<S>
+where
+    S: Stream + Sink<Item>,
+    S::Item: Clone,
+{
+    type Error = S::Error;
+
+    delegate_sink!(stream, Item);
+}
+
+pin_project! {
+    /// Stream for the [`intersperse_with`](super::StreamExt::intersperse_with) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct IntersperseWith<St, F>
+        where St: Stream,
+    {
+        #[pin]
+        stream: Fuse<St>,
+        f: F,
+        next_item: Option<St::Item>,
+        started: bool,
+    }
+}
+
+impl<St, F> fmt::Debug for IntersperseWith<St, F>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntersperseWith")
+            .field("stream", &self.stream)
+            .field("next_item", &self.next_item)
+            .field("started", &self.started)
+            .finish()
+    }
+}
+
+impl<St, F> IntersperseWith<St, F>
+where
+    St: Stream,
+    F: FnMut() -> St::Item,
+{
+    pub(super) fn new(stream: St, f: F) -> Self {
+        Self { stream: super::Fuse::new(stream), f, next_item: None, started: false }
+    }
+
+    delegate_access_inner!(stream, St, (.));
+}
+
+impl<St, F> Stream for IntersperseWith<St, F>
+where
+    St: Stream,
+    F: FnMut() -> St::Item,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if let Some(item) = this.next_item.take() {
+            return Poll::Ready(Some(item));
+        }
+
+        match ready!(this.stream.as_mut().poll_next(cx)) {
+            Some(item) => {
+                if mem::replace(this.started, true) {
+                    *this.next_item = Some(item);
+                    Poll::Ready(Some((this.f)()))
+                } else {
+                    Poll::Ready(Some(item))
+                }
+            }
+            None => Poll::Ready(None),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        intersperse_size_hint(&self.stream, self.next_item.is_some(), self.started)
+    }
+}
+
+impl<St, F> FusedStream for IntersperseWith<St, F>
+where
+    St: Stream,
+    F: FnMut() -> St::Item,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_done() && self.next_item.is_none()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, F, Item> Sink<Item> for IntersperseWith<S, F>
+where
+    S: Stream + Sink<Item>,
+{
+    type Error = S::Error;
+
+    delegate_sink!(stream, Item);
+}
+
+fn intersperse_size_hint<St: Stream>(
+    stream: &St,
+    buffered: bool,
+    started: bool,
+) -> (usize, Option<usize>) {
+    let buffered = buffered as usize;
+    let (lower, upper) = stream.size_hint();
+    // Every remaining item after the first is preceded by a separator.
+    let lower = {
+        let items = lower.saturating_add(buffered);
+        let separators = if started { items } else { items.saturating_sub(1) };
+        items.saturating_add(separators)
+    };
+    let upper = upper.and_then(|n| {
+        let items = n.checked_add(buffered)?;
+        let separators = if started { items } else { items.saturating_sub(1) };
+        items.checked_add(separators)
+    });
+    (lower, upper)
+}
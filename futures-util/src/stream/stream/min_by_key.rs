@@ -0,0 +1,79 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the [`min_by_key`](super::StreamExt::min_by_key) method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct MinByKey<St: Stream, F, K> {
+        #[pin]
+        stream: St,
+        f: F,
+        best: Option<(K, St::Item)>,
+    }
+}
+
+impl<St, F, K> fmt::Debug for MinByKey<St, F, K>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+    K: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MinByKey").field("stream", &self.stream).field("best", &self.best).finish()
+    }
+}
+
+impl<St, F, K> MinByKey<St, F, K>
+where
+    St: Stream,
+    F: FnMut(&St::Item) -> K,
+    K: Ord,
+{
+    pub(super) fn new(stream: St, f: F) -> Self {
+        Self { stream, f, best: None }
+    }
+}
+
+impl<St, F, K> FusedFuture for MinByKey<St, F, K>
+where
+    St: FusedStream,
+    F: FnMut(&St::Item) -> K,
+    K: Ord,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, F, K> Future for MinByKey<St, F, K>
+where
+    St: Stream,
+    F: FnMut(&St::Item) -> K,
+    K: Ord,
+{
+    type Output = Option<St::Item>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => {
+                    let key = (this.f)(&item);
+                    // Ties keep the current best so that, as with
+                    // `Iterator::min_by_key`, the first of several equally
+                    // minimal items wins.
+                    match this.best {
+                        Some((best_key, _)) if key >= *best_key => {}
+                        _ => *this.best = Some((key, item)),
+                    }
+                }
+                None => return Poll::Ready(this.best.take().map(|(_, item)| item)),
+            }
+        }
+    }
+}
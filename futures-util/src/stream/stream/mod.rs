@@ -3,13 +3,18 @@
 //! This module contains a number of functions for working with `Stream`s,
 //! including the `StreamExt` trait which adds methods to `Stream` types.
 
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+use crate::abortable::{AbortHandle, Abortable};
 use crate::future::{assert_future, Either};
 use crate::stream::assert_stream;
+use crate::time::Timer;
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 use core::pin::Pin;
+use core::time::Duration;
 #[cfg(feature = "alloc")]
 use futures_core::stream::{BoxStream, LocalBoxStream};
 use futures_core::{
@@ -34,6 +39,10 @@ mod unzip;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::unzip::Unzip;
 
+mod partition;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::partition::Partition;
+
 mod concat;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::concat::Concat;
@@ -42,10 +51,32 @@ mod count;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::count::Count;
 
+#[cfg(feature = "std")]
+mod counts;
+#[cfg(feature = "std")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::counts::{Counts, CountsBy};
+
 mod cycle;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::cycle::Cycle;
 
+mod cycle_n;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::cycle_n::CycleN;
+
+mod debounce;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::debounce::Debounce;
+
+mod dedup;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::dedup::{Dedup, DedupBy, DedupByKey};
+
+mod coalesce;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::coalesce::Coalesce;
+
 mod enumerate;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::enumerate::Enumerate;
@@ -58,6 +89,14 @@ mod filter_map;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::filter_map::FilterMap;
 
+mod find;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::find::Find;
+
+mod find_map;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::find_map::FindMap;
+
 mod flatten;
 
 delegate_all!(
@@ -72,6 +111,10 @@ mod fold;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::fold::Fold;
 
+mod fold_while;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::fold_while::{FoldStep, FoldWhile};
+
 mod any;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::any::Any;
@@ -93,6 +136,13 @@ delegate_all!(
     where St: Stream
 );
 
+#[cfg(feature = "sink")]
+mod unzip_into;
+#[cfg(feature = "sink")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sink")))]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::unzip_into::UnzipInto;
+
 mod for_each;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::for_each::ForEach;
@@ -101,6 +151,24 @@ mod fuse;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::fuse::Fuse;
 
+#[cfg(feature = "std")]
+mod group_by;
+#[cfg(feature = "std")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::group_by::{GroupBy, GroupStream};
+
+mod merge;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::merge::Merge;
+
+mod interleave;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::interleave::Interleave;
+
+mod intersperse;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::intersperse::{Intersperse, IntersperseWith};
+
 mod into_future;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::into_future::StreamFuture;
@@ -116,6 +184,14 @@ mod map;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::map::Map;
 
+mod max_by_key;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::max_by_key::MaxByKey;
+
+mod min_by_key;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::min_by_key::MinByKey;
+
 delegate_all!(
     /// Stream for the [`flat_map`](StreamExt::flat_map) method.
     FlatMap<St, U, F>(
@@ -123,19 +199,62 @@ delegate_all!(
     ): Debug + Sink + Stream + FusedStream + AccessInner[St, (. .)] + New[|x: St, f: F| flatten::Flatten::new(Map::new(x, f))]
 );
 
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+mod flat_map_unordered;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::flat_map_unordered::FlatMapUnordered;
+
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+mod flatten_unordered;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::flatten_unordered::FlattenUnordered;
+
 mod switch_map;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::switch_map::SwitchMap;
 
+mod inspect_done;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::inspect_done::InspectDone;
+
+mod last;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::last::Last;
+
 mod next;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::next::Next;
 
+mod nth;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::nth::Nth;
+
+mod position;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::position::FindPosition;
+
+mod product;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::product::Product;
+
+mod sum;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::sum::Sum;
+
 mod select_next_some;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::select_next_some::SelectNextSome;
 
 mod peek;
+#[cfg(feature = "alloc")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::peek::PeekN;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::peek::{NextIf, NextIfEq, Peek, PeekMut, Peekable};
 
@@ -147,6 +266,10 @@ mod skip_while;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::skip_while::SkipWhile;
 
+mod step_by;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::step_by::StepBy;
+
 mod take;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::take::Take;
@@ -159,10 +282,22 @@ mod take_until;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::take_until::TakeUntil;
 
+mod take_until_remainder;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::take_until_remainder::TakeUntilRemainder;
+
 mod then;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::then::Then;
 
+mod throttle;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::throttle::Throttle;
+
+mod timeout;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::timeout::Timeout;
+
 mod try_for_each;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::try_for_each::TryForEach;
@@ -171,22 +306,62 @@ mod try_fold;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::try_fold::TryFold;
 
+mod with_position;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::with_position::{Position, WithPosition};
+
 mod zip;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::zip::Zip;
 
+mod zip_longest;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::zip_longest::{EitherOrBoth, ZipLongest};
+
 #[cfg(feature = "alloc")]
 mod chunks;
 #[cfg(feature = "alloc")]
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::chunks::Chunks;
 
+#[cfg(feature = "alloc")]
+mod chunks_by;
+#[cfg(feature = "alloc")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::chunks_by::ChunksBy;
+
+#[cfg(feature = "alloc")]
+mod sorted;
+#[cfg(feature = "alloc")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::sorted::{Sorted, SortedBy};
+
+#[cfg(feature = "alloc")]
+mod chunks_timeout;
+#[cfg(feature = "alloc")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::chunks_timeout::ChunksTimeout;
+
 #[cfg(feature = "alloc")]
 mod ready_chunks;
 #[cfg(feature = "alloc")]
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::ready_chunks::ReadyChunks;
 
+mod rev;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::rev::Rev;
+
+#[cfg(feature = "alloc")]
+mod reverse;
+#[cfg(feature = "alloc")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::reverse::Reverse;
+
+mod sample;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::sample::Sample;
+
 mod scan;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::scan::Scan;
@@ -199,6 +374,22 @@ mod buffer_unordered;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::buffer_unordered::BufferUnordered;
 
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+mod buffer_unordered_weighted;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::buffer_unordered_weighted::BufferUnorderedWeighted;
+
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+mod rate_limit;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::rate_limit::RateLimit;
+
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
 mod buffered;
@@ -207,6 +398,14 @@ mod buffered;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::buffered::Buffered;
 
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "std")]
+mod buffered_by_key;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "std")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::buffered_by_key::BufferedByKey;
+
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
 mod for_each_concurrent;
@@ -241,6 +440,18 @@ mod catch_unwind;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::catch_unwind::CatchUnwind;
 
+#[cfg(feature = "std")]
+mod unique;
+#[cfg(feature = "std")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::unique::{Unique, UniqueBy};
+
+#[cfg(feature = "std")]
+mod split_round_robin;
+#[cfg(feature = "std")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::split_round_robin::RoundRobin;
+
 impl<T: ?Sized> StreamExt for T where T: Stream {}
 
 /// An extension trait for `Stream`s that provides a variety of convenient
@@ -276,6 +487,61 @@ pub trait StreamExt: Stream {
         assert_future::<Option<Self::Item>, _>(Next::new(self))
     }
 
+    /// Creates a future that resolves to the last element of the stream, or
+    /// [`None`] if the stream is empty.
+    ///
+    /// This mirrors [`Iterator::last`]: the whole stream is drained, so every
+    /// item (and any side effect of producing it) is processed on the way to
+    /// the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=3);
+    /// assert_eq!(stream.last().await, Some(3));
+    ///
+    /// let empty = stream::empty::<i32>();
+    /// assert_eq!(empty.last().await, None);
+    /// # });
+    /// ```
+    fn last(self) -> Last<Self>
+    where
+        Self: Sized,
+    {
+        assert_future::<Option<Self::Item>, _>(Last::new(self))
+    }
+
+    /// Creates a future that resolves to the `n`th element of the stream, or
+    /// [`None`] if the stream has fewer than `n + 1` elements.
+    ///
+    /// This mirrors [`Iterator::nth`]: the first `n` items are consumed and
+    /// discarded (any side effect of producing them still runs), and the
+    /// next item is returned. Like most indexing operations, the count starts
+    /// from zero, so `nth(0)` returns the first item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=5);
+    /// assert_eq!(stream.nth(2).await, Some(3));
+    ///
+    /// let stream = stream::iter(1..=5);
+    /// assert_eq!(stream.nth(7).await, None);
+    /// # });
+    /// ```
+    fn nth(self, n: usize) -> Nth<Self>
+    where
+        Self: Sized,
+    {
+        assert_future::<Option<Self::Item>, _>(Nth::new(self, n))
+    }
+
     /// Converts this stream into a future of `(next_item, tail_of_stream)`.
     /// If the stream terminates, then the next item is [`None`].
     ///
@@ -387,6 +653,42 @@ pub trait StreamExt: Stream {
         assert_stream::<(usize, Self::Item), _>(Enumerate::new(self))
     }
 
+    /// Creates a stream which gives the current iteration count as well as
+    /// the next value, starting the count at `start`.
+    ///
+    /// This works exactly like [`enumerate`](StreamExt::enumerate) — indeed,
+    /// a `start` of `0` is equivalent to it — but lets the numbering continue
+    /// from an arbitrary index, for example when resuming a paginated stream.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// Like [`enumerate`](StreamExt::enumerate), the count is not guarded
+    /// against overflow: past [`prim@usize::max_value()`] it wraps around in
+    /// release builds, and panics if debug assertions are enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec!['d', 'e', 'f']);
+    ///
+    /// let mut stream = stream.enumerate_from(3);
+    ///
+    /// assert_eq!(stream.next().await, Some((3, 'd')));
+    /// assert_eq!(stream.next().await, Some((4, 'e')));
+    /// assert_eq!(stream.next().await, Some((5, 'f')));
+    /// assert_eq!(stream.next().await, None);
+    /// # });
+    /// ```
+    fn enumerate_from(self, start: usize) -> Enumerate<Self>
+    where
+        Self: Sized,
+    {
+        assert_stream::<(usize, Self::Item), _>(Enumerate::new_from(self, start))
+    }
+
     /// Filters the values produced by this stream according to the provided
     /// asynchronous predicate.
     ///
@@ -456,45 +758,38 @@ pub trait StreamExt: Stream {
         assert_stream::<T, _>(FilterMap::new(self, f))
     }
 
-    /// Discards every value but the latest, maps it to a new stream and then returns
-    /// the items from the mapped stream.
-    /// When a new item comes from the root stream, the process is repeated.
+    /// Creates a future that resolves to the first element of the stream
+    /// satisfying a predicate, or [`None`] if no element does.
+    ///
+    /// This mirrors [`Iterator::find`]: items are consumed until the
+    /// predicate returns `true`, at which point the stream is not polled any
+    /// further. If the stream ends without a match, it is fully drained.
     ///
     /// # Examples
+    ///
     /// ```
     /// # futures::executor::block_on(async {
     /// use futures::stream::{self, StreamExt};
     ///
-    /// let stream = stream::iter([1,3,5,7]);
-    ///
-    /// // Maps each numbers to a stream of its multiples.
-    /// // Only the latest available item from `stream` is mapped.
-    /// let multiples = stream.switch_map(|x| {
-    ///     stream::iter(1..)
-    ///         .map(move |i| x*i).take(4)
-    /// });
-    ///
-    /// assert_eq!(vec![7, 14, 21, 28], multiples.collect::<Vec<_>>().await);
+    /// let stream = stream::iter(1..=10);
+    /// assert_eq!(stream.find(|x| x % 3 == 0).await, Some(3));
     /// # });
     /// ```
-    fn switch_map<T, F>(self, f: F) -> SwitchMap<Self, T, F>
+    fn find<F>(self, f: F) -> Find<Self, F>
     where
-        F: FnMut(Self::Item) -> T,
-        T: Stream,
+        F: FnMut(&Self::Item) -> bool,
         Self: Sized,
     {
-        assert_stream::<T::Item, _>(SwitchMap::new(self, f))
+        assert_future::<Option<Self::Item>, _>(Find::new(self, f))
     }
 
-    /// Computes from this stream's items new items of a different type using
-    /// an asynchronous closure.
-    ///
-    /// The provided closure `f` will be called with an `Item` once a value is
-    /// ready, it returns a future which will then be run to completion
-    /// to produce the next value on this stream.
+    /// Creates a future that applies a closure to the elements of the stream
+    /// and resolves to the first non-[`None`] result.
     ///
-    /// Note that this function consumes the stream passed into it and returns a
-    /// wrapped version of it.
+    /// This mirrors [`Iterator::find_map`] and behaves like
+    /// `stream.filter_map(f).next()`, but with a synchronous closure: items
+    /// are consumed until the closure returns `Some`, at which point the
+    /// stream is not polled any further.
     ///
     /// # Examples
     ///
@@ -502,144 +797,134 @@ pub trait StreamExt: Stream {
     /// # futures::executor::block_on(async {
     /// use futures::stream::{self, StreamExt};
     ///
-    /// let stream = stream::iter(1..=3);
-    /// let stream = stream.then(|x| async move { x + 3 });
-    ///
-    /// assert_eq!(vec![4, 5, 6], stream.collect::<Vec<_>>().await);
+    /// let stream = stream::iter(vec!["lorem", "42", "ipsum"]);
+    /// let first_number = stream.find_map(|s| s.parse::<i32>().ok()).await;
+    /// assert_eq!(first_number, Some(42));
     /// # });
     /// ```
-    fn then<Fut, F>(self, f: F) -> Then<Self, Fut, F>
+    fn find_map<F, B>(self, f: F) -> FindMap<Self, F>
     where
-        F: FnMut(Self::Item) -> Fut,
-        Fut: Future,
+        F: FnMut(Self::Item) -> Option<B>,
         Self: Sized,
     {
-        assert_stream::<Fut::Output, _>(Then::new(self, f))
+        assert_future::<Option<B>, _>(FindMap::new(self, f))
     }
 
-    /// Transforms a stream into a collection, returning a
-    /// future representing the result of that computation.
+    /// Creates a future that resolves to the zero-based index of the first
+    /// element of the stream satisfying a predicate, or [`None`] if no
+    /// element does.
     ///
-    /// The returned future will be resolved when the stream terminates.
+    /// This mirrors [`Iterator::position`]: every polled item counts towards
+    /// the index, matching or not, and a match stops polling the stream any
+    /// further.
     ///
     /// # Examples
     ///
     /// ```
     /// # futures::executor::block_on(async {
-    /// use futures::channel::mpsc;
-    /// use futures::stream::StreamExt;
-    /// use std::thread;
-    ///
-    /// let (tx, rx) = mpsc::unbounded();
-    ///
-    /// thread::spawn(move || {
-    ///     for i in 1..=5 {
-    ///         tx.unbounded_send(i).unwrap();
-    ///     }
-    /// });
+    /// use futures::stream::{self, StreamExt};
     ///
-    /// let output = rx.collect::<Vec<i32>>().await;
-    /// assert_eq!(output, vec![1, 2, 3, 4, 5]);
+    /// let stream = stream::iter(vec![1, 3, 4, 7]);
+    /// assert_eq!(stream.position(|x| x % 2 == 0).await, Some(2));
     /// # });
     /// ```
-    fn collect<C: Default + Extend<Self::Item>>(self) -> Collect<Self, C>
+    fn position<F>(self, f: F) -> FindPosition<Self, F>
     where
+        F: FnMut(&Self::Item) -> bool,
         Self: Sized,
     {
-        assert_future::<C, _>(Collect::new(self))
+        assert_future::<Option<usize>, _>(FindPosition::new(self, f))
     }
 
-    /// Converts a stream of pairs into a future, which
-    /// resolves to pair of containers.
-    ///
-    /// `unzip()` produces a future, which resolves to two
-    /// collections: one from the left elements of the pairs,
-    /// and one from the right elements.
+    /// Creates a future that resolves to the element yielding the maximum
+    /// key, or [`None`] if the stream is empty.
     ///
-    /// The returned future will be resolved when the stream terminates.
+    /// The stream is drained completely. As with [`Iterator::max_by_key`],
+    /// if several elements are equally maximal the last one is returned.
     ///
     /// # Examples
     ///
     /// ```
     /// # futures::executor::block_on(async {
-    /// use futures::channel::mpsc;
-    /// use futures::stream::StreamExt;
-    /// use std::thread;
+    /// use futures::stream::{self, StreamExt};
     ///
-    /// let (tx, rx) = mpsc::unbounded();
+    /// let stream = stream::iter(vec![-10i32, 3, -4]);
+    /// assert_eq!(stream.max_by_key(|x| x.abs()).await, Some(-10));
+    /// # });
+    /// ```
+    fn max_by_key<F, K>(self, f: F) -> MaxByKey<Self, F, K>
+    where
+        F: FnMut(&Self::Item) -> K,
+        K: Ord,
+        Self: Sized,
+    {
+        assert_future::<Option<Self::Item>, _>(MaxByKey::new(self, f))
+    }
+
+    /// Creates a future that resolves to the element yielding the minimum
+    /// key, or [`None`] if the stream is empty.
     ///
-    /// thread::spawn(move || {
-    ///     tx.unbounded_send((1, 2)).unwrap();
-    ///     tx.unbounded_send((3, 4)).unwrap();
-    ///     tx.unbounded_send((5, 6)).unwrap();
-    /// });
+    /// The stream is drained completely. As with [`Iterator::min_by_key`],
+    /// if several elements are equally minimal the first one is returned.
     ///
-    /// let (o1, o2): (Vec<_>, Vec<_>) = rx.unzip().await;
-    /// assert_eq!(o1, vec![1, 3, 5]);
-    /// assert_eq!(o2, vec![2, 4, 6]);
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![-10i32, 3, -4]);
+    /// assert_eq!(stream.min_by_key(|x| x.abs()).await, Some(3));
     /// # });
     /// ```
-    fn unzip<A, B, FromA, FromB>(self) -> Unzip<Self, FromA, FromB>
+    fn min_by_key<F, K>(self, f: F) -> MinByKey<Self, F, K>
     where
-        FromA: Default + Extend<A>,
-        FromB: Default + Extend<B>,
-        Self: Sized + Stream<Item = (A, B)>,
+        F: FnMut(&Self::Item) -> K,
+        K: Ord,
+        Self: Sized,
     {
-        assert_future::<(FromA, FromB), _>(Unzip::new(self))
+        assert_future::<Option<Self::Item>, _>(MinByKey::new(self, f))
     }
 
-    /// Concatenate all items of a stream into a single extendable
-    /// destination, returning a future representing the end result.
+    /// Buffers the entire stream, sorts the items, and yields them in
+    /// ascending order once the source stream ends.
     ///
-    /// This combinator will extend the first item with the contents
-    /// of all the subsequent results of the stream. If the stream is
-    /// empty, the default value will be returned.
+    /// Nothing is yielded until the source stream terminates, and every item
+    /// is held in memory until then, so this is only suitable for streams
+    /// known to be reasonably small.
     ///
-    /// Works with all collections that implement the
-    /// [`Extend`](std::iter::Extend) trait.
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
     ///
     /// # Examples
     ///
     /// ```
     /// # futures::executor::block_on(async {
-    /// use futures::channel::mpsc;
-    /// use futures::stream::StreamExt;
-    /// use std::thread;
-    ///
-    /// let (tx, rx) = mpsc::unbounded();
-    ///
-    /// thread::spawn(move || {
-    ///     for i in (0..3).rev() {
-    ///         let n = i * 3;
-    ///         tx.unbounded_send(vec![n + 1, n + 2, n + 3]).unwrap();
-    ///     }
-    /// });
-    ///
-    /// let result = rx.concat().await;
+    /// use futures::stream::{self, StreamExt};
     ///
-    /// assert_eq!(result, vec![7, 8, 9, 4, 5, 6, 1, 2, 3]);
+    /// let stream = stream::iter(vec![3, 1, 2]);
+    /// assert_eq!(stream.sorted().collect::<Vec<_>>().await, vec![1, 2, 3]);
     /// # });
     /// ```
-    fn concat(self) -> Concat<Self>
+    #[cfg(feature = "alloc")]
+    fn sorted(self) -> Sorted<Self>
     where
+        Self::Item: Ord,
         Self: Sized,
-        Self::Item: Extend<<<Self as Stream>::Item as IntoIterator>::Item> + IntoIterator + Default,
     {
-        assert_future::<Self::Item, _>(Concat::new(self))
+        assert_stream::<Self::Item, _>(Sorted::new(self))
     }
 
-    /// Drives the stream to completion, counting the number of items.
+    /// Buffers the entire stream, sorts the items with a comparator
+    /// function, and yields them in order once the source stream ends.
     ///
-    /// # Overflow Behavior
-    ///
-    /// The method does no guarding against overflows, so counting elements of a
-    /// stream with more than [`usize::MAX`] elements either produces the wrong
-    /// result or panics. If debug assertions are enabled, a panic is guaranteed.
-    ///
-    /// # Panics
+    /// The sort is stable, like [`slice::sort_by`]: items that compare equal
+    /// keep the order in which the stream produced them. As with
+    /// [`sorted`](StreamExt::sorted), every item is buffered in memory until
+    /// the source stream terminates.
     ///
-    /// This function might panic if the iterator has more than [`usize::MAX`]
-    /// elements.
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
     ///
     /// # Examples
     ///
@@ -647,57 +932,51 @@ pub trait StreamExt: Stream {
     /// # futures::executor::block_on(async {
     /// use futures::stream::{self, StreamExt};
     ///
-    /// let stream = stream::iter(1..=10);
-    /// let count = stream.count().await;
-    ///
-    /// assert_eq!(count, 10);
+    /// let stream = stream::iter(vec![3, 1, 2]);
+    /// let descending = stream.sorted_by(|a, b| b.cmp(a)).collect::<Vec<_>>().await;
+    /// assert_eq!(descending, vec![3, 2, 1]);
     /// # });
     /// ```
-    fn count(self) -> Count<Self>
+    #[cfg(feature = "alloc")]
+    fn sorted_by<F>(self, cmp: F) -> SortedBy<Self, F>
     where
+        F: FnMut(&Self::Item, &Self::Item) -> core::cmp::Ordering,
         Self: Sized,
     {
-        assert_future::<usize, _>(Count::new(self))
+        assert_stream::<Self::Item, _>(SortedBy::new(self, cmp))
     }
 
-    /// Repeats a stream endlessly.
+    /// Creates a future that sums the elements of the stream, draining it
+    /// completely.
     ///
-    /// The stream never terminates. Note that you likely want to avoid
-    /// usage of `collect` or such on the returned stream as it will exhaust
-    /// available memory as it tries to just fill up all RAM.
+    /// Like [`Iterator::sum`], an empty stream resolves to the additive
+    /// identity (zero for the numeric types), and overflow behavior follows
+    /// the underlying numeric type.
     ///
     /// # Examples
     ///
     /// ```
     /// # futures::executor::block_on(async {
     /// use futures::stream::{self, StreamExt};
-    /// let a = [1, 2, 3];
-    /// let mut s = stream::iter(a.iter()).cycle();
     ///
-    /// assert_eq!(s.next().await, Some(&1));
-    /// assert_eq!(s.next().await, Some(&2));
-    /// assert_eq!(s.next().await, Some(&3));
-    /// assert_eq!(s.next().await, Some(&1));
-    /// assert_eq!(s.next().await, Some(&2));
-    /// assert_eq!(s.next().await, Some(&3));
-    /// assert_eq!(s.next().await, Some(&1));
+    /// let stream = stream::iter(1..=4);
+    /// assert_eq!(stream.sum::<i32>().await, 10);
     /// # });
     /// ```
-    fn cycle(self) -> Cycle<Self>
+    fn sum<S>(self) -> Sum<Self, S>
     where
-        Self: Sized + Clone,
+        S: core::iter::Sum<Self::Item> + core::iter::Sum<S>,
+        Self: Sized,
     {
-        assert_stream::<Self::Item, _>(Cycle::new(self))
+        assert_future::<S, _>(Sum::new(self))
     }
 
-    /// Execute an accumulating asynchronous computation over a stream,
-    /// collecting all the values into one final result.
+    /// Creates a future that multiplies the elements of the stream, draining
+    /// it completely.
     ///
-    /// This combinator will accumulate all values returned by this stream
-    /// according to the closure provided. The initial state is also provided to
-    /// this method and then is returned again by each execution of the closure.
-    /// Once the entire stream has been exhausted the returned future will
-    /// resolve to this value.
+    /// Like [`Iterator::product`], an empty stream resolves to the
+    /// multiplicative identity (one for the numeric types), and overflow
+    /// behavior follows the underlying numeric type.
     ///
     /// # Examples
     ///
@@ -705,43 +984,57 @@ pub trait StreamExt: Stream {
     /// # futures::executor::block_on(async {
     /// use futures::stream::{self, StreamExt};
     ///
-    /// let number_stream = stream::iter(0..6);
-    /// let sum = number_stream.fold(0, |acc, x| async move { acc + x });
-    /// assert_eq!(sum.await, 15);
+    /// let stream = stream::iter(1..=4);
+    /// assert_eq!(stream.product::<i32>().await, 24);
     /// # });
     /// ```
-    fn fold<T, Fut, F>(self, init: T, f: F) -> Fold<Self, Fut, T, F>
+    fn product<P>(self) -> Product<Self, P>
     where
-        F: FnMut(T, Self::Item) -> Fut,
-        Fut: Future<Output = T>,
+        P: core::iter::Product<Self::Item> + core::iter::Product<P>,
         Self: Sized,
     {
-        assert_future::<T, _>(Fold::new(self, f, init))
+        assert_future::<P, _>(Product::new(self))
     }
 
-    /// Execute predicate over asynchronous stream, and return `true` if any element in stream satisfied a predicate.
+    /// Discards every value but the latest, maps it to a new stream and then returns
+    /// the items from the mapped stream.
+    /// When a new item comes from the root stream, the process is repeated.
     ///
     /// # Examples
-    ///
     /// ```
     /// # futures::executor::block_on(async {
     /// use futures::stream::{self, StreamExt};
     ///
-    /// let number_stream = stream::iter(0..10);
-    /// let contain_three = number_stream.any(|i| async move { i == 3 });
-    /// assert_eq!(contain_three.await, true);
+    /// let stream = stream::iter([1,3,5,7]);
+    ///
+    /// // Maps each numbers to a stream of its multiples.
+    /// // Only the latest available item from `stream` is mapped.
+    /// let multiples = stream.switch_map(|x| {
+    ///     stream::iter(1..)
+    ///         .map(move |i| x*i).take(4)
+    /// });
+    ///
+    /// assert_eq!(vec![7, 14, 21, 28], multiples.collect::<Vec<_>>().await);
     /// # });
     /// ```
-    fn any<Fut, F>(self, f: F) -> Any<Self, Fut, F>
+    fn switch_map<T, F>(self, f: F) -> SwitchMap<Self, T, F>
     where
-        F: FnMut(Self::Item) -> Fut,
-        Fut: Future<Output = bool>,
+        F: FnMut(Self::Item) -> T,
+        T: Stream,
         Self: Sized,
     {
-        assert_future::<bool, _>(Any::new(self, f))
+        assert_stream::<T::Item, _>(SwitchMap::new(self, f))
     }
 
-    /// Execute predicate over asynchronous stream, and return `true` if all element in stream satisfied a predicate.
+    /// Computes from this stream's items new items of a different type using
+    /// an asynchronous closure.
+    ///
+    /// The provided closure `f` will be called with an `Item` once a value is
+    /// ready, it returns a future which will then be run to completion
+    /// to produce the next value on this stream.
+    ///
+    /// Note that this function consumes the stream passed into it and returns a
+    /// wrapped version of it.
     ///
     /// # Examples
     ///
@@ -749,21 +1042,25 @@ pub trait StreamExt: Stream {
     /// # futures::executor::block_on(async {
     /// use futures::stream::{self, StreamExt};
     ///
-    /// let number_stream = stream::iter(0..10);
-    /// let less_then_twenty = number_stream.all(|i| async move { i < 20 });
-    /// assert_eq!(less_then_twenty.await, true);
+    /// let stream = stream::iter(1..=3);
+    /// let stream = stream.then(|x| async move { x + 3 });
+    ///
+    /// assert_eq!(vec![4, 5, 6], stream.collect::<Vec<_>>().await);
     /// # });
     /// ```
-    fn all<Fut, F>(self, f: F) -> All<Self, Fut, F>
+    fn then<Fut, F>(self, f: F) -> Then<Self, Fut, F>
     where
         F: FnMut(Self::Item) -> Fut,
-        Fut: Future<Output = bool>,
+        Fut: Future,
         Self: Sized,
     {
-        assert_future::<bool, _>(All::new(self, f))
+        assert_stream::<Fut::Output, _>(Then::new(self, f))
     }
 
-    /// Flattens a stream of streams into just one continuous stream.
+    /// Transforms a stream into a collection, returning a
+    /// future representing the result of that computation.
+    ///
+    /// The returned future will be resolved when the stream terminates.
     ///
     /// # Examples
     ///
@@ -773,47 +1070,32 @@ pub trait StreamExt: Stream {
     /// use futures::stream::StreamExt;
     /// use std::thread;
     ///
-    /// let (tx1, rx1) = mpsc::unbounded();
-    /// let (tx2, rx2) = mpsc::unbounded();
-    /// let (tx3, rx3) = mpsc::unbounded();
+    /// let (tx, rx) = mpsc::unbounded();
     ///
     /// thread::spawn(move || {
-    ///     tx1.unbounded_send(1).unwrap();
-    ///     tx1.unbounded_send(2).unwrap();
-    /// });
-    /// thread::spawn(move || {
-    ///     tx2.unbounded_send(3).unwrap();
-    ///     tx2.unbounded_send(4).unwrap();
-    /// });
-    /// thread::spawn(move || {
-    ///     tx3.unbounded_send(rx1).unwrap();
-    ///     tx3.unbounded_send(rx2).unwrap();
+    ///     for i in 1..=5 {
+    ///         tx.unbounded_send(i).unwrap();
+    ///     }
     /// });
     ///
-    /// let output = rx3.flatten().collect::<Vec<i32>>().await;
-    /// assert_eq!(output, vec![1, 2, 3, 4]);
+    /// let output = rx.collect::<Vec<i32>>().await;
+    /// assert_eq!(output, vec![1, 2, 3, 4, 5]);
     /// # });
     /// ```
-    fn flatten(self) -> Flatten<Self>
+    fn collect<C: Default + Extend<Self::Item>>(self) -> Collect<Self, C>
     where
-        Self::Item: Stream,
         Self: Sized,
     {
-        assert_stream::<<Self::Item as Stream>::Item, _>(Flatten::new(self))
+        assert_future::<C, _>(Collect::new(self))
     }
 
-    /// Maps a stream like [`StreamExt::map`] but flattens nested `Stream`s.
-    ///
-    /// [`StreamExt::map`] is very useful, but if it produces a `Stream` instead,
-    /// you would have to chain combinators like `.map(f).flatten()` while this
-    /// combinator provides ability to write `.flat_map(f)` instead of chaining.
-    ///
-    /// The provided closure which produce inner streams is executed over all elements
-    /// of stream as last inner stream is terminated and next stream item is available.
+    /// Collects the items of this stream into a provided collection,
+    /// extending whatever it already contains.
     ///
-    /// Note that this function consumes the stream passed into it and returns a
-    /// wrapped version of it, similar to the existing `flat_map` methods in the
-    /// standard library.
+    /// This behaves like [`collect`](StreamExt::collect), but instead of
+    /// allocating a fresh collection it appends to `collection` and resolves
+    /// to it once the stream terminates, allowing capacity or existing
+    /// contents to be reused across runs.
     ///
     /// # Examples
     ///
@@ -821,31 +1103,29 @@ pub trait StreamExt: Stream {
     /// # futures::executor::block_on(async {
     /// use futures::stream::{self, StreamExt};
     ///
-    /// let stream = stream::iter(1..=3);
-    /// let stream = stream.flat_map(|x| stream::iter(vec![x + 3; x]));
+    /// let stream = stream::iter(4..=6);
     ///
-    /// assert_eq!(vec![4, 5, 5, 6, 6, 6], stream.collect::<Vec<_>>().await);
+    /// let output = stream.collect_into(vec![1, 2, 3]).await;
+    /// assert_eq!(output, vec![1, 2, 3, 4, 5, 6]);
     /// # });
     /// ```
-    fn flat_map<U, F>(self, f: F) -> FlatMap<Self, U, F>
+    fn collect_into<C: Default + Extend<Self::Item>>(self, collection: C) -> Collect<Self, C>
     where
-        F: FnMut(Self::Item) -> U,
-        U: Stream,
         Self: Sized,
     {
-        assert_stream::<U::Item, _>(FlatMap::new(self, f))
+        assert_future::<C, _>(Collect::with_collection(self, collection))
     }
 
-    /// Combinator similar to [`StreamExt::fold`] that holds internal state
-    /// and produces a new stream.
+    /// Transforms a stream into a [`Vec`], preallocating capacity from the
+    /// stream's [`size_hint`](Stream::size_hint) lower bound.
     ///
-    /// Accepts initial state and closure which will be applied to each element
-    /// of the stream until provided closure returns `None`. Once `None` is
-    /// returned, stream will be terminated.
+    /// This behaves like [`collect`](StreamExt::collect) into a `Vec`, but
+    /// avoids repeated reallocations when the stream reports a useful lower
+    /// bound, e.g. for [`iter`](super::iter()) streams and adapters that
+    /// forward size hints.
     ///
-    /// Unlike [`Iterator::scan`], the closure takes the state by value instead of
-    /// mutable reference to avoid [the limitation of the async
-    /// block](https://github.com/rust-lang/futures-rs/issues/2171).
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
     ///
     /// # Examples
     ///
@@ -853,247 +1133,1933 @@ pub trait StreamExt: Stream {
     /// # futures::executor::block_on(async {
     /// use futures::stream::{self, StreamExt};
     ///
-    /// let stream = stream::iter(1..=10);
-    ///
-    /// let stream = stream.scan(0, |mut state, x| async move {
-    ///     state += x;
-    ///     if state < 10 {
-    ///         Some((state, x))
-    ///     } else {
-    ///         None
-    ///     }
-    /// });
+    /// let stream = stream::iter(1..=5).map(|x| x * 2);
     ///
-    /// assert_eq!(vec![1, 2, 3], stream.collect::<Vec<_>>().await);
+    /// let output = stream.collect_with_capacity().await;
+    /// assert_eq!(output, vec![2, 4, 6, 8, 10]);
     /// # });
     /// ```
-    fn scan<S, B, Fut, F>(self, initial_state: S, f: F) -> Scan<Self, S, Fut, F>
+    #[cfg(feature = "alloc")]
+    fn collect_with_capacity(self) -> Collect<Self, Vec<Self::Item>>
     where
-        F: FnMut(S, Self::Item) -> Fut,
-        Fut: Future<Output = Option<(S, B)>>,
         Self: Sized,
     {
-        assert_stream::<B, _>(Scan::new(self, initial_state, f))
+        let (lower, _) = self.size_hint();
+        assert_future::<Vec<Self::Item>, _>(Collect::with_collection(
+            self,
+            Vec::with_capacity(lower),
+        ))
     }
 
-    /// Skip elements on this stream while the provided asynchronous predicate
-    /// resolves to `true`.
+    /// Converts a stream of pairs into a future, which
+    /// resolves to pair of containers.
     ///
-    /// This function, like `Iterator::skip_while`, will skip elements on the
-    /// stream until the predicate `f` resolves to `false`. Once one element
-    /// returns `false`, all future elements will be returned from the underlying
-    /// stream.
+    /// `unzip()` produces a future, which resolves to two
+    /// collections: one from the left elements of the pairs,
+    /// and one from the right elements.
+    ///
+    /// The returned future will be resolved when the stream terminates.
     ///
     /// # Examples
     ///
     /// ```
     /// # futures::executor::block_on(async {
-    /// use futures::future;
-    /// use futures::stream::{self, StreamExt};
+    /// use futures::channel::mpsc;
+    /// use futures::stream::StreamExt;
+    /// use std::thread;
     ///
-    /// let stream = stream::iter(1..=10);
+    /// let (tx, rx) = mpsc::unbounded();
     ///
-    /// let stream = stream.skip_while(|x| future::ready(*x <= 5));
+    /// thread::spawn(move || {
+    ///     tx.unbounded_send((1, 2)).unwrap();
+    ///     tx.unbounded_send((3, 4)).unwrap();
+    ///     tx.unbounded_send((5, 6)).unwrap();
+    /// });
     ///
-    /// assert_eq!(vec![6, 7, 8, 9, 10], stream.collect::<Vec<_>>().await);
+    /// let (o1, o2): (Vec<_>, Vec<_>) = rx.unzip().await;
+    /// assert_eq!(o1, vec![1, 3, 5]);
+    /// assert_eq!(o2, vec![2, 4, 6]);
     /// # });
     /// ```
-    fn skip_while<Fut, F>(self, f: F) -> SkipWhile<Self, Fut, F>
+    fn unzip<A, B, FromA, FromB>(self) -> Unzip<Self, FromA, FromB>
     where
-        F: FnMut(&Self::Item) -> Fut,
-        Fut: Future<Output = bool>,
-        Self: Sized,
+        FromA: Default + Extend<A>,
+        FromB: Default + Extend<B>,
+        Self: Sized + Stream<Item = (A, B)>,
+    {
+        assert_future::<(FromA, FromB), _>(Unzip::new(self))
+    }
+
+    /// Routes a stream of pairs into two sinks, without collecting.
+    ///
+    /// The left element of each pair is sent to `sink_a` and the right
+    /// element to `sink_b`. At most one pair is held at a time: the stream is
+    /// not polled again until both halves have been accepted, so backpressure
+    /// from either sink pauses the stream. The future resolves once the
+    /// stream has ended and both sinks have been closed; an error from either
+    /// sink aborts it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::mpsc;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let (tx_a, rx_a) = mpsc::unbounded();
+    /// let (tx_b, rx_b) = mpsc::unbounded();
+    ///
+    /// stream::iter(vec![(1, 'a'), (2, 'b'), (3, 'c')])
+    ///     .unzip_into(tx_a, tx_b)
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// assert_eq!(rx_a.collect::<Vec<_>>().await, vec![1, 2, 3]);
+    /// assert_eq!(rx_b.collect::<Vec<_>>().await, vec!['a', 'b', 'c']);
+    /// # });
+    /// ```
+    #[cfg(feature = "sink")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sink")))]
+    fn unzip_into<A, B, SiA, SiB, E>(
+        self,
+        sink_a: SiA,
+        sink_b: SiB,
+    ) -> UnzipInto<Self, SiA, SiB, A, B>
+    where
+        SiA: Sink<A, Error = E>,
+        SiB: Sink<B, Error = E>,
+        Self: Sized + Stream<Item = (A, B)>,
+    {
+        assert_future::<Result<(), E>, _>(UnzipInto::new(self, sink_a, sink_b))
+    }
+
+    /// Consumes the stream, splitting its items into two collections
+    /// depending on a predicate, and returns a future representing the end
+    /// result.
+    ///
+    /// Items for which `f` returns `true` are extended into the first
+    /// collection, all other items into the second one, mirroring
+    /// [`Iterator::partition`]. The predicate is invoked exactly once per
+    /// item.
+    ///
+    /// The returned future will be resolved when the stream terminates. An
+    /// empty stream resolves to two empty collections.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(0..6);
+    ///
+    /// let (even, odd): (Vec<_>, Vec<_>) = stream.partition(|n| n % 2 == 0).await;
+    /// assert_eq!(even, vec![0, 2, 4]);
+    /// assert_eq!(odd, vec![1, 3, 5]);
+    /// # });
+    /// ```
+    fn partition<F, A, B>(self, f: F) -> Partition<Self, F, A, B>
+    where
+        F: FnMut(&Self::Item) -> bool,
+        A: Default + Extend<Self::Item>,
+        B: Default + Extend<Self::Item>,
+        Self: Sized,
+    {
+        assert_future::<(A, B), _>(Partition::new(self, f))
+    }
+
+    /// Concatenate all items of a stream into a single extendable
+    /// destination, returning a future representing the end result.
+    ///
+    /// This combinator will extend the first item with the contents
+    /// of all the subsequent results of the stream. If the stream is
+    /// empty, the default value will be returned.
+    ///
+    /// Works with all collections that implement the
+    /// [`Extend`](std::iter::Extend) trait.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::mpsc;
+    /// use futures::stream::StreamExt;
+    /// use std::thread;
+    ///
+    /// let (tx, rx) = mpsc::unbounded();
+    ///
+    /// thread::spawn(move || {
+    ///     for i in (0..3).rev() {
+    ///         let n = i * 3;
+    ///         tx.unbounded_send(vec![n + 1, n + 2, n + 3]).unwrap();
+    ///     }
+    /// });
+    ///
+    /// let result = rx.concat().await;
+    ///
+    /// assert_eq!(result, vec![7, 8, 9, 4, 5, 6, 1, 2, 3]);
+    /// # });
+    /// ```
+    fn concat(self) -> Concat<Self>
+    where
+        Self: Sized,
+        Self::Item: Extend<<<Self as Stream>::Item as IntoIterator>::Item> + IntoIterator + Default,
+    {
+        assert_future::<Self::Item, _>(Concat::new(self))
+    }
+
+    /// Drives the stream to completion, counting the number of items.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so counting elements of a
+    /// stream with more than [`usize::MAX`] elements either produces the wrong
+    /// result or panics. If debug assertions are enabled, a panic is guaranteed.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic if the iterator has more than [`usize::MAX`]
+    /// elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=10);
+    /// let count = stream.count().await;
+    ///
+    /// assert_eq!(count, 10);
+    /// # });
+    /// ```
+    fn count(self) -> Count<Self>
+    where
+        Self: Sized,
+    {
+        assert_future::<usize, _>(Count::new(self))
+    }
+
+    /// Consumes the stream, tallying how often each distinct item occurs.
+    ///
+    /// The returned future resolves to a `HashMap` mapping each item to its
+    /// number of occurrences once the stream has ended. An empty stream
+    /// produces an empty map.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let counts = stream::iter(vec!["a", "b", "a", "a"]).counts().await;
+    ///
+    /// assert_eq!(counts[&"a"], 3);
+    /// assert_eq!(counts[&"b"], 1);
+    /// # });
+    /// ```
+    #[cfg(feature = "std")]
+    fn counts(self) -> Counts<Self>
+    where
+        Self::Item: core::hash::Hash + Eq,
+        Self: Sized,
+    {
+        assert_future::<std::collections::HashMap<Self::Item, usize>, _>(Counts::new(self))
+    }
+
+    /// Consumes the stream, tallying how often each key produced by `f`
+    /// occurs.
+    ///
+    /// Like [`counts`](StreamExt::counts), but items are first projected
+    /// through a key function, which is handy for histograms over a smaller
+    /// domain than the items themselves.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let counts = stream::iter(0..10).counts_by(|n| n % 3).await;
+    ///
+    /// assert_eq!(counts[&0], 4);
+    /// assert_eq!(counts[&1], 3);
+    /// assert_eq!(counts[&2], 3);
+    /// # });
+    /// ```
+    #[cfg(feature = "std")]
+    fn counts_by<F, K>(self, f: F) -> CountsBy<Self, F, K>
+    where
+        F: FnMut(Self::Item) -> K,
+        K: core::hash::Hash + Eq,
+        Self: Sized,
+    {
+        assert_future::<std::collections::HashMap<K, usize>, _>(CountsBy::new(self, f))
+    }
+
+    /// Repeats a stream endlessly.
+    ///
+    /// The stream never terminates. Note that you likely want to avoid
+    /// usage of `collect` or such on the returned stream as it will exhaust
+    /// available memory as it tries to just fill up all RAM.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    /// let a = [1, 2, 3];
+    /// let mut s = stream::iter(a.iter()).cycle();
+    ///
+    /// assert_eq!(s.next().await, Some(&1));
+    /// assert_eq!(s.next().await, Some(&2));
+    /// assert_eq!(s.next().await, Some(&3));
+    /// assert_eq!(s.next().await, Some(&1));
+    /// assert_eq!(s.next().await, Some(&2));
+    /// assert_eq!(s.next().await, Some(&3));
+    /// assert_eq!(s.next().await, Some(&1));
+    /// # });
+    /// ```
+    fn cycle(self) -> Cycle<Self>
+    where
+        Self: Sized + Clone,
+    {
+        assert_stream::<Self::Item, _>(Cycle::new(self))
+    }
+
+    /// Reverses a [`DoubleEndedStream`], yielding its items from the back.
+    ///
+    /// The reversed stream is itself double-ended, with the two ends swapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=3).rev();
+    ///
+    /// assert_eq!(vec![3, 2, 1], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    ///
+    /// [`DoubleEndedStream`]: crate::stream::DoubleEndedStream
+    fn rev(self) -> Rev<Self>
+    where
+        Self: crate::stream::DoubleEndedStream + Sized,
+    {
+        assert_stream::<Self::Item, _>(Rev::new(self))
+    }
+
+    /// Reverses a finite stream by buffering all of its items, then yielding
+    /// them back to front.
+    ///
+    /// Unlike [`rev`](StreamExt::rev) this does not require the stream to be
+    /// double-ended, but it has to collect every item into memory before the
+    /// first one is yielded, so memory use grows with the length of the
+    /// stream. This future will never complete for an infinite stream.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![1, 2, 3]).chain(stream::once(async { 4 }));
+    ///
+    /// assert_eq!(vec![4, 3, 2, 1], stream.reverse().collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn reverse(self) -> Reverse<Self>
+    where
+        Self: Sized,
+    {
+        assert_stream::<Self::Item, _>(Reverse::new(self))
+    }
+
+    /// Repeats the stream a fixed number of times, rather than
+    /// [forever](StreamExt::cycle).
+    ///
+    /// Like [`cycle`](StreamExt::cycle), this requires the stream to be
+    /// [`Clone`]: each repetition replays a fresh clone of the original
+    /// stream instead of buffering the yielded items, so no memory
+    /// proportional to the stream's length is held.
+    ///
+    /// An `n` of `0` yields an empty stream, an `n` of `1` yields the stream
+    /// unchanged, and an empty source stays empty regardless of `n`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![1, 2]).cycle_n(3);
+    ///
+    /// assert_eq!(stream.collect::<Vec<_>>().await, vec![1, 2, 1, 2, 1, 2]);
+    /// # });
+    /// ```
+    fn cycle_n(self, n: usize) -> CycleN<Self>
+    where
+        Self: Sized + Clone,
+    {
+        assert_stream::<Self::Item, _>(CycleN::new(self, n))
+    }
+
+    /// Execute an accumulating asynchronous computation over a stream,
+    /// collecting all the values into one final result.
+    ///
+    /// This combinator will accumulate all values returned by this stream
+    /// according to the closure provided. The initial state is also provided to
+    /// this method and then is returned again by each execution of the closure.
+    /// Once the entire stream has been exhausted the returned future will
+    /// resolve to this value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let number_stream = stream::iter(0..6);
+    /// let sum = number_stream.fold(0, |acc, x| async move { acc + x });
+    /// assert_eq!(sum.await, 15);
+    /// # });
+    /// ```
+    fn fold<T, Fut, F>(self, init: T, f: F) -> Fold<Self, Fut, T, F>
+    where
+        F: FnMut(T, Self::Item) -> Fut,
+        Fut: Future<Output = T>,
+        Self: Sized,
+    {
+        assert_future::<T, _>(Fold::new(self, f, init))
+    }
+
+    /// Execute an accumulating asynchronous computation over a stream that
+    /// can stop early, collecting the values into one final result.
+    ///
+    /// This works like [`fold`](StreamExt::fold), except the closure resolves
+    /// to a [`FoldStep`] deciding whether to keep going:
+    /// [`Continue`](FoldStep::Continue) feeds the accumulator back into the
+    /// next step, while [`Done`](FoldStep::Done) resolves the returned future
+    /// to the accumulator immediately, without polling the stream again. An
+    /// empty stream resolves to the initial value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, FoldStep, StreamExt};
+    ///
+    /// // Sum until the total exceeds a threshold.
+    /// let number_stream = stream::iter(1..=100);
+    /// let sum = number_stream.fold_while(0, |acc, x| async move {
+    ///     let acc = acc + x;
+    ///     if acc >= 10 {
+    ///         FoldStep::Done(acc)
+    ///     } else {
+    ///         FoldStep::Continue(acc)
+    ///     }
+    /// });
+    /// assert_eq!(sum.await, 10);
+    /// # });
+    /// ```
+    fn fold_while<T, Fut, F>(self, init: T, f: F) -> FoldWhile<Self, Fut, T, F>
+    where
+        F: FnMut(T, Self::Item) -> Fut,
+        Fut: Future<Output = FoldStep<T>>,
+        Self: Sized,
+    {
+        assert_future::<T, _>(FoldWhile::new(self, f, init))
+    }
+
+    /// Execute predicate over asynchronous stream, and return `true` if any element in stream satisfied a predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let number_stream = stream::iter(0..10);
+    /// let contain_three = number_stream.any(|i| async move { i == 3 });
+    /// assert_eq!(contain_three.await, true);
+    /// # });
+    /// ```
+    fn any<Fut, F>(self, f: F) -> Any<Self, Fut, F>
+    where
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future<Output = bool>,
+        Self: Sized,
+    {
+        assert_future::<bool, _>(Any::new(self, f))
+    }
+
+    /// Execute predicate over asynchronous stream, and return `true` if all element in stream satisfied a predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let number_stream = stream::iter(0..10);
+    /// let less_then_twenty = number_stream.all(|i| async move { i < 20 });
+    /// assert_eq!(less_then_twenty.await, true);
+    /// # });
+    /// ```
+    fn all<Fut, F>(self, f: F) -> All<Self, Fut, F>
+    where
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future<Output = bool>,
+        Self: Sized,
+    {
+        assert_future::<bool, _>(All::new(self, f))
+    }
+
+    /// Flattens a stream of streams into just one continuous stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::mpsc;
+    /// use futures::stream::StreamExt;
+    /// use std::thread;
+    ///
+    /// let (tx1, rx1) = mpsc::unbounded();
+    /// let (tx2, rx2) = mpsc::unbounded();
+    /// let (tx3, rx3) = mpsc::unbounded();
+    ///
+    /// thread::spawn(move || {
+    ///     tx1.unbounded_send(1).unwrap();
+    ///     tx1.unbounded_send(2).unwrap();
+    /// });
+    /// thread::spawn(move || {
+    ///     tx2.unbounded_send(3).unwrap();
+    ///     tx2.unbounded_send(4).unwrap();
+    /// });
+    /// thread::spawn(move || {
+    ///     tx3.unbounded_send(rx1).unwrap();
+    ///     tx3.unbounded_send(rx2).unwrap();
+    /// });
+    ///
+    /// let output = rx3.flatten().collect::<Vec<i32>>().await;
+    /// assert_eq!(output, vec![1, 2, 3, 4]);
+    /// # });
+    /// ```
+    fn flatten(self) -> Flatten<Self>
+    where
+        Self::Item: Stream,
+        Self: Sized,
+    {
+        assert_stream::<<Self::Item as Stream>::Item, _>(Flatten::new(self))
+    }
+
+    /// Maps a stream like [`StreamExt::map`] but flattens nested `Stream`s.
+    ///
+    /// [`StreamExt::map`] is very useful, but if it produces a `Stream` instead,
+    /// you would have to chain combinators like `.map(f).flatten()` while this
+    /// combinator provides ability to write `.flat_map(f)` instead of chaining.
+    ///
+    /// The provided closure which produce inner streams is executed over all elements
+    /// of stream as last inner stream is terminated and next stream item is available.
+    ///
+    /// Note that this function consumes the stream passed into it and returns a
+    /// wrapped version of it, similar to the existing `flat_map` methods in the
+    /// standard library.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=3);
+    /// let stream = stream.flat_map(|x| stream::iter(vec![x + 3; x]));
+    ///
+    /// assert_eq!(vec![4, 5, 5, 6, 6, 6], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn flat_map<U, F>(self, f: F) -> FlatMap<Self, U, F>
+    where
+        F: FnMut(Self::Item) -> U,
+        U: Stream,
+        Self: Sized,
+    {
+        assert_stream::<U::Item, _>(FlatMap::new(self, f))
+    }
+
+    /// Maps a stream like [`StreamExt::flat_map`] but polls the produced
+    /// sub-streams concurrently, yielding items in the order they become
+    /// ready.
+    ///
+    /// The first argument is an optional limit on the number of concurrently
+    /// polled sub-streams. If this limit is not `None`, no more than `limit`
+    /// sub-streams will be polled at the same time and the underlying stream
+    /// will not be polled for new items while the limit is reached. The
+    /// `limit` value can be `None` in which case no limit is imposed, and a
+    /// limit of zero is interpreted as no limit.
+    ///
+    /// Because the sub-streams are moved in and out of the internal set as
+    /// they produce items, they are required to be [`Unpin`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=3);
+    /// let stream = stream.flat_map_unordered(None, |x| stream::iter(vec![x; x]));
+    ///
+    /// let mut values = stream.collect::<Vec<_>>().await;
+    /// values.sort();
+    /// assert_eq!(vec![1, 2, 2, 3, 3, 3], values);
+    /// # });
+    /// ```
+    #[cfg(not(futures_no_atomic_cas))]
+    #[cfg(feature = "alloc")]
+    fn flat_map_unordered<U, F>(
+        self,
+        limit: impl Into<Option<usize>>,
+        f: F,
+    ) -> FlatMapUnordered<Self, U, F>
+    where
+        U: Stream + Unpin,
+        F: FnMut(Self::Item) -> U,
+        Self: Sized,
+    {
+        assert_stream::<U::Item, _>(FlatMapUnordered::new(self, limit.into(), f))
+    }
+
+    /// Flattens a stream of streams into just one continuous stream, polling
+    /// the inner streams concurrently and yielding items in the order they
+    /// become ready.
+    ///
+    /// This is the counterpart of [`flatten`](StreamExt::flatten) in the same
+    /// way [`flat_map_unordered`](StreamExt::flat_map_unordered) is the
+    /// counterpart of [`flat_map`](StreamExt::flat_map): a pending inner
+    /// stream does not block the others from making progress.
+    ///
+    /// The argument is an optional limit on the number of concurrently polled
+    /// inner streams. If this limit is not `None`, no more than `limit` inner
+    /// streams will be polled at the same time and the underlying stream will
+    /// not be polled for new ones while the limit is reached. The `limit`
+    /// value can be `None` in which case no limit is imposed, and a limit of
+    /// zero is interpreted as no limit.
+    ///
+    /// Because the inner streams are moved in and out of the internal set as
+    /// they produce items, they are required to be [`Unpin`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter((1..=3).map(|x| stream::iter(vec![x; x])));
+    /// let stream = stream.flatten_unordered(None);
+    ///
+    /// let mut values = stream.collect::<Vec<_>>().await;
+    /// values.sort();
+    /// assert_eq!(vec![1, 2, 2, 3, 3, 3], values);
+    /// # });
+    /// ```
+    #[cfg(not(futures_no_atomic_cas))]
+    #[cfg(feature = "alloc")]
+    fn flatten_unordered(self, limit: impl Into<Option<usize>>) -> FlattenUnordered<Self>
+    where
+        Self::Item: Stream + Unpin,
+        Self: Sized,
+    {
+        assert_stream::<<Self::Item as Stream>::Item, _>(FlattenUnordered::new(self, limit.into()))
+    }
+
+    /// Combinator similar to [`StreamExt::fold`] that holds internal state
+    /// and produces a new stream.
+    ///
+    /// Accepts initial state and closure which will be applied to each element
+    /// of the stream until provided closure returns `None`. Once `None` is
+    /// returned, stream will be terminated.
+    ///
+    /// Unlike [`Iterator::scan`], the closure takes the state by value instead of
+    /// mutable reference to avoid [the limitation of the async
+    /// block](https://github.com/rust-lang/futures-rs/issues/2171).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=10);
+    ///
+    /// let stream = stream.scan(0, |mut state, x| async move {
+    ///     state += x;
+    ///     if state < 10 {
+    ///         Some((state, x))
+    ///     } else {
+    ///         None
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(vec![1, 2, 3], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn scan<S, B, Fut, F>(self, initial_state: S, f: F) -> Scan<Self, S, Fut, F>
+    where
+        F: FnMut(S, Self::Item) -> Fut,
+        Fut: Future<Output = Option<(S, B)>>,
+        Self: Sized,
+    {
+        assert_stream::<B, _>(Scan::new(self, initial_state, f))
+    }
+
+    /// Skip elements on this stream while the provided asynchronous predicate
+    /// resolves to `true`.
+    ///
+    /// This function, like `Iterator::skip_while`, will skip elements on the
+    /// stream until the predicate `f` resolves to `false`. Once one element
+    /// returns `false`, all future elements will be returned from the underlying
+    /// stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=10);
+    ///
+    /// let stream = stream.skip_while(|x| future::ready(*x <= 5));
+    ///
+    /// assert_eq!(vec![6, 7, 8, 9, 10], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn skip_while<Fut, F>(self, f: F) -> SkipWhile<Self, Fut, F>
+    where
+        F: FnMut(&Self::Item) -> Fut,
+        Fut: Future<Output = bool>,
+        Self: Sized,
+    {
+        assert_stream::<Self::Item, _>(SkipWhile::new(self, f))
+    }
+
+    /// Take elements from this stream while the provided asynchronous predicate
+    /// resolves to `true`.
+    ///
+    /// This function, like `Iterator::take_while`, will take elements from the
+    /// stream until the predicate `f` resolves to `false`. Once one element
+    /// returns `false`, it will always return that the stream is done.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=10);
+    ///
+    /// let stream = stream.take_while(|x| future::ready(*x <= 5));
+    ///
+    /// assert_eq!(vec![1, 2, 3, 4, 5], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn take_while<Fut, F>(self, f: F) -> TakeWhile<Self, Fut, F>
+    where
+        F: FnMut(&Self::Item) -> Fut,
+        Fut: Future<Output = bool>,
+        Self: Sized,
+    {
+        assert_stream::<Self::Item, _>(TakeWhile::new(self, f))
+    }
+
+    /// Take elements from this stream until the provided future resolves.
+    ///
+    /// This function will take elements from the stream until the provided
+    /// stopping future `fut` resolves. Once the `fut` future becomes ready,
+    /// this stream combinator will always return that the stream is done.
+    ///
+    /// The stopping future may return any type. Once the stream is stopped
+    /// the result of the stopping future may be accessed with `TakeUntil::take_result()`.
+    /// The stream may also be resumed with `TakeUntil::take_future()`.
+    /// See the documentation of [`TakeUntil`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    /// use futures::task::Poll;
+    ///
+    /// let stream = stream::iter(1..=10);
+    ///
+    /// let mut i = 0;
+    /// let stop_fut = future::poll_fn(|_cx| {
+    ///     i += 1;
+    ///     if i <= 5 {
+    ///         Poll::Pending
+    ///     } else {
+    ///         Poll::Ready(())
+    ///     }
+    /// });
+    ///
+    /// let stream = stream.take_until(stop_fut);
+    ///
+    /// assert_eq!(vec![1, 2, 3, 4, 5], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn take_until<Fut>(self, fut: Fut) -> TakeUntil<Self, Fut>
+    where
+        Fut: Future,
+        Self: Sized,
+    {
+        assert_stream::<Self::Item, _>(TakeUntil::new(self, fut))
+    }
+
+    /// Take elements from this stream until the provided future resolves,
+    /// keeping the rest of the stream recoverable.
+    ///
+    /// This is like [`take_until`](StreamExt::take_until), except that once
+    /// the future resolves, the still-unconsumed inner stream can be taken
+    /// back out with [`TakeUntilRemainder::into_inner`] and consumed further.
+    /// This supports handoff patterns such as reading the header section of a
+    /// protocol until a delimiter and then passing the remaining stream on to
+    /// a body parser.
+    ///
+    /// The future is polled before the stream on each iteration, so if both
+    /// are ready at the same time the stream stops and the pending item stays
+    /// in the recovered remainder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    /// use futures::task::Poll;
+    ///
+    /// let stream = stream::iter(1..=10);
+    ///
+    /// let mut i = 0;
+    /// let stop_fut = future::poll_fn(|_cx| {
+    ///     i += 1;
+    ///     if i <= 5 {
+    ///         Poll::Pending
+    ///     } else {
+    ///         Poll::Ready(())
+    ///     }
+    /// });
+    ///
+    /// let mut stream = stream.take_until_remainder(stop_fut);
+    ///
+    /// assert_eq!(vec![1, 2, 3, 4, 5], stream.by_ref().collect::<Vec<_>>().await);
+    ///
+    /// let remainder = stream.into_inner().unwrap();
+    /// assert_eq!(vec![6, 7, 8, 9, 10], remainder.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn take_until_remainder<Fut>(self, fut: Fut) -> TakeUntilRemainder<Self, Fut>
+    where
+        Fut: Future,
+        Self: Sized,
+    {
+        assert_stream::<Self::Item, _>(TakeUntilRemainder::new(self, fut))
+    }
+
+    /// Runs this stream to completion, executing the provided asynchronous
+    /// closure for each element on the stream.
+    ///
+    /// The closure provided will be called for each item this stream produces,
+    /// yielding a future. That future will then be executed to completion
+    /// before moving on to the next item.
+    ///
+    /// The returned value is a `Future` where the `Output` type is `()`; it is
+    /// executed entirely for its side effects.
+    ///
+    /// To process each item in the stream and produce another stream instead
+    /// of a single future, use `then` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let mut x = 0;
+    ///
+    /// {
+    ///     let fut = stream::repeat(1).take(3).for_each(|item| {
+    ///         x += item;
+    ///         future::ready(())
+    ///     });
+    ///     fut.await;
+    /// }
+    ///
+    /// assert_eq!(x, 3);
+    /// # });
+    /// ```
+    fn for_each<Fut, F>(self, f: F) -> ForEach<Self, Fut, F>
+    where
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future<Output = ()>,
+        Self: Sized,
+    {
+        assert_future::<(), _>(ForEach::new(self, f))
+    }
+
+    /// Runs this stream to completion, executing the provided asynchronous
+    /// closure for each element on the stream concurrently as elements become
+    /// available.
+    ///
+    /// This is similar to [`StreamExt::for_each`], but the futures
+    /// produced by the closure are run concurrently (but not in parallel--
+    /// this combinator does not introduce any threads).
+    ///
+    /// The closure provided will be called for each item this stream produces,
+    /// yielding a future. That future will then be executed to completion
+    /// concurrently with the other futures produced by the closure.
+    ///
+    /// The first argument is an optional limit on the number of concurrent
+    /// futures. If this limit is not `None`, no more than `limit` futures
+    /// will be run concurrently. The `limit` argument is of type
+    /// `Into<Option<usize>>`, and so can be provided as either `None`,
+    /// `Some(10)`, or just `10`. Note: a limit of zero is interpreted as
+    /// no limit at all, and will have the same result as passing in `None`.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::oneshot;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let (tx1, rx1) = oneshot::channel();
+    /// let (tx2, rx2) = oneshot::channel();
+    /// let (tx3, rx3) = oneshot::channel();
+    ///
+    /// let fut = stream::iter(vec![rx1, rx2, rx3]).for_each_concurrent(
+    ///     /* limit */ 2,
+    ///     |rx| async move {
+    ///         rx.await.unwrap();
+    ///     }
+    /// );
+    /// tx1.send(()).unwrap();
+    /// tx2.send(()).unwrap();
+    /// tx3.send(()).unwrap();
+    /// fut.await;
+    /// # })
+    /// ```
+    #[cfg(not(futures_no_atomic_cas))]
+    #[cfg(feature = "alloc")]
+    fn for_each_concurrent<Fut, F>(
+        self,
+        limit: impl Into<Option<usize>>,
+        f: F,
+    ) -> ForEachConcurrent<Self, Fut, F>
+    where
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future<Output = ()>,
+        Self: Sized,
+    {
+        assert_future::<(), _>(ForEachConcurrent::new(self, limit.into(), f))
+    }
+
+    /// Attempt to execute an accumulating asynchronous computation over a
+    /// stream, collecting all the values into one final result.
+    ///
+    /// This combinator will accumulate all values returned by this stream
+    /// according to the closure provided. The initial state is also provided to
+    /// this method and then is returned again by each execution of the closure.
+    /// Once the entire stream has been exhausted the returned future will
+    /// resolve to this value.
+    ///
+    /// This method is similar to [`fold`](crate::stream::StreamExt::fold), but
+    /// will exit early if an error is encountered in the provided closure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let number_stream = stream::iter(vec![1, 2]);
+    /// let sum = number_stream.try_fold(0, |acc, x| async move { Ok::<i32, i32>(acc + x) });
+    /// assert_eq!(sum.await, Ok(3));
+    ///
+    /// let number_stream_with_err = stream::iter(vec![Ok::<i32, i32>(1), Err(2), Ok(1)]);
+    /// let sum = number_stream_with_err.try_fold(0, |acc, x| async move { Ok(acc + x?) });
+    /// assert_eq!(sum.await, Err(2));
+    /// # })
+    /// ```
+    fn try_fold<T, Fut, F>(self, init: T, f: F) -> TryFold<Self, Fut, T, F>
+    where
+        F: FnMut(T, Self::Item) -> Fut,
+        Fut: TryFuture<Ok = T>,
+        Self: Sized,
+    {
+        assert_future::<Result<T, Fut::Error>, _>(TryFold::new(self, f, init))
+    }
+
+    /// Attempts to run this stream to completion, executing the provided
+    /// asynchronous closure for each element on the stream.
+    ///
+    /// The provided closure will be called for each item this stream produces,
+    /// yielding a future. That future will then be executed to completion
+    /// before moving on to the next item.
+    ///
+    /// The returned value is a [`Future`](futures_core::future::Future) where
+    /// the [`Output`](futures_core::future::Future::Output) type is
+    /// `Result<(), Fut::Error>`. If any of the intermediate futures returns
+    /// an error, this future will return immediately with an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let mut x = 0i32;
+    ///
+    /// {
+    ///     let fut = stream::repeat(1).try_for_each(|item| {
+    ///         x += item;
+    ///         future::ready(if x == 3 { Err(()) } else { Ok(()) })
+    ///     });
+    ///     assert_eq!(fut.await, Err(()));
+    /// }
+    ///
+    /// assert_eq!(x, 3);
+    /// # })
+    /// ```
+    fn try_for_each<Fut, F>(self, f: F) -> TryForEach<Self, Fut, F>
+    where
+        F: FnMut(Self::Item) -> Fut,
+        Fut: TryFuture<Ok = ()>,
+        Self: Sized,
+    {
+        assert_future::<Result<(), Fut::Error>, _>(TryForEach::new(self, f))
+    }
+
+    /// Attempts to run this stream to completion, executing the provided asynchronous
+    /// closure for each element on the stream concurrently as elements become
+    /// available, exiting as soon as an error occurs.
+    ///
+    /// This is similar to
+    /// [`StreamExt::for_each_concurrent`](crate::stream::StreamExt::for_each_concurrent),
+    /// but will resolve to an error immediately if the provided closure returns
+    /// an error.
+    ///
+    /// Upon the first error, all futures still in flight are dropped
+    /// (cancelling them) and the error is returned without awaiting them; no
+    /// further elements are pulled from the stream either.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::oneshot;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let (tx1, rx1) = oneshot::channel();
+    /// let (tx2, rx2) = oneshot::channel();
+    /// let (_tx3, rx3) = oneshot::channel();
+    ///
+    /// let stream = stream::iter(vec![rx1, rx2, rx3]);
+    /// let fut = stream.try_for_each_concurrent(
+    ///     /* limit */ 2,
+    ///     |rx| async move {
+    ///         let res: Result<(), oneshot::Canceled> = rx.await;
+    ///         res
+    ///     }
+    /// );
+    ///
+    /// tx1.send(()).unwrap();
+    /// // Drop the second sender so that `rx2` resolves to `Canceled`.
+    /// drop(tx2);
+    ///
+    /// // The final result is an error because the second future
+    /// // resulted in an error.
+    /// assert_eq!(Err(oneshot::Canceled), fut.await);
+    /// # })
+    /// ```
+    #[cfg(not(futures_no_atomic_cas))]
+    #[cfg(feature = "alloc")]
+    fn try_for_each_concurrent<Fut, F, E>(
+        self,
+        limit: impl Into<Option<usize>>,
+        f: F,
+    ) -> TryForEachConcurrent<Self, Fut, F>
+    where
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future<Output = Result<(), E>>,
+        Self: Sized,
+    {
+        assert_future::<Result<(), E>, _>(TryForEachConcurrent::new(self, limit.into(), f))
+    }
+
+    /// Creates a new stream of at most `n` items of the underlying stream.
+    ///
+    /// Once `n` items have been yielded from this stream then it will always
+    /// return that the stream is done.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=10).take(3);
+    ///
+    /// assert_eq!(vec![1, 2, 3], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn take(self, n: usize) -> Take<Self>
+    where
+        Self: Sized,
+    {
+        assert_stream::<Self::Item, _>(Take::new(self, n))
+    }
+
+    /// Creates a new stream which skips `n` items of the underlying stream.
+    ///
+    /// Once `n` items have been skipped from this stream then it will always
+    /// return the remaining items on this stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=10).skip(5);
+    ///
+    /// assert_eq!(vec![6, 7, 8, 9, 10], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn skip(self, n: usize) -> Skip<Self>
+    where
+        Self: Sized,
+    {
+        assert_stream::<Self::Item, _>(Skip::new(self, n))
+    }
+
+    /// Creates a new stream which yields the first item and then every
+    /// `step`-th item thereafter, mirroring [`Iterator::step_by`].
+    ///
+    /// The skipped items are still pulled from the underlying stream and
+    /// discarded, so their side effects are preserved.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `step` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(0..=10).step_by(3);
+    ///
+    /// assert_eq!(vec![0, 3, 6, 9], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn step_by(self, step: usize) -> StepBy<Self>
+    where
+        Self: Sized,
+    {
+        assert_stream::<Self::Item, _>(StepBy::new(self, step))
+    }
+
+    /// Fuse a stream such that [`poll_next`](Stream::poll_next) will never
+    /// again be called once it has finished. This method can be used to turn
+    /// any `Stream` into a `FusedStream`.
+    ///
+    /// Normally, once a stream has returned [`None`] from
+    /// [`poll_next`](Stream::poll_next) any further calls could exhibit bad
+    /// behavior such as block forever, panic, never return, etc. If it is known
+    /// that [`poll_next`](Stream::poll_next) may be called after stream
+    /// has already finished, then this method can be used to ensure that it has
+    /// defined semantics.
+    ///
+    /// The [`poll_next`](Stream::poll_next) method of a `fuse`d stream
+    /// is guaranteed to return [`None`] after the underlying stream has
+    /// finished.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on_stream;
+    /// use futures::stream::{self, StreamExt};
+    /// use futures::task::Poll;
+    ///
+    /// let mut x = 0;
+    /// let stream = stream::poll_fn(|_| {
+    ///     x += 1;
+    ///     match x {
+    ///         0..=2 => Poll::Ready(Some(x)),
+    ///         3 => Poll::Ready(None),
+    ///         _ => panic!("should not happen")
+    ///     }
+    /// }).fuse();
+    ///
+    /// let mut iter = block_on_stream(stream);
+    /// assert_eq!(Some(1), iter.next());
+    /// assert_eq!(Some(2), iter.next());
+    /// assert_eq!(None, iter.next());
+    /// assert_eq!(None, iter.next());
+    /// // ...
+    /// ```
+    fn fuse(self) -> Fuse<Self>
+    where
+        Self: Sized,
+    {
+        assert_stream::<Self::Item, _>(Fuse::new(self))
+    }
+
+    /// Borrows a stream, rather than consuming it.
+    ///
+    /// This is useful to allow applying stream adaptors while still retaining
+    /// ownership of the original stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let mut stream = stream::iter(1..5);
+    ///
+    /// let sum = stream.by_ref()
+    ///                 .take(2)
+    ///                 .fold(0, |a, b| async move { a + b })
+    ///                 .await;
+    /// assert_eq!(sum, 3);
+    ///
+    /// // You can use the stream again
+    /// let sum = stream.take(2)
+    ///                 .fold(0, |a, b| async move { a + b })
+    ///                 .await;
+    /// assert_eq!(sum, 7);
+    /// # });
+    /// ```
+    fn by_ref(&mut self) -> &mut Self {
+        self
+    }
+
+    /// Catches unwinding panics while polling the stream.
+    ///
+    /// Caught panic (if any) will be the last element of the resulting stream.
+    ///
+    /// In general, panics within a stream can propagate all the way out to the
+    /// task level. This combinator makes it possible to halt unwinding within
+    /// the stream itself. It's most commonly used within task executors. This
+    /// method should not be used for error handling.
+    ///
+    /// Note that this method requires the `UnwindSafe` bound from the standard
+    /// library. This isn't always applied automatically, and the standard
+    /// library provides an `AssertUnwindSafe` wrapper type to apply it
+    /// after-the fact. To assist using this method, the [`Stream`] trait is
+    /// also implemented for `AssertUnwindSafe<St>` where `St` implements
+    /// [`Stream`].
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![Some(10), None, Some(11)]);
+    /// // Panic on second element
+    /// let stream_panicking = stream.map(|o| o.unwrap());
+    /// // Collect all the results
+    /// let stream = stream_panicking.catch_unwind();
+    ///
+    /// let results: Vec<Result<i32, _>> = stream.collect().await;
+    /// match results[0] {
+    ///     Ok(10) => {}
+    ///     _ => panic!("unexpected result!"),
+    /// }
+    /// assert!(results[1].is_err());
+    /// assert_eq!(results.len(), 2);
+    /// # });
+    /// ```
+    #[cfg(feature = "std")]
+    fn catch_unwind(self) -> CatchUnwind<Self>
+    where
+        Self: Sized + std::panic::UnwindSafe,
+    {
+        assert_stream(CatchUnwind::new(self))
+    }
+
+    /// Splits the stream into `n` output streams, distributing the items
+    /// among them round-robin.
+    ///
+    /// The first item goes to the first output stream, the second item to the
+    /// second, and so on, wrapping around. Each output stream buffers at most
+    /// one item that was pulled on its behalf by a sibling, so a consumer
+    /// that lags behind exerts backpressure on the others: they cannot run
+    /// more than one round ahead of it.
+    ///
+    /// Dropping an output stream removes it from the rotation: an item
+    /// already set aside for it is discarded, and subsequent items are
+    /// redistributed among the remaining output streams. Once the underlying
+    /// stream is exhausted every output stream yields `None`.
+    ///
+    /// An `n` of zero drops the stream and returns no output streams.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::future::join;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let mut parts = stream::iter(0..6).split_round_robin(2);
+    /// let odd = parts.pop().unwrap();
+    /// let even = parts.pop().unwrap();
+    ///
+    /// let (even, odd) = join(even.collect::<Vec<_>>(), odd.collect::<Vec<_>>()).await;
+    /// assert_eq!(even, vec![0, 2, 4]);
+    /// assert_eq!(odd, vec![1, 3, 5]);
+    /// # });
+    /// ```
+    #[cfg(feature = "std")]
+    fn split_round_robin(self, n: usize) -> Vec<RoundRobin<Self>>
+    where
+        Self: Sized + Unpin,
+    {
+        split_round_robin::split_round_robin(self, n)
+    }
+
+    /// Wrap the stream in a Box, pinning it.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    #[cfg(feature = "alloc")]
+    fn boxed<'a>(self) -> BoxStream<'a, Self::Item>
+    where
+        Self: Sized + Send + 'a,
+    {
+        assert_stream::<Self::Item, _>(Box::pin(self))
+    }
+
+    /// Wrap the stream in a Box, pinning it.
+    ///
+    /// Similar to `boxed`, but without the `Send` requirement.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    #[cfg(feature = "alloc")]
+    fn boxed_local<'a>(self) -> LocalBoxStream<'a, Self::Item>
+    where
+        Self: Sized + 'a,
+    {
+        assert_stream::<Self::Item, _>(Box::pin(self))
+    }
+
+    /// An adaptor for creating a buffered list of pending futures.
+    ///
+    /// If this stream's item can be converted into a future, then this adaptor
+    /// will buffer up to at most `n` futures and then return the outputs in the
+    /// same order as the underlying stream. No more than `n` futures will be
+    /// buffered at any point in time, and less than `n` may also be buffered
+    /// depending on the state of each future.
+    ///
+    /// The limit argument is of type `Into<Option<usize>>`, and so can be
+    /// provided as either `None`, `Some(10)`, or just `10`. Note: a limit of zero is
+    /// interpreted as no limit at all, and will have the same result as passing in `None`.
+    ///
+    /// The returned stream will be a stream of each future's output.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    #[cfg(not(futures_no_atomic_cas))]
+    #[cfg(feature = "alloc")]
+    fn buffered(self, n: impl Into<Option<usize>>) -> Buffered<Self>
+    where
+        Self::Item: Future,
+        Self: Sized,
+    {
+        assert_stream::<<Self::Item as Future>::Output, _>(Buffered::new(self, n.into()))
+    }
+
+    /// An adaptor for creating a buffered list of pending futures (unordered).
+    ///
+    /// If this stream's item can be converted into a future, then this adaptor
+    /// will buffer up to `n` futures and then return the outputs in the order
+    /// in which they complete. No more than `n` futures will be buffered at
+    /// any point in time, and less than `n` may also be buffered depending on
+    /// the state of each future.
+    ///
+    /// The limit argument is of type `Into<Option<usize>>`, and so can be
+    /// provided as either `None`, `Some(10)`, or just `10`. Note: a limit of zero is
+    /// interpreted as no limit at all, and will have the same result as passing in `None`.
+    ///
+    /// The returned stream will be a stream of each future's output.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::oneshot;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let (send_one, recv_one) = oneshot::channel();
+    /// let (send_two, recv_two) = oneshot::channel();
+    ///
+    /// let stream_of_futures = stream::iter(vec![recv_one, recv_two]);
+    /// let mut buffered = stream_of_futures.buffer_unordered(10);
+    ///
+    /// send_two.send(2i32)?;
+    /// assert_eq!(buffered.next().await, Some(Ok(2i32)));
+    ///
+    /// send_one.send(1i32)?;
+    /// assert_eq!(buffered.next().await, Some(Ok(1i32)));
+    ///
+    /// assert_eq!(buffered.next().await, None);
+    /// # Ok::<(), i32>(()) }).unwrap();
+    /// ```
+    #[cfg(not(futures_no_atomic_cas))]
+    #[cfg(feature = "alloc")]
+    fn buffer_unordered(self, n: impl Into<Option<usize>>) -> BufferUnordered<Self>
+    where
+        Self::Item: Future,
+        Self: Sized,
     {
-        assert_stream::<Self::Item, _>(SkipWhile::new(self, f))
+        assert_stream::<<Self::Item as Future>::Output, _>(BufferUnordered::new(self, n.into()))
     }
 
-    /// Take elements from this stream while the provided asynchronous predicate
-    /// resolves to `true`.
+    /// An adaptor like [`buffer_unordered`](StreamExt::buffer_unordered), but
+    /// with a per-future cost instead of a simple count.
+    ///
+    /// Each future produced by the stream is assigned a weight by
+    /// `weight_fn`, and futures are buffered as long as the total weight of
+    /// the futures in flight stays within `capacity`. Outputs are returned in
+    /// the order in which the futures complete, and a future's weight is
+    /// released when it completes.
+    ///
+    /// A single future whose weight exceeds `capacity` is still run, once no
+    /// other futures are in flight, so an oversized item cannot deadlock the
+    /// stream.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// // Weigh each future by the value it will produce; at most a total
+    /// // weight of 3 runs at once.
+    /// let results: Vec<i32> = stream::iter((1..=4).map(future::ready))
+    ///     .buffer_unordered_weighted(3, |_| 1)
+    ///     .collect()
+    ///     .await;
+    /// assert_eq!(results, vec![1, 2, 3, 4]);
+    /// # });
+    /// ```
+    #[cfg(not(futures_no_atomic_cas))]
+    #[cfg(feature = "alloc")]
+    fn buffer_unordered_weighted<W>(
+        self,
+        capacity: usize,
+        weight_fn: W,
+    ) -> BufferUnorderedWeighted<Self, W>
+    where
+        Self::Item: Future,
+        W: FnMut(&Self::Item) -> usize,
+        Self: Sized,
+    {
+        assert_stream::<<Self::Item as Future>::Output, _>(BufferUnorderedWeighted::new(
+            self, capacity, weight_fn,
+        ))
+    }
+
+    /// An adaptor for running futures concurrently while keeping the outputs
+    /// of futures mapped from items with the same key in submission order.
+    ///
+    /// Each item is assigned a key with `key_fn` and turned into a future
+    /// with `f`. Up to `limit` futures run concurrently, like
+    /// [`buffer_unordered`](StreamExt::buffer_unordered), but an output is
+    /// held back until every earlier output with the same key has been
+    /// yielded. Outputs for different keys may still interleave in any order.
+    ///
+    /// With all-distinct keys this behaves like
+    /// [`buffer_unordered`](StreamExt::buffer_unordered); with a single key it
+    /// behaves like [`buffered`](StreamExt::buffered).
+    ///
+    /// The limit argument is of type `Into<Option<usize>>`, and so can be
+    /// provided as either `None`, `Some(10)`, or just `10`. Note: a limit of zero is
+    /// interpreted as no limit at all, and will have the same result as passing in `None`.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// // A single key keeps every output in submission order.
+    /// let results: Vec<i32> = stream::iter(1..=5)
+    ///     .buffered_by_key(2, |_| 0, |x| future::ready(x * 2))
+    ///     .collect()
+    ///     .await;
+    /// assert_eq!(results, vec![2, 4, 6, 8, 10]);
+    /// # });
+    /// ```
+    #[cfg(not(futures_no_atomic_cas))]
+    #[cfg(feature = "std")]
+    fn buffered_by_key<K, Fut, KF, F>(
+        self,
+        limit: impl Into<Option<usize>>,
+        key_fn: KF,
+        f: F,
+    ) -> BufferedByKey<Self, Fut, K, KF, F>
+    where
+        K: Eq + core::hash::Hash + Clone,
+        KF: FnMut(&Self::Item) -> K,
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future,
+        Self: Sized,
+    {
+        assert_stream::<Fut::Output, _>(BufferedByKey::new(self, limit.into(), key_fn, f))
+    }
+
+    /// An adapter for zipping two streams together.
+    ///
+    /// The zipped stream waits for both streams to produce an item, and then
+    /// returns that pair. If either stream ends then the zipped stream will
+    /// also end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream1 = stream::iter(1..=3);
+    /// let stream2 = stream::iter(5..=10);
+    ///
+    /// let vec = stream1.zip(stream2)
+    ///                  .collect::<Vec<_>>()
+    ///                  .await;
+    /// assert_eq!(vec![(1, 5), (2, 6), (3, 7)], vec);
+    /// # });
+    /// ```
+    ///
+    fn zip<St>(self, other: St) -> Zip<Self, St>
+    where
+        St: Stream,
+        Self: Sized,
+    {
+        assert_stream::<(Self::Item, St::Item), _>(Zip::new(self, other))
+    }
+
+    /// An adapter for zipping two streams together, continuing until both
+    /// streams end.
+    ///
+    /// Unlike [`zip`](StreamExt::zip), which ends with the shorter stream,
+    /// the zipped stream keeps going after one side ends: items are yielded
+    /// as [`EitherOrBoth::Both`] while both streams produce values, and as
+    /// [`EitherOrBoth::Left`] or [`EitherOrBoth::Right`] once only one of
+    /// them still does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, EitherOrBoth, StreamExt};
+    ///
+    /// let stream1 = stream::iter(1..=3);
+    /// let stream2 = stream::iter(5..=6);
+    ///
+    /// let vec = stream1.zip_longest(stream2)
+    ///                  .collect::<Vec<_>>()
+    ///                  .await;
+    /// assert_eq!(
+    ///     vec![
+    ///         EitherOrBoth::Both(1, 5),
+    ///         EitherOrBoth::Both(2, 6),
+    ///         EitherOrBoth::Left(3),
+    ///     ],
+    ///     vec,
+    /// );
+    /// # });
+    /// ```
+    fn zip_longest<St>(self, other: St) -> ZipLongest<Self, St>
+    where
+        St: Stream,
+        Self: Sized,
+    {
+        assert_stream::<EitherOrBoth<Self::Item, St::Item>, _>(ZipLongest::new(self, other))
+    }
+
+    /// Adapter for chaining two streams.
+    ///
+    /// The resulting stream emits elements from the first stream, and when
+    /// first stream reaches the end, emits the elements from the second stream.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream1 = stream::iter(vec![Ok(10), Err(false)]);
+    /// let stream2 = stream::iter(vec![Err(true), Ok(20)]);
+    ///
+    /// let stream = stream1.chain(stream2);
+    ///
+    /// let result: Vec<_> = stream.collect().await;
+    /// assert_eq!(result, vec![
+    ///     Ok(10),
+    ///     Err(false),
+    ///     Err(true),
+    ///     Ok(20),
+    /// ]);
+    /// # });
+    /// ```
+    fn chain<St>(self, other: St) -> Chain<Self, St>
+    where
+        St: Stream<Item = Self::Item>,
+        Self: Sized,
+    {
+        assert_stream::<Self::Item, _>(Chain::new(self, other))
+    }
+
+    /// Creates a new stream which exposes a `peek` method.
+    ///
+    /// Calling `peek` returns a reference to the next item in the stream.
+    fn peekable(self) -> Peekable<Self>
+    where
+        Self: Sized,
+    {
+        assert_stream::<Self::Item, _>(Peekable::new(self))
+    }
+
+    /// An adaptor for chunking up items of the stream inside a vector.
+    ///
+    /// This combinator will attempt to pull items from this stream and buffer
+    /// them into a local vector. At most `capacity` items will get buffered
+    /// before they're yielded from the returned stream.
+    ///
+    /// Note that the vectors returned from this iterator may not always have
+    /// `capacity` elements. If the underlying stream ended and only a partial
+    /// vector was created, it'll be returned. Additionally if an error happens
+    /// from the underlying stream then the currently buffered items will be
+    /// yielded.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `capacity` is zero.
+    #[cfg(feature = "alloc")]
+    fn chunks(self, capacity: usize) -> Chunks<Self>
+    where
+        Self: Sized,
+    {
+        assert_stream::<Vec<Self::Item>, _>(Chunks::new(self, capacity))
+    }
+
+    /// An adaptor for chunking up items of the stream inside a vector, starting
+    /// a new chunk whenever a boundary predicate matches.
+    ///
+    /// This combinator will buffer items from this stream into a local vector.
+    /// Whenever `f` returns `true` for an item, that item begins a new chunk
+    /// and the previously buffered items are yielded. The first item always
+    /// starts the first chunk, so a boundary at the very beginning does not
+    /// produce an empty chunk. The final partial chunk is flushed when the
+    /// underlying stream ends; an empty stream yields nothing.
+    ///
+    /// This is useful for record framing, e.g. splitting a stream of lines
+    /// into records where a marker line starts a new record.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec!["# a", "1", "2", "# b", "3"]);
+    /// let chunks: Vec<_> = stream.chunks_by(|line| line.starts_with('#')).collect().await;
+    ///
+    /// assert_eq!(chunks, vec![vec!["# a", "1", "2"], vec!["# b", "3"]]);
+    /// # });
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn chunks_by<F>(self, f: F) -> ChunksBy<Self, F>
+    where
+        F: FnMut(&Self::Item) -> bool,
+        Self: Sized,
+    {
+        assert_stream::<Vec<Self::Item>, _>(ChunksBy::new(self, f))
+    }
+
+    /// An adaptor for chunking up ready items of the stream inside a vector.
+    ///
+    /// This combinator will attempt to pull ready items from this stream and
+    /// buffer them into a local vector. At most `capacity` items will get
+    /// buffered before they're yielded from the returned stream. If underlying
+    /// stream returns `Poll::Pending`, and collected chunk is not empty, it will
+    /// be immediately returned.
+    ///
+    /// If the underlying stream ended and only a partial vector was created,
+    /// it'll be returned. Additionally if an error happens from the underlying
+    /// stream then the currently buffered items will be yielded.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `capacity` is zero.
+    #[cfg(feature = "alloc")]
+    fn ready_chunks(self, capacity: usize) -> ReadyChunks<Self>
+    where
+        Self: Sized,
+    {
+        assert_stream::<Vec<Self::Item>, _>(ReadyChunks::new(self, capacity))
+    }
+
+    /// An adaptor for chunking up items of the stream inside a vector, with
+    /// a time-based flush for partial chunks.
+    ///
+    /// This behaves like [`chunks`](StreamExt::chunks), but additionally
+    /// yields a partial chunk once `duration` has elapsed since its first
+    /// item was buffered, so batches are not held back indefinitely when the
+    /// source goes quiet. A capacity-triggered flush resets the timer, and an
+    /// empty window never yields an empty vector.
+    ///
+    /// To stay runtime agnostic this combinator does not provide a timer of
+    /// its own; `f` is called to create a new delay future whenever one is
+    /// needed, so any timer implementation (e.g. `tokio::time::sleep` or
+    /// `async_io::Timer`) can be plugged in.
+    ///
+    /// If the underlying stream ends mid-window, the partial chunk is
+    /// flushed before the stream terminates.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `capacity` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use std::time::Duration;
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream =
+    ///     stream::iter(1..=5).chunks_timeout(2, Duration::from_millis(10), |_| future::pending());
+    ///
+    /// assert_eq!(vec![vec![1, 2], vec![3, 4], vec![5]], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn chunks_timeout<Fut, F>(
+        self,
+        capacity: usize,
+        duration: Duration,
+        f: F,
+    ) -> ChunksTimeout<Self, Fut, F>
+    where
+        F: FnMut(Duration) -> Fut,
+        Fut: Future<Output = ()>,
+        Self: Sized,
+    {
+        assert_stream::<Vec<Self::Item>, _>(ChunksTimeout::new(self, capacity, duration, f))
+    }
+
+    /// Debounces items from this stream, only yielding the most recent item
+    /// once `duration` has elapsed without a new item arriving.
+    ///
+    /// Each time the underlying stream produces an item, the item is buffered
+    /// and a fresh delay is started by calling `f` with `duration`. While the
+    /// delay is running, newer items replace the buffered one. Only once the
+    /// delay completes without being reset is the buffered item yielded.
     ///
-    /// This function, like `Iterator::take_while`, will take elements from the
-    /// stream until the predicate `f` resolves to `false`. Once one element
-    /// returns `false`, it will always return that the stream is done.
+    /// To stay runtime agnostic this combinator does not provide a timer of
+    /// its own; `f` is called to create a new delay future whenever one is
+    /// needed, so any timer implementation (e.g. `tokio::time::sleep` or
+    /// `async_io::Timer`) can be plugged in.
+    ///
+    /// If the underlying stream ends while an item is still buffered, that
+    /// item is yielded immediately before the stream terminates, so no item
+    /// is ever lost.
     ///
     /// # Examples
     ///
     /// ```
     /// # futures::executor::block_on(async {
+    /// use std::time::Duration;
     /// use futures::future;
     /// use futures::stream::{self, StreamExt};
     ///
-    /// let stream = stream::iter(1..=10);
-    ///
-    /// let stream = stream.take_while(|x| future::ready(*x <= 5));
+    /// // With a delay that completes immediately, items that are already
+    /// // available are coalesced down to the most recent one.
+    /// let stream = stream::iter(1..=5).debounce(Duration::from_millis(10), |_| future::ready(()));
     ///
-    /// assert_eq!(vec![1, 2, 3, 4, 5], stream.collect::<Vec<_>>().await);
+    /// assert_eq!(vec![5], stream.collect::<Vec<_>>().await);
     /// # });
     /// ```
-    fn take_while<Fut, F>(self, f: F) -> TakeWhile<Self, Fut, F>
+    fn debounce<Fut, F>(self, duration: Duration, f: F) -> Debounce<Self, Fut, F>
     where
-        F: FnMut(&Self::Item) -> Fut,
-        Fut: Future<Output = bool>,
+        F: FnMut(Duration) -> Fut,
+        Fut: Future<Output = ()>,
         Self: Sized,
     {
-        assert_stream::<Self::Item, _>(TakeWhile::new(self, f))
+        assert_stream::<Self::Item, _>(Debounce::new(self, duration, f))
     }
 
-    /// Take elements from this stream until the provided future resolves.
-    ///
-    /// This function will take elements from the stream until the provided
-    /// stopping future `fut` resolves. Once the `fut` future becomes ready,
-    /// this stream combinator will always return that the stream is done.
+    /// Interleaves two streams of the same item type, alternating between
+    /// them deterministically.
     ///
-    /// The stopping future may return any type. Once the stream is stopped
-    /// the result of the stopping future may be accessed with `TakeUntil::take_result()`.
-    /// The stream may also be resumed with `TakeUntil::take_future()`.
-    /// See the documentation of [`TakeUntil`] for more information.
+    /// Items are taken from `self` and `other` in strict turns, starting with
+    /// `self`. Unlike [`select`](self::select), which polls whichever side is
+    /// ready, this combinator waits for the stream whose turn it is even if
+    /// the other one has items available. Once one side is exhausted, the
+    /// remainder of the other is drained in order.
     ///
     /// # Examples
     ///
     /// ```
     /// # futures::executor::block_on(async {
-    /// use futures::future;
     /// use futures::stream::{self, StreamExt};
-    /// use futures::task::Poll;
     ///
-    /// let stream = stream::iter(1..=10);
-    ///
-    /// let mut i = 0;
-    /// let stop_fut = future::poll_fn(|_cx| {
-    ///     i += 1;
-    ///     if i <= 5 {
-    ///         Poll::Pending
-    ///     } else {
-    ///         Poll::Ready(())
-    ///     }
-    /// });
+    /// let left = stream::iter(vec![1, 2, 3]);
+    /// let right = stream::iter(vec![10, 20, 30, 40, 50]);
     ///
-    /// let stream = stream.take_until(stop_fut);
+    /// let interleaved = left.interleave(right);
     ///
-    /// assert_eq!(vec![1, 2, 3, 4, 5], stream.collect::<Vec<_>>().await);
+    /// assert_eq!(vec![1, 10, 2, 20, 3, 30, 40, 50], interleaved.collect::<Vec<_>>().await);
     /// # });
     /// ```
-    fn take_until<Fut>(self, fut: Fut) -> TakeUntil<Self, Fut>
+    fn interleave<St>(self, other: St) -> Interleave<Self, St>
     where
-        Fut: Future,
+        St: Stream<Item = Self::Item>,
         Self: Sized,
     {
-        assert_stream::<Self::Item, _>(TakeUntil::new(self, fut))
+        assert_stream::<Self::Item, _>(Interleave::new(self, other))
     }
 
-    /// Runs this stream to completion, executing the provided asynchronous
-    /// closure for each element on the stream.
-    ///
-    /// The closure provided will be called for each item this stream produces,
-    /// yielding a future. That future will then be executed to completion
-    /// before moving on to the next item.
-    ///
-    /// The returned value is a `Future` where the `Output` type is `()`; it is
-    /// executed entirely for its side effects.
+    /// Merges two streams of the same item type, yielding items from
+    /// whichever stream is ready.
     ///
-    /// To process each item in the stream and produce another stream instead
-    /// of a single future, use `then` instead.
+    /// Both streams are polled in a round-robin fashion, so if both sides
+    /// are always ready the output alternates between them rather than
+    /// favoring `self`. After one of the two streams completes, the other
+    /// one is polled exclusively and drained to completion. This is the
+    /// method form of [`select`](self::select).
     ///
     /// # Examples
     ///
     /// ```
     /// # futures::executor::block_on(async {
-    /// use futures::future;
     /// use futures::stream::{self, StreamExt};
     ///
-    /// let mut x = 0;
+    /// let left = stream::repeat(1);
+    /// let right = stream::repeat(2);
     ///
-    /// {
-    ///     let fut = stream::repeat(1).take(3).for_each(|item| {
-    ///         x += item;
-    ///         future::ready(())
-    ///     });
-    ///     fut.await;
-    /// }
+    /// let mut merged = left.merge(right);
     ///
-    /// assert_eq!(x, 3);
+    /// for _ in 0..10 {
+    ///     // We should be alternating.
+    ///     assert_eq!(Some(1), merged.next().await);
+    ///     assert_eq!(Some(2), merged.next().await);
+    /// }
     /// # });
     /// ```
-    fn for_each<Fut, F>(self, f: F) -> ForEach<Self, Fut, F>
+    fn merge<St>(self, other: St) -> Merge<Self, St>
     where
-        F: FnMut(Self::Item) -> Fut,
-        Fut: Future<Output = ()>,
+        St: Stream<Item = Self::Item>,
         Self: Sized,
     {
-        assert_future::<(), _>(ForEach::new(self, f))
+        assert_stream::<Self::Item, _>(Merge::new(self, other))
     }
 
-    /// Runs this stream to completion, executing the provided asynchronous
-    /// closure for each element on the stream concurrently as elements become
-    /// available.
-    ///
-    /// This is similar to [`StreamExt::for_each`], but the futures
-    /// produced by the closure are run concurrently (but not in parallel--
-    /// this combinator does not introduce any threads).
-    ///
-    /// The closure provided will be called for each item this stream produces,
-    /// yielding a future. That future will then be executed to completion
-    /// concurrently with the other futures produced by the closure.
-    ///
-    /// The first argument is an optional limit on the number of concurrent
-    /// futures. If this limit is not `None`, no more than `limit` futures
-    /// will be run concurrently. The `limit` argument is of type
-    /// `Into<Option<usize>>`, and so can be provided as either `None`,
-    /// `Some(10)`, or just `10`. Note: a limit of zero is interpreted as
-    /// no limit at all, and will have the same result as passing in `None`.
+    /// Tags each item with its [`Position`] in the stream: `First`, `Middle`,
+    /// `Last`, or `Only`.
     ///
-    /// This method is only available when the `std` or `alloc` feature of this
-    /// library is activated, and it is activated by default.
+    /// Because an item is only known to be last once the underlying stream
+    /// ends, this adapter keeps one item of lookahead buffered and yields an
+    /// item together with its position as soon as its successor (or the end
+    /// of the stream) has been seen.
     ///
     /// # Examples
     ///
     /// ```
     /// # futures::executor::block_on(async {
-    /// use futures::channel::oneshot;
-    /// use futures::stream::{self, StreamExt};
+    /// use futures::stream::{self, Position, StreamExt};
     ///
-    /// let (tx1, rx1) = oneshot::channel();
-    /// let (tx2, rx2) = oneshot::channel();
-    /// let (tx3, rx3) = oneshot::channel();
+    /// let stream = stream::iter(vec!['a', 'b', 'c']).with_position();
     ///
-    /// let fut = stream::iter(vec![rx1, rx2, rx3]).for_each_concurrent(
-    ///     /* limit */ 2,
-    ///     |rx| async move {
-    ///         rx.await.unwrap();
-    ///     }
+    /// assert_eq!(
+    ///     vec![(Position::First, 'a'), (Position::Middle, 'b'), (Position::Last, 'c')],
+    ///     stream.collect::<Vec<_>>().await,
     /// );
-    /// tx1.send(()).unwrap();
-    /// tx2.send(()).unwrap();
-    /// tx3.send(()).unwrap();
-    /// fut.await;
-    /// # })
+    ///
+    /// let stream = stream::iter(vec!['a']).with_position();
+    ///
+    /// assert_eq!(vec![(Position::Only, 'a')], stream.collect::<Vec<_>>().await);
+    /// # });
     /// ```
-    #[cfg(not(futures_no_atomic_cas))]
-    #[cfg(feature = "alloc")]
-    fn for_each_concurrent<Fut, F>(
-        self,
-        limit: impl Into<Option<usize>>,
-        f: F,
-    ) -> ForEachConcurrent<Self, Fut, F>
+    fn with_position(self) -> WithPosition<Self>
     where
-        F: FnMut(Self::Item) -> Fut,
-        Fut: Future<Output = ()>,
         Self: Sized,
     {
-        assert_future::<(), _>(ForEachConcurrent::new(self, limit.into(), f))
+        assert_stream::<(Position, Self::Item), _>(WithPosition::new(self))
     }
 
-    /// Attempt to execute an accumulating asynchronous computation over a
-    /// stream, collecting all the values into one final result.
+    /// Yields a clone of `separator` between adjacent items of this stream,
+    /// mirroring [`Iterator::intersperse`].
     ///
-    /// This combinator will accumulate all values returned by this stream
-    /// according to the closure provided. The initial state is also provided to
-    /// this method and then is returned again by each execution of the closure.
-    /// Once the entire stream has been exhausted the returned future will
-    /// resolve to this value.
+    /// No trailing separator is produced: a stream of one item yields just
+    /// that item and an empty stream stays empty.
     ///
-    /// This method is similar to [`fold`](crate::stream::StreamExt::fold), but
-    /// will exit early if an error is encountered in the provided closure.
+    /// [`Iterator::intersperse`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.intersperse
     ///
     /// # Examples
     ///
@@ -1101,125 +3067,90 @@ pub trait StreamExt: Stream {
     /// # futures::executor::block_on(async {
     /// use futures::stream::{self, StreamExt};
     ///
-    /// let number_stream = stream::iter(vec![1, 2]);
-    /// let sum = number_stream.try_fold(0, |acc, x| async move { Ok::<i32, i32>(acc + x) });
-    /// assert_eq!(sum.await, Ok(3));
+    /// let stream = stream::iter(vec![1, 2, 3]).intersperse(0);
     ///
-    /// let number_stream_with_err = stream::iter(vec![Ok::<i32, i32>(1), Err(2), Ok(1)]);
-    /// let sum = number_stream_with_err.try_fold(0, |acc, x| async move { Ok(acc + x?) });
-    /// assert_eq!(sum.await, Err(2));
-    /// # })
+    /// assert_eq!(vec![1, 0, 2, 0, 3], stream.collect::<Vec<_>>().await);
+    /// # });
     /// ```
-    fn try_fold<T, Fut, F>(self, init: T, f: F) -> TryFold<Self, Fut, T, F>
+    fn intersperse(self, separator: Self::Item) -> Intersperse<Self>
     where
-        F: FnMut(T, Self::Item) -> Fut,
-        Fut: TryFuture<Ok = T>,
+        Self::Item: Clone,
         Self: Sized,
     {
-        assert_future::<Result<T, Fut::Error>, _>(TryFold::new(self, f, init))
+        assert_stream::<Self::Item, _>(Intersperse::new(self, separator))
     }
 
-    /// Attempts to run this stream to completion, executing the provided
-    /// asynchronous closure for each element on the stream.
-    ///
-    /// The provided closure will be called for each item this stream produces,
-    /// yielding a future. That future will then be executed to completion
-    /// before moving on to the next item.
+    /// Yields an item produced by calling `f` between adjacent items of this
+    /// stream.
     ///
-    /// The returned value is a [`Future`](futures_core::future::Future) where
-    /// the [`Output`](futures_core::future::Future::Output) type is
-    /// `Result<(), Fut::Error>`. If any of the intermediate futures returns
-    /// an error, this future will return immediately with an error.
+    /// Like [`intersperse`](StreamExt::intersperse), but the separator is
+    /// created on demand, so it does not need to be `Clone`.
     ///
     /// # Examples
     ///
     /// ```
     /// # futures::executor::block_on(async {
-    /// use futures::future;
     /// use futures::stream::{self, StreamExt};
     ///
-    /// let mut x = 0i32;
-    ///
-    /// {
-    ///     let fut = stream::repeat(1).try_for_each(|item| {
-    ///         x += item;
-    ///         future::ready(if x == 3 { Err(()) } else { Ok(()) })
-    ///     });
-    ///     assert_eq!(fut.await, Err(()));
-    /// }
+    /// let stream = stream::iter(vec!["a", "b", "c"]).intersperse_with(|| ", ");
     ///
-    /// assert_eq!(x, 3);
-    /// # })
+    /// assert_eq!("a, b, c", stream.collect::<String>().await);
+    /// # });
     /// ```
-    fn try_for_each<Fut, F>(self, f: F) -> TryForEach<Self, Fut, F>
+    fn intersperse_with<F>(self, f: F) -> IntersperseWith<Self, F>
     where
-        F: FnMut(Self::Item) -> Fut,
-        Fut: TryFuture<Ok = ()>,
+        F: FnMut() -> Self::Item,
         Self: Sized,
     {
-        assert_future::<Result<(), Fut::Error>, _>(TryForEach::new(self, f))
+        assert_stream::<Self::Item, _>(IntersperseWith::new(self, f))
     }
 
-    /// Attempts to run this stream to completion, executing the provided asynchronous
-    /// closure for each element on the stream concurrently as elements become
-    /// available, exiting as soon as an error occurs.
+    /// Groups runs of consecutive items sharing a key, yielding `(key,
+    /// group)` pairs where each group is a sub-stream of the items of that
+    /// run.
     ///
-    /// This is similar to
-    /// [`StreamExt::for_each_concurrent`](crate::stream::StreamExt::for_each_concurrent),
-    /// but will resolve to an error immediately if the provided closure returns
-    /// an error.
+    /// A new group is started each time `f` returns a key different from the
+    /// previous item's key, like `Itertools::group_by`. The parent stream
+    /// returns `Poll::Pending` while a group is still being consumed, so
+    /// groups must be consumed in order. Dropping a group early skips the
+    /// remaining items of that run; the parent then resumes at the start of
+    /// the next group.
     ///
-    /// This method is only available when the `std` or `alloc` feature of this
+    /// This method is only available when the `std` feature of this
     /// library is activated, and it is activated by default.
     ///
     /// # Examples
     ///
     /// ```
     /// # futures::executor::block_on(async {
-    /// use futures::channel::oneshot;
     /// use futures::stream::{self, StreamExt};
     ///
-    /// let (tx1, rx1) = oneshot::channel();
-    /// let (tx2, rx2) = oneshot::channel();
-    /// let (_tx3, rx3) = oneshot::channel();
-    ///
-    /// let stream = stream::iter(vec![rx1, rx2, rx3]);
-    /// let fut = stream.try_for_each_concurrent(
-    ///     /* limit */ 2,
-    ///     |rx| async move {
-    ///         let res: Result<(), oneshot::Canceled> = rx.await;
-    ///         res
-    ///     }
-    /// );
+    /// let mut groups = stream::iter(vec![1, 1, 2, 3, 3]).group_by(|x| *x);
     ///
-    /// tx1.send(()).unwrap();
-    /// // Drop the second sender so that `rx2` resolves to `Canceled`.
-    /// drop(tx2);
+    /// let mut out = Vec::new();
+    /// while let Some((key, group)) = groups.next().await {
+    ///     out.push((key, group.collect::<Vec<_>>().await));
+    /// }
     ///
-    /// // The final result is an error because the second future
-    /// // resulted in an error.
-    /// assert_eq!(Err(oneshot::Canceled), fut.await);
-    /// # })
+    /// assert_eq!(out, vec![(1, vec![1, 1]), (2, vec![2]), (3, vec![3, 3])]);
+    /// # });
     /// ```
-    #[cfg(not(futures_no_atomic_cas))]
-    #[cfg(feature = "alloc")]
-    fn try_for_each_concurrent<Fut, F, E>(
-        self,
-        limit: impl Into<Option<usize>>,
-        f: F,
-    ) -> TryForEachConcurrent<Self, Fut, F>
+    #[cfg(feature = "std")]
+    fn group_by<K, F>(self, f: F) -> GroupBy<Self, K, F>
     where
-        F: FnMut(Self::Item) -> Fut,
-        Fut: Future<Output = Result<(), E>>,
+        F: FnMut(&Self::Item) -> K,
+        K: PartialEq + Clone,
         Self: Sized,
     {
-        assert_future::<Result<(), E>, _>(TryForEachConcurrent::new(self, limit.into(), f))
+        assert_stream::<(K, GroupStream<Self, K, F>), _>(GroupBy::new(self, f))
     }
 
-    /// Creates a new stream of at most `n` items of the underlying stream.
+    /// Collapses runs of consecutive identical items, yielding an item only
+    /// if it differs from the previously yielded one.
     ///
-    /// Once `n` items have been yielded from this stream then it will always
-    /// return that the stream is done.
+    /// The first item always passes through. Only the most recently yielded
+    /// item is retained for comparison, so the adapter never buffers more
+    /// than a single item.
     ///
     /// # Examples
     ///
@@ -1227,22 +3158,26 @@ pub trait StreamExt: Stream {
     /// # futures::executor::block_on(async {
     /// use futures::stream::{self, StreamExt};
     ///
-    /// let stream = stream::iter(1..=10).take(3);
+    /// let stream = stream::iter(vec![1, 1, 2, 2, 2, 3, 1]).dedup();
     ///
-    /// assert_eq!(vec![1, 2, 3], stream.collect::<Vec<_>>().await);
+    /// assert_eq!(vec![1, 2, 3, 1], stream.collect::<Vec<_>>().await);
     /// # });
     /// ```
-    fn take(self, n: usize) -> Take<Self>
+    fn dedup(self) -> Dedup<Self>
     where
+        Self::Item: PartialEq + Clone,
         Self: Sized,
     {
-        assert_stream::<Self::Item, _>(Take::new(self, n))
+        assert_stream::<Self::Item, _>(Dedup::new(self))
     }
 
-    /// Creates a new stream which skips `n` items of the underlying stream.
+    /// Collapses runs of consecutive items that the given comparison closure
+    /// considers equal.
     ///
-    /// Once `n` items have been skipped from this stream then it will always
-    /// return the remaining items on this stream.
+    /// The closure is called with a reference to the most recently yielded
+    /// item and a reference to the incoming item, and should return `true`
+    /// if the incoming item is a duplicate. The first item always passes
+    /// through.
     ///
     /// # Examples
     ///
@@ -1250,109 +3185,93 @@ pub trait StreamExt: Stream {
     /// # futures::executor::block_on(async {
     /// use futures::stream::{self, StreamExt};
     ///
-    /// let stream = stream::iter(1..=10).skip(5);
+    /// let stream = stream::iter(vec![1i32, -1, 2, -2, 3]).dedup_by(|a, b| a.abs() == b.abs());
     ///
-    /// assert_eq!(vec![6, 7, 8, 9, 10], stream.collect::<Vec<_>>().await);
+    /// assert_eq!(vec![1, 2, 3], stream.collect::<Vec<_>>().await);
     /// # });
     /// ```
-    fn skip(self, n: usize) -> Skip<Self>
+    fn dedup_by<F>(self, f: F) -> DedupBy<Self, F>
     where
+        F: FnMut(&Self::Item, &Self::Item) -> bool,
+        Self::Item: Clone,
         Self: Sized,
     {
-        assert_stream::<Self::Item, _>(Skip::new(self, n))
+        assert_stream::<Self::Item, _>(DedupBy::new(self, f))
     }
 
-    /// Fuse a stream such that [`poll_next`](Stream::poll_next) will never
-    /// again be called once it has finished. This method can be used to turn
-    /// any `Stream` into a `FusedStream`.
-    ///
-    /// Normally, once a stream has returned [`None`] from
-    /// [`poll_next`](Stream::poll_next) any further calls could exhibit bad
-    /// behavior such as block forever, panic, never return, etc. If it is known
-    /// that [`poll_next`](Stream::poll_next) may be called after stream
-    /// has already finished, then this method can be used to ensure that it has
-    /// defined semantics.
+    /// Collapses runs of consecutive items that map to the same key.
     ///
-    /// The [`poll_next`](Stream::poll_next) method of a `fuse`d stream
-    /// is guaranteed to return [`None`] after the underlying stream has
-    /// finished.
+    /// Only the key of the most recently yielded item is retained, so unlike
+    /// [`dedup`](StreamExt::dedup) the items themselves do not need to be
+    /// `Clone`. The first item always passes through.
     ///
     /// # Examples
     ///
     /// ```
-    /// use futures::executor::block_on_stream;
-    /// use futures::stream::{self, StreamExt};
-    /// use futures::task::Poll;
-    ///
-    /// let mut x = 0;
-    /// let stream = stream::poll_fn(|_| {
-    ///     x += 1;
-    ///     match x {
-    ///         0..=2 => Poll::Ready(Some(x)),
-    ///         3 => Poll::Ready(None),
-    ///         _ => panic!("should not happen")
-    ///     }
-    /// }).fuse();
-    ///
-    /// let mut iter = block_on_stream(stream);
-    /// assert_eq!(Some(1), iter.next());
-    /// assert_eq!(Some(2), iter.next());
-    /// assert_eq!(None, iter.next());
-    /// assert_eq!(None, iter.next());
-    /// // ...
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec!["apple", "avocado", "banana", "cherry"])
+    ///     .dedup_by_key(|s| s.chars().next());
+    ///
+    /// assert_eq!(vec!["apple", "banana", "cherry"], stream.collect::<Vec<_>>().await);
+    /// # });
     /// ```
-    fn fuse(self) -> Fuse<Self>
+    fn dedup_by_key<F, K>(self, f: F) -> DedupByKey<Self, F, K>
     where
+        F: FnMut(&Self::Item) -> K,
+        K: PartialEq,
         Self: Sized,
     {
-        assert_stream::<Self::Item, _>(Fuse::new(self))
+        assert_stream::<Self::Item, _>(DedupByKey::new(self, f))
     }
 
-    /// Borrows a stream, rather than consuming it.
+    /// Merges adjacent items of the stream with a closure, mirroring
+    /// `Iterator`-adaptor libraries' `coalesce`.
     ///
-    /// This is useful to allow applying stream adaptors while still retaining
-    /// ownership of the original stream.
+    /// One pending item is held back, and `f` is called with the held item
+    /// and each incoming item. Returning `Ok(merged)` replaces the held item
+    /// with the merged result; returning `Err((unmerged, incoming))` emits
+    /// `unmerged` and holds `incoming` back instead. A single item passes
+    /// through unmerged, and the final held item is emitted when the stream
+    /// ends.
     ///
     /// # Examples
     ///
+    /// Run-length-style compaction of consecutive equal items:
+    ///
     /// ```
     /// # futures::executor::block_on(async {
     /// use futures::stream::{self, StreamExt};
     ///
-    /// let mut stream = stream::iter(1..5);
-    ///
-    /// let sum = stream.by_ref()
-    ///                 .take(2)
-    ///                 .fold(0, |a, b| async move { a + b })
-    ///                 .await;
-    /// assert_eq!(sum, 3);
+    /// let stream = stream::iter(vec![(b'a', 1), (b'a', 1), (b'b', 1), (b'a', 1)]).coalesce(
+    ///     |(x, n), (y, m)| {
+    ///         if x == y {
+    ///             Ok((x, n + m))
+    ///         } else {
+    ///             Err(((x, n), (y, m)))
+    ///         }
+    ///     },
+    /// );
     ///
-    /// // You can use the stream again
-    /// let sum = stream.take(2)
-    ///                 .fold(0, |a, b| async move { a + b })
-    ///                 .await;
-    /// assert_eq!(sum, 7);
+    /// assert_eq!(vec![(b'a', 2), (b'b', 1), (b'a', 1)], stream.collect::<Vec<_>>().await);
     /// # });
     /// ```
-    fn by_ref(&mut self) -> &mut Self {
-        self
+    fn coalesce<F>(self, f: F) -> Coalesce<Self, F>
+    where
+        F: FnMut(Self::Item, Self::Item) -> Result<Self::Item, (Self::Item, Self::Item)>,
+        Self: Sized,
+    {
+        assert_stream::<Self::Item, _>(Coalesce::new(self, f))
     }
 
-    /// Catches unwinding panics while polling the stream.
-    ///
-    /// Caught panic (if any) will be the last element of the resulting stream.
+    /// Yields each distinct item of the stream only once, in first-seen
+    /// order.
     ///
-    /// In general, panics within a stream can propagate all the way out to the
-    /// task level. This combinator makes it possible to halt unwinding within
-    /// the stream itself. It's most commonly used within task executors. This
-    /// method should not be used for error handling.
-    ///
-    /// Note that this method requires the `UnwindSafe` bound from the standard
-    /// library. This isn't always applied automatically, and the standard
-    /// library provides an `AssertUnwindSafe` wrapper type to apply it
-    /// after-the fact. To assist using this method, the [`Stream`] trait is
-    /// also implemented for `AssertUnwindSafe<St>` where `St` implements
-    /// [`Stream`].
+    /// Unlike [`dedup`](StreamExt::dedup), which only collapses consecutive
+    /// duplicates, this suppresses duplicates anywhere in the stream. Every
+    /// distinct item seen so far is remembered in a [`HashSet`], so memory
+    /// use grows with the number of distinct items.
     ///
     /// This method is only available when the `std` feature of this
     /// library is activated, and it is activated by default.
@@ -1363,251 +3282,246 @@ pub trait StreamExt: Stream {
     /// # futures::executor::block_on(async {
     /// use futures::stream::{self, StreamExt};
     ///
-    /// let stream = stream::iter(vec![Some(10), None, Some(11)]);
-    /// // Panic on second element
-    /// let stream_panicking = stream.map(|o| o.unwrap());
-    /// // Collect all the results
-    /// let stream = stream_panicking.catch_unwind();
+    /// let stream = stream::iter(vec![1, 2, 1, 3, 2, 4]).unique();
     ///
-    /// let results: Vec<Result<i32, _>> = stream.collect().await;
-    /// match results[0] {
-    ///     Ok(10) => {}
-    ///     _ => panic!("unexpected result!"),
-    /// }
-    /// assert!(results[1].is_err());
-    /// assert_eq!(results.len(), 2);
+    /// assert_eq!(vec![1, 2, 3, 4], stream.collect::<Vec<_>>().await);
     /// # });
     /// ```
-    #[cfg(feature = "std")]
-    fn catch_unwind(self) -> CatchUnwind<Self>
-    where
-        Self: Sized + std::panic::UnwindSafe,
-    {
-        assert_stream(CatchUnwind::new(self))
-    }
-
-    /// Wrap the stream in a Box, pinning it.
     ///
-    /// This method is only available when the `std` or `alloc` feature of this
-    /// library is activated, and it is activated by default.
-    #[cfg(feature = "alloc")]
-    fn boxed<'a>(self) -> BoxStream<'a, Self::Item>
+    /// [`HashSet`]: std::collections::HashSet
+    #[cfg(feature = "std")]
+    fn unique(self) -> Unique<Self>
     where
-        Self: Sized + Send + 'a,
+        Self::Item: core::hash::Hash + Eq + Clone,
+        Self: Sized,
     {
-        assert_stream::<Self::Item, _>(Box::pin(self))
+        assert_stream::<Self::Item, _>(Unique::new(self))
     }
 
-    /// Wrap the stream in a Box, pinning it.
+    /// Yields only the first item of the stream for each distinct key, in
+    /// first-seen order.
     ///
-    /// Similar to `boxed`, but without the `Send` requirement.
+    /// This behaves like [`unique`](StreamExt::unique), but dedups by a
+    /// projected key instead of the item itself, so the items do not need to
+    /// be `Clone` or hashable. Every distinct key seen so far is remembered
+    /// in a [`HashSet`], so memory use grows with the number of distinct
+    /// keys.
     ///
-    /// This method is only available when the `std` or `alloc` feature of this
+    /// This method is only available when the `std` feature of this
     /// library is activated, and it is activated by default.
-    #[cfg(feature = "alloc")]
-    fn boxed_local<'a>(self) -> LocalBoxStream<'a, Self::Item>
-    where
-        Self: Sized + 'a,
-    {
-        assert_stream::<Self::Item, _>(Box::pin(self))
-    }
-
-    /// An adaptor for creating a buffered list of pending futures.
     ///
-    /// If this stream's item can be converted into a future, then this adaptor
-    /// will buffer up to at most `n` futures and then return the outputs in the
-    /// same order as the underlying stream. No more than `n` futures will be
-    /// buffered at any point in time, and less than `n` may also be buffered
-    /// depending on the state of each future.
+    /// # Examples
     ///
-    /// The limit argument is of type `Into<Option<usize>>`, and so can be
-    /// provided as either `None`, `Some(10)`, or just `10`. Note: a limit of zero is
-    /// interpreted as no limit at all, and will have the same result as passing in `None`.
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
     ///
-    /// The returned stream will be a stream of each future's output.
+    /// let stream = stream::iter(vec!["apple", "avocado", "banana", "cherry"])
+    ///     .unique_by(|s| s.chars().next());
     ///
-    /// This method is only available when the `std` or `alloc` feature of this
-    /// library is activated, and it is activated by default.
-    #[cfg(not(futures_no_atomic_cas))]
-    #[cfg(feature = "alloc")]
-    fn buffered(self, n: impl Into<Option<usize>>) -> Buffered<Self>
+    /// assert_eq!(vec!["apple", "banana", "cherry"], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    ///
+    /// [`HashSet`]: std::collections::HashSet
+    #[cfg(feature = "std")]
+    fn unique_by<F, K>(self, f: F) -> UniqueBy<Self, F, K>
     where
-        Self::Item: Future,
+        F: FnMut(&Self::Item) -> K,
+        K: core::hash::Hash + Eq,
         Self: Sized,
     {
-        assert_stream::<<Self::Item as Future>::Output, _>(Buffered::new(self, n.into()))
+        assert_stream::<Self::Item, _>(UniqueBy::new(self, f))
     }
 
-    /// An adaptor for creating a buffered list of pending futures (unordered).
-    ///
-    /// If this stream's item can be converted into a future, then this adaptor
-    /// will buffer up to `n` futures and then return the outputs in the order
-    /// in which they complete. No more than `n` futures will be buffered at
-    /// any point in time, and less than `n` may also be buffered depending on
-    /// the state of each future.
+    /// Rate-limits this stream, yielding at most one item per `min_interval`.
     ///
-    /// The limit argument is of type `Into<Option<usize>>`, and so can be
-    /// provided as either `None`, `Some(10)`, or just `10`. Note: a limit of zero is
-    /// interpreted as no limit at all, and will have the same result as passing in `None`.
+    /// The first item is forwarded immediately and a delay of `min_interval`
+    /// is started by calling `f`. Items that arrive while the delay is
+    /// running are suppressed, with only the most recent one retained; it is
+    /// yielded once the interval elapses and a new interval begins. When no
+    /// item was suppressed during an interval, the next item is again
+    /// forwarded immediately.
     ///
-    /// The returned stream will be a stream of each future's output.
+    /// To stay runtime agnostic this combinator does not provide a timer of
+    /// its own; `f` is called to create a new delay future whenever one is
+    /// needed, so any timer implementation (e.g. `tokio::time::sleep` or
+    /// `async_io::Timer`) can be plugged in.
     ///
-    /// This method is only available when the `std` or `alloc` feature of this
-    /// library is activated, and it is activated by default.
+    /// If the underlying stream ends while an item is suppressed, that item
+    /// is yielded immediately before the stream terminates, so the trailing
+    /// value is never lost.
     ///
     /// # Examples
     ///
     /// ```
     /// # futures::executor::block_on(async {
-    /// use futures::channel::oneshot;
+    /// use std::time::Duration;
+    /// use futures::future;
     /// use futures::stream::{self, StreamExt};
     ///
-    /// let (send_one, recv_one) = oneshot::channel();
-    /// let (send_two, recv_two) = oneshot::channel();
-    ///
-    /// let stream_of_futures = stream::iter(vec![recv_one, recv_two]);
-    /// let mut buffered = stream_of_futures.buffer_unordered(10);
-    ///
-    /// send_two.send(2i32)?;
-    /// assert_eq!(buffered.next().await, Some(Ok(2i32)));
-    ///
-    /// send_one.send(1i32)?;
-    /// assert_eq!(buffered.next().await, Some(Ok(1i32)));
+    /// // The first item passes through immediately; the rest of the readily
+    /// // available items collapse to the most recent one.
+    /// let stream = stream::iter(1..=5).throttle(Duration::from_millis(10), |_| future::ready(()));
     ///
-    /// assert_eq!(buffered.next().await, None);
-    /// # Ok::<(), i32>(()) }).unwrap();
+    /// assert_eq!(vec![1, 5], stream.collect::<Vec<_>>().await);
+    /// # });
     /// ```
-    #[cfg(not(futures_no_atomic_cas))]
-    #[cfg(feature = "alloc")]
-    fn buffer_unordered(self, n: impl Into<Option<usize>>) -> BufferUnordered<Self>
+    fn throttle<Fut, F>(self, min_interval: Duration, f: F) -> Throttle<Self, Fut, F>
     where
-        Self::Item: Future,
+        F: FnMut(Duration) -> Fut,
+        Fut: Future<Output = ()>,
         Self: Sized,
     {
-        assert_stream::<<Self::Item as Future>::Output, _>(BufferUnordered::new(self, n.into()))
+        assert_stream::<Self::Item, _>(Throttle::new(self, min_interval, f))
     }
 
-    /// An adapter for zipping two streams together.
+    /// Samples the most recent item of the stream on a fixed interval.
     ///
-    /// The zipped stream waits for both streams to produce an item, and then
-    /// returns that pair. If either stream ends then the zipped stream will
-    /// also end.
+    /// Every `interval`, the latest item produced by the underlying stream
+    /// since the previous tick is yielded; items arriving in between are
+    /// discarded in its favor. Ticks where nothing new arrived emit
+    /// nothing. This is useful for displaying a bounded view of a
+    /// fast-changing value, e.g. driving a dashboard from a metrics stream.
+    ///
+    /// When the underlying stream ends, the last unsampled item (if any) is
+    /// yielded immediately and the ticking stops.
+    ///
+    /// The `timer` is anything implementing [`Timer`](crate::time::Timer),
+    /// such as a closure returning a runtime's sleep future or the
+    /// thread-based [`ThreadTimer`](crate::time::ThreadTimer).
     ///
     /// # Examples
     ///
     /// ```
     /// # futures::executor::block_on(async {
+    /// use std::time::Duration;
     /// use futures::stream::{self, StreamExt};
+    /// use futures::time::ThreadTimer;
     ///
-    /// let stream1 = stream::iter(1..=3);
-    /// let stream2 = stream::iter(5..=10);
+    /// // All items are readily available, so they coalesce to the last one,
+    /// // which is flushed when the source ends.
+    /// let stream = stream::iter(1..=5).sample(Duration::from_secs(60), ThreadTimer);
     ///
-    /// let vec = stream1.zip(stream2)
-    ///                  .collect::<Vec<_>>()
-    ///                  .await;
-    /// assert_eq!(vec![(1, 5), (2, 6), (3, 7)], vec);
+    /// assert_eq!(vec![5], stream.collect::<Vec<_>>().await);
     /// # });
     /// ```
-    ///
-    fn zip<St>(self, other: St) -> Zip<Self, St>
+    fn sample<T>(self, interval: Duration, timer: T) -> Sample<Self, T>
     where
-        St: Stream,
+        T: Timer,
         Self: Sized,
     {
-        assert_stream::<(Self::Item, St::Item), _>(Zip::new(self, other))
+        assert_stream::<Self::Item, _>(Sample::new(self, interval, timer))
     }
 
-    /// Adapter for chaining two streams.
+    /// Requires each item to be produced within `duration`, yielding an
+    /// error item when the deadline elapses.
     ///
-    /// The resulting stream emits elements from the first stream, and when
-    /// first stream reaches the end, emits the elements from the second stream.
+    /// The returned stream wraps each item in `Ok` and yields
+    /// `Err(`[`Elapsed`](crate::future::Elapsed)`)` whenever `duration`
+    /// passes without the underlying stream producing anything. A timeout
+    /// does not terminate the stream: the deadline resets after every item
+    /// *and* after every reported timeout, so this can be used to detect
+    /// missing heartbeats for as long as the connection lives. The deadline
+    /// for an item starts when the previous one is observed, and the timer
+    /// is dropped once the underlying stream ends.
+    ///
+    /// The `timer` is anything implementing [`Timer`](crate::time::Timer),
+    /// such as a closure returning a runtime's sleep future or the
+    /// thread-based [`ThreadTimer`](crate::time::ThreadTimer).
+    ///
+    /// # Examples
     ///
     /// ```
     /// # futures::executor::block_on(async {
+    /// use std::time::Duration;
     /// use futures::stream::{self, StreamExt};
+    /// use futures::time::ThreadTimer;
     ///
-    /// let stream1 = stream::iter(vec![Ok(10), Err(false)]);
-    /// let stream2 = stream::iter(vec![Err(true), Ok(20)]);
-    ///
-    /// let stream = stream1.chain(stream2);
+    /// // Readily available items never hit the deadline.
+    /// let stream = stream::iter(1..=3).timeout(Duration::from_secs(60), ThreadTimer);
     ///
-    /// let result: Vec<_> = stream.collect().await;
-    /// assert_eq!(result, vec![
-    ///     Ok(10),
-    ///     Err(false),
-    ///     Err(true),
-    ///     Ok(20),
-    /// ]);
+    /// assert_eq!(vec![Ok(1), Ok(2), Ok(3)], stream.collect::<Vec<_>>().await);
     /// # });
     /// ```
-    fn chain<St>(self, other: St) -> Chain<Self, St>
-    where
-        St: Stream<Item = Self::Item>,
-        Self: Sized,
-    {
-        assert_stream::<Self::Item, _>(Chain::new(self, other))
-    }
-
-    /// Creates a new stream which exposes a `peek` method.
-    ///
-    /// Calling `peek` returns a reference to the next item in the stream.
-    fn peekable(self) -> Peekable<Self>
+    fn timeout<T>(self, duration: Duration, timer: T) -> Timeout<Self, T>
     where
+        T: Timer,
         Self: Sized,
     {
-        assert_stream::<Self::Item, _>(Peekable::new(self))
+        assert_stream::<Result<Self::Item, crate::future::Elapsed>, _>(Timeout::new(
+            self, duration, timer,
+        ))
     }
 
-    /// An adaptor for chunking up items of the stream inside a vector.
+    /// Limits the rate at which items are yielded using a token bucket.
     ///
-    /// This combinator will attempt to pull items from this stream and buffer
-    /// them into a local vector. At most `capacity` items will get buffered
-    /// before they're yielded from the returned stream.
+    /// The bucket starts out with `burst` tokens and refills at `rate`
+    /// tokens per second, never holding more than `burst`. Each item costs
+    /// one token; when the bucket is empty the stream waits for the next
+    /// refill. This allows short bursts of up to `burst` items while
+    /// enforcing `rate` on average, which is the usual contract of
+    /// politeness-limited external APIs.
     ///
-    /// Note that the vectors returned from this iterator may not always have
-    /// `capacity` elements. If the underlying stream ended and only a partial
-    /// vector was created, it'll be returned. Additionally if an error happens
-    /// from the underlying stream then the currently buffered items will be
-    /// yielded.
+    /// Unlike [`throttle`](StreamExt::throttle), no item is ever skipped;
+    /// items are only delayed.
     ///
-    /// This method is only available when the `std` or `alloc` feature of this
-    /// library is activated, and it is activated by default.
+    /// The `timer` is anything implementing [`Timer`](crate::time::Timer),
+    /// such as a closure returning a runtime's sleep future or the
+    /// thread-based [`ThreadTimer`](crate::time::ThreadTimer).
     ///
     /// # Panics
     ///
-    /// This method will panic if `capacity` is zero.
-    #[cfg(feature = "alloc")]
-    fn chunks(self, capacity: usize) -> Chunks<Self>
+    /// Panics if `rate` is not positive or `burst` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    /// use futures::time::ThreadTimer;
+    ///
+    /// // One item per millisecond after an initial burst of three.
+    /// let stream = stream::iter(1..=5).rate_limit(1000.0, 3, ThreadTimer);
+    ///
+    /// assert_eq!(vec![1, 2, 3, 4, 5], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn rate_limit<T>(self, rate: f64, burst: u32, timer: T) -> RateLimit<Self, T>
     where
+        T: Timer,
         Self: Sized,
     {
-        assert_stream::<Vec<Self::Item>, _>(Chunks::new(self, capacity))
+        assert_stream::<Self::Item, _>(RateLimit::new(self, rate, burst, timer))
     }
 
-    /// An adaptor for chunking up ready items of the stream inside a vector.
-    ///
-    /// This combinator will attempt to pull ready items from this stream and
-    /// buffer them into a local vector. At most `capacity` items will get
-    /// buffered before they're yielded from the returned stream. If underlying
-    /// stream returns `Poll::Pending`, and collected chunk is not empty, it will
-    /// be immediately returned.
+    /// Wraps the stream in an [`Abortable`] stream, returning it together
+    /// with an [`AbortHandle`] which can be used to stop it.
     ///
-    /// If the underlying stream ended and only a partial vector was created,
-    /// it'll be returned. Additionally if an error happens from the underlying
-    /// stream then the currently buffered items will be yielded.
+    /// This is a method-style alternative to the free function
+    /// [`abortable`](super::abortable()) and behaves identically.
     ///
     /// This method is only available when the `std` or `alloc` feature of this
     /// library is activated, and it is activated by default.
     ///
-    /// # Panics
+    /// # Examples
     ///
-    /// This method will panic if `capacity` is zero.
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let (mut stream, abort_handle) = stream::iter(vec![1, 2, 3]).abortable();
+    /// abort_handle.abort();
+    /// assert_eq!(stream.next().await, None);
+    /// # });
+    /// ```
+    #[cfg(not(futures_no_atomic_cas))]
     #[cfg(feature = "alloc")]
-    fn ready_chunks(self, capacity: usize) -> ReadyChunks<Self>
+    fn abortable(self) -> (Abortable<Self>, AbortHandle)
     where
         Self: Sized,
     {
-        assert_stream::<Vec<Self::Item>, _>(ReadyChunks::new(self, capacity))
+        super::abortable(self)
     }
 
     /// A future that completes after the given stream has been fully processed
@@ -1667,6 +3581,36 @@ pub trait StreamExt: Stream {
         assert_stream::<Self::Item, _>(Inspect::new(self, f))
     }
 
+    /// Do something exactly once when this stream completes.
+    ///
+    /// Unlike [`inspect`](StreamExt::inspect), which fires per item, the
+    /// given closure is called the first time the underlying stream yields
+    /// `None`. The returned stream is fused, so polling it again after
+    /// completion keeps yielding `None` without invoking the closure a
+    /// second time. If the stream is dropped before completing, the closure
+    /// is never called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let mut done = false;
+    /// let stream = stream::iter(1..=3).inspect_done(|| done = true);
+    ///
+    /// assert_eq!(vec![1, 2, 3], stream.collect::<Vec<_>>().await);
+    /// assert!(done);
+    /// # });
+    /// ```
+    fn inspect_done<F>(self, f: F) -> InspectDone<Self, F>
+    where
+        F: FnOnce(),
+        Self: Sized,
+    {
+        assert_stream::<Self::Item, _>(InspectDone::new(self, f))
+    }
+
     /// Wrap this stream in an `Either` stream, making it the left-hand variant
     /// of that `Either`.
     ///
@@ -0,0 +1,48 @@
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the [`nth`](super::StreamExt::nth) method.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Nth<St> {
+        #[pin]
+        stream: St,
+        remaining: usize,
+    }
+}
+
+impl<St: Stream> Nth<St> {
+    pub(super) fn new(stream: St, n: usize) -> Self {
+        Self { stream, remaining: n }
+    }
+}
+
+impl<St: FusedStream> FusedFuture for Nth<St> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St: Stream> Future for Nth<St> {
+    type Output = Option<St::Item>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => {
+                    if *this.remaining == 0 {
+                        return Poll::Ready(Some(item));
+                    }
+                    *this.remaining -= 1;
+                }
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use core::fmt;
+use core::mem;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the [`partition`](super::StreamExt::partition) method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Partition<St, F, A, B> {
+        #[pin]
+        stream: St,
+        f: F,
+        left: A,
+        right: B,
+    }
+}
+
+impl<St, F, A, B> fmt::Debug for Partition<St, F, A, B>
+where
+    St: fmt::Debug,
+    A: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Partition")
+            .field("stream", &self.stream)
+            .field("left", &self.left)
+            .field("right", &self.right)
+            .finish()
+    }
+}
+
+impl<St: Stream, F, A: Default, B: Default> Partition<St, F, A, B> {
+    fn finish(self: Pin<&mut Self>) -> (A, B) {
+        let this = self.project();
+        (mem::replace(this.left, Default::default()), mem::replace(this.right, Default::default()))
+    }
+
+    pub(super) fn new(stream: St, f: F) -> Self {
+        Self { stream, f, left: Default::default(), right: Default::default() }
+    }
+}
+
+impl<St, F, A, B> FusedFuture for Partition<St, F, A, B>
+where
+    St: FusedStream,
+    F: FnMut(&St::Item) -> bool,
+    A: Default + Extend<St::Item>,
+    B: Default + Extend<St::Item>,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, F, A, B> Future for Partition<St, F, A, B>
+where
+    St: Stream,
+    F: FnMut(&St::Item) -> bool,
+    A: Default + Extend<St::Item>,
+    B: Default + Extend<St::Item>,
+{
+    type Output = (A, B);
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<(A, B)> {
+        let mut this = self.as_mut().project();
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => {
+                    if (this.f)(&item) {
+                        this.left.extend(Some(item));
+                    } else {
+                        this.right.extend(Some(item));
+                    }
+                }
+                None => return Poll::Ready(self.finish()),
+            }
+        }
+    }
+}
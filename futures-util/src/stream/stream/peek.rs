@@ -1,5 +1,7 @@
 use crate::fns::FnOnce1;
 use crate::stream::{Fuse, StreamExt};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::fmt;
 use core::marker::PhantomData;
 use core::pin::Pin;
@@ -11,6 +13,28 @@ use futures_core::task::{Context, Poll};
 use futures_sink::Sink;
 use pin_project_lite::pin_project;
 
+// `pin_project!` does not support `#[cfg]` on fields, so the struct is
+// defined twice: with the `peek_n` lookahead buffer when `alloc` is
+// available, and without it otherwise.
+#[cfg(feature = "alloc")]
+pin_project! {
+    /// A `Stream` that implements a `peek` method.
+    ///
+    /// The `peek` method can be used to retrieve a reference
+    /// to the next `Stream::Item` if available. A subsequent
+    /// call to `poll` will return the owned item.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Peekable<St: Stream> {
+        #[pin]
+        stream: Fuse<St>,
+        peeked: Option<St::Item>,
+        // Buffered items after `peeked`, filled by `peek_n`.
+        lookahead: Vec<St::Item>,
+    }
+}
+
+#[cfg(not(feature = "alloc"))]
 pin_project! {
     /// A `Stream` that implements a `peek` method.
     ///
@@ -28,7 +52,12 @@ pin_project! {
 
 impl<St: Stream> Peekable<St> {
     pub(super) fn new(stream: St) -> Self {
-        Self { stream: stream.fuse(), peeked: None }
+        Self {
+            stream: stream.fuse(),
+            peeked: None,
+            #[cfg(feature = "alloc")]
+            lookahead: Vec::new(),
+        }
     }
 
     delegate_access_inner!(stream, St, (.));
@@ -49,7 +78,13 @@ impl<St: Stream> Peekable<St> {
         Poll::Ready(loop {
             if this.peeked.is_some() {
                 break this.peeked.as_ref();
-            } else if let Some(item) = ready!(this.stream.as_mut().poll_next(cx)) {
+            }
+            #[cfg(feature = "alloc")]
+            if !this.lookahead.is_empty() {
+                *this.peeked = Some(this.lookahead.remove(0));
+                continue;
+            }
+            if let Some(item) = ready!(this.stream.as_mut().poll_next(cx)) {
                 *this.peeked = Some(item);
             } else {
                 break None;
@@ -97,7 +132,13 @@ impl<St: Stream> Peekable<St> {
         Poll::Ready(loop {
             if this.peeked.is_some() {
                 break this.peeked.as_mut();
-            } else if let Some(item) = ready!(this.stream.as_mut().poll_next(cx)) {
+            }
+            #[cfg(feature = "alloc")]
+            if !this.lookahead.is_empty() {
+                *this.peeked = Some(this.lookahead.remove(0));
+                continue;
+            }
+            if let Some(item) = ready!(this.stream.as_mut().poll_next(cx)) {
                 *this.peeked = Some(item);
             } else {
                 break None;
@@ -105,6 +146,65 @@ impl<St: Stream> Peekable<St> {
         })
     }
 
+    /// Produces a future which retrieves a slice of up to `n` upcoming items
+    /// in the stream, awaiting until that many are buffered or the underlying
+    /// stream terminates.
+    ///
+    /// If the stream ends before `n` items are available, the returned slice
+    /// contains the remaining items; it is empty when the stream is exhausted.
+    /// The buffered items are still yielded, in order, by subsequent calls to
+    /// [`next`](StreamExt::next).
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    /// use futures::pin_mut;
+    ///
+    /// let stream = stream::iter(vec![1, 2, 3]).peekable();
+    /// pin_mut!(stream);
+    ///
+    /// assert_eq!(stream.as_mut().peek_n(2).await, &[1, 2]);
+    /// // Only two items were left near the end.
+    /// assert_eq!(stream.as_mut().next().await, Some(1));
+    /// assert_eq!(stream.as_mut().peek_n(5).await, &[2, 3]);
+    ///
+    /// // The buffered items are still yielded in order.
+    /// assert_eq!(stream.collect::<Vec<_>>().await, vec![2, 3]);
+    /// # });
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn peek_n(self: Pin<&mut Self>, n: usize) -> PeekN<'_, St> {
+        PeekN { inner: Some(self), n }
+    }
+
+    /// Peek retrieves a slice of up to `n` upcoming items in the stream.
+    ///
+    /// This method polls the underlying stream until either `n` items have
+    /// been buffered or the stream terminates, and then returns a reference
+    /// to the buffered items.
+    #[cfg(feature = "alloc")]
+    pub fn poll_peek_n(self: Pin<&mut Self>, cx: &mut Context<'_>, n: usize) -> Poll<&[St::Item]> {
+        let mut this = self.project();
+
+        if let Some(item) = this.peeked.take() {
+            this.lookahead.insert(0, item);
+        }
+        while this.lookahead.len() < n {
+            if let Some(item) = ready!(this.stream.as_mut().poll_next(cx)) {
+                this.lookahead.push(item);
+            } else {
+                break;
+            }
+        }
+        let len = core::cmp::min(n, this.lookahead.len());
+        Poll::Ready(&this.lookahead[..len])
+    }
+
     /// Creates a future which will consume and return the next value of this
     /// stream if a condition is true.
     ///
@@ -188,6 +288,10 @@ impl<St: Stream> Peekable<St> {
 
 impl<St: Stream> FusedStream for Peekable<St> {
     fn is_terminated(&self) -> bool {
+        #[cfg(feature = "alloc")]
+        if !self.lookahead.is_empty() {
+            return false;
+        }
         self.peeked.is_none() && self.stream.is_terminated()
     }
 }
@@ -200,11 +304,20 @@ impl<S: Stream> Stream for Peekable<S> {
         if let Some(item) = this.peeked.take() {
             return Poll::Ready(Some(item));
         }
+        #[cfg(feature = "alloc")]
+        if !this.lookahead.is_empty() {
+            return Poll::Ready(Some(this.lookahead.remove(0)));
+        }
         this.stream.poll_next(cx)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let peek_len = if self.peeked.is_some() { 1 } else { 0 };
+        #[allow(unused_mut)]
+        let mut peek_len = if self.peeked.is_some() { 1 } else { 0 };
+        #[cfg(feature = "alloc")]
+        {
+            peek_len += self.lookahead.len();
+        }
         let (lower, upper) = self.stream.size_hint();
         let lower = lower.saturating_add(peek_len);
         let upper = match upper {
@@ -310,6 +423,55 @@ where
     }
 }
 
+pin_project! {
+    /// Future for the [`Peekable::peek_n`](self::Peekable::peek_n) method.
+    #[cfg(feature = "alloc")]
+    #[must_use = "futures do nothing unless polled"]
+    pub struct PeekN<'a, St: Stream> {
+        inner: Option<Pin<&'a mut Peekable<St>>>,
+        n: usize,
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<St> fmt::Debug for PeekN<'_, St>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PeekN").field("inner", &self.inner).field("n", &self.n).finish()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<St: Stream> FusedFuture for PeekN<'_, St> {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_none()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, St> Future for PeekN<'a, St>
+where
+    St: Stream,
+{
+    type Output = &'a [St::Item];
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let n = *this.n;
+        let inner = this.inner;
+        if let Some(peekable) = inner {
+            ready!(peekable.as_mut().poll_peek_n(cx, n));
+
+            inner.take().unwrap().poll_peek_n(cx, n)
+        } else {
+            panic!("PeekN polled after completion")
+        }
+    }
+}
+
 pin_project! {
     /// Future for the [`Peekable::next_if`](self::Peekable::next_if) method.
     #[must_use = "futures do nothing unless polled"]
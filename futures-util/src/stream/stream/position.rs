@@ -0,0 +1,83 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the [`position`](super::StreamExt::position) method.
+    ///
+    /// This is not called `Position` because that name is taken by the
+    /// [`Position`](super::Position) enum used by
+    /// [`with_position`](super::StreamExt::with_position).
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct FindPosition<St, F> {
+        #[pin]
+        stream: St,
+        f: F,
+        index: usize,
+        done: bool,
+    }
+}
+
+impl<St, F> fmt::Debug for FindPosition<St, F>
+where
+    St: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FindPosition")
+            .field("stream", &self.stream)
+            .field("index", &self.index)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<St, F> FindPosition<St, F>
+where
+    St: Stream,
+    F: FnMut(&St::Item) -> bool,
+{
+    pub(super) fn new(stream: St, f: F) -> Self {
+        Self { stream, f, index: 0, done: false }
+    }
+}
+
+impl<St, F> FusedFuture for FindPosition<St, F>
+where
+    St: FusedStream,
+    F: FnMut(&St::Item) -> bool,
+{
+    fn is_terminated(&self) -> bool {
+        self.done || self.stream.is_terminated()
+    }
+}
+
+impl<St, F> Future for FindPosition<St, F>
+where
+    St: Stream,
+    F: FnMut(&St::Item) -> bool,
+{
+    type Output = Option<usize>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => {
+                    if (this.f)(&item) {
+                        *this.done = true;
+                        return Poll::Ready(Some(*this.index));
+                    }
+                    *this.index += 1;
+                }
+                None => {
+                    *this.done = true;
+                    return Poll::Ready(None);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use core::fmt;
+use core::iter;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the [`product`](super::StreamExt::product) method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Product<St, P> {
+        #[pin]
+        stream: St,
+        acc: Option<P>,
+    }
+}
+
+impl<St, P> fmt::Debug for Product<St, P>
+where
+    St: fmt::Debug,
+    P: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Product").field("stream", &self.stream).field("acc", &self.acc).finish()
+    }
+}
+
+impl<St, P> Product<St, P>
+where
+    St: Stream,
+    P: iter::Product<St::Item> + iter::Product<P>,
+{
+    pub(super) fn new(stream: St) -> Self {
+        Self { stream, acc: None }
+    }
+}
+
+impl<St, P> FusedFuture for Product<St, P>
+where
+    St: FusedStream,
+    P: iter::Product<St::Item> + iter::Product<P>,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, P> Future for Product<St, P>
+where
+    St: Stream,
+    P: iter::Product<St::Item> + iter::Product<P>,
+{
+    type Output = P;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => {
+                    let item = P::product(iter::once(item));
+                    *this.acc = Some(match this.acc.take() {
+                        Some(acc) => P::product(iter::once(acc).chain(iter::once(item))),
+                        None => item,
+                    });
+                }
+                None => {
+                    // An empty stream multiplies to the multiplicative
+                    // identity.
+                    return Poll::Ready(
+                        this.acc.take().unwrap_or_else(|| P::product(iter::empty::<St::Item>())),
+                    );
+                }
+            }
+        }
+    }
+}
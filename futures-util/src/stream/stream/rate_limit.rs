@@ -0,0 +1,147 @@
+use crate::stream::{Fuse, FuturesUnordered};
+use crate::time::Timer;
+use core::fmt;
+use core::pin::Pin;
+use core::time::Duration;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`rate_limit`](super::StreamExt::rate_limit) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct RateLimit<St, T>
+        where St: Stream, T: Timer,
+    {
+        #[pin]
+        stream: Fuse<St>,
+        // One in-flight sleep per spent token, each maturing when its token
+        // is due back. Every completion credits one token, so `tokens` can
+        // never exceed `burst`, and tokens keep accruing while the stream
+        // sits idle.
+        refills: FuturesUnordered<T::Sleep>,
+        pending: Option<St::Item>,
+        timer: T,
+        // Time it takes to accumulate one token.
+        interval: Duration,
+        tokens: u32,
+    }
+}
+
+impl<St, T> fmt::Debug for RateLimit<St, T>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+    T: Timer + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RateLimit")
+            .field("stream", &self.stream)
+            .field("pending", &self.pending)
+            .field("timer", &self.timer)
+            .field("interval", &self.interval)
+            .field("tokens", &self.tokens)
+            .finish()
+    }
+}
+
+impl<St, T> RateLimit<St, T>
+where
+    St: Stream,
+    T: Timer,
+{
+    pub(super) fn new(stream: St, rate: f64, burst: u32, timer: T) -> Self {
+        assert!(rate > 0.0, "rate must be positive");
+        assert!(burst > 0, "burst must be at least one");
+
+        // The bucket starts out full and refills one token every `1 / rate`
+        // seconds.
+        let interval = Duration::from_nanos((1_000_000_000.0 / rate) as u64);
+        Self {
+            stream: super::Fuse::new(stream),
+            refills: FuturesUnordered::new(),
+            pending: None,
+            timer,
+            interval,
+            tokens: burst,
+        }
+    }
+
+    delegate_access_inner!(stream, St, (.));
+}
+
+impl<St, T> Stream for RateLimit<St, T>
+where
+    St: Stream,
+    T: Timer,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        // Credit every refill that has matured since the last poll.
+        while let Poll::Ready(Some(())) = Pin::new(&mut *this.refills).poll_next(cx) {
+            *this.tokens += 1;
+        }
+
+        // Pull the next item; it is buffered if no token is available yet.
+        if this.pending.is_none() {
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => *this.pending = Some(item),
+                Poll::Ready(None) | Poll::Pending => {}
+            }
+        }
+
+        if this.pending.is_some() {
+            if *this.tokens == 0 {
+                // A matured refill will wake us; one is always in flight
+                // while the bucket is not full.
+                return Poll::Pending;
+            }
+            *this.tokens -= 1;
+            // Schedule the replacement token. Sleeps spent in quick
+            // succession are staggered so the bucket refills at `rate`, not
+            // all at once.
+            let deficit = this.refills.len() as u32 + 1;
+            this.refills.push(this.timer.sleep(*this.interval * deficit));
+            return Poll::Ready(this.pending.take());
+        }
+
+        if this.stream.is_done() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending_len = if self.pending.is_some() { 1 } else { 0 };
+        let (lower, upper) = self.stream.size_hint();
+        (lower.saturating_add(pending_len), upper.and_then(|x| x.checked_add(pending_len)))
+    }
+}
+
+impl<St, T> FusedStream for RateLimit<St, T>
+where
+    St: Stream,
+    T: Timer,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_done() && self.pending.is_none()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, T, Item> Sink<Item> for RateLimit<S, T>
+where
+    S: Stream + Sink<Item>,
+    T: Timer,
+{
+    type Error = S::Error;
+
+    delegate_sink!(stream, Item);
+}
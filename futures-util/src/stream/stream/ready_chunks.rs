@@ -35,6 +35,53 @@ where
     }
 
     delegate_access_inner!(stream, St, (.));
+
+    /// Polls for the next chunk, collecting it into `buf` instead of
+    /// allocating a fresh `Vec` per chunk.
+    ///
+    /// `buf` is cleared and then filled with up to the configured capacity of
+    /// ready items, so its allocation is retained and reused across chunks.
+    /// On success the number of collected items is returned; `None` is
+    /// returned once the underlying stream has terminated and all buffered
+    /// items have been drained.
+    pub fn poll_next_into(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut Vec<St::Item>,
+    ) -> Poll<Option<usize>> {
+        let mut this = self.project();
+
+        buf.clear();
+        // Drain any items carried over from a previous `Stream::poll_next`
+        // call.
+        buf.append(this.items);
+
+        loop {
+            if buf.len() >= *this.cap {
+                return Poll::Ready(Some(buf.len()));
+            }
+
+            match this.stream.as_mut().poll_next(cx) {
+                // Flush all collected data if underlying stream doesn't
+                // contain more ready values
+                Poll::Pending => {
+                    return if buf.is_empty() {
+                        Poll::Pending
+                    } else {
+                        Poll::Ready(Some(buf.len()))
+                    }
+                }
+
+                Poll::Ready(Some(item)) => buf.push(item),
+
+                // Since the underlying stream ran out of values, return what
+                // we have buffered, if we have anything.
+                Poll::Ready(None) => {
+                    return Poll::Ready(if buf.is_empty() { None } else { Some(buf.len()) })
+                }
+            }
+        }
+    }
 }
 
 impl<St: Stream> Stream for ReadyChunks<St> {
@@ -0,0 +1,63 @@
+use alloc::vec::Vec;
+use core::pin::Pin;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`reverse`](super::StreamExt::reverse) method.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Reverse<St: Stream> {
+        #[pin]
+        stream: St,
+        // Buffered in source order, so items can be popped off the end.
+        items: Vec<St::Item>,
+        done: bool,
+    }
+}
+
+impl<St: Stream> Reverse<St> {
+    pub(super) fn new(stream: St) -> Self {
+        Self { stream, items: Vec::new(), done: false }
+    }
+}
+
+impl<St: Stream> Stream for Reverse<St> {
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if !*this.done {
+            loop {
+                match ready!(this.stream.as_mut().poll_next(cx)) {
+                    Some(item) => this.items.push(item),
+                    None => {
+                        *this.done = true;
+                        break;
+                    }
+                }
+            }
+        }
+        Poll::Ready(this.items.pop())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (self.items.len(), Some(self.items.len()))
+        } else {
+            let (lower, upper) = self.stream.size_hint();
+            (
+                lower.saturating_add(self.items.len()),
+                upper.and_then(|x| x.checked_add(self.items.len())),
+            )
+        }
+    }
+}
+
+impl<St: Stream> FusedStream for Reverse<St> {
+    fn is_terminated(&self) -> bool {
+        self.done && self.items.is_empty()
+    }
+}
@@ -0,0 +1,132 @@
+use crate::stream::Fuse;
+use crate::time::Timer;
+use core::fmt;
+use core::pin::Pin;
+use core::time::Duration;
+use futures_core::future::Future;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`sample`](super::StreamExt::sample) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Sample<St, T>
+        where St: Stream, T: Timer,
+    {
+        #[pin]
+        stream: Fuse<St>,
+        #[pin]
+        tick: Option<T::Sleep>,
+        latest: Option<St::Item>,
+        timer: T,
+        interval: Duration,
+    }
+}
+
+impl<St, T> fmt::Debug for Sample<St, T>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+    T: Timer + fmt::Debug,
+    T::Sleep: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sample")
+            .field("stream", &self.stream)
+            .field("tick", &self.tick)
+            .field("latest", &self.latest)
+            .field("timer", &self.timer)
+            .field("interval", &self.interval)
+            .finish()
+    }
+}
+
+impl<St, T> Sample<St, T>
+where
+    St: Stream,
+    T: Timer,
+{
+    pub(super) fn new(stream: St, interval: Duration, timer: T) -> Self {
+        Self { stream: super::Fuse::new(stream), tick: None, latest: None, timer, interval }
+    }
+
+    delegate_access_inner!(stream, St, (.));
+}
+
+impl<St, T> Stream for Sample<St, T>
+where
+    St: Stream,
+    T: Timer,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            // Coalesce everything the source has produced since the last
+            // tick down to the most recent item.
+            while !this.stream.is_done() {
+                match this.stream.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(item)) => *this.latest = Some(item),
+                    Poll::Ready(None) | Poll::Pending => break,
+                }
+            }
+
+            // Once the source ends the last unsampled item is flushed
+            // without waiting for its tick, and the ticking stops.
+            if this.stream.is_done() {
+                this.tick.set(None);
+                return Poll::Ready(this.latest.take());
+            }
+
+            // The first tick starts at the first poll; afterwards the
+            // interval restarts on every tick, whether or not it emitted.
+            if this.tick.is_none() {
+                this.tick.set(Some(this.timer.sleep(*this.interval)));
+            }
+
+            match this.tick.as_mut().as_pin_mut().expect("tick set above").poll(cx) {
+                Poll::Ready(()) => {
+                    this.tick.set(Some(this.timer.sleep(*this.interval)));
+                    if this.latest.is_some() {
+                        return Poll::Ready(this.latest.take());
+                    }
+                    // An empty tick emits nothing; wait for the next one.
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let latest_len = if self.latest.is_some() { 1 } else { 0 };
+        let (_, upper) = self.stream.size_hint();
+        (0, upper.and_then(|x| x.checked_add(latest_len)))
+    }
+}
+
+impl<St, T> FusedStream for Sample<St, T>
+where
+    St: Stream,
+    T: Timer,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_done() && self.latest.is_none()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, T, Item> Sink<Item> for Sample<S, T>
+where
+    S: Stream + Sink<Item>,
+    T: Timer,
+{
+    type Error = S::Error;
+
+    delegate_sink!(stream, Item);
+}
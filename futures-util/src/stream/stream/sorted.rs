@@ -0,0 +1,169 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::pin::Pin;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`sorted`](super::StreamExt::sorted) method.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Sorted<St: Stream> {
+        #[pin]
+        stream: St,
+        // Buffered in reverse order once sorted, so items can be popped off
+        // the end.
+        items: Vec<St::Item>,
+        done: bool,
+    }
+}
+
+impl<St> Sorted<St>
+where
+    St: Stream,
+    St::Item: Ord,
+{
+    pub(super) fn new(stream: St) -> Self {
+        Self { stream, items: Vec::new(), done: false }
+    }
+}
+
+impl<St> Stream for Sorted<St>
+where
+    St: Stream,
+    St::Item: Ord,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if !*this.done {
+            loop {
+                match ready!(this.stream.as_mut().poll_next(cx)) {
+                    Some(item) => this.items.push(item),
+                    None => {
+                        this.items.sort();
+                        this.items.reverse();
+                        *this.done = true;
+                        break;
+                    }
+                }
+            }
+        }
+        Poll::Ready(this.items.pop())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (self.items.len(), Some(self.items.len()))
+        } else {
+            let (lower, upper) = self.stream.size_hint();
+            (
+                lower.saturating_add(self.items.len()),
+                upper.and_then(|x| x.checked_add(self.items.len())),
+            )
+        }
+    }
+}
+
+impl<St> FusedStream for Sorted<St>
+where
+    St: Stream,
+    St::Item: Ord,
+{
+    fn is_terminated(&self) -> bool {
+        self.done && self.items.is_empty()
+    }
+}
+
+pin_project! {
+    /// Stream for the [`sorted_by`](super::StreamExt::sorted_by) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct SortedBy<St: Stream, F> {
+        #[pin]
+        stream: St,
+        f: F,
+        // Buffered in reverse order once sorted, so items can be popped off
+        // the end.
+        items: Vec<St::Item>,
+        done: bool,
+    }
+}
+
+impl<St, F> fmt::Debug for SortedBy<St, F>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SortedBy")
+            .field("stream", &self.stream)
+            .field("items", &self.items)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<St, F> SortedBy<St, F>
+where
+    St: Stream,
+    F: FnMut(&St::Item, &St::Item) -> Ordering,
+{
+    pub(super) fn new(stream: St, f: F) -> Self {
+        Self { stream, f, items: Vec::new(), done: false }
+    }
+}
+
+impl<St, F> Stream for SortedBy<St, F>
+where
+    St: Stream,
+    F: FnMut(&St::Item, &St::Item) -> Ordering,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if !*this.done {
+            loop {
+                match ready!(this.stream.as_mut().poll_next(cx)) {
+                    Some(item) => this.items.push(item),
+                    None => {
+                        let f = &mut *this.f;
+                        // `sort_by` is stable, so equal items keep their
+                        // arrival order.
+                        this.items.sort_by(|a, b| f(a, b));
+                        this.items.reverse();
+                        *this.done = true;
+                        break;
+                    }
+                }
+            }
+        }
+        Poll::Ready(this.items.pop())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (self.items.len(), Some(self.items.len()))
+        } else {
+            let (lower, upper) = self.stream.size_hint();
+            (
+                lower.saturating_add(self.items.len()),
+                upper.and_then(|x| x.checked_add(self.items.len())),
+            )
+        }
+    }
+}
+
+impl<St, F> FusedStream for SortedBy<St, F>
+where
+    St: Stream,
+    F: FnMut(&St::Item, &St::Item) -> Ordering,
+{
+    fn is_terminated(&self) -> bool {
+        self.done && self.items.is_empty()
+    }
+}
@@ -0,0 +1,147 @@
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt;
+use core::pin::Pin;
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll, Waker};
+use std::sync::Mutex as StdMutex;
+
+/// One of the output streams of the
+/// [`split_round_robin`](super::StreamExt::split_round_robin) method.
+#[must_use = "streams do nothing unless polled"]
+pub struct RoundRobin<St: Stream> {
+    shared: Arc<StdMutex<Inner<St>>>,
+    index: usize,
+}
+
+struct Inner<St: Stream> {
+    stream: St,
+    done: bool,
+    // Index of the output stream that receives the next item.
+    turn: usize,
+    // At most one item is buffered per output stream; a full slot for the
+    // stream whose turn it is blocks the others, providing backpressure when
+    // one consumer lags.
+    slots: Vec<Option<St::Item>>,
+    wakers: Vec<Option<Waker>>,
+    alive: Vec<bool>,
+}
+
+impl<St: Stream> Inner<St> {
+    /// Advances the turn to the next output stream that has not been dropped.
+    fn advance_turn(&mut self) {
+        if !self.alive.iter().any(|alive| *alive) {
+            return;
+        }
+        loop {
+            self.turn = (self.turn + 1) % self.alive.len();
+            if self.alive[self.turn] {
+                break;
+            }
+        }
+    }
+
+    fn wake_all(&mut self) {
+        for waker in &mut self.wakers {
+            if let Some(waker) = waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+pub(super) fn split_round_robin<St: Stream>(stream: St, n: usize) -> Vec<RoundRobin<St>> {
+    let shared = Arc::new(StdMutex::new(Inner {
+        stream,
+        done: false,
+        turn: 0,
+        slots: (0..n).map(|_| None).collect(),
+        wakers: (0..n).map(|_| None).collect(),
+        alive: (0..n).map(|_| true).collect(),
+    }));
+    (0..n).map(|index| RoundRobin { shared: shared.clone(), index }).collect()
+}
+
+impl<St: Stream> fmt::Debug for RoundRobin<St> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RoundRobin").field("index", &self.index).finish()
+    }
+}
+
+impl<St: Stream + Unpin> Stream for RoundRobin<St> {
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let mut inner = this.shared.lock().unwrap();
+
+        // A previous poll by a sibling already set an item aside for us.
+        if let Some(item) = inner.slots[this.index].take() {
+            // Draining the slot may unblock siblings waiting for their turn.
+            inner.wake_all();
+            return Poll::Ready(Some(item));
+        }
+
+        loop {
+            if inner.done {
+                return Poll::Ready(None);
+            }
+
+            let turn = inner.turn;
+            if turn != this.index && inner.slots[turn].is_some() {
+                // The stream whose turn it is hasn't consumed its item yet;
+                // pulling more from the source would buffer without bound.
+                inner.wakers[this.index] = Some(cx.waker().clone());
+                return Poll::Pending;
+            }
+
+            match Pin::new(&mut inner.stream).poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    inner.advance_turn();
+                    if turn == this.index {
+                        // Polling the source above clobbered whatever waker a
+                        // parked sibling had registered with it; hand the turn
+                        // off explicitly or the sibling is never woken.
+                        let next = inner.turn;
+                        if let Some(waker) = inner.wakers[next].take() {
+                            waker.wake();
+                        }
+                        return Poll::Ready(Some(item));
+                    }
+                    // The item belongs to a sibling; set it aside and keep
+                    // pulling until our own turn comes up.
+                    inner.slots[turn] = Some(item);
+                    if let Some(waker) = inner.wakers[turn].take() {
+                        waker.wake();
+                    }
+                }
+                Poll::Ready(None) => {
+                    inner.done = true;
+                    inner.wake_all();
+                    return Poll::Ready(None);
+                }
+                Poll::Pending => {
+                    inner.wakers[this.index] = Some(cx.waker().clone());
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+}
+
+impl<St: Stream> Drop for RoundRobin<St> {
+    fn drop(&mut self) {
+        let mut inner = match self.shared.lock() {
+            Ok(inner) => inner,
+            Err(_) => return,
+        };
+        inner.alive[self.index] = false;
+        // An item already set aside for this stream is discarded; future
+        // items are redistributed among the remaining output streams.
+        inner.slots[self.index] = None;
+        if inner.turn == self.index {
+            inner.advance_turn();
+        }
+        inner.wake_all();
+    }
+}
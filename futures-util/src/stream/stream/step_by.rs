@@ -0,0 +1,79 @@
+use core::pin::Pin;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`step_by`](super::StreamExt::step_by) method.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct StepBy<St> {
+        #[pin]
+        stream: St,
+        step: usize,
+        to_skip: usize,
+    }
+}
+
+impl<St: Stream> StepBy<St> {
+    pub(super) fn new(stream: St, step: usize) -> Self {
+        assert!(step != 0);
+
+        Self { stream, step, to_skip: 0 }
+    }
+
+    delegate_access_inner!(stream, St, ());
+}
+
+impl<St: Stream> Stream for StepBy<St> {
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<St::Item>> {
+        let mut this = self.project();
+
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => {
+                    // Skipped items are actively pulled from the underlying
+                    // stream and discarded, preserving their side effects.
+                    if *this.to_skip == 0 {
+                        *this.to_skip = *this.step - 1;
+                        return Poll::Ready(Some(item));
+                    }
+                    *this.to_skip -= 1;
+                }
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint = |n: usize| {
+            let after_skip = n.saturating_sub(self.to_skip);
+            // One item per started window of `step`.
+            after_skip / self.step + usize::from(after_skip % self.step != 0)
+        };
+        let (lower, upper) = self.stream.size_hint();
+        (hint(lower), upper.map(hint))
+    }
+}
+
+impl<St: FusedStream> FusedStream for StepBy<St> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, Item> Sink<Item> for StepBy<S>
+where
+    S: Stream + Sink<Item>,
+{
+    type Error = S::Error;
+
+    delegate_sink!(stream, Item);
+}
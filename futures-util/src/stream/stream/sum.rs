@@ -0,0 +1,77 @@
+use core::fmt;
+use core::iter;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the [`sum`](super::StreamExt::sum) method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Sum<St, S> {
+        #[pin]
+        stream: St,
+        acc: Option<S>,
+    }
+}
+
+impl<St, S> fmt::Debug for Sum<St, S>
+where
+    St: fmt::Debug,
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sum").field("stream", &self.stream).field("acc", &self.acc).finish()
+    }
+}
+
+impl<St, S> Sum<St, S>
+where
+    St: Stream,
+    S: iter::Sum<St::Item> + iter::Sum<S>,
+{
+    pub(super) fn new(stream: St) -> Self {
+        Self { stream, acc: None }
+    }
+}
+
+impl<St, S> FusedFuture for Sum<St, S>
+where
+    St: FusedStream,
+    S: iter::Sum<St::Item> + iter::Sum<S>,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, S> Future for Sum<St, S>
+where
+    St: Stream,
+    S: iter::Sum<St::Item> + iter::Sum<S>,
+{
+    type Output = S;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => {
+                    let item = S::sum(iter::once(item));
+                    *this.acc = Some(match this.acc.take() {
+                        Some(acc) => S::sum(iter::once(acc).chain(iter::once(item))),
+                        None => item,
+                    });
+                }
+                None => {
+                    // An empty stream sums to the additive identity.
+                    return Poll::Ready(
+                        this.acc.take().unwrap_or_else(|| S::sum(iter::empty::<St::Item>())),
+                    );
+                }
+            }
+        }
+    }
+}
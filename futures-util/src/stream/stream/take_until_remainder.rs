@@ -0,0 +1,151 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`take_until_remainder`](super::StreamExt::take_until_remainder) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct TakeUntilRemainder<St: Stream, Fut: Future> {
+        #[pin]
+        stream: St,
+        // Contains the signal future until it resolves, and None afterwards.
+        #[pin]
+        fut: Option<Fut>,
+        // Contains the signal's return value once it is resolved.
+        fut_result: Option<Fut::Output>,
+        // Whether the signal has fired and the stream has stopped.
+        stopped: bool,
+    }
+}
+
+impl<St, Fut> fmt::Debug for TakeUntilRemainder<St, Fut>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+    Fut: Future + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TakeUntilRemainder")
+            .field("stream", &self.stream)
+            .field("fut", &self.fut)
+            .field("stopped", &self.stopped)
+            .finish()
+    }
+}
+
+impl<St, Fut> TakeUntilRemainder<St, Fut>
+where
+    St: Stream,
+    Fut: Future,
+{
+    pub(super) fn new(stream: St, fut: Fut) -> Self {
+        Self { stream, fut: Some(fut), fut_result: None, stopped: false }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// Once the signal future is resolved, this method can be used
+    /// to extract the value returned by the signal future.
+    ///
+    /// This method will return `None` if the signal isn't resolved yet,
+    /// or if the result was already taken out.
+    pub fn take_result(&mut self) -> Option<Fut::Output> {
+        self.fut_result.take()
+    }
+
+    /// Whether the stream was stopped by the signal future resolving.
+    pub fn is_stopped(&self) -> bool {
+        self.stopped
+    }
+
+    /// Consumes this combinator, returning the underlying stream so that it
+    /// can continue to be consumed past the signal.
+    ///
+    /// Items the underlying stream produced before the signal fired have
+    /// already been yielded by the combinator; the returned stream picks up
+    /// exactly where they left off. Returns `None` if the signal hasn't
+    /// fired yet, since at that point the combinator is still responsible
+    /// for the stream.
+    pub fn into_inner(self) -> Option<St> {
+        if self.stopped {
+            Some(self.stream)
+        } else {
+            None
+        }
+    }
+}
+
+impl<St, Fut> Stream for TakeUntilRemainder<St, Fut>
+where
+    St: Stream,
+    Fut: Future,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<St::Item>> {
+        let mut this = self.project();
+
+        if *this.stopped || this.fut.is_none() {
+            // Either the signal fired or the inner stream already ended.
+            return Poll::Ready(None);
+        }
+
+        // The signal is polled first, so if it fires while an item is also
+        // ready, the signal wins and the item stays in the inner stream for
+        // whoever recovers it through `into_inner`.
+        if let Some(f) = this.fut.as_mut().as_pin_mut() {
+            if let Poll::Ready(result) = f.poll(cx) {
+                this.fut.set(None);
+                *this.fut_result = Some(result);
+                *this.stopped = true;
+                return Poll::Ready(None);
+            }
+        }
+
+        let item = ready!(this.stream.poll_next(cx));
+        if item.is_none() {
+            this.fut.set(None);
+        }
+        Poll::Ready(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.stopped || self.fut.is_none() {
+            return (0, Some(0));
+        }
+
+        self.stream.size_hint()
+    }
+}
+
+impl<St, Fut> FusedStream for TakeUntilRemainder<St, Fut>
+where
+    St: Stream,
+    Fut: Future,
+{
+    fn is_terminated(&self) -> bool {
+        self.stopped || self.fut.is_none()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, Fut, Item> Sink<Item> for TakeUntilRemainder<S, Fut>
+where
+    S: Stream + Sink<Item>,
+    Fut: Future,
+{
+    type Error = S::Error;
+
+    delegate_sink!(stream, Item);
+}
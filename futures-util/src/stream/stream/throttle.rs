@@ -0,0 +1,146 @@
+use crate::stream::Fuse;
+use core::fmt;
+use core::pin::Pin;
+use core::time::Duration;
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`throttle`](super::StreamExt::throttle) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Throttle<St, Fut, F>
+        where St: Stream,
+    {
+        #[pin]
+        stream: Fuse<St>,
+        #[pin]
+        delay: Option<Fut>,
+        pending: Option<St::Item>,
+        duration: Duration,
+        f: F,
+    }
+}
+
+impl<St, Fut, F> fmt::Debug for Throttle<St, Fut, F>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+    Fut: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Throttle")
+            .field("stream", &self.stream)
+            .field("delay", &self.delay)
+            .field("pending", &self.pending)
+            .field("duration", &self.duration)
+            .finish()
+    }
+}
+
+impl<St, Fut, F> Throttle<St, Fut, F>
+where
+    St: Stream,
+    F: FnMut(Duration) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    pub(super) fn new(stream: St, duration: Duration, f: F) -> Self {
+        Self { stream: super::Fuse::new(stream), delay: None, pending: None, duration, f }
+    }
+
+    delegate_access_inner!(stream, St, (.));
+}
+
+impl<St, Fut, F> Stream for Throttle<St, Fut, F>
+where
+    St: Stream,
+    F: FnMut(Duration) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            // While the window is open any buffered or incoming item is
+            // forwarded right away, closing the window again.
+            if this.delay.is_none() {
+                if let Some(item) = this.pending.take() {
+                    this.delay.set(Some((this.f)(*this.duration)));
+                    return Poll::Ready(Some(item));
+                }
+
+                if this.stream.is_done() {
+                    return Poll::Ready(None);
+                }
+
+                return match ready!(this.stream.as_mut().poll_next(cx)) {
+                    Some(item) => {
+                        this.delay.set(Some((this.f)(*this.duration)));
+                        Poll::Ready(Some(item))
+                    }
+                    None => Poll::Ready(None),
+                };
+            }
+
+            // The window is closed: coalesce everything that is already
+            // available down to the most recent item.
+            while !this.stream.is_done() {
+                match this.stream.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(item)) => *this.pending = Some(item),
+                    Poll::Ready(None) | Poll::Pending => break,
+                }
+            }
+
+            // Once the underlying stream has ended the trailing item is
+            // flushed without waiting for the window to reopen, so it is
+            // never lost.
+            if this.stream.is_done() {
+                this.delay.set(None);
+                return Poll::Ready(this.pending.take());
+            }
+
+            match this.delay.as_mut().as_pin_mut().expect("window checked above").poll(cx) {
+                Poll::Ready(()) => this.delay.set(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending_len = if self.pending.is_some() { 1 } else { 0 };
+        let (_, upper) = self.stream.size_hint();
+        let upper = match upper {
+            Some(x) => x.checked_add(pending_len),
+            None => None,
+        };
+        (pending_len, upper)
+    }
+}
+
+impl<St, Fut, F> FusedStream for Throttle<St, Fut, F>
+where
+    St: Stream,
+    F: FnMut(Duration) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_done() && self.pending.is_none()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, Fut, F, Item> Sink<Item> for Throttle<S, Fut, F>
+where
+    S: Stream + Sink<Item>,
+{
+    type Error = S::Error;
+
+    delegate_sink!(stream, Item);
+}
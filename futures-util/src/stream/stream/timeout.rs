@@ -0,0 +1,124 @@
+use crate::future::Elapsed;
+use crate::stream::Fuse;
+use crate::time::Timer;
+use core::fmt;
+use core::pin::Pin;
+use core::time::Duration;
+use futures_core::future::Future;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`timeout`](super::StreamExt::timeout) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Timeout<St, T>
+        where St: Stream, T: Timer,
+    {
+        #[pin]
+        stream: Fuse<St>,
+        #[pin]
+        sleep: Option<T::Sleep>,
+        timer: T,
+        duration: Duration,
+    }
+}
+
+impl<St, T> fmt::Debug for Timeout<St, T>
+where
+    St: Stream + fmt::Debug,
+    T: Timer + fmt::Debug,
+    T::Sleep: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Timeout")
+            .field("stream", &self.stream)
+            .field("sleep", &self.sleep)
+            .field("timer", &self.timer)
+            .field("duration", &self.duration)
+            .finish()
+    }
+}
+
+impl<St, T> Timeout<St, T>
+where
+    St: Stream,
+    T: Timer,
+{
+    pub(super) fn new(stream: St, duration: Duration, timer: T) -> Self {
+        Self { stream: super::Fuse::new(stream), sleep: None, timer, duration }
+    }
+
+    delegate_access_inner!(stream, St, (.));
+}
+
+impl<St, T> Stream for Timeout<St, T>
+where
+    St: Stream,
+    T: Timer,
+{
+    type Item = Result<St::Item, Elapsed>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        match this.stream.as_mut().poll_next(cx) {
+            // An item restarts the deadline; the end of the stream cancels
+            // it.
+            Poll::Ready(Some(item)) => {
+                this.sleep.set(Some(this.timer.sleep(*this.duration)));
+                return Poll::Ready(Some(Ok(item)));
+            }
+            Poll::Ready(None) => {
+                this.sleep.set(None);
+                return Poll::Ready(None);
+            }
+            Poll::Pending => {}
+        }
+
+        // The deadline for the first item starts at the first poll.
+        if this.sleep.is_none() {
+            this.sleep.set(Some(this.timer.sleep(*this.duration)));
+        }
+
+        match this.sleep.as_mut().as_pin_mut().expect("sleep set above").poll(cx) {
+            Poll::Ready(()) => {
+                // The deadline elapsed without an item: report it and start
+                // a fresh one, keeping the stream itself alive.
+                this.sleep.set(Some(this.timer.sleep(*this.duration)));
+                Poll::Ready(Some(Err(Elapsed)))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Any number of timeout errors may be interleaved with the items.
+        let (lower, _) = self.stream.size_hint();
+        (lower, None)
+    }
+}
+
+impl<St, T> FusedStream for Timeout<St, T>
+where
+    St: Stream,
+    T: Timer,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_done()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, T, Item> Sink<Item> for Timeout<S, T>
+where
+    S: Stream + Sink<Item>,
+    T: Timer,
+{
+    type Error = S::Error;
+
+    delegate_sink!(stream, Item);
+}
@@ -0,0 +1,169 @@
+use core::fmt;
+use core::hash::Hash;
+use core::pin::Pin;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+use std::collections::HashSet;
+
+pin_project! {
+    /// Stream for the [`unique`](super::StreamExt::unique) method.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Unique<St: Stream> {
+        #[pin]
+        stream: St,
+        seen: HashSet<St::Item>,
+    }
+}
+
+impl<St> Unique<St>
+where
+    St: Stream,
+    St::Item: Hash + Eq + Clone,
+{
+    pub(super) fn new(stream: St) -> Self {
+        Self { stream, seen: HashSet::new() }
+    }
+
+    delegate_access_inner!(stream, St, ());
+}
+
+impl<St> Stream for Unique<St>
+where
+    St: Stream,
+    St::Item: Hash + Eq + Clone,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => {
+                    if this.seen.insert(item.clone()) {
+                        return Poll::Ready(Some(item));
+                    }
+                }
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // All remaining items could be duplicates.
+        (0, self.stream.size_hint().1)
+    }
+}
+
+impl<St> FusedStream for Unique<St>
+where
+    St: FusedStream,
+    St::Item: Hash + Eq + Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<St, Item> Sink<Item> for Unique<St>
+where
+    St: Stream + Sink<Item>,
+    St::Item: Hash + Eq + Clone,
+{
+    type Error = St::Error;
+
+    delegate_sink!(stream, Item);
+}
+
+pin_project! {
+    /// Stream for the [`unique_by`](super::StreamExt::unique_by) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct UniqueBy<St, F, K> {
+        #[pin]
+        stream: St,
+        f: F,
+        seen: HashSet<K>,
+    }
+}
+
+impl<St, F, K> fmt::Debug for UniqueBy<St, F, K>
+where
+    St: fmt::Debug,
+    K: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UniqueBy").field("stream", &self.stream).field("seen", &self.seen).finish()
+    }
+}
+
+impl<St, F, K> UniqueBy<St, F, K>
+where
+    St: Stream,
+    F: FnMut(&St::Item) -> K,
+    K: Hash + Eq,
+{
+    pub(super) fn new(stream: St, f: F) -> Self {
+        Self { stream, f, seen: HashSet::new() }
+    }
+
+    delegate_access_inner!(stream, St, ());
+}
+
+impl<St, F, K> Stream for UniqueBy<St, F, K>
+where
+    St: Stream,
+    F: FnMut(&St::Item) -> K,
+    K: Hash + Eq,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => {
+                    let key = (this.f)(&item);
+                    if this.seen.insert(key) {
+                        return Poll::Ready(Some(item));
+                    }
+                }
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // All remaining items could be duplicates.
+        (0, self.stream.size_hint().1)
+    }
+}
+
+impl<St, F, K> FusedStream for UniqueBy<St, F, K>
+where
+    St: FusedStream,
+    F: FnMut(&St::Item) -> K,
+    K: Hash + Eq,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<St, F, K, Item> Sink<Item> for UniqueBy<St, F, K>
+where
+    St: Stream + Sink<Item>,
+    F: FnMut(&St::Item) -> K,
+    K: Hash + Eq,
+{
+    type Error = St::Error;
+
+    delegate_sink!(stream, Item);
+}
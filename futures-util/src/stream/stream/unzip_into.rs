@@ -0,0 +1,97 @@
+use crate::stream::Fuse;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::ready;
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the [`unzip_into`](super::StreamExt::unzip_into) method.
+    #[project = UnzipIntoProj]
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct UnzipInto<St, SiA, SiB, A, B> {
+        #[pin]
+        sink_a: Option<SiA>,
+        #[pin]
+        sink_b: Option<SiB>,
+        #[pin]
+        stream: Fuse<St>,
+        buffered_a: Option<A>,
+        buffered_b: Option<B>,
+    }
+}
+
+impl<St, SiA, SiB, A, B> UnzipInto<St, SiA, SiB, A, B> {
+    pub(super) fn new(stream: St, sink_a: SiA, sink_b: SiB) -> Self {
+        Self {
+            sink_a: Some(sink_a),
+            sink_b: Some(sink_b),
+            stream: Fuse::new(stream),
+            buffered_a: None,
+            buffered_b: None,
+        }
+    }
+}
+
+impl<St, SiA, SiB, A, B, E> FusedFuture for UnzipInto<St, SiA, SiB, A, B>
+where
+    St: Stream<Item = (A, B)>,
+    SiA: Sink<A, Error = E>,
+    SiB: Sink<B, Error = E>,
+{
+    fn is_terminated(&self) -> bool {
+        self.sink_a.is_none()
+    }
+}
+
+impl<St, SiA, SiB, A, B, E> Future for UnzipInto<St, SiA, SiB, A, B>
+where
+    St: Stream<Item = (A, B)>,
+    SiA: Sink<A, Error = E>,
+    SiB: Sink<B, Error = E>,
+{
+    type Output = Result<(), E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let UnzipIntoProj { mut sink_a, mut sink_b, mut stream, buffered_a, buffered_b } =
+            self.project();
+        let mut si_a = sink_a.as_mut().as_pin_mut().expect("polled `UnzipInto` after completion");
+        let mut si_b = sink_b.as_mut().as_pin_mut().expect("polled `UnzipInto` after completion");
+
+        loop {
+            // Both halves of a buffered pair must reach their sinks before
+            // another item is pulled from the stream, so the stream pauses
+            // while either sink exerts backpressure.
+            if buffered_a.is_some() {
+                ready!(si_a.as_mut().poll_ready(cx))?;
+                si_a.as_mut().start_send(buffered_a.take().unwrap())?;
+            }
+            if buffered_b.is_some() {
+                ready!(si_b.as_mut().poll_ready(cx))?;
+                si_b.as_mut().start_send(buffered_b.take().unwrap())?;
+            }
+
+            match stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some((a, b))) => {
+                    *buffered_a = Some(a);
+                    *buffered_b = Some(b);
+                }
+                Poll::Ready(None) => {
+                    ready!(si_a.as_mut().poll_close(cx))?;
+                    ready!(si_b.as_mut().poll_close(cx))?;
+                    sink_a.set(None);
+                    sink_b.set(None);
+                    return Poll::Ready(Ok(()));
+                }
+                Poll::Pending => {
+                    ready!(si_a.as_mut().poll_flush(cx))?;
+                    ready!(si_b.as_mut().poll_flush(cx))?;
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use crate::stream::Fuse;
+use core::mem;
+use core::pin::Pin;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+/// Position of an item yielded by
+/// [`with_position`](super::StreamExt::with_position).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Position {
+    /// The first of several items.
+    First,
+    /// Neither the first nor the last item.
+    Middle,
+    /// The last of several items.
+    Last,
+    /// The only item of the stream.
+    Only,
+}
+
+pin_project! {
+    /// Stream for the [`with_position`](super::StreamExt::with_position) method.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct WithPosition<St>
+        where St: Stream,
+    {
+        #[pin]
+        stream: Fuse<St>,
+        // One item of lookahead, needed to tell the last item apart.
+        peeked: Option<St::Item>,
+        yielded_first: bool,
+    }
+}
+
+impl<St: Stream> WithPosition<St> {
+    pub(super) fn new(stream: St) -> Self {
+        Self { stream: super::Fuse::new(stream), peeked: None, yielded_first: false }
+    }
+
+    delegate_access_inner!(stream, St, (.));
+}
+
+impl<St: Stream> Stream for WithPosition<St> {
+    type Item = (Position, St::Item);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        // Fill the lookahead slot first.
+        if this.peeked.is_none() {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => *this.peeked = Some(item),
+                None => return Poll::Ready(None),
+            }
+        }
+
+        // Fetch the successor to learn whether the buffered item is last.
+        if this.stream.is_done() {
+            let item = this.peeked.take().expect("lookahead filled above");
+            let position = if *this.yielded_first { Position::Last } else { Position::Only };
+            return Poll::Ready(Some((position, item)));
+        }
+
+        match ready!(this.stream.as_mut().poll_next(cx)) {
+            Some(next) => {
+                let item = this.peeked.replace(next).expect("lookahead filled above");
+                let position = if mem::replace(this.yielded_first, true) {
+                    Position::Middle
+                } else {
+                    Position::First
+                };
+                Poll::Ready(Some((position, item)))
+            }
+            None => {
+                let item = this.peeked.take().expect("lookahead filled above");
+                let position = if *this.yielded_first { Position::Last } else { Position::Only };
+                Poll::Ready(Some((position, item)))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let peeked_len = if self.peeked.is_some() { 1 } else { 0 };
+        let (lower, upper) = self.stream.size_hint();
+        (lower.saturating_add(peeked_len), upper.and_then(|x| x.checked_add(peeked_len)))
+    }
+}
+
+impl<St: Stream> FusedStream for WithPosition<St> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_done() && self.peeked.is_none()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, Item> Sink<Item> for WithPosition<S>
+where
+    S: Stream + Sink<Item>,
+{
+    type Error = S::Error;
+
+    delegate_sink!(stream, Item);
+}
@@ -14,6 +14,8 @@ use crate::stream::{assert_stream, Inspect, Map};
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 use core::pin::Pin;
+#[cfg(feature = "alloc")]
+use core::time::Duration;
 use futures_core::{
     future::{Future, TryFuture},
     stream::TryStream,
@@ -102,6 +104,10 @@ mod try_collect;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::try_collect::TryCollect;
 
+mod try_collect_into;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::try_collect_into::TryCollectInto;
+
 mod try_concat;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::try_concat::TryConcat;
@@ -112,6 +118,18 @@ mod try_chunks;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::try_chunks::{TryChunks, TryChunksError};
 
+#[cfg(feature = "alloc")]
+mod try_ready_chunks;
+#[cfg(feature = "alloc")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::try_ready_chunks::{TryReadyChunks, TryReadyChunksError};
+
+#[cfg(feature = "alloc")]
+mod try_chunks_timeout;
+#[cfg(feature = "alloc")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::try_chunks_timeout::{TryChunksTimeout, TryChunksTimeoutError};
+
 mod try_unfold;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::try_unfold::{try_unfold, TryUnfold};
@@ -124,6 +142,14 @@ mod try_take_while;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::try_take_while::TryTakeWhile;
 
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+mod try_flatten_unordered;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::try_flatten_unordered::TryFlattenUnordered;
+
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
 mod try_buffer_unordered;
@@ -501,6 +527,44 @@ pub trait TryStreamExt: TryStream {
         assert_future::<Result<C, Self::Error>, _>(TryCollect::new(self))
     }
 
+    /// Attempt to collect the successful results of this stream into a
+    /// provided collection, extending whatever it already contains.
+    ///
+    /// This behaves like [`try_collect`](TryStreamExt::try_collect), but
+    /// instead of allocating a fresh collection it appends to `collection`,
+    /// allowing capacity or existing contents to be reused across runs.
+    ///
+    /// If an error happens, the error is returned together with the
+    /// collection in the state it had reached at that point: existing
+    /// contents plus every item collected before the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, TryStreamExt};
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, i32>(1), Ok(2), Ok(3)]);
+    ///
+    /// let output = stream.try_collect_into(vec![0]).await;
+    /// assert_eq!(output, Ok(vec![0, 1, 2, 3]));
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, i32>(1), Err(2), Ok(3)]);
+    ///
+    /// let output = stream.try_collect_into(vec![0]).await;
+    /// assert_eq!(output, Err((vec![0, 1], 2)));
+    /// # })
+    /// ```
+    fn try_collect_into<C: Default + Extend<Self::Ok>>(
+        self,
+        collection: C,
+    ) -> TryCollectInto<Self, C>
+    where
+        Self: Sized,
+    {
+        assert_future::<Result<C, (C, Self::Error)>, _>(TryCollectInto::new(self, collection))
+    }
+
     /// An adaptor for chunking up successful items of the stream inside a vector.
     ///
     /// This combinator will attempt to pull successful items from this stream and buffer
@@ -548,6 +612,114 @@ pub trait TryStreamExt: TryStream {
         )
     }
 
+    /// An adaptor for chunking up successful, ready items of the stream inside a vector.
+    ///
+    /// This combinator will attempt to pull successful items from this stream and buffer
+    /// them into a local vector. At most `capacity` items will get buffered
+    /// before they're yielded from the returned stream. If the underlying stream
+    /// returns `Poll::Pending`, and the collected chunk is not empty, it will
+    /// be immediately returned.
+    ///
+    /// Note that the vectors returned from this iterator may not always have
+    /// `capacity` elements. If the underlying stream ended and only a partial
+    /// vector was created, it'll be returned. Additionally if an error happens
+    /// from the underlying stream then the currently buffered items will be
+    /// carried in the error payload.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// This function is similar to
+    /// [`StreamExt::ready_chunks`](crate::stream::StreamExt::ready_chunks) but exits
+    /// early if an error occurs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, TryReadyChunksError, TryStreamExt};
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, i32>(1), Ok(2), Ok(3), Err(4), Ok(5), Ok(6)]);
+    /// let mut stream = stream.try_ready_chunks(2);
+    ///
+    /// assert_eq!(stream.try_next().await, Ok(Some(vec![1, 2])));
+    /// assert_eq!(stream.try_next().await, Err(TryReadyChunksError(vec![3], 4)));
+    /// assert_eq!(stream.try_next().await, Ok(Some(vec![5, 6])));
+    /// # })
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `capacity` is zero.
+    #[cfg(feature = "alloc")]
+    fn try_ready_chunks(self, capacity: usize) -> TryReadyChunks<Self>
+    where
+        Self: Sized,
+    {
+        assert_stream::<Result<Vec<Self::Ok>, TryReadyChunksError<Self::Ok, Self::Error>>, _>(
+            TryReadyChunks::new(self, capacity),
+        )
+    }
+
+    /// An adaptor for chunking up successful items of the stream inside a
+    /// vector, with a time-based flush for partial chunks.
+    ///
+    /// This behaves like [`try_chunks`](TryStreamExt::try_chunks), but
+    /// additionally yields a partial chunk once `duration` has elapsed since
+    /// its first item was buffered, so batches are not held back indefinitely
+    /// when the source goes quiet. A capacity-triggered flush resets the
+    /// timer, and an empty window never yields an empty vector.
+    ///
+    /// If an error happens from the underlying stream, the currently buffered
+    /// items are carried inside the error payload, matching
+    /// [`try_chunks`](TryStreamExt::try_chunks).
+    ///
+    /// To stay runtime agnostic this combinator does not provide a timer of
+    /// its own; `f` is called to create a new delay future whenever one is
+    /// needed, so any timer implementation (e.g. `tokio::time::sleep` or
+    /// `async_io::Timer`) can be plugged in.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `capacity` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use std::time::Duration;
+    /// use futures::future;
+    /// use futures::stream::{self, TryChunksTimeoutError, TryStreamExt};
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, i32>(1), Ok(2), Ok(3), Err(4), Ok(5)]);
+    /// let mut stream =
+    ///     stream.try_chunks_timeout(2, Duration::from_millis(10), |_| future::pending());
+    ///
+    /// assert_eq!(stream.try_next().await, Ok(Some(vec![1, 2])));
+    /// assert_eq!(stream.try_next().await, Err(TryChunksTimeoutError(vec![3], 4)));
+    /// assert_eq!(stream.try_next().await, Ok(Some(vec![5])));
+    /// # })
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn try_chunks_timeout<Fut, F>(
+        self,
+        capacity: usize,
+        duration: Duration,
+        f: F,
+    ) -> TryChunksTimeout<Self, Fut, F>
+    where
+        F: FnMut(Duration) -> Fut,
+        Fut: Future<Output = ()>,
+        Self: Sized,
+    {
+        assert_stream::<Result<Vec<Self::Ok>, TryChunksTimeoutError<Self::Ok, Self::Error>>, _>(
+            TryChunksTimeout::new(self, capacity, duration, f),
+        )
+    }
+
     /// Attempt to filter the values produced by this stream according to the
     /// provided asynchronous closure.
     ///
@@ -681,6 +853,58 @@ pub trait TryStreamExt: TryStream {
         )
     }
 
+    /// Flattens a stream of streams into just one continuous stream, polling
+    /// up to `limit` of the inner streams concurrently.
+    ///
+    /// Unlike [`try_flatten`](TryStreamExt::try_flatten), the inner streams
+    /// are not exhausted one at a time: their `Ok` items are yielded in
+    /// whatever order they become ready. The first error — whether from the
+    /// outer stream or from one of the inner streams — drops the in-flight
+    /// inner streams and is surfaced immediately.
+    ///
+    /// A `limit` of `None` (or zero) places no bound on the number of inner
+    /// streams polled concurrently.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::mpsc;
+    /// use futures::stream::TryStreamExt;
+    ///
+    /// let (tx1, rx1) = mpsc::unbounded::<Result<i32, i32>>();
+    /// let (tx2, rx2) = mpsc::unbounded();
+    /// let (tx3, rx3) = mpsc::unbounded::<Result<_, i32>>();
+    ///
+    /// tx3.unbounded_send(Ok(rx1)).unwrap();
+    /// tx3.unbounded_send(Ok(rx2)).unwrap();
+    /// drop(tx3);
+    ///
+    /// // The inner streams are driven concurrently, so items from the
+    /// // second stream do not wait for the first one to finish.
+    /// tx2.unbounded_send(Ok(2)).unwrap();
+    /// drop(tx2);
+    /// tx1.unbounded_send(Ok(1)).unwrap();
+    /// drop(tx1);
+    ///
+    /// let mut items = rx3.try_flatten_unordered(None).try_collect::<Vec<_>>().await.unwrap();
+    /// items.sort_unstable();
+    /// assert_eq!(items, vec![1, 2]);
+    /// # });
+    /// ```
+    #[cfg(not(futures_no_atomic_cas))]
+    #[cfg(feature = "alloc")]
+    fn try_flatten_unordered(self, limit: impl Into<Option<usize>>) -> TryFlattenUnordered<Self>
+    where
+        Self::Ok: TryStream + Unpin,
+        <Self::Ok as TryStream>::Error: From<Self::Error>,
+        Self: Sized,
+    {
+        assert_stream::<Result<<Self::Ok as TryStream>::Ok, <Self::Ok as TryStream>::Error>, _>(
+            TryFlattenUnordered::new(self, limit.into()),
+        )
+    }
+
     /// Attempt to concatenate all items of a stream into a single
     /// extendable destination, returning a future representing the end result.
     ///
@@ -802,8 +1026,14 @@ pub trait TryStreamExt: TryStream {
     /// that matches the stream's `Error` type.
     ///
     /// This adaptor will buffer up to `n` futures and then return their
-    /// outputs in the order. If the underlying stream returns an error, it will
-    /// be immediately propagated.
+    /// outputs in the order in which they were submitted. If the underlying
+    /// stream returns an error, it will be immediately propagated.
+    ///
+    /// The adaptor is fail-fast: an error from one of the futures is yielded
+    /// at that future's position in the submission order — it never jumps
+    /// ahead of the results of earlier-submitted futures — and once it is
+    /// yielded, the futures submitted after the failed one are dropped
+    /// without being driven to completion.
     ///
     /// The limit argument is of type `Into<Option<usize>>`, and so can be
     /// provided as either `None`, `Some(10)`, or just `10`. Note: a limit of zero is
@@ -62,6 +62,13 @@ where
 
         // Attempt to pull the next value from the in_progress_queue
         match this.in_progress_queue.poll_next_unpin(cx) {
+            Poll::Ready(Some(Err(e))) => {
+                // Fail fast: the error surfaces at its position in the
+                // submission order, and the futures submitted after it are
+                // dropped rather than driven to completion.
+                *this.in_progress_queue = FuturesOrdered::new();
+                return Poll::Ready(Some(Err(e)));
+            }
             x @ Poll::Pending | x @ Poll::Ready(Some(_)) => return x,
             Poll::Ready(None) => {}
         }
@@ -0,0 +1,181 @@
+use crate::stream::{Fuse, IntoStream, StreamExt};
+
+use alloc::vec::Vec;
+use core::pin::Pin;
+use core::time::Duration;
+use core::{fmt, mem};
+use futures_core::future::Future;
+use futures_core::stream::{FusedStream, Stream, TryStream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`try_chunks_timeout`](super::TryStreamExt::try_chunks_timeout) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct TryChunksTimeout<St: TryStream, Fut, F> {
+        #[pin]
+        stream: Fuse<IntoStream<St>>,
+        #[pin]
+        delay: Option<Fut>,
+        items: Vec<St::Ok>,
+        cap: usize, // https://github.com/rust-lang/futures-rs/issues/1475
+        duration: Duration,
+        f: F,
+    }
+}
+
+impl<St, Fut, F> fmt::Debug for TryChunksTimeout<St, Fut, F>
+where
+    St: TryStream + fmt::Debug,
+    St::Ok: fmt::Debug,
+    Fut: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryChunksTimeout")
+            .field("stream", &self.stream)
+            .field("delay", &self.delay)
+            .field("items", &self.items)
+            .field("cap", &self.cap)
+            .field("duration", &self.duration)
+            .finish()
+    }
+}
+
+impl<St, Fut, F> TryChunksTimeout<St, Fut, F>
+where
+    St: TryStream,
+    F: FnMut(Duration) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    pub(super) fn new(stream: St, capacity: usize, duration: Duration, f: F) -> Self {
+        assert!(capacity > 0);
+
+        Self {
+            stream: IntoStream::new(stream).fuse(),
+            delay: None,
+            items: Vec::with_capacity(capacity),
+            cap: capacity,
+            duration,
+            f,
+        }
+    }
+
+    fn take(self: Pin<&mut Self>) -> Vec<St::Ok> {
+        let cap = self.cap;
+        mem::replace(self.project().items, Vec::with_capacity(cap))
+    }
+
+    delegate_access_inner!(stream, St, (. .));
+}
+
+impl<St, Fut, F> Stream for TryChunksTimeout<St, Fut, F>
+where
+    St: TryStream,
+    F: FnMut(Duration) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    #[allow(clippy::type_complexity)]
+    type Item = Result<Vec<St::Ok>, TryChunksTimeoutError<St::Ok, St::Error>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.as_mut().project();
+
+        // Buffer every successful item that is already available. The delay
+        // is armed when the first item of a fresh chunk arrives and cleared
+        // whenever the chunk is flushed, so a capacity-triggered flush resets
+        // it. An error flushes the buffered items inside the error payload,
+        // matching `try_chunks`.
+        while !this.stream.is_done() {
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(Ok(item))) => {
+                    if this.items.is_empty() {
+                        this.delay.set(Some((this.f)(*this.duration)));
+                    }
+                    this.items.push(item);
+                    if this.items.len() >= *this.cap {
+                        this.delay.set(None);
+                        return Poll::Ready(Some(Ok(self.take())));
+                    }
+                }
+                Poll::Ready(Some(Err(e))) => {
+                    this.delay.set(None);
+                    return Poll::Ready(Some(Err(TryChunksTimeoutError(self.take(), e))));
+                }
+                Poll::Ready(None) | Poll::Pending => break,
+            }
+        }
+
+        // The underlying stream ended: flush the partial chunk, if any.
+        if this.stream.is_done() {
+            this.delay.set(None);
+            let last = if this.items.is_empty() { None } else { Some(self.take()) };
+            return Poll::Ready(last.map(Ok));
+        }
+
+        if let Some(delay) = this.delay.as_mut().as_pin_mut() {
+            if delay.poll(cx).is_ready() {
+                this.delay.set(None);
+                debug_assert!(!this.items.is_empty());
+                return Poll::Ready(Some(Ok(self.take())));
+            }
+        }
+
+        Poll::Pending
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let chunk_len = if self.items.is_empty() { 0 } else { 1 };
+        let (lower, upper) = self.stream.size_hint();
+        let lower = lower.saturating_add(chunk_len);
+        let upper = match upper {
+            Some(x) => x.checked_add(chunk_len),
+            None => None,
+        };
+        (lower, upper)
+    }
+}
+
+impl<St, Fut, F> FusedStream for TryChunksTimeout<St, Fut, F>
+where
+    St: TryStream + FusedStream,
+    F: FnMut(Duration) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated() && self.items.is_empty()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, Fut, F, Item> Sink<Item> for TryChunksTimeout<S, Fut, F>
+where
+    S: TryStream + Sink<Item>,
+{
+    type Error = <S as Sink<Item>>::Error;
+
+    delegate_sink!(stream, Item);
+}
+
+/// Error indicating, that while chunk was collected inner stream produced an error.
+///
+/// Contains all items that were collected before an error occurred, and the stream error itself.
+#[derive(PartialEq, Eq)]
+pub struct TryChunksTimeoutError<T, E>(pub Vec<T>, pub E);
+
+impl<T, E: fmt::Debug> fmt::Debug for TryChunksTimeoutError<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.1.fmt(f)
+    }
+}
+
+impl<T, E: fmt::Display> fmt::Display for TryChunksTimeoutError<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.1.fmt(f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, E: fmt::Debug + fmt::Display> std::error::Error for TryChunksTimeoutError<T, E> {}
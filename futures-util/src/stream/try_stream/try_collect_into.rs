@@ -0,0 +1,57 @@
+use core::mem;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::ready;
+use futures_core::stream::{FusedStream, TryStream};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the [`try_collect_into`](super::TryStreamExt::try_collect_into) method.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct TryCollectInto<St, C> {
+        #[pin]
+        stream: St,
+        items: C,
+    }
+}
+
+impl<St: TryStream, C> TryCollectInto<St, C> {
+    pub(super) fn new(stream: St, items: C) -> Self {
+        Self { stream, items }
+    }
+}
+
+impl<St, C> FusedFuture for TryCollectInto<St, C>
+where
+    St: TryStream + FusedStream,
+    C: Default + Extend<St::Ok>,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, C> Future for TryCollectInto<St, C>
+where
+    St: TryStream,
+    C: Default + Extend<St::Ok>,
+{
+    type Output = Result<C, (C, St::Error)>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        Poll::Ready(loop {
+            match ready!(this.stream.as_mut().try_poll_next(cx)) {
+                Some(Ok(x)) => this.items.extend(Some(x)),
+                Some(Err(e)) => {
+                    // Hand the partially filled collection back alongside the
+                    // error so already collected items aren't lost.
+                    break Err((mem::replace(this.items, Default::default()), e));
+                }
+                None => break Ok(mem::replace(this.items, Default::default())),
+            }
+        })
+    }
+}
@@ -0,0 +1,123 @@
+use crate::stream::{Fuse, FuturesUnordered, IntoStream, StreamExt, StreamFuture};
+use core::num::NonZeroUsize;
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream, TryStream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the
+    /// [`try_flatten_unordered`](super::TryStreamExt::try_flatten_unordered) method.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct TryFlattenUnordered<St>
+        where St: TryStream,
+              St::Ok: TryStream,
+              St::Ok: Unpin,
+    {
+        #[pin]
+        stream: Fuse<IntoStream<St>>,
+        active: FuturesUnordered<StreamFuture<IntoStream<St::Ok>>>,
+        limit: Option<NonZeroUsize>,
+    }
+}
+
+impl<St> TryFlattenUnordered<St>
+where
+    St: TryStream,
+    St::Ok: TryStream + Unpin,
+    <St::Ok as TryStream>::Error: From<St::Error>,
+{
+    pub(super) fn new(stream: St, limit: Option<usize>) -> Self {
+        Self {
+            stream: IntoStream::new(stream).fuse(),
+            active: FuturesUnordered::new(),
+            limit: limit.and_then(NonZeroUsize::new),
+        }
+    }
+
+    delegate_access_inner!(stream, St, (. .));
+}
+
+impl<St> Stream for TryFlattenUnordered<St>
+where
+    St: TryStream,
+    St::Ok: TryStream + Unpin,
+    <St::Ok as TryStream>::Error: From<St::Error>,
+{
+    type Item = Result<<St::Ok as TryStream>::Ok, <St::Ok as TryStream>::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            // First up, pull as many inner streams out of the outer stream as
+            // the concurrency limit allows.
+            while this.limit.map(|limit| this.active.len() < limit.get()).unwrap_or(true) {
+                match this.stream.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(Ok(inner))) => {
+                        this.active.push(IntoStream::new(inner).into_future())
+                    }
+                    Poll::Ready(Some(Err(e))) => {
+                        // An errored outer stream takes the in-flight inner
+                        // streams down with it.
+                        this.active.clear();
+                        return Poll::Ready(Some(Err(e.into())));
+                    }
+                    Poll::Ready(None) | Poll::Pending => break,
+                }
+            }
+
+            // Then look for an item in the inner streams being polled
+            // concurrently.
+            match this.active.poll_next_unpin(cx) {
+                Poll::Ready(Some((Some(Ok(item)), remaining))) => {
+                    this.active.push(remaining.into_future());
+                    return Poll::Ready(Some(Ok(item)));
+                }
+                Poll::Ready(Some((Some(Err(e)), _))) => {
+                    // The first error cancels the other in-flight inner
+                    // streams rather than continuing to drive them.
+                    this.active.clear();
+                    return Poll::Ready(Some(Err(e)));
+                }
+                Poll::Ready(Some((None, _))) => {
+                    // An inner stream finished, freeing a concurrency slot;
+                    // go back around to let a held-back inner stream take it.
+                }
+                Poll::Ready(None) => {
+                    // If more inner streams are still coming from the outer
+                    // stream, we're not done yet.
+                    return if this.stream.is_done() { Poll::Ready(None) } else { Poll::Pending };
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+impl<St> FusedStream for TryFlattenUnordered<St>
+where
+    St: TryStream,
+    St::Ok: TryStream + Unpin,
+    <St::Ok as TryStream>::Error: From<St::Error>,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_done() && self.active.is_empty()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, Item, E> Sink<Item> for TryFlattenUnordered<S>
+where
+    S: TryStream + Sink<Item, Error = E>,
+    S::Ok: TryStream + Unpin,
+    <S::Ok as TryStream>::Error: From<E>,
+{
+    type Error = E;
+
+    delegate_sink!(stream, Item);
+}
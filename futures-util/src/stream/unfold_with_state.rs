@@ -0,0 +1,153 @@
+use super::assert_stream;
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+/// Creates a `Stream` from a seed and a closure returning a `Future`, like
+/// [`unfold`](crate::stream::unfold), but the state can be recovered from the
+/// stream afterwards.
+///
+/// Unlike [`unfold`](crate::stream::unfold), the returned future yields the
+/// next state on every step, including the final one: `(Some(item), state)`
+/// yields `item` and continues with `state`, while `(None, state)` ends the
+/// stream but keeps `state` around. The accumulated state can then be taken
+/// back out of the stream with [`StatefulUnfold::into_state`], whether the
+/// stream was consumed to completion or abandoned partway through. This makes
+/// it suitable for resumable iteration.
+///
+/// # Example
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::future;
+/// use futures::stream::{self, StreamExt};
+///
+/// let mut stream = stream::unfold_with_state(0, |state| {
+///     future::ready(if state <= 2 { (Some(state * 2), state + 1) } else { (None, state) })
+/// });
+///
+/// assert_eq!((&mut stream).collect::<Vec<i32>>().await, vec![0, 2, 4]);
+/// assert_eq!(stream.into_state(), Some(3));
+/// # });
+/// ```
+pub fn unfold_with_state<T, F, Fut, Item>(init: T, f: F) -> StatefulUnfold<T, F, Fut>
+where
+    F: FnMut(T) -> Fut,
+    Fut: Future<Output = (Option<Item>, T)>,
+{
+    assert_stream::<Item, _>(StatefulUnfold { f, state: State::Value { value: init } })
+}
+
+pin_project! {
+    #[project = StateProj]
+    #[project_replace = StateProjReplace]
+    #[derive(Debug)]
+    enum State<T, Fut> {
+        // Waiting for the next step to be started.
+        Value { value: T },
+        // A step future is in flight; the state is inside the future.
+        Future { #[pin] future: Fut },
+        // The stream has ended, keeping the final state recoverable.
+        Done { value: T },
+        // Used only transiently while swapping states.
+        Empty,
+    }
+}
+
+impl<T, Fut> State<T, Fut> {
+    fn take_value(self: Pin<&mut Self>) -> Option<T> {
+        match &*self {
+            Self::Value { .. } => match self.project_replace(Self::Empty) {
+                StateProjReplace::Value { value } => Some(value),
+                _ => unreachable!(),
+            },
+            _ => None,
+        }
+    }
+}
+
+pin_project! {
+    /// Stream for the [`unfold_with_state`] function.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct StatefulUnfold<T, F, Fut> {
+        f: F,
+        #[pin]
+        state: State<T, Fut>,
+    }
+}
+
+impl<T, F, Fut> StatefulUnfold<T, F, Fut> {
+    /// Consumes the stream, returning the current state.
+    ///
+    /// Returns [`None`] if a step future is currently in flight, since the
+    /// state is then owned by that future. This can only happen when the
+    /// stream was abandoned after a poll that returned
+    /// [`Poll::Pending`](futures_core::task::Poll::Pending); after the stream
+    /// has yielded an item or finished, the state is always available.
+    pub fn into_state(self) -> Option<T> {
+        match self.state {
+            State::Value { value } | State::Done { value } => Some(value),
+            State::Future { .. } | State::Empty => None,
+        }
+    }
+}
+
+impl<T, F, Fut> fmt::Debug for StatefulUnfold<T, F, Fut>
+where
+    T: fmt::Debug,
+    Fut: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StatefulUnfold").field("state", &self.state).finish()
+    }
+}
+
+impl<T, F, Fut, Item> FusedStream for StatefulUnfold<T, F, Fut>
+where
+    F: FnMut(T) -> Fut,
+    Fut: Future<Output = (Option<Item>, T)>,
+{
+    fn is_terminated(&self) -> bool {
+        match self.state {
+            State::Done { .. } => true,
+            _ => false,
+        }
+    }
+}
+
+impl<T, F, Fut, Item> Stream for StatefulUnfold<T, F, Fut>
+where
+    F: FnMut(T) -> Fut,
+    Fut: Future<Output = (Option<Item>, T)>,
+{
+    type Item = Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if let Some(state) = this.state.as_mut().take_value() {
+            this.state.set(State::Future { future: (this.f)(state) });
+        }
+
+        let step = match this.state.as_mut().project() {
+            StateProj::Future { future } => ready!(future.poll(cx)),
+            StateProj::Done { .. } => return Poll::Ready(None),
+            _ => panic!("StatefulUnfold polled after completion"),
+        };
+
+        match step {
+            (Some(item), next_state) => {
+                this.state.set(State::Value { value: next_state });
+                Poll::Ready(Some(item))
+            }
+            (None, final_state) => {
+                this.state.set(State::Done { value: final_state });
+                Poll::Ready(None)
+            }
+        }
+    }
+}
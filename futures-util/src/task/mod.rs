@@ -15,6 +15,7 @@ pub use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
 
 pub use futures_task::{FutureObj, LocalFutureObj, LocalSpawn, Spawn, SpawnError, UnsafeFutureObj};
 
+pub use futures_task::noop_context;
 pub use futures_task::noop_waker;
 pub use futures_task::noop_waker_ref;
 
@@ -30,6 +31,9 @@ pub use futures_task::waker;
 #[cfg(feature = "alloc")]
 pub use futures_task::{waker_ref, WakerRef};
 
+#[cfg(feature = "alloc")]
+pub use futures_task::{local_waker, LocalWake, LocalWaker};
+
 #[cfg(not(futures_no_atomic_cas))]
 pub use futures_core::task::__internal::AtomicWaker;
 
@@ -0,0 +1,125 @@
+//! Timer abstraction.
+//!
+//! This module contains the [`Timer`] trait, which lets time-based combinators
+//! like [`FutureExt::timeout`](crate::future::FutureExt::timeout) request
+//! delay futures without binding this crate to any particular runtime. Any
+//! closure of type `FnMut(Duration) -> impl Future<Output = ()>` is a
+//! [`Timer`], so runtime-provided sleeps can be passed in directly, e.g.
+//! `|duration| tokio::time::sleep(duration)`.
+
+use core::time::Duration;
+use futures_core::future::Future;
+
+/// A source of delay futures.
+///
+/// A `Timer` hands out futures that resolve after a given duration. Dropping
+/// a returned future before it resolves must cancel the underlying delay.
+pub trait Timer {
+    /// The future returned by [`sleep`](Timer::sleep).
+    type Sleep: Future<Output = ()>;
+
+    /// Returns a future that resolves after `duration` has elapsed.
+    fn sleep(&mut self, duration: Duration) -> Self::Sleep;
+}
+
+impl<F, Fut> Timer for F
+where
+    F: FnMut(Duration) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    type Sleep = Fut;
+
+    fn sleep(&mut self, duration: Duration) -> Self::Sleep {
+        self(duration)
+    }
+}
+
+#[cfg(feature = "std")]
+mod thread_timer {
+    use super::Timer;
+    use futures_core::future::Future;
+    use futures_core::task::{Context, Poll, Waker};
+    use std::pin::Pin;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::Duration;
+
+    /// A [`Timer`] backed by one std thread per in-flight sleep.
+    ///
+    /// This is a lowest-common-denominator default for use where no runtime
+    /// timer is available; each [`sleep`](Timer::sleep) spawns a thread that
+    /// blocks for the duration and then wakes the task. Dropping the returned
+    /// future cancels the wake-up, though the helper thread finishes its nap
+    /// before exiting.
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct ThreadTimer;
+
+    impl Timer for ThreadTimer {
+        type Sleep = ThreadSleep;
+
+        fn sleep(&mut self, duration: Duration) -> Self::Sleep {
+            ThreadSleep { duration, shared: None }
+        }
+    }
+
+    /// Future returned by [`ThreadTimer::sleep`].
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct ThreadSleep {
+        duration: Duration,
+        shared: Option<Arc<Mutex<SleepState>>>,
+    }
+
+    #[derive(Debug)]
+    struct SleepState {
+        done: bool,
+        cancelled: bool,
+        waker: Option<Waker>,
+    }
+
+    impl Future for ThreadSleep {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let duration = self.duration;
+            let shared = self.shared.get_or_insert_with(|| {
+                let shared =
+                    Arc::new(Mutex::new(SleepState { done: false, cancelled: false, waker: None }));
+                let thread_shared = shared.clone();
+                thread::spawn(move || {
+                    thread::sleep(duration);
+                    let mut state = thread_shared.lock().unwrap();
+                    state.done = true;
+                    if !state.cancelled {
+                        if let Some(waker) = state.waker.take() {
+                            waker.wake();
+                        }
+                    }
+                });
+                shared
+            });
+
+            let mut state = shared.lock().unwrap();
+            if state.done {
+                Poll::Ready(())
+            } else {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+
+    impl Drop for ThreadSleep {
+        fn drop(&mut self) {
+            if let Some(shared) = &self.shared {
+                let mut state = shared.lock().unwrap();
+                state.cancelled = true;
+                state.waker = None;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::thread_timer::{ThreadSleep, ThreadTimer};
@@ -131,7 +131,7 @@ pub use futures_util::{join, pending, poll, select_biased, try_join}; // Async-a
 
 // Module reexports
 #[doc(inline)]
-pub use futures_util::{future, sink, stream, task};
+pub use futures_util::{future, sink, stream, task, time};
 
 #[cfg(feature = "std")]
 #[cfg(feature = "async-await")]
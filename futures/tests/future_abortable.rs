@@ -1,8 +1,9 @@
 use futures::channel::oneshot;
 use futures::executor::block_on;
-use futures::future::{abortable, Aborted, FutureExt};
+use futures::future::{self, abortable, AbortHandle, Abortable, Aborted, FutureExt};
 use futures::task::{Context, Poll};
 use futures_test::task::new_count_waker;
+use std::time::Duration;
 
 #[test]
 fn abortable_works() {
@@ -32,6 +33,24 @@ fn abortable_awakens() {
     assert_eq!(Poll::Ready(Err(Aborted)), abortable_rx.poll_unpin(&mut cx));
 }
 
+#[test]
+fn handle_is_aborted_reflects_abort() {
+    let (_tx, a_rx) = oneshot::channel::<()>();
+    let (abortable_rx, abort_handle) = abortable(a_rx);
+
+    assert!(!abort_handle.is_aborted());
+    abort_handle.abort();
+
+    // The abort is observable on both the handle and the abortable before
+    // the wrapped future is polled again.
+    assert!(abort_handle.is_aborted());
+    assert!(abortable_rx.is_aborted());
+
+    // Clones of the handle share the same state.
+    assert!(abort_handle.clone().is_aborted());
+    assert_eq!(Err(Aborted), block_on(abortable_rx));
+}
+
 #[test]
 fn abortable_resolves() {
     let (tx, a_rx) = oneshot::channel::<()>();
@@ -42,3 +61,69 @@ fn abortable_resolves() {
     assert!(!abortable_rx.is_aborted());
     assert_eq!(Ok(Ok(())), block_on(abortable_rx));
 }
+
+#[test]
+fn with_deadline_aborts_at_deadline() {
+    let (_tx, a_rx) = oneshot::channel::<()>();
+    let (_handle, reg) = AbortHandle::new_pair();
+
+    // A mock timer whose delay resolves on the first poll.
+    let task =
+        Abortable::with_deadline(a_rx, reg, Duration::from_millis(10), |_| future::ready(()));
+
+    assert_eq!(Err(Aborted), block_on(task));
+}
+
+#[test]
+fn with_deadline_completion_beats_deadline() {
+    let (tx, a_rx) = oneshot::channel::<()>();
+    let (_handle, reg) = AbortHandle::new_pair();
+
+    let task =
+        Abortable::with_deadline(a_rx, reg, Duration::from_millis(10), |_| future::ready(()));
+
+    // The task is ready before the deadline is ever polled.
+    tx.send(()).unwrap();
+    assert_eq!(Ok(Ok(())), block_on(task));
+}
+
+#[test]
+fn with_deadline_cancels_timer_on_completion() {
+    let (tx, a_rx) = oneshot::channel::<()>();
+    let (_handle, reg) = AbortHandle::new_pair();
+
+    let (cancel_tx, cancel_rx) = oneshot::channel::<()>();
+    let mut cancel_tx = Some(cancel_tx);
+    // A mock timer that reports cancellation by being dropped.
+    let task = Abortable::with_deadline(a_rx, reg, Duration::from_millis(10), move |_| {
+        let tx = cancel_tx.take().unwrap();
+        async move {
+            let _tx = tx;
+            future::pending::<()>().await
+        }
+    });
+    futures::pin_mut!(task);
+
+    let (waker, _counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    assert_eq!(Poll::Pending, task.poll_unpin(&mut cx));
+
+    tx.send(()).unwrap();
+    assert_eq!(Poll::Ready(Ok(Ok(()))), task.poll_unpin(&mut cx));
+
+    // The delay future was dropped when the task completed.
+    assert_eq!(Err(oneshot::Canceled), block_on(cancel_rx));
+}
+
+#[test]
+fn with_deadline_handle_abort_still_works() {
+    let (_tx, a_rx) = oneshot::channel::<()>();
+    let (handle, reg) = AbortHandle::new_pair();
+
+    let task =
+        Abortable::with_deadline(a_rx, reg, Duration::from_millis(10), |_| future::pending());
+
+    handle.abort();
+    assert!(task.is_aborted());
+    assert_eq!(Err(Aborted), block_on(task));
+}
@@ -14,3 +14,32 @@ fn smoke() {
 
     assert_eq!(counter, 40);
 }
+
+#[test]
+fn fires_exactly_once() {
+    let mut fired = 0;
+
+    {
+        let work = future::ready(42).inspect(|&val| {
+            assert_eq!(val, 42);
+            fired += 1;
+        });
+        assert_eq!(block_on(work), 42);
+    }
+
+    assert_eq!(fired, 1);
+}
+
+#[test]
+fn not_called_on_early_drop() {
+    let mut fired = false;
+
+    {
+        let work = future::ready(42).inspect(|_| {
+            fired = true;
+        });
+        drop(work);
+    }
+
+    assert!(!fired);
+}
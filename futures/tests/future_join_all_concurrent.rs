@@ -0,0 +1,130 @@
+use futures::executor::block_on;
+use futures::future::{self, join_all_concurrent, try_join_all_concurrent, Future};
+use futures::task::{Context, Poll};
+use std::cell::Cell;
+use std::pin::Pin;
+use std::rc::Rc;
+
+#[derive(Clone, Default)]
+struct Gauge {
+    active: Rc<Cell<usize>>,
+    max_active: Rc<Cell<usize>>,
+}
+
+/// A future that stays pending for a few polls while tracking how many of its
+/// siblings are running at the same time.
+struct Instrumented {
+    value: u32,
+    polls_left: usize,
+    started: bool,
+    gauge: Gauge,
+}
+
+impl Instrumented {
+    fn new(value: u32, polls_left: usize, gauge: &Gauge) -> Self {
+        Self { value, polls_left, started: false, gauge: gauge.clone() }
+    }
+}
+
+impl Future for Instrumented {
+    type Output = u32;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if !self.started {
+            self.started = true;
+            let active = self.gauge.active.get() + 1;
+            self.gauge.active.set(active);
+            self.gauge.max_active.set(self.gauge.max_active.get().max(active));
+        }
+        if self.polls_left == 0 {
+            self.gauge.active.set(self.gauge.active.get() - 1);
+            Poll::Ready(self.value)
+        } else {
+            self.polls_left -= 1;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+#[test]
+fn limit_bounds_concurrency_and_keeps_order() {
+    let gauge = Gauge::default();
+    let futures: Vec<_> = (0..10).map(|i| Instrumented::new(i, 3, &gauge)).collect();
+
+    let results = block_on(join_all_concurrent(futures, 4));
+
+    assert_eq!(results, (0..10).collect::<Vec<_>>());
+    assert!(gauge.max_active.get() <= 4, "max active was {}", gauge.max_active.get());
+    assert_eq!(gauge.active.get(), 0);
+}
+
+#[test]
+fn limit_larger_than_input_behaves_like_join_all() {
+    let gauge = Gauge::default();
+    let futures: Vec<_> = (0..5).map(|i| Instrumented::new(i, 2, &gauge)).collect();
+
+    let results = block_on(join_all_concurrent(futures, 100));
+
+    assert_eq!(results, (0..5).collect::<Vec<_>>());
+    // With a limit this generous everything runs at once.
+    assert_eq!(gauge.max_active.get(), 5);
+}
+
+#[test]
+fn no_limit_means_unbounded() {
+    let results = block_on(join_all_concurrent((0..5).map(future::ready), None));
+    assert_eq!(results, (0..5).collect::<Vec<_>>());
+}
+
+#[test]
+fn try_variant_collects_in_order() {
+    let futures: Vec<_> = (0..8).map(future::ok::<u32, u32>).collect();
+    let results = block_on(try_join_all_concurrent(futures, 3));
+    assert_eq!(results, Ok((0..8).collect::<Vec<_>>()));
+}
+
+#[test]
+fn try_variant_error_cancels_outstanding() {
+    struct TrackDrop {
+        polled: Rc<Cell<bool>>,
+        dropped: Rc<Cell<bool>>,
+    }
+
+    impl Future for TrackDrop {
+        type Output = Result<u32, u32>;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            self.polled.set(true);
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+
+    impl Drop for TrackDrop {
+        fn drop(&mut self) {
+            self.dropped.set(true);
+        }
+    }
+
+    let in_flight = (Rc::new(Cell::new(false)), Rc::new(Cell::new(false)));
+    let never_started = (Rc::new(Cell::new(false)), Rc::new(Cell::new(false)));
+
+    // A pending future, then one that fails, then one that must never start
+    // because the limit of two keeps it queued behind the failure.
+    let futures: Vec<Pin<Box<dyn Future<Output = Result<u32, u32>>>>> = vec![
+        Box::pin(TrackDrop { polled: in_flight.0.clone(), dropped: in_flight.1.clone() }),
+        Box::pin(future::err::<u32, u32>(7)),
+        Box::pin(TrackDrop { polled: never_started.0.clone(), dropped: never_started.1.clone() }),
+    ];
+
+    let result = block_on(try_join_all_concurrent(futures, 2));
+    assert_eq!(result, Err(7));
+
+    // The in-flight future was polled but cancelled by the error; the queued
+    // one was dropped without ever being polled.
+    assert!(in_flight.0.get());
+    assert!(in_flight.1.get());
+    assert!(!never_started.0.get());
+    assert!(never_started.1.get());
+}
@@ -0,0 +1,45 @@
+use futures::executor::block_on;
+use futures::future::{self, TryFutureExt};
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[test]
+fn context_reaches_mapper_on_error() {
+    let future = future::err::<i32, &str>("boom")
+        .map_err_with("request 7", |ctx, e| format!("{}: {}", ctx, e));
+    assert_eq!(block_on(future), Err("request 7: boom".to_string()));
+}
+
+#[test]
+fn context_dropped_without_call_on_ok() {
+    struct TrackDrop(Rc<Cell<bool>>);
+
+    impl Drop for TrackDrop {
+        fn drop(&mut self) {
+            self.0.set(true);
+        }
+    }
+
+    let called = Rc::new(Cell::new(false));
+    let dropped = Rc::new(Cell::new(false));
+
+    let called2 = called.clone();
+    let future =
+        future::ok::<i32, i32>(1).map_err_with(TrackDrop(dropped.clone()), move |_ctx, e| {
+            called2.set(true);
+            e
+        });
+
+    assert_eq!(block_on(future), Ok(1));
+    assert!(!called.get());
+    assert!(dropped.get());
+}
+
+#[test]
+fn context_moved_by_value() {
+    // The context is handed to the closure by value, so non-`Copy` data can
+    // be consumed inside the mapper.
+    let future = future::err::<(), i32>(2)
+        .map_err_with(vec![10, 20, 30], |mut ctx, e| ctx.remove(e as usize));
+    assert_eq!(block_on(future), Err(30));
+}
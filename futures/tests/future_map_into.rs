@@ -0,0 +1,28 @@
+use futures::executor::block_on;
+use futures::future::{self, FutureExt};
+
+#[test]
+fn map_into_widens_integer() {
+    let future = future::ready(1u8).map_into::<u32>();
+    assert_eq!(block_on(future), 1u32);
+}
+
+#[test]
+fn map_into_custom_conversion() {
+    struct Wrapped(i32);
+
+    impl From<i32> for Wrapped {
+        fn from(x: i32) -> Self {
+            Self(x)
+        }
+    }
+
+    let future = future::ready(5).map_into::<Wrapped>();
+    assert_eq!(block_on(future).0, 5);
+}
+
+#[test]
+fn map_into_composes_with_other_adapters() {
+    let future = future::ready(200u8).map(|x| x + 55).map_into::<u64>().map(|x| x + 1);
+    assert_eq!(block_on(future), 256u64);
+}
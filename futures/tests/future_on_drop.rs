@@ -0,0 +1,40 @@
+use futures::executor::block_on;
+use futures::future::{self, FutureExt};
+use std::cell::Cell;
+
+#[test]
+fn closure_runs_when_dropped_before_completion() {
+    let cancelled = Cell::new(false);
+
+    {
+        let work = future::pending::<()>().on_drop(|| cancelled.set(true));
+        drop(work);
+    }
+
+    assert!(cancelled.get());
+}
+
+#[test]
+fn closure_does_not_run_on_completion() {
+    let cancelled = Cell::new(false);
+
+    {
+        let work = future::ready(42).on_drop(|| cancelled.set(true));
+        assert_eq!(block_on(work), 42);
+    }
+
+    assert!(!cancelled.get());
+}
+
+#[test]
+fn dropping_after_completion_does_not_fire() {
+    block_on(async {
+        let cancelled = Cell::new(false);
+
+        let mut work = Box::pin(future::ready(7).on_drop(|| cancelled.set(true)));
+        assert_eq!(work.as_mut().await, 7);
+        drop(work);
+
+        assert!(!cancelled.get());
+    })
+}
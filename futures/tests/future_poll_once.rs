@@ -0,0 +1,42 @@
+use futures::executor::block_on;
+use futures::future::{self, FutureExt};
+
+#[test]
+fn ready_immediately() {
+    assert_eq!(future::ready(5).poll_once().ok(), Some(5));
+}
+
+#[test]
+fn pending_then_retry() {
+    let (tx, rx) = futures::channel::oneshot::channel::<i32>();
+
+    let rx = rx.poll_once().unwrap_err();
+    tx.send(9).unwrap();
+    assert_eq!(rx.poll_once().unwrap(), Ok(9));
+}
+
+#[test]
+fn consumed_only_when_ready() {
+    // A fused future panics if polled after completion; getting ownership
+    // back only while pending means that can't happen through `poll_once`.
+    let mut fut = future::ready(1).fuse();
+    loop {
+        match fut.poll_once() {
+            Ok(x) => {
+                assert_eq!(x, 1);
+                break;
+            }
+            Err(pending) => fut = pending,
+        }
+    }
+}
+
+#[test]
+fn pending_future_still_usable() {
+    let (tx, rx) = futures::channel::oneshot::channel::<i32>();
+
+    let rx = rx.poll_once().unwrap_err();
+    tx.send(3).unwrap();
+    // The returned future can still be awaited normally.
+    assert_eq!(block_on(rx), Ok(3));
+}
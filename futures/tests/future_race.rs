@@ -0,0 +1,85 @@
+use futures::executor::block_on;
+use futures::future::{self, race, race_all, FutureExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// A pending future that records when it is dropped.
+struct TrackDrop(Rc<Cell<bool>>);
+
+impl future::Future for TrackDrop {
+    type Output = i32;
+
+    fn poll(self: std::pin::Pin<&mut Self>, _: &mut std::task::Context<'_>) -> Poll<Self::Output> {
+        Poll::Pending
+    }
+}
+
+impl Drop for TrackDrop {
+    fn drop(&mut self) {
+        self.0.set(true);
+    }
+}
+
+#[test]
+fn race_staggered_completion() {
+    let a = future::pending::<i32>().boxed_local();
+    let b = future::ready(2).boxed_local();
+    assert_eq!(block_on(race(a, b)), 2);
+
+    let a = future::ready(1).boxed_local();
+    let b = future::pending::<i32>().boxed_local();
+    assert_eq!(block_on(race(a, b)), 1);
+}
+
+#[test]
+fn race_is_left_biased() {
+    // Both are ready on the first poll; the left future wins.
+    assert_eq!(block_on(race(future::ready(1), future::ready(2))), 1);
+}
+
+#[test]
+fn race_drops_loser_on_completion() {
+    let mut cx = noop_context();
+
+    let dropped = Rc::new(Cell::new(false));
+    let mut fut = race(TrackDrop(dropped.clone()), future::ready(2).boxed_local());
+
+    assert_eq!(fut.poll_unpin(&mut cx), Poll::Ready(2));
+    assert!(dropped.get());
+}
+
+#[test]
+fn race_all_returns_first_to_finish() {
+    let futures = vec![
+        future::pending().boxed_local(),
+        future::ready(2).boxed_local(),
+        future::pending().boxed_local(),
+        future::ready(4).boxed_local(),
+    ];
+    // Both index 1 and index 3 are ready; the earlier one wins.
+    assert_eq!(block_on(race_all(futures)), 2);
+}
+
+#[test]
+fn race_all_drops_losers_on_completion() {
+    let mut cx = noop_context();
+
+    let dropped = (Rc::new(Cell::new(false)), Rc::new(Cell::new(false)));
+    let mut fut = race_all(vec![
+        TrackDrop(dropped.0.clone()).boxed_local(),
+        future::ready(7).boxed_local(),
+        TrackDrop(dropped.1.clone()).boxed_local(),
+    ]);
+
+    assert_eq!(fut.poll_unpin(&mut cx), Poll::Ready(7));
+    assert!(dropped.0.get());
+    assert!(dropped.1.get());
+}
+
+#[test]
+#[should_panic]
+fn race_all_panics_on_empty() {
+    let _ = race_all(Vec::<future::Ready<()>>::new());
+}
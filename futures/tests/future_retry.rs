@@ -0,0 +1,73 @@
+use futures::executor::block_on;
+use futures::future::{self, retry};
+use std::cell::RefCell;
+use std::time::Duration;
+
+/// A timer that resolves immediately but records every requested delay.
+fn recording_timer(log: &RefCell<Vec<Duration>>) -> impl FnMut(Duration) -> future::Ready<()> + '_ {
+    move |duration| {
+        log.borrow_mut().push(duration);
+        future::ready(())
+    }
+}
+
+#[test]
+fn first_try_success_skips_the_timer() {
+    let sleeps = RefCell::new(Vec::new());
+
+    let result = block_on(retry(
+        || future::ok::<_, u32>(7),
+        |_, _: &u32| Some(Duration::from_secs(1)),
+        recording_timer(&sleeps),
+    ));
+
+    assert_eq!(result, Ok(7));
+    assert!(sleeps.borrow().is_empty());
+}
+
+#[test]
+fn retries_until_factory_succeeds() {
+    let sleeps = RefCell::new(Vec::new());
+    let mut attempts = 0;
+
+    let result = block_on(retry(
+        || {
+            attempts += 1;
+            if attempts <= 3 {
+                future::err(attempts)
+            } else {
+                future::ok("done")
+            }
+        },
+        // Back off a little longer after every failure.
+        |attempt, _: &i32| Some(Duration::from_millis(attempt as u64)),
+        recording_timer(&sleeps),
+    ));
+
+    assert_eq!(result, Ok("done"));
+    assert_eq!(attempts, 4);
+    assert_eq!(
+        *sleeps.borrow(),
+        vec![Duration::from_millis(1), Duration::from_millis(2), Duration::from_millis(3)]
+    );
+}
+
+#[test]
+fn capped_policy_surfaces_last_error() {
+    let sleeps = RefCell::new(Vec::new());
+    let mut attempts = 0;
+
+    let result = block_on(retry(
+        || {
+            attempts += 1;
+            future::err::<(), _>(attempts)
+        },
+        |attempt, _: &i32| if attempt < 3 { Some(Duration::from_secs(0)) } else { None },
+        recording_timer(&sleeps),
+    ));
+
+    // The third failure exhausts the policy; its error comes back out.
+    assert_eq!(result, Err(3));
+    assert_eq!(attempts, 3);
+    assert_eq!(sleeps.borrow().len(), 2);
+}
@@ -0,0 +1,121 @@
+use futures::executor::block_on;
+use futures::future::{self, Elapsed, FutureExt};
+use futures::task::Poll;
+use futures::time::ThreadTimer;
+use futures_test::task::noop_context;
+use std::cell::Cell;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::Context;
+use std::time::Duration;
+
+/// A delay future driven by hand: resolves once its flag is set, and records
+/// whether it was dropped before resolving.
+struct MockSleep {
+    fired: Rc<Cell<bool>>,
+    dropped: Rc<Cell<bool>>,
+    done: bool,
+}
+
+impl future::Future for MockSleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fired.get() {
+            self.done = true;
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for MockSleep {
+    fn drop(&mut self) {
+        if !self.done {
+            self.dropped.set(true);
+        }
+    }
+}
+
+struct MockTimer {
+    fired: Rc<Cell<bool>>,
+    dropped: Rc<Cell<bool>>,
+    requested: Rc<Cell<Option<Duration>>>,
+}
+
+impl MockTimer {
+    fn new() -> Self {
+        Self {
+            fired: Rc::new(Cell::new(false)),
+            dropped: Rc::new(Cell::new(false)),
+            requested: Rc::new(Cell::new(None)),
+        }
+    }
+
+    fn sleep_fn(&self) -> impl FnMut(Duration) -> MockSleep {
+        let fired = self.fired.clone();
+        let dropped = self.dropped.clone();
+        let requested = self.requested.clone();
+        move |duration| {
+            requested.set(Some(duration));
+            MockSleep { fired: fired.clone(), dropped: dropped.clone(), done: false }
+        }
+    }
+}
+
+#[test]
+fn future_completes_in_time() {
+    let mut cx = noop_context();
+    let timer = MockTimer::new();
+
+    let mut fut = future::ready(5).timeout(Duration::from_secs(1), timer.sleep_fn());
+    assert_eq!(fut.poll_unpin(&mut cx), Poll::Ready(Ok(5)));
+    assert_eq!(timer.requested.get(), Some(Duration::from_secs(1)));
+}
+
+#[test]
+fn timer_firing_yields_elapsed() {
+    let mut cx = noop_context();
+    let timer = MockTimer::new();
+
+    let mut fut = future::pending::<i32>().timeout(Duration::from_secs(1), timer.sleep_fn());
+    assert_eq!(fut.poll_unpin(&mut cx), Poll::Pending);
+
+    timer.fired.set(true);
+    assert_eq!(fut.poll_unpin(&mut cx), Poll::Ready(Err(Elapsed)));
+}
+
+#[test]
+fn future_preferred_when_both_ready() {
+    let mut cx = noop_context();
+    let timer = MockTimer::new();
+
+    // The timer is already able to fire on the first poll, but the future is
+    // ready too, so its value wins.
+    timer.fired.set(true);
+    let mut fut = future::ready(5).timeout(Duration::from_secs(1), timer.sleep_fn());
+    assert_eq!(fut.poll_unpin(&mut cx), Poll::Ready(Ok(5)));
+}
+
+#[test]
+fn dropping_timeout_cancels_sleep() {
+    let mut cx = noop_context();
+    let timer = MockTimer::new();
+
+    let mut fut = future::pending::<i32>().timeout(Duration::from_secs(1), timer.sleep_fn());
+    assert_eq!(fut.poll_unpin(&mut cx), Poll::Pending);
+    assert!(!timer.dropped.get());
+
+    drop(fut);
+    assert!(timer.dropped.get());
+}
+
+#[test]
+fn thread_timer() {
+    let result = block_on(future::ready(1).timeout(Duration::from_secs(60), ThreadTimer));
+    assert_eq!(result, Ok(1));
+
+    let result = block_on(future::pending::<i32>().timeout(Duration::from_millis(10), ThreadTimer));
+    assert_eq!(result, Err(Elapsed));
+}
@@ -430,3 +430,22 @@ fn maybe_pending_seek() {
     Pin::new(&mut reader).consume(1);
     assert_eq!(run(reader.seek(SeekFrom::Current(-2))).ok(), Some(3));
 }
+
+#[test]
+fn buf_reader_capacity() {
+    let reader = BufReader::with_capacity(10, &[][..]);
+    assert_eq!(reader.capacity(), 10);
+
+    let reader = BufReader::new(&[][..]);
+    assert_eq!(reader.capacity(), 8 * 1024);
+}
+
+#[test]
+fn buf_reader_various_capacities_roundtrip() {
+    for cap in [1, 2, 3, 8, 64] {
+        let mut reader = BufReader::with_capacity(cap, &[0u8, 1, 2, 3, 4, 5, 6, 7][..]);
+        let mut buf = Vec::new();
+        run(reader.read_to_end(&mut buf)).unwrap();
+        assert_eq!(buf, [0, 1, 2, 3, 4, 5, 6, 7], "capacity {}", cap);
+    }
+}
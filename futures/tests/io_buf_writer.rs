@@ -237,3 +237,22 @@ fn maybe_pending_buf_writer_seek() {
     run(w.flush()).unwrap();
     assert_eq!(&w.into_inner().inner.into_inner()[..], &[0, 1, 8, 9, 4, 5, 6, 7]);
 }
+
+#[test]
+fn buf_writer_capacity() {
+    let writer = BufWriter::with_capacity(10, Vec::new());
+    assert_eq!(writer.capacity(), 10);
+
+    let writer = BufWriter::new(Vec::new());
+    assert_eq!(writer.capacity(), 8 * 1024);
+}
+
+#[test]
+fn buf_writer_various_capacities_roundtrip() {
+    for cap in [0, 1, 2, 3, 8, 64] {
+        let mut writer = BufWriter::with_capacity(cap, Vec::new());
+        block_on(writer.write_all(&[0, 1, 2, 3, 4, 5, 6, 7])).unwrap();
+        block_on(writer.flush()).unwrap();
+        assert_eq!(writer.into_inner(), [0, 1, 2, 3, 4, 5, 6, 7], "capacity {}", cap);
+    }
+}
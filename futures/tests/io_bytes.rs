@@ -0,0 +1,56 @@
+use futures::executor::block_on;
+use futures::io::{self, AsyncRead, AsyncReadExt, Cursor};
+use futures::stream::StreamExt;
+use futures::task::{Context, Poll};
+use std::pin::Pin;
+
+#[test]
+fn bytes_yields_each_byte_then_ends() {
+    block_on(async {
+        let reader = Cursor::new([1, 2, 3, 4]);
+        let mut stream = reader.bytes();
+
+        let mut collected = Vec::new();
+        while let Some(byte) = stream.next().await {
+            collected.push(byte.unwrap());
+        }
+        assert_eq!(collected, [1, 2, 3, 4]);
+
+        // EOF is terminal; the stream stays finished.
+        assert!(stream.next().await.is_none());
+    })
+}
+
+/// Reader that yields a few bytes and then fails.
+struct FaultyReader {
+    data: Vec<u8>,
+}
+
+impl AsyncRead for FaultyReader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        if self.data.is_empty() {
+            return Poll::Ready(Err(io::ErrorKind::Other.into()));
+        }
+        buf[0] = self.data.remove(0);
+        Poll::Ready(Ok(1))
+    }
+}
+
+#[test]
+fn bytes_ends_after_error() {
+    block_on(async {
+        let reader = FaultyReader { data: vec![7, 8] };
+        let mut stream = reader.bytes();
+
+        assert_eq!(stream.next().await.unwrap().unwrap(), 7);
+        assert_eq!(stream.next().await.unwrap().unwrap(), 8);
+        assert_eq!(stream.next().await.unwrap().unwrap_err().kind(), io::ErrorKind::Other);
+
+        // The error is the final item.
+        assert!(stream.next().await.is_none());
+    })
+}
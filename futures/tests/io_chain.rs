@@ -0,0 +1,67 @@
+use futures::executor::block_on;
+use futures::io::{AsyncBufReadExt, AsyncReadExt, Cursor};
+use futures::stream::StreamExt;
+
+#[test]
+fn chain_reads_in_order() {
+    block_on(async {
+        let first = Cursor::new(b"header ".to_vec());
+        let second = Cursor::new(b"body".to_vec());
+        let mut chain = first.chain(second);
+
+        let mut buf = Vec::new();
+        let n = chain.read_to_end(&mut buf).await.unwrap();
+        assert_eq!(n, 11);
+        assert_eq!(buf, b"header body");
+
+        // Both readers are exhausted, so the next read is a clean EOF.
+        let mut probe = [0u8; 1];
+        assert_eq!(chain.read(&mut probe).await.unwrap(), 0);
+    })
+}
+
+#[test]
+fn empty_first_reader() {
+    block_on(async {
+        let first = Cursor::new(Vec::new());
+        let second = Cursor::new(b"data".to_vec());
+        let mut chain = first.chain(second);
+
+        let mut buf = [0u8; 4];
+        let n = chain.read(&mut buf).await.unwrap();
+        assert_eq!(n, 4);
+        assert_eq!(&buf, b"data");
+    })
+}
+
+#[test]
+fn no_eof_until_both_exhausted() {
+    block_on(async {
+        let first = Cursor::new(b"a".to_vec());
+        let second = Cursor::new(b"b".to_vec());
+        let mut chain = first.chain(second);
+
+        let mut buf = [0u8; 8];
+        // The first read drains the first reader; the switch to the second
+        // reader must not surface as an EOF in between.
+        let n = chain.read(&mut buf).await.unwrap();
+        assert_eq!(&buf[..n], b"a");
+        let n = chain.read(&mut buf).await.unwrap();
+        assert_eq!(&buf[..n], b"b");
+        assert_eq!(chain.read(&mut buf).await.unwrap(), 0);
+    })
+}
+
+#[test]
+fn buf_read_across_chain() {
+    block_on(async {
+        let first = Cursor::new(b"one\ntw".to_vec());
+        let second = Cursor::new(b"o\nthree".to_vec());
+        let mut lines = first.chain(second).lines();
+
+        assert_eq!(lines.next().await.unwrap().unwrap(), "one");
+        assert_eq!(lines.next().await.unwrap().unwrap(), "two");
+        assert_eq!(lines.next().await.unwrap().unwrap(), "three");
+        assert!(lines.next().await.is_none());
+    })
+}
@@ -0,0 +1,153 @@
+use futures::executor::block_on;
+use futures::io::{copy_bidirectional, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use futures::join;
+use futures::task::{Context, Poll, Waker};
+use std::io;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+#[derive(Default)]
+struct Pipe {
+    buf: Vec<u8>,
+    closed: bool,
+    waker: Option<Waker>,
+}
+
+type Shared = Arc<Mutex<Pipe>>;
+
+/// One side of an in-memory duplex connection.
+struct Endpoint {
+    read: Shared,
+    write: Shared,
+}
+
+fn duplex() -> (Endpoint, Endpoint) {
+    let ab: Shared = Default::default();
+    let ba: Shared = Default::default();
+    (Endpoint { read: ba.clone(), write: ab.clone() }, Endpoint { read: ab, write: ba })
+}
+
+impl AsyncRead for Endpoint {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let mut pipe = self.read.lock().unwrap();
+        if pipe.buf.is_empty() {
+            if pipe.closed {
+                return Poll::Ready(Ok(0));
+            }
+            pipe.waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+        let n = pipe.buf.len().min(buf.len());
+        buf[..n].copy_from_slice(&pipe.buf[..n]);
+        pipe.buf.drain(..n);
+        Poll::Ready(Ok(n))
+    }
+}
+
+impl AsyncWrite for Endpoint {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let mut pipe = self.write.lock().unwrap();
+        if pipe.closed {
+            return Poll::Ready(Err(io::Error::new(io::ErrorKind::BrokenPipe, "pipe closed")));
+        }
+        pipe.buf.extend_from_slice(buf);
+        if let Some(waker) = pipe.waker.take() {
+            waker.wake();
+        }
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let mut pipe = self.write.lock().unwrap();
+        pipe.closed = true;
+        if let Some(waker) = pipe.waker.take() {
+            waker.wake();
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[test]
+fn proxies_both_directions() {
+    block_on(async {
+        let (mut client, mut proxy_a) = duplex();
+        let (mut proxy_b, mut server) = duplex();
+
+        let proxy = copy_bidirectional(&mut proxy_a, &mut proxy_b);
+
+        let client_side = async move {
+            client.write_all(b"ping").await.unwrap();
+            client.close().await.unwrap();
+            let mut response = Vec::new();
+            client.read_to_end(&mut response).await.unwrap();
+            response
+        };
+
+        let server_side = async move {
+            let mut request = Vec::new();
+            server.read_to_end(&mut request).await.unwrap();
+            assert_eq!(request, b"ping");
+            // Half-close: our read side already hit EOF, but writing back
+            // still works.
+            server.write_all(b"pong!").await.unwrap();
+            server.close().await.unwrap();
+        };
+
+        let (copied, response, ()) = join!(proxy, client_side, server_side);
+        assert_eq!(copied.unwrap(), (4, 5));
+        assert_eq!(response, b"pong!");
+    });
+}
+
+#[test]
+fn error_in_one_direction_resolves_to_error() {
+    struct FailingRead;
+
+    impl AsyncRead for FailingRead {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            _buf: &mut [u8],
+        ) -> Poll<io::Result<usize>> {
+            Poll::Ready(Err(io::Error::new(io::ErrorKind::Other, "broken")))
+        }
+    }
+
+    impl AsyncWrite for FailingRead {
+        fn poll_write(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            Poll::Ready(Ok(buf.len()))
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    block_on(async {
+        let (_other_end, mut healthy) = duplex();
+        let mut failing = FailingRead;
+
+        let err = copy_bidirectional(&mut failing, &mut healthy).await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Other);
+    });
+}
@@ -0,0 +1,102 @@
+use futures::executor::block_on;
+use futures::io::{self, AsyncRead, AsyncWrite, AsyncWriteExt, Cursor};
+use futures::task::{Context, Poll};
+use std::pin::Pin;
+
+/// A writer that accepts at most `chunk` bytes per write.
+struct ChunkWriter {
+    written: Vec<u8>,
+    chunk: usize,
+}
+
+impl AsyncWrite for ChunkWriter {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let n = buf.len().min(self.chunk);
+        self.written.extend_from_slice(&buf[..n]);
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// A reader that fails after yielding its data.
+struct FailingReader {
+    data: Vec<u8>,
+    given: bool,
+}
+
+impl AsyncRead for FailingReader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        if self.given {
+            return Poll::Ready(Err(io::Error::new(io::ErrorKind::Other, "boom")));
+        }
+        self.given = true;
+        let n = self.data.len().min(buf.len());
+        buf[..n].copy_from_slice(&self.data[..n]);
+        Poll::Ready(Ok(n))
+    }
+}
+
+#[test]
+fn reports_cumulative_totals() {
+    block_on(async {
+        let reader = Cursor::new((0..100).collect::<Vec<u8>>());
+        let mut writer = ChunkWriter { written: Vec::new(), chunk: 30 };
+
+        let mut reported = Vec::new();
+        let bytes = io::copy_with_progress(reader, &mut writer, |copied| reported.push(copied))
+            .await
+            .unwrap();
+
+        assert_eq!(bytes, 100);
+        assert_eq!(writer.written, (0..100).collect::<Vec<u8>>());
+        // Cumulative totals follow the writer's 30-byte chunks.
+        assert_eq!(reported, vec![30, 60, 90, 100]);
+    })
+}
+
+#[test]
+fn zero_length_source_never_reports() {
+    block_on(async {
+        let reader = Cursor::new(Vec::new());
+        let mut writer = Cursor::new(Vec::new());
+
+        let mut calls = 0;
+        let bytes = io::copy_with_progress(reader, &mut writer, |_| calls += 1).await.unwrap();
+
+        assert_eq!(bytes, 0);
+        assert_eq!(calls, 0);
+        writer.close().await.unwrap();
+    })
+}
+
+#[test]
+fn error_mid_copy_reports_bytes_so_far() {
+    block_on(async {
+        let reader = FailingReader { data: vec![1, 2, 3], given: false };
+        let mut writer = Cursor::new(Vec::new());
+
+        let mut last = 0;
+        let err =
+            io::copy_with_progress(reader, &mut writer, |copied| last = copied).await.unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::Other);
+        // The callback saw everything that made it to the writer.
+        assert_eq!(last, 3);
+        assert_eq!(writer.get_ref(), &[1, 2, 3]);
+    })
+}
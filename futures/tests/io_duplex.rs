@@ -0,0 +1,99 @@
+use futures::executor::block_on;
+use futures::future::poll_fn;
+use futures::io::{self, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+use std::pin::Pin;
+
+#[test]
+fn round_trip() {
+    block_on(async {
+        let (mut a, mut b) = io::duplex(64);
+
+        a.write_all(b"ping").await.unwrap();
+        let mut buf = [0; 4];
+        b.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf, b"ping");
+
+        b.write_all(b"pong").await.unwrap();
+        a.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf, b"pong");
+    })
+}
+
+#[test]
+fn backpressure_on_full_buffer() {
+    let (mut a, mut b) = io::duplex(4);
+    let mut cx = noop_context();
+
+    // Only `max_buf_size` bytes fit; the rest has to wait for the reader.
+    assert_eq!(
+        Pin::new(&mut a).poll_write(&mut cx, b"abcdef").map_err(|e| e.kind()),
+        Poll::Ready(Ok(4))
+    );
+    assert!(Pin::new(&mut a).poll_write(&mut cx, b"ef").is_pending());
+
+    // Draining the buffer unblocks the writer.
+    let mut buf = [0; 4];
+    assert_eq!(
+        Pin::new(&mut b).poll_read(&mut cx, &mut buf).map_err(|e| e.kind()),
+        Poll::Ready(Ok(4))
+    );
+    assert_eq!(&buf, b"abcd");
+    assert_eq!(
+        Pin::new(&mut a).poll_write(&mut cx, b"ef").map_err(|e| e.kind()),
+        Poll::Ready(Ok(2))
+    );
+}
+
+#[test]
+fn close_propagates_eof() {
+    block_on(async {
+        let (mut a, mut b) = io::duplex(64);
+
+        a.write_all(b"last words").await.unwrap();
+        a.close().await.unwrap();
+
+        // Buffered data is still delivered before EOF.
+        let mut buf = Vec::new();
+        b.read_to_end(&mut buf).await.unwrap();
+        assert_eq!(buf, b"last words");
+    })
+}
+
+#[test]
+fn drop_causes_eof_and_broken_pipe() {
+    block_on(async {
+        let (a, mut b) = io::duplex(64);
+        drop(a);
+
+        let mut buf = [0; 4];
+        assert_eq!(b.read(&mut buf).await.unwrap(), 0);
+        let err = b.write(b"nobody home").await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+    })
+}
+
+#[test]
+fn pending_read_woken_by_write() {
+    block_on(async {
+        let (mut a, mut b) = io::duplex(64);
+
+        let mut buf = [0; 4];
+        let mut started = false;
+        poll_fn(|cx| {
+            if !started {
+                started = true;
+                // Nothing buffered yet; the read must park and be woken by
+                // the write below.
+                assert!(Pin::new(&mut b).poll_read(cx, &mut buf).is_pending());
+                assert!(Pin::new(&mut a).poll_write(cx, b"wake").is_ready());
+                return Poll::Pending;
+            }
+            Pin::new(&mut b).poll_read(cx, &mut buf)
+        })
+        .await
+        .unwrap();
+        assert_eq!(&buf, b"wake");
+    })
+}
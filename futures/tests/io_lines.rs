@@ -41,6 +41,32 @@ fn lines() {
     assert!(block_on(s.next()).is_none());
 }
 
+#[test]
+fn missing_final_newline() {
+    let buf = Cursor::new(&b"one\ntwo\nthree"[..]);
+    let mut s = buf.lines();
+    assert_eq!(block_on_next!(s), "one".to_string());
+    assert_eq!(block_on_next!(s), "two".to_string());
+    assert_eq!(block_on_next!(s), "three".to_string());
+    assert!(block_on(s.next()).is_none());
+}
+
+#[test]
+fn empty_reader() {
+    let buf = Cursor::new(&b""[..]);
+    let mut s = buf.lines();
+    assert!(block_on(s.next()).is_none());
+}
+
+#[test]
+fn invalid_utf8() {
+    let buf = Cursor::new(&b"valid\n\xff\xfe\n"[..]);
+    let mut s = buf.lines();
+    assert_eq!(block_on_next!(s), "valid".to_string());
+    let err = block_on(s.next()).unwrap().unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
+
 #[test]
 fn maybe_pending() {
     let buf =
@@ -0,0 +1,75 @@
+use futures::{
+    executor::block_on,
+    io::{self, AsyncRead, AsyncReadExt, Cursor},
+    task::{Context, Poll},
+};
+use std::pin::Pin;
+
+/// A reader that hands out its data at most `chunk` bytes at a time.
+struct Trickle {
+    data: Vec<u8>,
+    pos: usize,
+    chunk: usize,
+}
+
+impl AsyncRead for Trickle {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let n = buf.len().min(self.chunk).min(self.data.len() - self.pos);
+        buf[..n].copy_from_slice(&self.data[self.pos..self.pos + n]);
+        self.pos += n;
+        Poll::Ready(Ok(n))
+    }
+}
+
+#[test]
+fn fills_buffer_completely() {
+    block_on(async {
+        let mut reader = Cursor::new([1, 2, 3, 4, 5]);
+        let mut buf = [0u8; 4];
+
+        let n = reader.read_exact_or_eof(&mut buf).await.unwrap();
+        assert_eq!(n, 4);
+        assert_eq!(buf, [1, 2, 3, 4]);
+    })
+}
+
+#[test]
+fn short_read_at_eof() {
+    block_on(async {
+        let mut reader = Cursor::new([1, 2, 3]);
+        let mut buf = [0u8; 8];
+
+        let n = reader.read_exact_or_eof(&mut buf).await.unwrap();
+        assert_eq!(n, 3);
+        assert_eq!(&buf[..3], [1, 2, 3]);
+    })
+}
+
+#[test]
+fn zero_on_clean_eof() {
+    block_on(async {
+        let mut reader = Cursor::new([]);
+        let mut buf = [0u8; 4];
+
+        let n = reader.read_exact_or_eof(&mut buf).await.unwrap();
+        assert_eq!(n, 0);
+    })
+}
+
+#[test]
+fn loops_over_small_reads() {
+    block_on(async {
+        // The source yields two bytes per read, so filling the buffer takes
+        // several reads and the final record is still short.
+        let mut reader = Trickle { data: (1..=7).collect(), pos: 0, chunk: 2 };
+        let mut buf = [0u8; 10];
+
+        let n = reader.read_exact_or_eof(&mut buf).await.unwrap();
+        assert_eq!(n, 7);
+        assert_eq!(&buf[..7], [1, 2, 3, 4, 5, 6, 7]);
+    })
+}
@@ -0,0 +1,69 @@
+use futures::{
+    executor::block_on,
+    io::{AsyncReadExt, Cursor},
+};
+use std::io::ErrorKind;
+
+#[test]
+fn under_limit() {
+    block_on(async {
+        let mut reader = Cursor::new(vec![1, 2, 3, 4]);
+        let mut buf = Vec::new();
+
+        let bytes = reader.read_to_end_limited(&mut buf, 8).await.unwrap();
+        assert_eq!(bytes, 4);
+        assert_eq!(buf, vec![1, 2, 3, 4]);
+    })
+}
+
+#[test]
+fn exactly_at_limit() {
+    block_on(async {
+        let mut reader = Cursor::new(vec![1, 2, 3, 4]);
+        let mut buf = Vec::new();
+
+        let bytes = reader.read_to_end_limited(&mut buf, 4).await.unwrap();
+        assert_eq!(bytes, 4);
+        assert_eq!(buf, vec![1, 2, 3, 4]);
+    })
+}
+
+#[test]
+fn over_limit() {
+    block_on(async {
+        let mut reader = Cursor::new((0..100).collect::<Vec<u8>>());
+        let mut buf = Vec::new();
+
+        let err = reader.read_to_end_limited(&mut buf, 10).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Other);
+        // The buffer holds exactly the allowed prefix of the source.
+        assert_eq!(buf, (0..10).collect::<Vec<u8>>());
+    })
+}
+
+#[test]
+fn zero_limit() {
+    block_on(async {
+        let mut reader = Cursor::new(vec![1]);
+        let mut buf = Vec::new();
+        assert!(reader.read_to_end_limited(&mut buf, 0).await.is_err());
+        assert!(buf.is_empty());
+
+        let mut reader = Cursor::new(Vec::<u8>::new());
+        let mut buf = Vec::new();
+        let bytes = reader.read_to_end_limited(&mut buf, 0).await.unwrap();
+        assert_eq!(bytes, 0);
+    })
+}
+
+#[test]
+fn existing_contents_do_not_count_against_limit() {
+    block_on(async {
+        let mut reader = Cursor::new(vec![3, 4]);
+        let mut buf = vec![1, 2];
+
+        let bytes = reader.read_to_end_limited(&mut buf, 2).await.unwrap();
+        assert_eq!(bytes, 2);
+        assert_eq!(buf, vec![1, 2, 3, 4]);
+    })
+}
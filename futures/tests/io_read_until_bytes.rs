@@ -0,0 +1,96 @@
+use futures::executor::block_on;
+use futures::io::{AsyncBufReadExt, BufReader, Cursor};
+use std::io::ErrorKind;
+
+#[test]
+fn delimiter_in_single_fill() {
+    block_on(async {
+        let mut cursor = Cursor::new(b"header\r\nbody");
+        let mut buf = vec![];
+
+        let n = cursor.read_until_bytes(b"\r\n", &mut buf).await.unwrap();
+        assert_eq!(n, 8);
+        assert_eq!(buf, b"header\r\n");
+
+        // The rest of the input is still there to be read.
+        buf.clear();
+        let n = cursor.read_until_bytes(b"\r\n", &mut buf).await.unwrap();
+        assert_eq!(n, 4);
+        assert_eq!(buf, b"body");
+    })
+}
+
+#[test]
+fn delimiter_straddles_fill_boundary() {
+    block_on(async {
+        // Capacity 4 places the boundary between `\r` and `\n`.
+        let mut reader = BufReader::with_capacity(4, Cursor::new(b"abc\r\ndef"));
+        let mut buf = vec![];
+
+        let n = reader.read_until_bytes(b"\r\n", &mut buf).await.unwrap();
+        assert_eq!(n, 5);
+        assert_eq!(buf, b"abc\r\n");
+
+        buf.clear();
+        let n = reader.read_until_bytes(b"\r\n", &mut buf).await.unwrap();
+        assert_eq!(n, 3);
+        assert_eq!(buf, b"def");
+    })
+}
+
+#[test]
+fn long_delimiter_spans_multiple_fills() {
+    block_on(async {
+        // The delimiter is longer than the internal buffer, so every match
+        // crosses at least one fill boundary.
+        let mut reader = BufReader::with_capacity(2, Cursor::new(b"xxENDMARKyy"));
+        let mut buf = vec![];
+
+        let n = reader.read_until_bytes(b"ENDMARK", &mut buf).await.unwrap();
+        assert_eq!(n, 9);
+        assert_eq!(buf, b"xxENDMARK");
+
+        buf.clear();
+        let n = reader.read_until_bytes(b"ENDMARK", &mut buf).await.unwrap();
+        assert_eq!(n, 2);
+        assert_eq!(buf, b"yy");
+    })
+}
+
+#[test]
+fn eof_before_delimiter() {
+    block_on(async {
+        let mut cursor = Cursor::new(b"no delimiter here");
+        let mut buf = vec![];
+
+        let n = cursor.read_until_bytes(b"\r\n", &mut buf).await.unwrap();
+        assert_eq!(n, 17);
+        assert_eq!(buf, b"no delimiter here");
+    })
+}
+
+#[test]
+fn false_start_across_boundary() {
+    block_on(async {
+        // `ab` at a fill boundary looks like the start of `abc` but isn't;
+        // the real match comes later.
+        let mut reader = BufReader::with_capacity(3, Cursor::new(b"xab!abc."));
+        let mut buf = vec![];
+
+        let n = reader.read_until_bytes(b"abc", &mut buf).await.unwrap();
+        assert_eq!(n, 7);
+        assert_eq!(buf, b"xab!abc");
+    })
+}
+
+#[test]
+fn empty_delimiter_is_an_error() {
+    block_on(async {
+        let mut cursor = Cursor::new(b"data");
+        let mut buf = vec![];
+
+        let err = cursor.read_until_bytes(b"", &mut buf).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        assert!(buf.is_empty());
+    })
+}
@@ -0,0 +1,51 @@
+use futures::executor::block_on;
+use futures::io::{self, AsyncReadExt, AsyncWrite, AsyncWriteExt, IoSlice};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+use std::pin::Pin;
+
+#[test]
+fn sink_discards_large_writes() {
+    block_on(async {
+        let mut writer = io::sink();
+        let chunk = vec![0xab; 64 * 1024];
+        for _ in 0..16 {
+            writer.write_all(&chunk).await.unwrap();
+        }
+        writer.flush().await.unwrap();
+        writer.close().await.unwrap();
+    })
+}
+
+#[test]
+fn sink_accepts_vectored_writes() {
+    let mut writer = io::sink();
+    let mut cx = noop_context();
+
+    let bufs = [IoSlice::new(b"hello"), IoSlice::new(b" "), IoSlice::new(b"world")];
+    assert_eq!(
+        Pin::new(&mut writer).poll_write_vectored(&mut cx, &bufs).map_err(|e| e.kind()),
+        Poll::Ready(Ok(11))
+    );
+}
+
+#[test]
+fn repeat_fills_any_buffer_and_never_ends() {
+    block_on(async {
+        let mut reader = io::repeat(0b101);
+
+        // Buffers of any size are filled completely in one read.
+        let mut small = [0; 1];
+        assert_eq!(reader.read(&mut small).await.unwrap(), 1);
+        assert_eq!(small, [0b101]);
+
+        let mut large = vec![0; 64 * 1024];
+        assert_eq!(reader.read(&mut large).await.unwrap(), large.len());
+        assert!(large.iter().all(|b| *b == 0b101));
+
+        // The reader itself never hits EOF; a bounded prefix requires `take`.
+        let mut prefix = Vec::new();
+        reader.take(7).read_to_end(&mut prefix).await.unwrap();
+        assert_eq!(prefix, [0b101; 7]);
+    })
+}
@@ -0,0 +1,71 @@
+use futures::executor::block_on;
+use futures::io::{AsyncReadExt, Cursor};
+
+#[test]
+fn read_exactly_to_limit() {
+    block_on(async {
+        let reader = Cursor::new(b"12345678".to_vec());
+        let mut take = reader.take(4);
+
+        let mut buf = Vec::new();
+        let n = take.read_to_end(&mut buf).await.unwrap();
+        assert_eq!(n, 4);
+        assert_eq!(buf, b"1234");
+        assert_eq!(take.limit(), 0);
+
+        // Once the limit is hit, every further read reports EOF.
+        let mut probe = [0u8; 1];
+        assert_eq!(take.read(&mut probe).await.unwrap(), 0);
+
+        // The underlying reader was never asked for more than the limit.
+        let inner = take.into_inner();
+        assert_eq!(inner.position(), 4);
+    })
+}
+
+#[test]
+fn reads_are_clamped_to_remaining_limit() {
+    block_on(async {
+        let reader = Cursor::new(b"abcdef".to_vec());
+        let mut take = reader.take(3);
+
+        // A buffer larger than the limit only receives `limit` bytes.
+        let mut buf = [0u8; 6];
+        let n = take.read(&mut buf).await.unwrap();
+        assert_eq!(n, 3);
+        assert_eq!(&buf[..3], b"abc");
+        assert_eq!(take.into_inner().position(), 3);
+    })
+}
+
+#[test]
+fn limit_beyond_source_length() {
+    block_on(async {
+        let reader = Cursor::new(b"ab".to_vec());
+        let mut take = reader.take(10);
+
+        let mut buf = Vec::new();
+        let n = take.read_to_end(&mut buf).await.unwrap();
+        assert_eq!(n, 2);
+        assert_eq!(buf, b"ab");
+        // The source ran out first, so the limit isn't fully consumed.
+        assert_eq!(take.limit(), 8);
+    })
+}
+
+#[test]
+fn set_limit_resets_eof() {
+    block_on(async {
+        let reader = Cursor::new(b"12345678".to_vec());
+        let mut take = reader.take(2);
+
+        let mut buf = [0u8; 8];
+        assert_eq!(take.read(&mut buf).await.unwrap(), 2);
+        assert_eq!(take.read(&mut buf).await.unwrap(), 0);
+
+        take.set_limit(2);
+        let n = take.read(&mut buf).await.unwrap();
+        assert_eq!(n, 2);
+        assert_eq!(&buf[..2], b"34");
+    })
+}
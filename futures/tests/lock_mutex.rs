@@ -64,3 +64,79 @@ fn mutex_contested() {
         assert_eq!(num_tasks, *lock);
     })
 }
+
+#[test]
+fn owned_guard_provides_mutual_exclusion() {
+    let mutex = Arc::new(Mutex::new(()));
+
+    let guard = mutex.clone().try_lock_owned().unwrap();
+    assert!(mutex.clone().try_lock_owned().is_none());
+    assert!(mutex.try_lock().is_none());
+
+    drop(guard);
+    assert!(mutex.try_lock().is_some());
+}
+
+#[test]
+fn owned_guard_drop_wakes_waiters() {
+    let mutex = Arc::new(Mutex::new(()));
+    let (waker, counter) = new_count_waker();
+    let lock = mutex.clone().try_lock_owned().unwrap();
+
+    let mut cx = Context::from_waker(&waker);
+    let mut waiter = mutex.clone().lock_owned();
+    assert!(waiter.poll_unpin(&mut cx).is_pending());
+    assert_eq!(counter, 0);
+
+    drop(lock);
+
+    assert_eq!(counter, 1);
+    assert!(waiter.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[test]
+fn owned_guard_moves_into_spawned_task() {
+    let (tx, mut rx) = mpsc::unbounded();
+    let pool = ThreadPool::builder().pool_size(4).create().unwrap();
+
+    let tx = Arc::new(tx);
+    let mutex = Arc::new(Mutex::new(0));
+
+    let num_tasks = 100;
+    for _ in 0..num_tasks {
+        let tx = tx.clone();
+        let mutex = mutex.clone();
+        pool.spawn(async move {
+            let mut lock = mutex.lock_owned().await;
+            ready(()).pending_once().await;
+            *lock += 1;
+            tx.unbounded_send(()).unwrap();
+            drop(lock);
+        })
+        .unwrap();
+    }
+
+    block_on(async {
+        for _ in 0..num_tasks {
+            rx.next().await.unwrap();
+        }
+        let lock = mutex.lock().await;
+        assert_eq!(num_tasks, *lock);
+    })
+}
+
+#[test]
+fn get_mut_mutates_without_locking() {
+    let mut mutex = Mutex::new(1);
+    *mutex.get_mut() += 1;
+    assert_eq!(*block_on(mutex.lock()), 2);
+}
+
+#[test]
+fn into_inner_returns_value() {
+    let mutex = Mutex::new(vec![1, 2, 3]);
+    // Waiter bookkeeping from an abandoned lock attempt must not affect the
+    // extracted value.
+    drop(mutex.lock());
+    assert_eq!(mutex.into_inner(), vec![1, 2, 3]);
+}
@@ -0,0 +1,149 @@
+use futures::channel::mpsc;
+use futures::executor::{block_on, ThreadPool};
+use futures::future::{ready, FutureExt};
+use futures::lock::RwLock;
+use futures::stream::StreamExt;
+use futures::task::{Context, SpawnExt};
+use futures_test::future::FutureTestExt;
+use futures_test::task::{new_count_waker, panic_context};
+use std::sync::Arc;
+
+#[test]
+fn multiple_readers_proceed_concurrently() {
+    let rwlock = RwLock::new(1);
+    let a = rwlock.try_read().unwrap();
+    let b = rwlock.try_read().unwrap();
+    assert!(rwlock.read().poll_unpin(&mut panic_context()).is_ready());
+    assert_eq!(*a + *b, 2);
+    assert!(rwlock.try_write().is_none());
+}
+
+#[test]
+fn writer_excludes_readers_and_writers() {
+    let rwlock = RwLock::new(1);
+    let mut write = rwlock.try_write().unwrap();
+    *write += 1;
+
+    assert!(rwlock.try_read().is_none());
+    assert!(rwlock.try_write().is_none());
+
+    drop(write);
+    assert_eq!(*rwlock.try_read().unwrap(), 2);
+}
+
+#[test]
+fn writer_waits_for_all_readers() {
+    let rwlock = RwLock::new(());
+    let a = rwlock.try_read().unwrap();
+    let b = rwlock.try_read().unwrap();
+
+    let (waker, counter) = new_count_waker();
+    let mut write = rwlock.write();
+    assert!(write.poll_unpin(&mut Context::from_waker(&waker)).is_pending());
+
+    drop(a);
+    assert_eq!(counter, 0);
+    drop(b);
+    assert_eq!(counter, 1);
+    assert!(write.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[test]
+fn pending_writer_blocks_new_readers() {
+    let rwlock = RwLock::new(());
+    let read = rwlock.try_read().unwrap();
+
+    let (write_waker, _write_count) = new_count_waker();
+    let mut write = rwlock.write();
+    assert!(write.poll_unpin(&mut Context::from_waker(&write_waker)).is_pending());
+
+    // A reader arriving after the writer queues behind it instead of
+    // starving it.
+    assert!(rwlock.try_read().is_none());
+    let (read_waker, read_count) = new_count_waker();
+    let mut late_read = rwlock.read();
+    assert!(late_read.poll_unpin(&mut Context::from_waker(&read_waker)).is_pending());
+
+    drop(read);
+    let guard = block_on(write);
+    assert_eq!(read_count, 0);
+
+    drop(guard);
+    assert_eq!(read_count, 1);
+    assert!(late_read.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[test]
+fn readers_at_queue_front_are_granted_together() {
+    let rwlock = RwLock::new(());
+    let write = rwlock.try_write().unwrap();
+
+    let (waker1, count1) = new_count_waker();
+    let (waker2, count2) = new_count_waker();
+    let mut read1 = rwlock.read();
+    let mut read2 = rwlock.read();
+    assert!(read1.poll_unpin(&mut Context::from_waker(&waker1)).is_pending());
+    assert!(read2.poll_unpin(&mut Context::from_waker(&waker2)).is_pending());
+
+    drop(write);
+    assert_eq!(count1, 1);
+    assert_eq!(count2, 1);
+    assert!(read1.poll_unpin(&mut panic_context()).is_ready());
+    assert!(read2.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[test]
+fn dropped_waiter_passes_the_lock_on() {
+    let rwlock = RwLock::new(());
+    let read = rwlock.try_read().unwrap();
+
+    let (waker1, _count1) = new_count_waker();
+    let (waker2, count2) = new_count_waker();
+    let mut write = rwlock.write();
+    let mut late_read = rwlock.read();
+    assert!(write.poll_unpin(&mut Context::from_waker(&waker1)).is_pending());
+    assert!(late_read.poll_unpin(&mut Context::from_waker(&waker2)).is_pending());
+
+    // The exclusive lock is granted to the writer, which is dropped without
+    // ever observing it; the queued reader inherits the lock.
+    drop(read);
+    drop(write);
+    assert_eq!(count2, 1);
+    assert!(late_read.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[test]
+fn rwlock_contested() {
+    let (tx, mut rx) = mpsc::unbounded();
+    let pool = ThreadPool::builder().pool_size(8).create().unwrap();
+
+    let tx = Arc::new(tx);
+    let rwlock = Arc::new(RwLock::new(0));
+
+    let num_tasks = 100;
+    for i in 0..num_tasks {
+        let tx = tx.clone();
+        let rwlock = rwlock.clone();
+        pool.spawn(async move {
+            if i % 2 == 0 {
+                let mut lock = rwlock.write().await;
+                ready(()).pending_once().await;
+                *lock += 1;
+            } else {
+                let lock = rwlock.read().await;
+                ready(()).pending_once().await;
+                assert!(*lock <= num_tasks / 2);
+            }
+            tx.unbounded_send(()).unwrap();
+        })
+        .unwrap();
+    }
+
+    block_on(async {
+        for _ in 0..num_tasks {
+            rx.next().await.unwrap();
+        }
+        let lock = rwlock.read().await;
+        assert_eq!(num_tasks / 2, *lock);
+    })
+}
@@ -0,0 +1,184 @@
+use futures::channel::mpsc;
+use futures::executor::{block_on, ThreadPool};
+use futures::future::{ready, FutureExt};
+use futures::lock::Semaphore;
+use futures::stream::StreamExt;
+use futures::task::{Context, SpawnExt};
+use futures_test::future::FutureTestExt;
+use futures_test::task::{new_count_waker, panic_context};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+#[test]
+fn acquire_uncontested() {
+    let semaphore = Semaphore::new(2);
+    let a = block_on(semaphore.acquire()).unwrap();
+    let _b = block_on(semaphore.acquire()).unwrap();
+    assert_eq!(semaphore.available_permits(), 0);
+    assert!(semaphore.try_acquire().is_none());
+
+    drop(a);
+    assert_eq!(semaphore.available_permits(), 1);
+    assert!(semaphore.try_acquire().is_some());
+}
+
+#[test]
+fn waiters_are_woken_in_fifo_order() {
+    let semaphore = Semaphore::new(1);
+    let permit = semaphore.try_acquire().unwrap();
+
+    let (waker1, count1) = new_count_waker();
+    let (waker2, count2) = new_count_waker();
+    let mut first = semaphore.acquire();
+    let mut second = semaphore.acquire();
+    assert!(first.poll_unpin(&mut Context::from_waker(&waker1)).is_pending());
+    assert!(second.poll_unpin(&mut Context::from_waker(&waker2)).is_pending());
+
+    // Releasing one permit wakes only the first waiter, even though the
+    // second would also be satisfied by it.
+    drop(permit);
+    assert_eq!(count1, 1);
+    assert_eq!(count2, 0);
+
+    let first_permit = match first.poll_unpin(&mut panic_context()) {
+        futures::task::Poll::Ready(Ok(permit)) => permit,
+        _ => panic!("first waiter should hold the permit"),
+    };
+    assert!(second.poll_unpin(&mut Context::from_waker(&waker2)).is_pending());
+
+    drop(first_permit);
+    assert_eq!(count2, 1);
+    assert!(second.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[test]
+fn acquire_many_blocks_later_small_requests() {
+    let semaphore = Semaphore::new(2);
+    let permit = semaphore.try_acquire().unwrap();
+
+    let (waker1, _count1) = new_count_waker();
+    let (waker2, count2) = new_count_waker();
+    let mut big = semaphore.acquire_many(2);
+    let mut small = semaphore.acquire();
+    assert!(big.poll_unpin(&mut Context::from_waker(&waker1)).is_pending());
+    assert!(small.poll_unpin(&mut Context::from_waker(&waker2)).is_pending());
+
+    // One free permit is not handed to the later small request while the big
+    // one is still waiting at the front of the queue.
+    drop(permit);
+    assert_eq!(count2, 0);
+
+    let big_permit = block_on(big).unwrap();
+    assert_eq!(big_permit.permits(), 2);
+    drop(big_permit);
+    assert!(small.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[test]
+fn acquire_many_more_than_total_fails() {
+    let semaphore = Semaphore::new(2);
+    let err = block_on(semaphore.acquire_many(3)).unwrap_err();
+    assert!(err.is_too_many_permits());
+    assert!(!err.is_closed());
+}
+
+#[test]
+fn close_fails_pending_and_future_acquires() {
+    let semaphore = Semaphore::new(1);
+    let permit = semaphore.try_acquire().unwrap();
+
+    let (waker, count) = new_count_waker();
+    let mut waiter = semaphore.acquire();
+    assert!(waiter.poll_unpin(&mut Context::from_waker(&waker)).is_pending());
+
+    semaphore.close();
+    assert_eq!(count, 1);
+    let err = block_on(waiter).unwrap_err();
+    assert!(err.is_closed());
+    assert!(block_on(semaphore.acquire()).unwrap_err().is_closed());
+    assert!(semaphore.try_acquire().is_none());
+
+    // Outstanding permits are still returned on drop.
+    drop(permit);
+    assert_eq!(semaphore.available_permits(), 1);
+}
+
+#[test]
+fn release_after_close_does_not_grant() {
+    let semaphore = Semaphore::new(1);
+    let permit = semaphore.try_acquire().unwrap();
+
+    let (waker, _count) = new_count_waker();
+    let mut waiter = semaphore.acquire();
+    assert!(waiter.poll_unpin(&mut Context::from_waker(&waker)).is_pending());
+
+    // The permit comes back only after the semaphore has been closed. The
+    // pending waiter must still fail rather than be handed the permit.
+    semaphore.close();
+    drop(permit);
+    let err = block_on(waiter).unwrap_err();
+    assert!(err.is_closed());
+    assert_eq!(semaphore.available_permits(), 1);
+}
+
+#[test]
+fn dropped_waiter_passes_permits_on() {
+    let semaphore = Semaphore::new(1);
+    let permit = semaphore.try_acquire().unwrap();
+
+    let (waker1, _count1) = new_count_waker();
+    let (waker2, count2) = new_count_waker();
+    let mut first = semaphore.acquire();
+    let mut second = semaphore.acquire();
+    assert!(first.poll_unpin(&mut Context::from_waker(&waker1)).is_pending());
+    assert!(second.poll_unpin(&mut Context::from_waker(&waker2)).is_pending());
+
+    // The permit is granted to the first waiter, which is then dropped
+    // without ever observing it; the second waiter inherits it.
+    drop(permit);
+    drop(first);
+    assert_eq!(count2, 1);
+    assert!(second.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[test]
+fn bounds_concurrency_across_tasks() {
+    let (tx, mut rx) = mpsc::unbounded();
+    let pool = ThreadPool::builder().pool_size(8).create().unwrap();
+
+    let tx = Arc::new(tx);
+    let semaphore = Arc::new(Semaphore::new(3));
+    let running = Arc::new(AtomicUsize::new(0));
+    let max_running = Arc::new(AtomicUsize::new(0));
+
+    let num_tasks = 200;
+    for _ in 0..num_tasks {
+        let tx = tx.clone();
+        let semaphore = semaphore.clone();
+        let running = running.clone();
+        let max_running = max_running.clone();
+        pool.spawn(async move {
+            let _permit = semaphore.acquire().await.unwrap();
+            let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+            let mut max = max_running.load(Ordering::SeqCst);
+            while now > max {
+                match max_running.compare_exchange(max, now, Ordering::SeqCst, Ordering::SeqCst) {
+                    Ok(_) => break,
+                    Err(actual) => max = actual,
+                }
+            }
+            ready(()).pending_once().await;
+            running.fetch_sub(1, Ordering::SeqCst);
+            tx.unbounded_send(()).unwrap();
+        })
+        .unwrap();
+    }
+
+    block_on(async {
+        for _ in 0..num_tasks {
+            rx.next().await.unwrap();
+        }
+    });
+    assert!(max_running.load(Ordering::SeqCst) <= 3);
+    assert_eq!(semaphore.available_permits(), 3);
+}
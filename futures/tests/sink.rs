@@ -334,6 +334,46 @@ fn with_flat_map() {
     assert_eq!(sink.get_ref(), &[1, 2, 2, 3, 3, 3]);
 }
 
+// The expansion stream must be fully drained into the sink before
+// `poll_ready` accepts the next input.
+#[test]
+fn with_flat_map_drains_before_ready() {
+    let (tx, mut rx) = mpsc::channel::<i32>(0);
+    let mut tx = tx.with_flat_map(|item: i32| stream::iter(vec![item, item + 1, item + 2]).map(Ok));
+
+    block_on(future::lazy(|_| {
+        flag_cx(|_flag, cx| {
+            let mut tx = Pin::new(&mut tx);
+
+            assert_eq!(tx.as_mut().poll_ready(cx), Poll::Ready(Ok(())));
+            tx.as_mut().start_send(10).unwrap();
+
+            // Only part of the expansion fits into the channel, so the sink
+            // is not ready for a new input until all three items are through.
+            assert!(tx.as_mut().poll_ready(cx).is_pending());
+            sassert_next(&mut rx, 10);
+            assert!(tx.as_mut().poll_ready(cx).is_pending());
+            sassert_next(&mut rx, 11);
+            // The last expanded item goes through on this poll, so the sink
+            // becomes ready for the next input again.
+            assert_eq!(tx.as_mut().poll_ready(cx), Poll::Ready(Ok(())));
+            sassert_next(&mut rx, 12);
+        })
+    }));
+}
+
+// An error from the expansion stream propagates out of the sink.
+#[test]
+fn with_flat_map_propagates_stream_error() {
+    let mut sink = Vec::new()
+        .sink_map_err(|_: Infallible| "sink")
+        .with_flat_map(|item: i32| stream::iter(vec![Ok(item), Err("boom"), Ok(item)]));
+
+    assert_eq!(block_on(sink.send(1)), Err("boom"));
+    // The item produced before the error was still delivered.
+    assert_eq!(sink.get_ref().get_ref(), &[1]);
+}
+
 // Check that `with` propagates `poll_ready` to the inner sink.
 // Regression test for the issue #1834.
 #[test]
@@ -530,6 +570,59 @@ fn sink_unfold() {
     }))
 }
 
+#[test]
+fn sink_unfold_counter() {
+    // The state returned by each send future is threaded into the next one.
+    let observed = Rc::new(Cell::new(0));
+    block_on(async {
+        let observed = observed.clone();
+        let unfold = sink::unfold(0, move |sum, i: i32| {
+            let observed = observed.clone();
+            async move {
+                let sum = sum + i;
+                observed.set(sum);
+                Ok::<_, Infallible>(sum)
+            }
+        });
+        futures::pin_mut!(unfold);
+        for i in 1..=4 {
+            unfold.send(i).await.unwrap();
+        }
+        unfold.close().await.unwrap();
+    });
+    assert_eq!(observed.get(), 10);
+}
+
+#[test]
+fn sink_unfold_poll_ready_pending() {
+    block_on(poll_fn(|cx| {
+        let (tx, rx) = oneshot::channel::<()>();
+        let mut rx = Some(rx);
+        let unfold = sink::unfold((), move |(), ()| {
+            let rx = rx.take().unwrap();
+            async move {
+                rx.await.unwrap();
+                Ok::<_, Infallible>(())
+            }
+        });
+        futures::pin_mut!(unfold);
+
+        assert_eq!(unfold.as_mut().poll_ready(cx), Poll::Ready(Ok(())));
+        assert_eq!(unfold.as_mut().start_send(()), Ok(()));
+        // The send future is blocked on the oneshot, so the sink is neither
+        // ready for another item nor flushed or closable.
+        assert_eq!(unfold.as_mut().poll_ready(cx), Poll::Pending);
+        assert_eq!(unfold.as_mut().poll_flush(cx), Poll::Pending);
+        assert_eq!(unfold.as_mut().poll_close(cx), Poll::Pending);
+
+        tx.send(()).unwrap();
+        assert_eq!(unfold.as_mut().poll_ready(cx), Poll::Ready(Ok(())));
+        assert_eq!(unfold.as_mut().poll_close(cx), Poll::Ready(Ok(())));
+
+        Poll::Ready(())
+    }))
+}
+
 #[test]
 fn err_into() {
     #[derive(Copy, Clone, Debug, PartialEq, Eq)]
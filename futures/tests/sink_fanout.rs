@@ -1,8 +1,10 @@
 use futures::channel::mpsc;
 use futures::executor::block_on;
 use futures::join;
-use futures::sink::SinkExt;
+use futures::sink::{Sink, SinkExt};
 use futures::stream::{self, StreamExt};
+use futures::task::{Context, Poll};
+use std::pin::Pin;
 
 #[test]
 fn it_works() {
@@ -22,3 +24,94 @@ fn it_works() {
     assert_eq!(vec1, expected);
     assert_eq!(vec2, expected);
 }
+
+/// An always-ready sink that collects every item into a `Vec`.
+#[derive(Default)]
+struct VecSink<T> {
+    items: Vec<T>,
+}
+
+impl<T> Unpin for VecSink<T> {}
+
+impl<T> Sink<T> for VecSink<T> {
+    type Error = ();
+
+    fn poll_ready(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        self.items.push(item);
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[test]
+fn both_vec_sinks_receive_all_items() {
+    block_on(async {
+        let mut fanout = VecSink::default().fanout(VecSink::default());
+        for i in 0..10 {
+            fanout.send(i).await.unwrap();
+        }
+        fanout.close().await.unwrap();
+
+        let (sink1, sink2) = fanout.into_inner();
+        assert_eq!(sink1.items, (0..10).collect::<Vec<_>>());
+        assert_eq!(sink2.items, (0..10).collect::<Vec<_>>());
+    });
+}
+
+/// A sink that fails `start_send` after accepting a fixed number of items.
+struct FailAfter {
+    items: Vec<i32>,
+    remaining: usize,
+}
+
+impl Sink<i32> for FailAfter {
+    type Error = ();
+
+    fn poll_ready(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, item: i32) -> Result<(), Self::Error> {
+        if self.remaining == 0 {
+            return Err(());
+        }
+        self.remaining -= 1;
+        self.items.push(item);
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[test]
+fn error_in_first_sink_skips_second() {
+    block_on(async {
+        let mut fanout = FailAfter { items: Vec::new(), remaining: 1 }.fanout(VecSink::default());
+
+        assert_eq!(fanout.send(1).await, Ok(()));
+        // The first sink rejects the second item, so the error surfaces and
+        // the second sink never sees it.
+        assert_eq!(fanout.send(2).await, Err(()));
+
+        let (sink1, sink2) = fanout.into_inner();
+        assert_eq!(sink1.items, vec![1]);
+        assert_eq!(sink2.items, vec![1]);
+    });
+}
@@ -0,0 +1,173 @@
+use futures::executor::block_on;
+use futures::future::FutureExt;
+use futures::sink::{Sink, SinkExt};
+use futures::stream::{self, StreamExt};
+use futures::task::{noop_waker_ref, Context, Poll};
+use std::pin::Pin;
+
+/// A sink that records every item and counts flushes. It accepts up to
+/// `capacity` items between flushes and reports not-ready once full.
+struct CountingSink {
+    items: Vec<i32>,
+    unflushed: usize,
+    capacity: usize,
+    flushes: usize,
+}
+
+impl CountingSink {
+    fn new(capacity: usize) -> Self {
+        Self { items: Vec::new(), unflushed: 0, capacity, flushes: 0 }
+    }
+}
+
+impl Unpin for CountingSink {}
+
+impl Sink<i32> for CountingSink {
+    type Error = ();
+
+    fn poll_ready(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        if self.unflushed < self.capacity {
+            Poll::Ready(Ok(()))
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, item: i32) -> Result<(), Self::Error> {
+        assert!(self.unflushed < self.capacity);
+        self.items.push(item);
+        self.unflushed += 1;
+        Ok(())
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        if self.unflushed > 0 {
+            self.unflushed = 0;
+            self.flushes += 1;
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.poll_flush(cx)
+    }
+}
+
+#[test]
+fn flushes_once_when_sink_keeps_up() {
+    block_on(async {
+        let mut sink = CountingSink::new(usize::MAX);
+        sink.send_all_buffered(stream::iter((0..10).map(Ok))).await.unwrap();
+
+        assert_eq!(sink.items, (0..10).collect::<Vec<_>>());
+        // The sink never stalled, so the whole stream is one batch.
+        assert_eq!(sink.flushes, 1);
+    });
+}
+
+#[test]
+fn flushes_when_sink_is_not_ready() {
+    block_on(async {
+        let mut sink = CountingSink::new(3);
+        sink.send_all_buffered(stream::iter((0..10).map(Ok))).await.unwrap();
+
+        assert_eq!(sink.items, (0..10).collect::<Vec<_>>());
+        // The sink fills up after every three items, so ten items need four
+        // batches: three full ones and the final one on stream end.
+        assert_eq!(sink.flushes, 4);
+    });
+}
+
+#[test]
+fn flushes_when_stream_is_pending() {
+    let waker = noop_waker_ref();
+    let mut cx = Context::from_waker(waker);
+
+    let mut sink = CountingSink::new(usize::MAX);
+    let mut yielded = false;
+    let st = stream::iter((0..5).map(Ok)).chain(stream::poll_fn(move |_| {
+        if yielded {
+            Poll::Ready(None)
+        } else {
+            yielded = true;
+            Poll::Pending
+        }
+    }));
+
+    {
+        let mut fut = sink.send_all_buffered(st);
+        // The stream goes pending after the first five items, which must
+        // flush the batch accumulated so far.
+        assert_eq!(fut.poll_unpin(&mut cx), Poll::Pending);
+        assert_eq!(fut.poll_unpin(&mut cx), Poll::Ready(Ok(())));
+    }
+
+    assert_eq!(sink.items, (0..5).collect::<Vec<_>>());
+    assert_eq!(sink.flushes, 1);
+}
+
+#[test]
+fn stream_error_is_returned_without_flushing() {
+    block_on(async {
+        let mut sink = CountingSink::new(usize::MAX);
+        let st = stream::iter(vec![Ok(1), Ok(2), Err(()), Ok(3)]);
+        assert_eq!(sink.send_all_buffered(st).await, Err(()));
+
+        // Items before the error were handed to the sink but not flushed.
+        assert_eq!(sink.items, vec![1, 2]);
+        assert_eq!(sink.flushes, 0);
+    });
+}
+
+/// Like [`CountingSink`], but every flush takes two polls to complete.
+struct SlowFlushSink {
+    inner: CountingSink,
+    flush_started: bool,
+}
+
+impl Unpin for SlowFlushSink {}
+
+impl Sink<i32> for SlowFlushSink {
+    type Error = ();
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Pin::new(&mut self.inner).poll_ready(cx)
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, item: i32) -> Result<(), Self::Error> {
+        Pin::new(&mut self.inner).start_send(item)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        if self.inner.unflushed > 0 && !self.flush_started {
+            self.flush_started = true;
+            return Poll::Pending;
+        }
+        self.flush_started = false;
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.poll_flush(cx)
+    }
+}
+
+#[test]
+fn parked_item_is_delivered_after_flush() {
+    let waker = noop_waker_ref();
+    let mut cx = Context::from_waker(waker);
+
+    let mut sink = SlowFlushSink { inner: CountingSink::new(2), flush_started: false };
+    {
+        let mut fut = sink.send_all_buffered(stream::iter((0..3).map(Ok)));
+        // The first poll sends two items, parks the third and starts a flush;
+        // the second poll finishes the flush, delivers the parked item and
+        // starts the final flush, which the third poll completes.
+        assert_eq!(fut.poll_unpin(&mut cx), Poll::Pending);
+        assert_eq!(fut.poll_unpin(&mut cx), Poll::Pending);
+        assert_eq!(fut.poll_unpin(&mut cx), Poll::Ready(Ok(())));
+    }
+
+    assert_eq!(sink.inner.items, vec![0, 1, 2]);
+    assert_eq!(sink.inner.flushes, 2);
+}
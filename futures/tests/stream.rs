@@ -66,6 +66,49 @@ fn scan() {
     });
 }
 
+#[test]
+fn scan_with_awaiting_step_future() {
+    use futures_test::future::FutureTestExt;
+
+    // The accumulator step is an arbitrary future; only one step is in
+    // flight at a time, so output order matches input order even when each
+    // step yields to the executor before resolving.
+    block_on(async {
+        let values = stream::iter(vec![1u8, 2, 3])
+            .scan(0u8, |mut state, e| {
+                async move {
+                    state += e;
+                    Some((state, state))
+                }
+                .pending_once()
+            })
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(values, vec![1u8, 3, 6]);
+    });
+
+    // A step future resolving to `None` terminates the stream early, even
+    // if it only resolves after being pending.
+    block_on(async {
+        let values = stream::iter(vec![1u8, 2, 3, 4])
+            .scan((), |(), e| {
+                async move {
+                    if e < 3 {
+                        Some(((), e))
+                    } else {
+                        None
+                    }
+                }
+                .pending_once()
+            })
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(values, vec![1u8, 2]);
+    });
+}
+
 #[test]
 fn take_until() {
     fn make_stop_fut(stop_on: u32) -> impl Future<Output = ()> {
@@ -1,10 +1,11 @@
 use futures::channel::mpsc;
 use futures::executor::block_on;
-use futures::stream::{abortable, Stream, StreamExt};
+use futures::stream::{abortable, AbortHandle, Abortable, Stream, StreamExt};
 use futures::task::{Context, Poll};
 use futures::SinkExt;
 use futures_test::task::new_count_waker;
 use std::pin::Pin;
+use std::time::Duration;
 
 #[test]
 fn abortable_works() {
@@ -34,6 +35,20 @@ fn abortable_awakens() {
     assert_eq!(Poll::Ready(None), Pin::new(&mut abortable_rx).poll_next(&mut cx));
 }
 
+#[test]
+fn handle_is_aborted_reflects_abort() {
+    let (_tx, a_rx) = mpsc::channel::<()>(1);
+    let (abortable_rx, abort_handle) = abortable(a_rx);
+
+    assert!(!abort_handle.is_aborted());
+    abort_handle.abort();
+
+    // The abort is observable on both the handle and the abortable before
+    // the wrapped stream is polled again.
+    assert!(abort_handle.is_aborted());
+    assert!(abortable_rx.is_aborted());
+}
+
 #[test]
 fn abortable_resolves() {
     let (mut tx, a_rx) = mpsc::channel::<()>(1);
@@ -44,3 +59,31 @@ fn abortable_resolves() {
     assert!(!abortable_rx.is_aborted());
     assert_eq!(Some(()), block_on(abortable_rx.next()));
 }
+
+#[test]
+fn abortable_method_matches_free_function() {
+    let (_tx, a_rx) = mpsc::channel::<()>(1);
+    let (mut abortable_rx, abort_handle) = a_rx.abortable();
+
+    abort_handle.abort();
+    assert!(abort_handle.is_aborted());
+    assert!(abortable_rx.is_aborted());
+    assert_eq!(None, block_on(abortable_rx.next()));
+}
+
+#[test]
+fn with_deadline_ends_stream_at_deadline() {
+    let (mut tx, a_rx) = mpsc::channel::<i32>(2);
+    let (_handle, reg) = AbortHandle::new_pair();
+
+    // Buffered items are still yielded; the stream ends once the mock timer
+    // fires on a pending poll.
+    block_on(tx.send(1)).unwrap();
+    let mut stream = Abortable::with_deadline(a_rx, reg, Duration::from_millis(10), |_| {
+        futures::future::ready(())
+    });
+
+    assert_eq!(Some(1), block_on(stream.next()));
+    assert_eq!(None, block_on(stream.next()));
+    assert!(stream.is_aborted());
+}
@@ -0,0 +1,136 @@
+use futures::channel::oneshot;
+use futures::executor::{block_on, block_on_stream};
+use futures::future::{self, Future, FutureExt};
+use futures::stream::{self, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// A future that records how much weight is in flight while it runs.
+struct Tracked<Fut> {
+    future: Fut,
+    weight: usize,
+    in_flight: Rc<Cell<usize>>,
+    max_in_flight: Rc<Cell<usize>>,
+    started: bool,
+}
+
+impl<Fut: Future + Unpin> Future for Tracked<Fut> {
+    type Output = Fut::Output;
+
+    fn poll(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut futures::task::Context<'_>,
+    ) -> Poll<Self::Output> {
+        if !self.started {
+            self.started = true;
+            let in_flight = self.in_flight.get() + self.weight;
+            self.in_flight.set(in_flight);
+            self.max_in_flight.set(self.max_in_flight.get().max(in_flight));
+        }
+        let weight = self.weight;
+        let in_flight = self.in_flight.clone();
+        self.future.poll_unpin(cx).map(|output| {
+            in_flight.set(in_flight.get() - weight);
+            output
+        })
+    }
+}
+
+#[test]
+fn respects_weight_bound() {
+    let in_flight = Rc::new(Cell::new(0));
+    let max_in_flight = Rc::new(Cell::new(0));
+
+    let weights = vec![3usize, 1, 4, 1, 5, 2, 6];
+    let futures: Vec<_> = weights
+        .iter()
+        .map(|&weight| Tracked {
+            future: future::ready(weight),
+            weight,
+            in_flight: in_flight.clone(),
+            max_in_flight: max_in_flight.clone(),
+            started: false,
+        })
+        .collect();
+
+    let results: Vec<usize> =
+        block_on(stream::iter(futures).buffer_unordered_weighted(6, |fut| fut.weight).collect());
+
+    assert_eq!(results.len(), weights.len());
+    assert_eq!(in_flight.get(), 0);
+    assert!(max_in_flight.get() <= 6, "in-flight weight reached {}", max_in_flight.get());
+}
+
+#[test]
+fn heavy_item_waits_for_released_weight() {
+    let (tx1, rx1) = oneshot::channel::<i32>();
+    let (tx2, rx2) = oneshot::channel::<i32>();
+
+    // The second future weighs 3; with the first one (weight 2) in flight it
+    // does not fit under the capacity of 4 until the first completes.
+    let mut weights = vec![2usize, 3].into_iter();
+    let mut buffered = stream::iter(vec![rx1, rx2])
+        .buffer_unordered_weighted(4, move |_| weights.next().unwrap())
+        .map(|result| result.unwrap());
+
+    let mut cx = noop_context();
+    assert!(buffered.poll_next_unpin(&mut cx).is_pending());
+
+    // Completing the heavy future does nothing yet: it has not been admitted.
+    tx2.send(2).unwrap();
+    assert!(buffered.poll_next_unpin(&mut cx).is_pending());
+
+    tx1.send(1).unwrap();
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
+
+#[test]
+fn oversized_item_runs_alone() {
+    let in_flight = Rc::new(Cell::new(0));
+    let max_in_flight = Rc::new(Cell::new(0));
+
+    // The middle future is heavier than the whole capacity; it must still
+    // run (alone) rather than deadlock the stream.
+    let weights = vec![1usize, 10, 1];
+    let futures: Vec<_> = weights
+        .iter()
+        .map(|&weight| Tracked {
+            future: future::ready(weight),
+            weight,
+            in_flight: in_flight.clone(),
+            max_in_flight: max_in_flight.clone(),
+            started: false,
+        })
+        .collect();
+
+    let results: Vec<usize> =
+        block_on(stream::iter(futures).buffer_unordered_weighted(2, |fut| fut.weight).collect());
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(in_flight.get(), 0);
+    // The oversized future ran by itself, so nothing ever exceeded its own
+    // weight.
+    assert!(max_in_flight.get() <= 10);
+}
+
+#[test]
+fn outputs_arrive_in_completion_order() {
+    let (tx1, rx1) = oneshot::channel::<i32>();
+    let (tx2, rx2) = oneshot::channel::<i32>();
+    let (tx3, rx3) = oneshot::channel::<i32>();
+
+    let buffered = stream::iter(vec![rx1, rx2, rx3]).buffer_unordered_weighted(10, |_| 1);
+    let mut iter = block_on_stream(buffered);
+
+    tx2.send(2).unwrap();
+    assert_eq!(iter.next(), Some(Ok(2)));
+    tx3.send(3).unwrap();
+    assert_eq!(iter.next(), Some(Ok(3)));
+    tx1.send(1).unwrap();
+    assert_eq!(iter.next(), Some(Ok(1)));
+    assert_eq!(iter.next(), None);
+}
@@ -0,0 +1,127 @@
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use futures::future;
+use futures::stream::{self, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+
+#[test]
+fn same_key_emits_in_submission_order() {
+    let mut cx = noop_context();
+
+    let (send_a0, recv_a0) = oneshot::channel();
+    let (send_a1, recv_a1) = oneshot::channel();
+    let (send_b0, recv_b0) = oneshot::channel();
+
+    let items = vec![('a', recv_a0), ('a', recv_a1), ('b', recv_b0)];
+    let mut buffered = stream::iter(items).buffered_by_key(None, |(key, _)| *key, |(_, recv)| recv);
+
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Pending);
+
+    // The second 'a' item completes first, but must wait for the first one.
+    send_a1.send(2).unwrap();
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Pending);
+
+    // A different key is not held back by 'a''s pending predecessor.
+    send_b0.send(10).unwrap();
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(10))));
+
+    // Completing the first 'a' releases both of its outputs, in order.
+    send_a0.send(1).unwrap();
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(1))));
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(2))));
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
+
+#[test]
+fn interleaved_keys_keep_per_key_order() {
+    let mut cx = noop_context();
+
+    let (send_a0, recv_a0) = oneshot::channel();
+    let (send_a1, recv_a1) = oneshot::channel();
+    let (send_b0, recv_b0) = oneshot::channel();
+    let (send_b1, recv_b1) = oneshot::channel();
+
+    let items = vec![('a', recv_a0), ('b', recv_b0), ('a', recv_a1), ('b', recv_b1)];
+    let mut buffered = stream::iter(items).buffered_by_key(None, |(key, _)| *key, |(_, recv)| recv);
+
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Pending);
+
+    // Complete everything in reverse submission order.
+    send_b1.send("b1").unwrap();
+    send_a1.send("a1").unwrap();
+    send_b0.send("b0").unwrap();
+    send_a0.send("a0").unwrap();
+
+    let mut results = Vec::new();
+    while let Poll::Ready(Some(Ok(value))) = buffered.poll_next_unpin(&mut cx) {
+        results.push(value);
+    }
+    assert_eq!(results.len(), 4);
+
+    // Whatever the interleaving, each key's outputs are in submission order.
+    let a: Vec<_> = results.iter().filter(|v| v.starts_with('a')).collect();
+    let b: Vec<_> = results.iter().filter(|v| v.starts_with('b')).collect();
+    assert_eq!(a, vec![&"a0", &"a1"]);
+    assert_eq!(b, vec![&"b0", &"b1"]);
+}
+
+#[test]
+fn distinct_keys_behave_like_buffer_unordered() {
+    let mut cx = noop_context();
+
+    let (send_one, recv_one) = oneshot::channel();
+    let (send_two, recv_two) = oneshot::channel();
+
+    let mut buffered = stream::iter(vec![(1, recv_one), (2, recv_two)]).buffered_by_key(
+        10,
+        |(key, _)| *key,
+        |(_, recv)| recv,
+    );
+
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Pending);
+
+    // With one item per key, outputs come out in completion order.
+    send_two.send(2i32).unwrap();
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(2))));
+    send_one.send(1i32).unwrap();
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(1))));
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
+
+#[test]
+fn single_key_behaves_like_buffered() {
+    let results = block_on(
+        stream::iter(1..=5).buffered_by_key(2, |_| 0, |x| future::ready(x * 2)).collect::<Vec<_>>(),
+    );
+    assert_eq!(results, vec![2, 4, 6, 8, 10]);
+}
+
+#[test]
+fn limit_counts_only_running_futures() {
+    let mut cx = noop_context();
+
+    let (send_a0, recv_a0) = oneshot::channel::<i32>();
+    let (send_a1, recv_a1) = oneshot::channel();
+    let (send_a2, recv_a2) = oneshot::channel();
+
+    let items = vec![('a', recv_a0), ('a', recv_a1), ('a', recv_a2)];
+    let mut buffered = stream::iter(items).buffered_by_key(2, |(key, _)| *key, |(_, recv)| recv);
+
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Pending);
+
+    // Only the first two futures are admitted, so the third sender has no
+    // receiver polling it yet; completing the second frees a slot but its
+    // output is parked behind the first.
+    send_a1.send(2).unwrap();
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Pending);
+
+    send_a2.send(3).unwrap();
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Pending);
+
+    send_a0.send(1).unwrap();
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(1))));
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(2))));
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(3))));
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
@@ -0,0 +1,38 @@
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+
+#[test]
+fn splits_on_boundaries() {
+    let stream = stream::iter(vec![0, 1, 2, 0, 3, 0, 4, 5]);
+    let chunks: Vec<_> = block_on(stream.chunks_by(|x| *x == 0).collect());
+    assert_eq!(chunks, vec![vec![0, 1, 2], vec![0, 3], vec![0, 4, 5]]);
+}
+
+#[test]
+fn first_item_starts_first_chunk() {
+    // A non-boundary prefix still forms the first chunk.
+    let stream = stream::iter(vec![1, 2, 0, 3]);
+    let chunks: Vec<_> = block_on(stream.chunks_by(|x| *x == 0).collect());
+    assert_eq!(chunks, vec![vec![1, 2], vec![0, 3]]);
+}
+
+#[test]
+fn trailing_chunk_is_flushed() {
+    let stream = stream::iter(vec![0, 1, 0]);
+    let chunks: Vec<_> = block_on(stream.chunks_by(|x| *x == 0).collect());
+    assert_eq!(chunks, vec![vec![0, 1], vec![0]]);
+}
+
+#[test]
+fn empty_stream_yields_nothing() {
+    let stream = stream::iter(Vec::<i32>::new());
+    let chunks: Vec<_> = block_on(stream.chunks_by(|x| *x == 0).collect());
+    assert_eq!(chunks, Vec::<Vec<i32>>::new());
+}
+
+#[test]
+fn no_boundaries_yields_single_chunk() {
+    let stream = stream::iter(vec![1, 2, 3]);
+    let chunks: Vec<_> = block_on(stream.chunks_by(|x| *x == 0).collect());
+    assert_eq!(chunks, vec![vec![1, 2, 3]]);
+}
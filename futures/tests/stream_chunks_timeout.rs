@@ -0,0 +1,79 @@
+use std::cell::Cell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::stream::StreamExt;
+use futures_test::task::noop_context;
+
+/// A manually driven delay: completes once the shared flag has been set.
+#[derive(Clone)]
+struct MockDelay {
+    fired: Rc<Cell<bool>>,
+}
+
+impl Future for MockDelay {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+        if self.fired.get() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+#[test]
+fn chunks_timeout_count_path() {
+    block_on(async {
+        let stream =
+            futures::stream::iter(1..=5)
+                .chunks_timeout(2, Duration::from_millis(10), |_| futures::future::pending());
+        assert_eq!(vec![vec![1, 2], vec![3, 4], vec![5]], stream.collect::<Vec<_>>().await);
+    });
+}
+
+#[test]
+fn chunks_timeout_time_path() {
+    let fired = Rc::new(Cell::new(false));
+    let delay = MockDelay { fired: fired.clone() };
+
+    let (tx, rx) = mpsc::unbounded::<u32>();
+    let mut chunked = rx.chunks_timeout(3, Duration::from_millis(10), move |_| {
+        delay.fired.set(false);
+        delay.clone()
+    });
+
+    let mut cx = noop_context();
+
+    // An empty window never yields an empty chunk, even if the timer fires.
+    fired.set(true);
+    assert!(chunked.poll_next_unpin(&mut cx).is_pending());
+
+    // A partial chunk is held while the timer is running...
+    tx.unbounded_send(1).unwrap();
+    tx.unbounded_send(2).unwrap();
+    assert!(chunked.poll_next_unpin(&mut cx).is_pending());
+
+    // ...and flushed once the timeout elapses.
+    fired.set(true);
+    assert_eq!(chunked.poll_next_unpin(&mut cx), Poll::Ready(Some(vec![1, 2])));
+
+    // The capacity path still applies and resets the timer.
+    tx.unbounded_send(3).unwrap();
+    tx.unbounded_send(4).unwrap();
+    tx.unbounded_send(5).unwrap();
+    assert_eq!(chunked.poll_next_unpin(&mut cx), Poll::Ready(Some(vec![3, 4, 5])));
+
+    // A partial chunk is flushed when the source ends mid-window.
+    tx.unbounded_send(6).unwrap();
+    assert!(chunked.poll_next_unpin(&mut cx).is_pending());
+    drop(tx);
+    assert_eq!(chunked.poll_next_unpin(&mut cx), Poll::Ready(Some(vec![6])));
+    assert_eq!(chunked.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
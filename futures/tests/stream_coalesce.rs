@@ -0,0 +1,46 @@
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+
+fn merge_runs((x, n): (u8, u32), (y, m): (u8, u32)) -> Result<(u8, u32), ((u8, u32), (u8, u32))> {
+    if x == y {
+        Ok((x, n + m))
+    } else {
+        Err(((x, n), (y, m)))
+    }
+}
+
+#[test]
+fn merges_runs() {
+    let stream = stream::iter(vec![(1, 1), (1, 1), (1, 1), (2, 1), (3, 1), (3, 1)]);
+    let out: Vec<_> = block_on(stream.coalesce(merge_runs).collect());
+    assert_eq!(out, vec![(1, 3), (2, 1), (3, 2)]);
+}
+
+#[test]
+fn no_merge_passes_items_through() {
+    let stream = stream::iter(vec![(1, 1), (2, 1), (3, 1)]);
+    let out: Vec<_> = block_on(stream.coalesce(merge_runs).collect());
+    assert_eq!(out, vec![(1, 1), (2, 1), (3, 1)]);
+}
+
+#[test]
+fn single_item_passes_through() {
+    let stream = stream::iter(vec![(7, 1)]);
+    let out: Vec<_> = block_on(stream.coalesce(merge_runs).collect());
+    assert_eq!(out, vec![(7, 1)]);
+}
+
+#[test]
+fn empty_stream_yields_nothing() {
+    let stream = stream::iter(Vec::<(u8, u32)>::new());
+    let out: Vec<_> = block_on(stream.coalesce(merge_runs).collect());
+    assert_eq!(out, vec![]);
+}
+
+#[test]
+fn final_held_item_is_emitted() {
+    // The trailing run is only emitted once the stream ends.
+    let stream = stream::iter(vec![(1, 1), (2, 1), (2, 1)]);
+    let out: Vec<_> = block_on(stream.coalesce(merge_runs).collect());
+    assert_eq!(out, vec![(1, 1), (2, 2)]);
+}
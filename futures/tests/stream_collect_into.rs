@@ -0,0 +1,58 @@
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt, TryStreamExt};
+use std::collections::HashSet;
+
+#[test]
+fn collect_into_keeps_existing_contents() {
+    block_on(async {
+        let out = stream::iter(4..=6).collect_into(vec![1, 2, 3]).await;
+        assert_eq!(out, vec![1, 2, 3, 4, 5, 6]);
+    })
+}
+
+#[test]
+fn collect_into_hash_set() {
+    block_on(async {
+        let mut seed = HashSet::new();
+        seed.insert(1);
+        seed.insert(2);
+
+        let out = stream::iter(vec![2, 3, 4]).collect_into(seed).await;
+        let mut sorted: Vec<_> = out.into_iter().collect();
+        sorted.sort_unstable();
+        assert_eq!(sorted, vec![1, 2, 3, 4]);
+    })
+}
+
+#[test]
+fn collect_into_reuses_capacity() {
+    block_on(async {
+        let mut buf = Vec::with_capacity(32);
+        buf = stream::iter(0..5).collect_into(buf).await;
+        assert_eq!(buf, vec![0, 1, 2, 3, 4]);
+        assert!(buf.capacity() >= 32);
+
+        buf.clear();
+        buf = stream::iter(5..10).collect_into(buf).await;
+        assert_eq!(buf, vec![5, 6, 7, 8, 9]);
+        assert!(buf.capacity() >= 32);
+    })
+}
+
+#[test]
+fn try_collect_into_ok() {
+    block_on(async {
+        let stream = stream::iter(vec![Ok::<i32, i32>(2), Ok(3)]);
+        assert_eq!(stream.try_collect_into(vec![1]).await, Ok(vec![1, 2, 3]));
+    })
+}
+
+#[test]
+fn try_collect_into_returns_partial_state_on_error() {
+    block_on(async {
+        let stream = stream::iter(vec![Ok::<i32, i32>(2), Ok(3), Err(9), Ok(4)]);
+        let (partial, err) = stream.try_collect_into(vec![1]).await.unwrap_err();
+        assert_eq!(partial, vec![1, 2, 3]);
+        assert_eq!(err, 9);
+    })
+}
@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+
+#[test]
+fn counts_with_duplicates() {
+    block_on(async {
+        let counts = stream::iter(vec![1, 2, 1, 3, 1, 2]).counts().await;
+
+        let expected: HashMap<i32, usize> = vec![(1, 3), (2, 2), (3, 1)].into_iter().collect();
+        assert_eq!(counts, expected);
+    });
+}
+
+#[test]
+fn counts_empty_stream() {
+    block_on(async {
+        let counts = stream::iter(Vec::<u8>::new()).counts().await;
+        assert!(counts.is_empty());
+    });
+}
+
+#[test]
+fn counts_by_projects_to_smaller_domain() {
+    block_on(async {
+        let counts = stream::iter(0..10).counts_by(|n| n % 2 == 0).await;
+
+        let expected: HashMap<bool, usize> = vec![(true, 5), (false, 5)].into_iter().collect();
+        assert_eq!(counts, expected);
+    });
+}
@@ -0,0 +1,43 @@
+use futures::executor::block_on;
+use futures::stream::{self, Stream, StreamExt};
+
+#[test]
+fn zero_times_is_empty() {
+    block_on(async {
+        let items: Vec<i32> = stream::iter(vec![1, 2, 3]).cycle_n(0).collect().await;
+        assert!(items.is_empty());
+    });
+}
+
+#[test]
+fn once_is_identity() {
+    block_on(async {
+        let items: Vec<_> = stream::iter(vec![1, 2, 3]).cycle_n(1).collect().await;
+        assert_eq!(items, vec![1, 2, 3]);
+    });
+}
+
+#[test]
+fn three_times_repeats() {
+    block_on(async {
+        let items: Vec<_> = stream::iter(vec![1, 2]).cycle_n(3).collect().await;
+        assert_eq!(items, vec![1, 2, 1, 2, 1, 2]);
+    });
+}
+
+#[test]
+fn empty_source_stays_empty() {
+    block_on(async {
+        let items: Vec<i32> = stream::iter(Vec::<i32>::new()).cycle_n(5).collect().await;
+        assert!(items.is_empty());
+    });
+}
+
+#[test]
+fn size_hint_is_exact_for_sized_sources() {
+    let stream = stream::iter(vec![1, 2, 3]).cycle_n(3);
+    assert_eq!(stream.size_hint(), (9, Some(9)));
+
+    let stream = stream::iter(vec![1, 2, 3]).cycle_n(0);
+    assert_eq!(stream.size_hint(), (0, Some(0)));
+}
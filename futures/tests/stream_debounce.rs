@@ -0,0 +1,78 @@
+use std::cell::Cell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::stream::StreamExt;
+use futures_test::task::noop_context;
+
+/// A manually driven delay: completes once the shared flag has been set.
+#[derive(Clone)]
+struct MockDelay {
+    fired: Rc<Cell<bool>>,
+}
+
+impl Future for MockDelay {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+        if self.fired.get() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+#[test]
+fn debounce_coalesces_ready_items() {
+    block_on(async {
+        let stream = futures::stream::iter(1..=5)
+            .debounce(Duration::from_millis(10), |_| futures::future::ready(()));
+        assert_eq!(vec![5], stream.collect::<Vec<_>>().await);
+    });
+}
+
+#[test]
+fn debounce_waits_for_quiet_period() {
+    let fired = Rc::new(Cell::new(false));
+    let delay = MockDelay { fired: fired.clone() };
+
+    let (tx, rx) = mpsc::unbounded::<u32>();
+    let mut debounced = rx.debounce(Duration::from_millis(10), move |_| {
+        // Arming a new delay resets the clock.
+        delay.fired.set(false);
+        delay.clone()
+    });
+
+    let mut cx = noop_context();
+
+    // Nothing has arrived yet.
+    assert!(debounced.poll_next_unpin(&mut cx).is_pending());
+
+    // An item arrives; it must be held back until the delay fires.
+    tx.unbounded_send(1).unwrap();
+    assert!(debounced.poll_next_unpin(&mut cx).is_pending());
+
+    // A newer item replaces the buffered one and resets the delay.
+    tx.unbounded_send(2).unwrap();
+    assert!(debounced.poll_next_unpin(&mut cx).is_pending());
+
+    // Once the delay elapses only the most recent item is emitted.
+    fired.set(true);
+    assert_eq!(debounced.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    assert!(debounced.poll_next_unpin(&mut cx).is_pending());
+
+    // The final buffered item is flushed when the stream ends, even though
+    // the delay has not fired.
+    fired.set(false);
+    tx.unbounded_send(3).unwrap();
+    assert!(debounced.poll_next_unpin(&mut cx).is_pending());
+    drop(tx);
+    assert_eq!(debounced.poll_next_unpin(&mut cx), Poll::Ready(Some(3)));
+    assert_eq!(debounced.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
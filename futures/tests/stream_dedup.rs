@@ -0,0 +1,35 @@
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+
+#[test]
+fn dedup_collapses_runs() {
+    block_on(async {
+        let stream = stream::iter(vec![1, 1, 1, 2, 3, 3, 1, 1]).dedup();
+        assert_eq!(vec![1, 2, 3, 1], stream.collect::<Vec<_>>().await);
+    });
+}
+
+#[test]
+fn dedup_passes_alternating_values() {
+    block_on(async {
+        let stream = stream::iter(vec![1, 2, 1, 2]).dedup();
+        assert_eq!(vec![1, 2, 1, 2], stream.collect::<Vec<_>>().await);
+    });
+}
+
+#[test]
+fn dedup_by_custom_comparison() {
+    block_on(async {
+        let stream = stream::iter(vec![1i32, -1, 2, -2, -2, 3]).dedup_by(|a, b| a.abs() == b.abs());
+        assert_eq!(vec![1, 2, 3], stream.collect::<Vec<_>>().await);
+    });
+}
+
+#[test]
+fn dedup_by_key_custom_key() {
+    block_on(async {
+        let stream = stream::iter(vec!["ant", "apple", "bee", "bat", "cat"])
+            .dedup_by_key(|s| s.chars().next());
+        assert_eq!(vec!["ant", "bee", "cat"], stream.collect::<Vec<_>>().await);
+    });
+}
@@ -0,0 +1,55 @@
+use futures::executor::block_on;
+use futures::stream::{self, DoubleEndedStream, StreamExt};
+
+#[test]
+fn alternating_ends_meet_cleanly() {
+    block_on(async {
+        let mut stream = stream::iter(1..=5);
+
+        assert_eq!(stream.next().await, Some(1));
+        assert_eq!(stream.next_back().await, Some(5));
+        assert_eq!(stream.next().await, Some(2));
+        assert_eq!(stream.next_back().await, Some(4));
+
+        // The two ends meet: the last item is yielded exactly once.
+        assert_eq!(stream.next().await, Some(3));
+        assert_eq!(stream.next_back().await, None);
+        assert_eq!(stream.next().await, None);
+    });
+}
+
+#[test]
+fn back_only_consumption() {
+    block_on(async {
+        let mut stream = stream::iter(1..=3);
+
+        assert_eq!(stream.next_back().await, Some(3));
+        assert_eq!(stream.next_back().await, Some(2));
+        assert_eq!(stream.next_back().await, Some(1));
+        assert_eq!(stream.next_back().await, None);
+    });
+}
+
+#[test]
+fn rev_yields_items_backwards() {
+    let stream = stream::iter(1..=4).rev();
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![4, 3, 2, 1]);
+}
+
+#[test]
+fn rev_is_double_ended_with_swapped_ends() {
+    block_on(async {
+        let mut stream = stream::iter(1..=3).rev();
+
+        assert_eq!(stream.next().await, Some(3));
+        assert_eq!(stream.next_back().await, Some(1));
+        assert_eq!(stream.next().await, Some(2));
+        assert_eq!(stream.next().await, None);
+    });
+}
+
+#[test]
+fn double_rev_restores_order() {
+    let stream = stream::iter(1..=4).rev().rev();
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![1, 2, 3, 4]);
+}
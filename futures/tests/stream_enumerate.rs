@@ -0,0 +1,19 @@
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+
+#[test]
+fn enumerate_from_nonzero_start() {
+    block_on(async {
+        let items: Vec<_> = stream::iter(vec!['a', 'b', 'c']).enumerate_from(10).collect().await;
+        assert_eq!(items, vec![(10, 'a'), (11, 'b'), (12, 'c')]);
+    });
+}
+
+#[test]
+fn enumerate_from_zero_equals_enumerate() {
+    block_on(async {
+        let from_zero: Vec<_> = stream::iter(0..5).enumerate_from(0).collect().await;
+        let enumerated: Vec<_> = stream::iter(0..5).enumerate().collect().await;
+        assert_eq!(from_zero, enumerated);
+    });
+}
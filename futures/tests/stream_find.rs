@@ -0,0 +1,62 @@
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+use std::cell::Cell;
+
+#[test]
+fn find_early_match() {
+    block_on(async {
+        let seen = Cell::new(0);
+        let mut stream = stream::iter(1..=10).inspect(|_| {
+            seen.set(seen.get() + 1);
+        });
+        assert_eq!((&mut stream).find(|x| x % 3 == 0).await, Some(3));
+        // The match stops polling; the rest of the stream is untouched.
+        assert_eq!(seen.get(), 3);
+        assert_eq!(stream.next().await, Some(4));
+    })
+}
+
+#[test]
+fn find_no_match_drains() {
+    block_on(async {
+        let seen = Cell::new(0);
+        let found = stream::iter(1..=5)
+            .inspect(|_| {
+                seen.set(seen.get() + 1);
+            })
+            .find(|x| *x > 100)
+            .await;
+        assert_eq!(found, None);
+        assert_eq!(seen.get(), 5);
+    })
+}
+
+#[test]
+fn find_map_early_match() {
+    block_on(async {
+        let stream = stream::iter(vec!["lorem", "42", "ipsum", "7"]);
+        assert_eq!(stream.find_map(|s| s.parse::<i32>().ok()).await, Some(42));
+    })
+}
+
+#[test]
+fn find_map_no_match() {
+    block_on(async {
+        let stream = stream::iter(vec!["lorem", "ipsum"]);
+        assert_eq!(stream.find_map(|s| s.parse::<i32>().ok()).await, None);
+    })
+}
+
+#[test]
+fn find_map_stops_consuming_after_match() {
+    block_on(async {
+        let seen = Cell::new(0);
+        let mut stream = stream::iter(1..=10).inspect(|_| {
+            seen.set(seen.get() + 1);
+        });
+        let doubled = (&mut stream).find_map(|x| if x == 2 { Some(x * 2) } else { None }).await;
+        assert_eq!(doubled, Some(4));
+        assert_eq!(seen.get(), 2);
+        assert_eq!(stream.next().await, Some(3));
+    })
+}
@@ -0,0 +1,75 @@
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+use futures::task::Poll;
+use futures_test::stream::StreamTestExt;
+use futures_test::task::noop_context;
+
+#[test]
+fn works_like_flat_map_modulo_ordering() {
+    block_on(async {
+        let stream = stream::iter(1..=3).flat_map_unordered(None, |x| stream::iter(vec![x; x]));
+
+        let mut values = stream.collect::<Vec<_>>().await;
+        values.sort_unstable();
+        assert_eq!(values, vec![1, 2, 2, 3, 3, 3]);
+    });
+}
+
+#[test]
+fn overlapping_sub_streams_interleave() {
+    block_on(async {
+        let stream = stream::iter(1..=3)
+            .flat_map_unordered(None, |x| stream::iter(vec![x; x]).interleave_pending());
+
+        let mut values = stream.collect::<Vec<_>>().await;
+        values.sort_unstable();
+        assert_eq!(values, vec![1, 2, 2, 3, 3, 3]);
+    });
+}
+
+#[test]
+fn pending_sub_stream_does_not_block_others() {
+    let mut cx = noop_context();
+
+    let (tx1, rx1) = mpsc::unbounded::<i32>();
+    let (tx2, rx2) = mpsc::unbounded::<i32>();
+    let mut stream = stream::iter(vec![rx1, rx2]).flat_map_unordered(None, |rx| rx);
+
+    // The second sub-stream has an item ready while the first is pending.
+    tx2.unbounded_send(2).unwrap();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+
+    tx1.unbounded_send(1).unwrap();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+
+    drop((tx1, tx2));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
+
+#[test]
+fn limit_bounds_active_sub_streams() {
+    let mut cx = noop_context();
+
+    let (tx1, rx1) = mpsc::unbounded::<i32>();
+    let (tx2, rx2) = mpsc::unbounded::<i32>();
+    let mut stream = stream::iter(vec![rx1, rx2]).flat_map_unordered(1, |rx| rx);
+
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+
+    // Only the first sub-stream is active, so an item on the second one is
+    // not seen yet.
+    tx2.unbounded_send(2).unwrap();
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+
+    tx1.unbounded_send(1).unwrap();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+
+    // Finishing the first sub-stream frees up the slot for the second one.
+    drop(tx1);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+
+    drop(tx2);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
@@ -0,0 +1,62 @@
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+use futures_test::task::noop_context;
+use std::task::Poll;
+
+#[test]
+fn interleaves_inner_streams() {
+    block_on(async {
+        let (tx1, rx1) = mpsc::unbounded();
+        let (tx2, rx2) = mpsc::unbounded();
+
+        let mut flattened = stream::iter(vec![rx1, rx2]).flatten_unordered(None);
+
+        // A pending first inner stream does not block the second.
+        tx2.unbounded_send(1).unwrap();
+        assert_eq!(flattened.next().await, Some(1));
+        tx1.unbounded_send(2).unwrap();
+        assert_eq!(flattened.next().await, Some(2));
+        tx2.unbounded_send(3).unwrap();
+        assert_eq!(flattened.next().await, Some(3));
+
+        drop((tx1, tx2));
+        assert_eq!(flattened.next().await, None);
+    });
+}
+
+#[test]
+fn limit_holds_back_inner_streams() {
+    let mut cx = noop_context();
+
+    let (tx1, rx1) = mpsc::unbounded();
+    let (tx2, rx2) = mpsc::unbounded();
+
+    let mut flattened = stream::iter(vec![rx1, rx2]).flatten_unordered(1);
+
+    // Only the first inner stream is being polled while the limit is reached,
+    // so an item on the second one is not seen yet.
+    tx2.unbounded_send(2).unwrap();
+    assert_eq!(flattened.poll_next_unpin(&mut cx), Poll::Pending);
+
+    // Once the first inner stream finishes, its slot goes to the second.
+    tx1.unbounded_send(1).unwrap();
+    assert_eq!(flattened.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    drop(tx1);
+    assert_eq!(flattened.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+
+    drop(tx2);
+    assert_eq!(flattened.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
+
+#[test]
+fn unbounded_collects_everything() {
+    block_on(async {
+        let mut values = stream::iter((1..=3).map(|x| stream::iter(vec![x; x])))
+            .flatten_unordered(None)
+            .collect::<Vec<_>>()
+            .await;
+        values.sort_unstable();
+        assert_eq!(values, vec![1, 2, 2, 3, 3, 3]);
+    });
+}
@@ -0,0 +1,48 @@
+use futures::executor::block_on;
+use futures::stream::{self, FoldStep, StreamExt};
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[test]
+fn breaks_early_without_draining() {
+    block_on(async {
+        let polls = Rc::new(Cell::new(0));
+        let counter = polls.clone();
+        let source = stream::iter(1..=100).inspect(move |_| counter.set(counter.get() + 1));
+
+        let sum = source
+            .fold_while(0, |acc, x| async move {
+                let acc = acc + x;
+                if acc >= 10 {
+                    FoldStep::Done(acc)
+                } else {
+                    FoldStep::Continue(acc)
+                }
+            })
+            .await;
+
+        assert_eq!(sum, 10);
+        // 1 + 2 + 3 + 4 = 10; the fifth item was never pulled.
+        assert_eq!(polls.get(), 4);
+    });
+}
+
+#[test]
+fn empty_stream_returns_init() {
+    block_on(async {
+        let sum = stream::iter(std::iter::empty::<i32>())
+            .fold_while(7, |acc, x| async move { FoldStep::Continue(acc + x) })
+            .await;
+        assert_eq!(sum, 7);
+    });
+}
+
+#[test]
+fn runs_to_completion_without_break() {
+    block_on(async {
+        let sum = stream::iter(0..6)
+            .fold_while(0, |acc, x| async move { FoldStep::Continue(acc + x) })
+            .await;
+        assert_eq!(sum, 15);
+    });
+}
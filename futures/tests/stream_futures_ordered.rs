@@ -82,3 +82,61 @@ fn queue_never_unblocked() {
     assert!(stream.poll_next_unpin(cx).is_pending());
     assert!(stream.poll_next_unpin(cx).is_pending());
 }
+
+#[test]
+fn push_front_yields_before_queued_futures() {
+    let (a_tx, a_rx) = oneshot::channel::<i32>();
+    let (b_tx, b_rx) = oneshot::channel::<i32>();
+    let (c_tx, c_rx) = oneshot::channel::<i32>();
+
+    let mut stream = FuturesOrdered::new();
+    stream.push_back(a_rx);
+    stream.push_back(b_rx);
+    stream.push_front(c_rx);
+    assert_eq!(stream.len(), 3);
+
+    a_tx.send(1).unwrap();
+    b_tx.send(2).unwrap();
+    c_tx.send(3).unwrap();
+
+    let mut iter = block_on_stream(stream);
+    assert_eq!(Some(Ok(3)), iter.next());
+    assert_eq!(Some(Ok(1)), iter.next());
+    assert_eq!(Some(Ok(2)), iter.next());
+    assert_eq!(None, iter.next());
+}
+
+#[test]
+fn mixed_push_front_and_back() {
+    let mut cx = noop_context();
+
+    let (a_tx, a_rx) = oneshot::channel::<i32>();
+    let (b_tx, b_rx) = oneshot::channel::<i32>();
+    let (c_tx, c_rx) = oneshot::channel::<i32>();
+    let (d_tx, d_rx) = oneshot::channel::<i32>();
+
+    let mut stream = FuturesOrdered::new();
+    stream.push_back(a_rx);
+    stream.push_front(b_rx);
+
+    // Results queue up out of order while earlier slots are still pending.
+    a_tx.send(1).unwrap();
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+    assert_eq!(stream.len(), 2);
+
+    // Front pushes keep stacking in front of everything already queued.
+    stream.push_front(c_rx);
+    stream.push_back(d_rx);
+    assert_eq!(stream.len(), 4);
+
+    b_tx.send(2).unwrap();
+    c_tx.send(3).unwrap();
+    d_tx.send(4).unwrap();
+
+    let mut iter = block_on_stream(stream);
+    assert_eq!(Some(Ok(3)), iter.next());
+    assert_eq!(Some(Ok(2)), iter.next());
+    assert_eq!(Some(Ok(1)), iter.next());
+    assert_eq!(Some(Ok(4)), iter.next());
+    assert_eq!(None, iter.next());
+}
@@ -6,8 +6,10 @@ use futures::task::{Context, Poll};
 use futures_test::future::FutureTestExt;
 use futures_test::task::noop_context;
 use futures_test::{assert_stream_done, assert_stream_next, assert_stream_pending};
+use std::cell::Cell;
 use std::iter::FromIterator;
 use std::pin::Pin;
+use std::rc::Rc;
 use std::sync::atomic::{AtomicBool, Ordering};
 
 #[test]
@@ -367,3 +369,349 @@ fn clear() {
     tasks.clear();
     assert!(!tasks.is_terminated());
 }
+
+#[test]
+fn retain() {
+    // Futures that complete only after their oneshot fires, so the set stays
+    // populated while we retain.
+    let mut txs = Vec::new();
+    let mut tasks = FuturesUnordered::new();
+    for i in 0..10 {
+        let (tx, rx) = oneshot::channel::<i32>();
+        txs.push((i, tx));
+        tasks.push(rx.map(move |res| (i, res.unwrap())));
+    }
+    assert_eq!(tasks.len(), 10);
+
+    // Start tracking wake-ups for every future before removing any.
+    let mut cx = noop_context();
+    assert!(tasks.poll_next_unpin(&mut cx).is_pending());
+
+    // Keep only the even-numbered futures.
+    let mut i = 0;
+    tasks.retain(|_| {
+        i += 1;
+        i % 2 == 0
+    });
+    assert_eq!(tasks.len(), 5);
+
+    // The dropped futures' senders observe cancellation, the retained ones
+    // still complete and are yielded.
+    let mut completed = Vec::new();
+    for (i, tx) in txs {
+        if tx.send(i * 10).is_err() {
+            continue;
+        }
+        completed.push(i);
+    }
+    assert_eq!(completed.len(), 5);
+
+    let mut results = block_on(tasks.collect::<Vec<_>>());
+    results.sort_unstable();
+    assert_eq!(results, completed.iter().map(|&i| (i, i * 10)).collect::<Vec<_>>());
+}
+
+#[test]
+fn retain_all_or_nothing() {
+    let mut tasks = FuturesUnordered::from_iter((0..4).map(future::ready));
+
+    tasks.retain(|_| true);
+    assert_eq!(tasks.len(), 4);
+
+    tasks.retain(|_| false);
+    assert!(tasks.is_empty());
+    assert_eq!(block_on(tasks.next()), None);
+}
+
+#[test]
+fn iter_pin_mut_walks_without_polling() {
+    // `iter`/`iter_pin_mut` must yield every future in the set without
+    // driving any of them.
+    let mut tasks = FuturesUnordered::<Pin<Box<dyn Future<Output = i32>>>>::new();
+    assert_eq!(tasks.iter().count(), 0);
+    assert_eq!(Pin::new(&mut tasks).iter_pin_mut().count(), 0);
+
+    for i in 0..5 {
+        tasks.push(Box::pin(future::lazy(move |_| panic!("future {} polled during iteration", i))));
+    }
+
+    assert_eq!(tasks.iter().count(), 5);
+    assert_eq!(tasks.iter_mut().count(), 5);
+    assert_eq!(Pin::new(&mut tasks).iter_pin_mut().count(), 5);
+    assert_eq!(tasks.len(), 5);
+}
+
+#[test]
+fn iter_identity() {
+    // The iterator yields references to the very futures that were pushed.
+    let mut tasks = FuturesUnordered::new();
+    for i in 0..3 {
+        tasks.push(future::ready(i));
+    }
+
+    let mut seen: Vec<*const _> = tasks.iter().map(|f| f as *const _).collect();
+    let seen_mut: Vec<*const _> = tasks.iter_mut().map(|f| f as *const _ as *const ()).collect();
+    seen.sort_unstable();
+    let mut seen = seen.iter().map(|&p| p as *const ()).collect::<Vec<_>>();
+    let mut seen_mut = seen_mut;
+    seen_mut.sort_unstable();
+    seen.sort_unstable();
+    assert_eq!(seen, seen_mut);
+}
+
+/// A future that records how many instrumented futures are being polled
+/// concurrently, and completes once its `done` flag is set.
+struct Instrumented {
+    id: usize,
+    started: bool,
+    done: Rc<Cell<bool>>,
+    active: Rc<Cell<usize>>,
+    max_active: Rc<Cell<usize>>,
+}
+
+impl Future for Instrumented {
+    type Output = usize;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<usize> {
+        if !self.started {
+            self.started = true;
+            self.active.set(self.active.get() + 1);
+            self.max_active.set(std::cmp::max(self.max_active.get(), self.active.get()));
+        }
+        if self.done.get() {
+            self.active.set(self.active.get() - 1);
+            Poll::Ready(self.id)
+        } else {
+            // Stay hot so that the next `poll_next` sees this future again
+            // without an external wake-up.
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+struct Instruments {
+    done: Vec<Rc<Cell<bool>>>,
+    active: Rc<Cell<usize>>,
+    max_active: Rc<Cell<usize>>,
+}
+
+fn instrumented_futures(count: usize) -> (Vec<Instrumented>, Instruments) {
+    let active = Rc::new(Cell::new(0));
+    let max_active = Rc::new(Cell::new(0));
+    let mut futures = Vec::new();
+    let mut done = Vec::new();
+    for id in 0..count {
+        let flag = Rc::new(Cell::new(false));
+        done.push(flag.clone());
+        futures.push(Instrumented {
+            id,
+            started: false,
+            done: flag,
+            active: active.clone(),
+            max_active: max_active.clone(),
+        });
+    }
+    (futures, Instruments { done, active, max_active })
+}
+
+#[test]
+fn max_concurrent_limits_polling() {
+    let mut cx = noop_context();
+
+    let mut futures = FuturesUnordered::with_max_concurrent(2);
+    assert_eq!(futures.max_concurrent(), 2);
+
+    let (instrumented, instruments) = instrumented_futures(5);
+    for future in instrumented {
+        futures.push(future);
+    }
+
+    assert_eq!(futures.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(instruments.active.get(), 2);
+
+    // Completing an active future frees a slot for a held-back one.
+    instruments.done[0].set(true);
+    assert_eq!(futures.poll_next_unpin(&mut cx), Poll::Ready(Some(0)));
+    assert_eq!(futures.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(instruments.active.get(), 2);
+
+    // Drain the rest; the limit must never be exceeded.
+    for flag in &instruments.done {
+        flag.set(true);
+    }
+    let mut seen = Vec::new();
+    while let Poll::Ready(Some(id)) = futures.poll_next_unpin(&mut cx) {
+        seen.push(id);
+    }
+    assert_eq!(futures.poll_next_unpin(&mut cx), Poll::Ready(None));
+    seen.sort_unstable();
+    assert_eq!(seen, vec![1, 2, 3, 4]);
+    assert_eq!(instruments.max_active.get(), 2);
+}
+
+#[test]
+fn set_max_concurrent_promotes_and_demotes() {
+    let mut cx = noop_context();
+
+    let mut futures = FuturesUnordered::with_max_concurrent(1);
+    let (instrumented, instruments) = instrumented_futures(4);
+    for future in instrumented {
+        futures.push(future);
+    }
+
+    assert_eq!(futures.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(instruments.active.get(), 1);
+
+    // Raising the limit promotes held-back futures.
+    futures.set_max_concurrent(3);
+    assert_eq!(futures.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(instruments.active.get(), 3);
+
+    // Lowering it again leaves active futures running but holds back the
+    // remaining one until enough of them complete.
+    futures.set_max_concurrent(1);
+    instruments.done[0].set(true);
+    instruments.done[1].set(true);
+    let mut seen = Vec::new();
+    while let Poll::Ready(Some(id)) = futures.poll_next_unpin(&mut cx) {
+        seen.push(id);
+    }
+    seen.sort_unstable();
+    assert_eq!(seen, vec![0, 1]);
+    assert_eq!(futures.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(instruments.active.get(), 1);
+
+    // Removing the limit starts everything.
+    futures.set_max_concurrent(0);
+    assert_eq!(futures.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(instruments.active.get(), 2);
+    assert_eq!(instruments.max_active.get(), 3);
+}
+
+#[test]
+fn max_concurrent_clear_and_drop_release_dormant() {
+    let mut cx = noop_context();
+
+    let mut futures = FuturesUnordered::with_max_concurrent(1);
+    let (instrumented, instruments) = instrumented_futures(3);
+    for future in instrumented {
+        futures.push(future);
+    }
+    assert_eq!(futures.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(instruments.active.get(), 1);
+
+    futures.clear();
+    assert!(futures.is_empty());
+
+    // The set is still usable with the same limit after clearing.
+    let (instrumented, instruments) = instrumented_futures(2);
+    for future in instrumented {
+        futures.push(future);
+    }
+    assert_eq!(futures.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(instruments.active.get(), 1);
+
+    // Dropping the set with a dormant future must not leak or unwind.
+    drop(futures);
+}
+
+#[test]
+fn retain_purges_dormant_futures() {
+    // A future dropped by `retain` while held back by the concurrency limit
+    // must not consume a slot once one frees up; the held-back futures
+    // behind it still get started.
+    let mut cx = noop_context();
+
+    let mut futures = FuturesUnordered::with_max_concurrent(1);
+    let (instrumented, instruments) = instrumented_futures(3);
+    for future in instrumented {
+        futures.push(future);
+    }
+
+    // Future 0 is active, futures 1 and 2 are held back.
+    assert_eq!(futures.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(instruments.active.get(), 1);
+
+    // Drop the first held-back future while it is dormant.
+    futures.retain(|future| future.id != 1);
+    assert_eq!(futures.len(), 2);
+
+    // Completing future 0 frees the slot; it must go to future 2 rather
+    // than be wasted on the removed future, which would strand future 2.
+    instruments.done[0].set(true);
+    instruments.done[2].set(true);
+    assert_eq!(futures.poll_next_unpin(&mut cx), Poll::Ready(Some(0)));
+    assert_eq!(futures.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    assert_eq!(futures.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert_eq!(instruments.max_active.get(), 1);
+}
+
+#[test]
+fn poll_count_and_peak_len() {
+    let mut cx = noop_context();
+
+    let mut futures = FuturesUnordered::new();
+    assert_eq!(futures.poll_count(), 0);
+    assert_eq!(futures.peak_len(), 0);
+
+    let (a_tx, a_rx) = oneshot::channel::<i32>();
+    let (b_tx, b_rx) = oneshot::channel::<i32>();
+    futures.push(a_rx);
+    futures.push(b_rx);
+    assert_eq!(futures.peak_len(), 2);
+
+    // Both pending futures are polled once; pending polls count too.
+    assert_eq!(futures.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(futures.poll_count(), 2);
+
+    a_tx.send(1).unwrap();
+    assert_eq!(futures.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(1))));
+    assert_eq!(futures.poll_count(), 3);
+
+    // The peak is monotonic: completions don't lower it...
+    assert_eq!(futures.len(), 1);
+    assert_eq!(futures.peak_len(), 2);
+
+    // ...and it only moves when the previous high-water mark is exceeded.
+    let (c_tx, c_rx) = oneshot::channel::<i32>();
+    futures.push(c_rx);
+    assert_eq!(futures.peak_len(), 2);
+    let (d_tx, d_rx) = oneshot::channel::<i32>();
+    futures.push(d_rx);
+    assert_eq!(futures.peak_len(), 3);
+
+    drop((b_tx, c_tx, d_tx));
+}
+
+#[test]
+fn reaping_removed_future_promotes_dormant() {
+    // A future removed while already scheduled in the ready to run queue
+    // leaves a placeholder entry behind. Cleaning that entry out must also
+    // hand freed slots to held-back futures.
+    let mut cx = noop_context();
+
+    let mut futures = FuturesUnordered::with_max_concurrent(1);
+    let (instrumented, instruments) = instrumented_futures(3);
+    for future in instrumented {
+        futures.push(future);
+    }
+
+    // Future 0 is active and stays hot, so its queue entry is pending when
+    // `retain` removes it.
+    assert_eq!(futures.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(instruments.active.get(), 1);
+    futures.retain(|future| future.id != 0);
+    assert_eq!(futures.len(), 2);
+
+    // The held-back futures take over the freed slot one at a time.
+    instruments.done[1].set(true);
+    instruments.done[2].set(true);
+    let mut seen = Vec::new();
+    while let Poll::Ready(Some(id)) = futures.poll_next_unpin(&mut cx) {
+        seen.push(id);
+    }
+    assert!(futures.is_empty());
+    seen.sort_unstable();
+    assert_eq!(seen, vec![1, 2]);
+}
@@ -0,0 +1,61 @@
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+
+#[test]
+fn group_by_key_transitions() {
+    block_on(async {
+        let mut groups =
+            stream::iter(vec!["ant", "apple", "bee", "bat", "cat"]).group_by(|s| s.chars().next());
+
+        let mut out = Vec::new();
+        while let Some((key, group)) = groups.next().await {
+            out.push((key, group.collect::<Vec<_>>().await));
+        }
+
+        assert_eq!(
+            out,
+            vec![
+                (Some('a'), vec!["ant", "apple"]),
+                (Some('b'), vec!["bee", "bat"]),
+                (Some('c'), vec!["cat"]),
+            ]
+        );
+    });
+}
+
+#[test]
+fn group_by_single_run() {
+    block_on(async {
+        let mut groups = stream::iter(vec![7, 7, 7]).group_by(|x| *x);
+
+        let (key, group) = groups.next().await.unwrap();
+        assert_eq!(key, 7);
+        assert_eq!(group.collect::<Vec<_>>().await, vec![7, 7, 7]);
+        assert!(groups.next().await.is_none());
+    });
+}
+
+#[test]
+fn group_by_early_drop_skips_rest_of_group() {
+    block_on(async {
+        let mut groups = stream::iter(vec![1, 1, 1, 2, 2, 3]).group_by(|x| *x);
+
+        // Drop the first group after reading a single item; the rest of the
+        // run must be skipped.
+        let (key, mut group) = groups.next().await.unwrap();
+        assert_eq!(key, 1);
+        assert_eq!(group.next().await, Some(1));
+        drop(group);
+
+        let (key, group) = groups.next().await.unwrap();
+        assert_eq!(key, 2);
+        assert_eq!(group.collect::<Vec<_>>().await, vec![2, 2]);
+
+        // Dropping a group without polling it at all also works.
+        let (key, group) = groups.next().await.unwrap();
+        assert_eq!(key, 3);
+        drop(group);
+
+        assert!(groups.next().await.is_none());
+    });
+}
@@ -0,0 +1,41 @@
+use futures::stream::{self, FusedStream, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[test]
+fn fires_exactly_once_on_completion() {
+    let mut cx = noop_context();
+
+    let calls = Rc::new(Cell::new(0));
+    let calls2 = calls.clone();
+    let mut stream = stream::iter(vec![1, 2]).inspect_done(move || calls2.set(calls2.get() + 1));
+
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    assert_eq!(calls.get(), 0);
+
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert_eq!(calls.get(), 1);
+
+    // Fused: polling after completion yields `None` without firing again.
+    assert!(stream.is_terminated());
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn does_not_fire_on_early_drop() {
+    let mut cx = noop_context();
+
+    let calls = Rc::new(Cell::new(0));
+    let calls2 = calls.clone();
+    let mut stream = stream::iter(vec![1, 2, 3]).inspect_done(move || calls2.set(calls2.get() + 1));
+
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    drop(stream);
+
+    assert_eq!(calls.get(), 0);
+}
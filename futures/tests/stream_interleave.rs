@@ -0,0 +1,35 @@
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+use futures_test::stream::StreamTestExt;
+
+#[test]
+fn interleave_equal_length() {
+    block_on(async {
+        let interleaved = stream::iter(vec![1, 2, 3]).interleave(stream::iter(vec![4, 5, 6]));
+        assert_eq!(vec![1, 4, 2, 5, 3, 6], interleaved.collect::<Vec<_>>().await);
+    });
+}
+
+#[test]
+fn interleave_unequal_length_drains_remainder() {
+    block_on(async {
+        let interleaved = stream::iter(vec![1, 2]).interleave(stream::iter(vec![4, 5, 6, 7]));
+        assert_eq!(vec![1, 4, 2, 5, 6, 7], interleaved.collect::<Vec<_>>().await);
+
+        let interleaved = stream::iter(vec![1, 2, 3, 4]).interleave(stream::iter(vec![10]));
+        assert_eq!(vec![1, 10, 2, 3, 4], interleaved.collect::<Vec<_>>().await);
+    });
+}
+
+#[test]
+fn interleave_waits_for_the_side_whose_turn_it_is() {
+    block_on(async {
+        // Even with one side intermittently pending, the output ordering
+        // stays strictly alternating.
+        let left = stream::iter(vec![1, 2, 3]).interleave_pending();
+        let right = stream::iter(vec![4, 5, 6]);
+
+        let interleaved = left.interleave(right);
+        assert_eq!(vec![1, 4, 2, 5, 3, 6], interleaved.collect::<Vec<_>>().await);
+    });
+}
@@ -0,0 +1,38 @@
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+
+#[test]
+fn intersperse_empty() {
+    block_on(async {
+        let stream = stream::iter(Vec::<u32>::new()).intersperse(0);
+        assert_eq!(Vec::<u32>::new(), stream.collect::<Vec<_>>().await);
+    });
+}
+
+#[test]
+fn intersperse_single_item() {
+    block_on(async {
+        let stream = stream::iter(vec![1]).intersperse(0);
+        assert_eq!(vec![1], stream.collect::<Vec<_>>().await);
+    });
+}
+
+#[test]
+fn intersperse_many_items() {
+    block_on(async {
+        let stream = stream::iter(vec![1, 2, 3, 4]).intersperse(0);
+        assert_eq!(vec![1, 0, 2, 0, 3, 0, 4], stream.collect::<Vec<_>>().await);
+    });
+}
+
+#[test]
+fn intersperse_with_closure() {
+    block_on(async {
+        let mut n = 0;
+        let stream = stream::iter(vec![1, 2, 3]).intersperse_with(move || {
+            n -= 1;
+            n
+        });
+        assert_eq!(vec![1, -1, 2, -2, 3], stream.collect::<Vec<_>>().await);
+    });
+}
@@ -0,0 +1,39 @@
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+use std::cell::Cell;
+
+#[test]
+fn last_empty() {
+    block_on(async {
+        assert_eq!(stream::empty::<i32>().last().await, None);
+    })
+}
+
+#[test]
+fn last_single() {
+    block_on(async {
+        assert_eq!(stream::once(async { 7 }).last().await, Some(7));
+    })
+}
+
+#[test]
+fn last_multiple() {
+    block_on(async {
+        assert_eq!(stream::iter(1..=5).last().await, Some(5));
+    })
+}
+
+#[test]
+fn last_drains_intermediate_items() {
+    block_on(async {
+        let seen = Cell::new(0);
+        let last = stream::iter(1..=4)
+            .inspect(|_| {
+                seen.set(seen.get() + 1);
+            })
+            .last()
+            .await;
+        assert_eq!(last, Some(4));
+        assert_eq!(seen.get(), 4);
+    })
+}
@@ -0,0 +1,53 @@
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+
+#[test]
+fn both_ready_alternates() {
+    block_on(async {
+        let left = stream::repeat(1);
+        let right = stream::repeat(2);
+
+        let merged = left.merge(right);
+        assert_eq!(vec![1, 2, 1, 2, 1, 2], merged.take(6).collect::<Vec<_>>().await);
+    });
+}
+
+#[test]
+fn pending_side_does_not_block_the_other() {
+    let mut cx = noop_context();
+
+    let (tx, rx) = mpsc::unbounded::<i32>();
+    let mut merged = rx.merge(stream::repeat(0).take(2));
+
+    // The channel is empty, but the other side keeps producing.
+    assert_eq!(merged.poll_next_unpin(&mut cx), Poll::Ready(Some(0)));
+    assert_eq!(merged.poll_next_unpin(&mut cx), Poll::Ready(Some(0)));
+    assert!(merged.poll_next_unpin(&mut cx).is_pending());
+
+    tx.unbounded_send(1).unwrap();
+    assert_eq!(merged.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+}
+
+#[test]
+fn drains_remaining_side_after_one_ends() {
+    block_on(async {
+        let left = stream::iter(vec![1, 2]);
+        let right = stream::iter(vec![10, 20, 30, 40]);
+
+        let mut items = left.merge(right).collect::<Vec<_>>().await;
+        assert_eq!(items.len(), 6);
+        items.sort_unstable();
+        assert_eq!(vec![1, 2, 10, 20, 30, 40], items);
+    });
+}
+
+#[test]
+fn completes_when_both_end() {
+    block_on(async {
+        let merged = stream::iter(vec![1]).merge(stream::empty());
+        assert_eq!(vec![1], merged.collect::<Vec<_>>().await);
+    });
+}
@@ -0,0 +1,56 @@
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+
+#[test]
+fn max_by_key_matches_iterator() {
+    block_on(async {
+        let items = vec![-10i32, 3, -4, 10, 7];
+        let expected = items.iter().copied().max_by_key(|x| x.abs());
+        assert_eq!(stream::iter(items).max_by_key(|x| x.abs()).await, expected);
+    })
+}
+
+#[test]
+fn min_by_key_matches_iterator() {
+    block_on(async {
+        let items = vec![-10i32, 3, -4, 10, -3];
+        let expected = items.iter().copied().min_by_key(|x| x.abs());
+        assert_eq!(stream::iter(items).min_by_key(|x| x.abs()).await, expected);
+    })
+}
+
+#[test]
+fn max_by_key_ties_return_last() {
+    block_on(async {
+        // Both -5 and 5 have the maximal key; std returns the last.
+        let items = vec![-5i32, 1, 5];
+        assert_eq!(items.iter().copied().max_by_key(|x| x.abs()), Some(5));
+        assert_eq!(stream::iter(items).max_by_key(|x| x.abs()).await, Some(5));
+    })
+}
+
+#[test]
+fn min_by_key_ties_return_first() {
+    block_on(async {
+        // Both -1 and 1 have the minimal key; std returns the first.
+        let items = vec![-1i32, 4, 1];
+        assert_eq!(items.iter().copied().min_by_key(|x| x.abs()), Some(-1));
+        assert_eq!(stream::iter(items).min_by_key(|x| x.abs()).await, Some(-1));
+    })
+}
+
+#[test]
+fn empty_returns_none() {
+    block_on(async {
+        assert_eq!(stream::empty::<i32>().max_by_key(|x| *x).await, None);
+        assert_eq!(stream::empty::<i32>().min_by_key(|x| *x).await, None);
+    })
+}
+
+#[test]
+fn single_item_returns_itself() {
+    block_on(async {
+        assert_eq!(stream::iter(vec![7]).max_by_key(|x| *x).await, Some(7));
+        assert_eq!(stream::iter(vec![7]).min_by_key(|x| *x).await, Some(7));
+    })
+}
@@ -0,0 +1,47 @@
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+use std::cell::Cell;
+
+#[test]
+fn nth_in_range() {
+    block_on(async {
+        assert_eq!(stream::iter(1..=5).nth(2).await, Some(3));
+    })
+}
+
+#[test]
+fn nth_zero_returns_first() {
+    block_on(async {
+        assert_eq!(stream::iter(1..=5).nth(0).await, Some(1));
+    })
+}
+
+#[test]
+fn nth_out_of_range_drains() {
+    block_on(async {
+        let seen = Cell::new(0);
+        let out = stream::iter(1..=3)
+            .inspect(|_| {
+                seen.set(seen.get() + 1);
+            })
+            .nth(7)
+            .await;
+        assert_eq!(out, None);
+        assert_eq!(seen.get(), 3);
+    })
+}
+
+#[test]
+fn nth_consumes_up_to_n_plus_one() {
+    block_on(async {
+        let seen = Cell::new(0);
+        let mut stream = stream::iter(1..=5).inspect(|_| {
+            seen.set(seen.get() + 1);
+        });
+        assert_eq!((&mut stream).nth(1).await, Some(2));
+        assert_eq!(seen.get(), 2);
+
+        // The remainder of the stream is untouched.
+        assert_eq!(stream.next().await, Some(3));
+    })
+}
@@ -0,0 +1,41 @@
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+
+#[test]
+fn partition_splits_by_predicate() {
+    block_on(async {
+        let (even, odd): (Vec<i32>, Vec<i32>) =
+            stream::iter(vec![1, 2, 3, 4, 5, 6]).partition(|n| n % 2 == 0).await;
+
+        assert_eq!(even, vec![2, 4, 6]);
+        assert_eq!(odd, vec![1, 3, 5]);
+    });
+}
+
+#[test]
+fn partition_empty_stream() {
+    block_on(async {
+        let (yes, no): (Vec<i32>, Vec<i32>) =
+            stream::iter(Vec::<i32>::new()).partition(|_| true).await;
+
+        assert!(yes.is_empty());
+        assert!(no.is_empty());
+    });
+}
+
+#[test]
+fn predicate_runs_once_per_item() {
+    block_on(async {
+        let mut calls = 0;
+        let (all, none): (Vec<i32>, Vec<i32>) = stream::iter(vec![1, 2, 3])
+            .partition(|_| {
+                calls += 1;
+                true
+            })
+            .await;
+
+        assert_eq!(calls, 3);
+        assert_eq!(all, vec![1, 2, 3]);
+        assert!(none.is_empty());
+    });
+}
@@ -0,0 +1,73 @@
+use futures::executor::block_on;
+use futures::pin_mut;
+use futures::stream::{self, Stream, StreamExt};
+
+#[test]
+fn peek_two_then_consume() {
+    block_on(async {
+        let stream = stream::iter(1..=5).peekable();
+        pin_mut!(stream);
+
+        assert_eq!(stream.as_mut().peek_n(2).await, &[1, 2]);
+        // Peeking does not consume: subsequent `next()` calls yield the
+        // buffered items in order.
+        assert_eq!(stream.as_mut().next().await, Some(1));
+        assert_eq!(stream.as_mut().next().await, Some(2));
+        assert_eq!(stream.as_mut().next().await, Some(3));
+    });
+}
+
+#[test]
+fn peek_three_mixes_with_peek() {
+    block_on(async {
+        let stream = stream::iter(1..=4).peekable();
+        pin_mut!(stream);
+
+        // Single-item peek first, then a wider lookahead over the same items.
+        assert_eq!(stream.as_mut().peek().await, Some(&1));
+        assert_eq!(stream.as_mut().peek_n(3).await, &[1, 2, 3]);
+        assert_eq!(stream.as_mut().peek().await, Some(&1));
+
+        assert_eq!(stream.collect::<Vec<_>>().await, vec![1, 2, 3, 4]);
+    });
+}
+
+#[test]
+fn peek_past_end_returns_remainder() {
+    block_on(async {
+        let stream = stream::iter(vec![1, 2]).peekable();
+        pin_mut!(stream);
+
+        assert_eq!(stream.as_mut().peek_n(5).await, &[1, 2]);
+        assert_eq!(stream.as_mut().next().await, Some(1));
+        assert_eq!(stream.as_mut().next().await, Some(2));
+        assert_eq!(stream.as_mut().peek_n(5).await, &[] as &[i32]);
+        assert_eq!(stream.as_mut().next().await, None);
+    });
+}
+
+#[test]
+fn repeated_peeks_grow_lookahead() {
+    block_on(async {
+        let stream = stream::iter(1..=6).peekable();
+        pin_mut!(stream);
+
+        assert_eq!(stream.as_mut().peek_n(2).await, &[1, 2]);
+        assert_eq!(stream.as_mut().peek_n(4).await, &[1, 2, 3, 4]);
+        // A smaller peek just returns a prefix of what is already buffered.
+        assert_eq!(stream.as_mut().peek_n(3).await, &[1, 2, 3]);
+
+        assert_eq!(stream.collect::<Vec<_>>().await, vec![1, 2, 3, 4, 5, 6]);
+    });
+}
+
+#[test]
+fn size_hint_includes_lookahead() {
+    block_on(async {
+        let stream = stream::iter(1..=4).peekable();
+        pin_mut!(stream);
+
+        assert_eq!(stream.as_mut().peek_n(3).await, &[1, 2, 3]);
+        assert_eq!(stream.size_hint(), (4, Some(4)));
+    });
+}
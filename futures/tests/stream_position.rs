@@ -0,0 +1,44 @@
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+use std::cell::Cell;
+
+#[test]
+fn position_at_start() {
+    block_on(async {
+        assert_eq!(stream::iter(vec![2, 3, 5]).position(|x| x % 2 == 0).await, Some(0));
+    })
+}
+
+#[test]
+fn position_in_middle() {
+    block_on(async {
+        assert_eq!(stream::iter(vec![1, 3, 4, 7]).position(|x| x % 2 == 0).await, Some(2));
+    })
+}
+
+#[test]
+fn position_at_end() {
+    block_on(async {
+        assert_eq!(stream::iter(vec![1, 3, 5, 8]).position(|x| x % 2 == 0).await, Some(3));
+    })
+}
+
+#[test]
+fn position_no_match() {
+    block_on(async {
+        assert_eq!(stream::iter(vec![1, 3, 5]).position(|x| x % 2 == 0).await, None);
+    })
+}
+
+#[test]
+fn position_short_circuits() {
+    block_on(async {
+        let seen = Cell::new(0);
+        let mut stream = stream::iter(1..=10).inspect(|_| {
+            seen.set(seen.get() + 1);
+        });
+        assert_eq!((&mut stream).position(|x| *x == 3).await, Some(2));
+        assert_eq!(seen.get(), 3);
+        assert_eq!(stream.next().await, Some(4));
+    })
+}
@@ -0,0 +1,141 @@
+use futures::future::Future;
+use futures::stream::{self, FusedStream, StreamExt};
+use futures::task::{Context, Poll};
+use futures_test::task::noop_context;
+use std::cell::{Cell, RefCell};
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::Waker;
+use std::time::Duration;
+
+/// A manually advanced clock whose sleeps resolve once enough time has been
+/// added with [`advance`](MockClock::advance).
+#[derive(Clone, Default)]
+struct MockClock {
+    now: Rc<Cell<Duration>>,
+    wakers: Rc<RefCell<Vec<Waker>>>,
+}
+
+impl MockClock {
+    fn advance(&self, duration: Duration) {
+        self.now.set(self.now.get() + duration);
+        for waker in self.wakers.borrow_mut().drain(..) {
+            waker.wake();
+        }
+    }
+
+    fn timer(&self) -> impl FnMut(Duration) -> MockSleep {
+        let clock = self.clone();
+        move |duration| MockSleep { deadline: clock.now.get() + duration, clock: clock.clone() }
+    }
+}
+
+struct MockSleep {
+    deadline: Duration,
+    clock: MockClock,
+}
+
+impl Future for MockSleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.clock.now.get() >= self.deadline {
+            Poll::Ready(())
+        } else {
+            self.clock.wakers.borrow_mut().push(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+#[test]
+fn burst_then_steady_state() {
+    let clock = MockClock::default();
+    let mut stream = stream::iter(0..8).rate_limit(1.0, 3, clock.timer());
+    let mut cx = noop_context();
+
+    // The full burst is available immediately.
+    for i in 0..3 {
+        assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(i)));
+    }
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+
+    // Afterwards items are spaced one per second.
+    clock.advance(Duration::from_secs(1));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(3)));
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+
+    clock.advance(Duration::from_secs(1));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(4)));
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+}
+
+#[test]
+fn fractional_refill_accumulates() {
+    let clock = MockClock::default();
+    let mut stream = stream::iter(0..4).rate_limit(1.0, 1, clock.timer());
+    let mut cx = noop_context();
+
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(0)));
+
+    // Half a second is not enough for a token...
+    clock.advance(Duration::from_millis(500));
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+
+    // ...but the partial progress is not lost: another half completes it.
+    clock.advance(Duration::from_millis(500));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+}
+
+#[test]
+fn idle_refill_is_capped_at_burst() {
+    let clock = MockClock::default();
+    let mut stream = stream::iter(0..10).rate_limit(1.0, 3, clock.timer());
+    let mut cx = noop_context();
+
+    // Drain the initial burst, then go idle for a long time.
+    for i in 0..3 {
+        assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(i)));
+    }
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+    clock.advance(Duration::from_secs(100));
+
+    // Only `burst` tokens accumulated, not a hundred.
+    for i in 3..6 {
+        assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(i)));
+    }
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+}
+
+#[test]
+fn source_completion_ends_stream() {
+    let clock = MockClock::default();
+    let mut stream = stream::iter(0..2).rate_limit(1.0, 2, clock.timer());
+    let mut cx = noop_context();
+
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(0)));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(stream.is_terminated());
+}
+
+#[test]
+fn high_rate_passes_items_through() {
+    let clock = MockClock::default();
+    let stream = stream::iter(0..5).rate_limit(1000.0, 1, clock.timer());
+    let mut cx = noop_context();
+
+    // 1000 tokens per second with an always-advancing clock: every poll
+    // after an advance has a token available.
+    let mut stream = Box::pin(stream);
+    let mut collected = Vec::new();
+    loop {
+        match stream.poll_next_unpin(&mut cx) {
+            Poll::Ready(Some(item)) => collected.push(item),
+            Poll::Ready(None) => break,
+            Poll::Pending => clock.advance(Duration::from_millis(1)),
+        }
+    }
+    assert_eq!(collected, [0, 1, 2, 3, 4]);
+}
@@ -0,0 +1,61 @@
+use futures::pin_mut;
+use futures::stream::{self, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// Counts allocations so buffer reuse can be verified.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+// A single test so no other test in this binary allocates concurrently
+// while the allocation count is being measured.
+#[test]
+fn poll_next_into_reuses_buffer() {
+    let stream = stream::iter(0..35).ready_chunks(10);
+    pin_mut!(stream);
+    let mut cx = noop_context();
+    let mut buf = Vec::new();
+
+    // The first chunk grows the buffer to its working capacity.
+    assert_eq!(stream.as_mut().poll_next_into(&mut cx, &mut buf), Poll::Ready(Some(10)));
+    assert_eq!(buf, (0..10).collect::<Vec<_>>());
+    let cap = buf.capacity();
+
+    // Subsequent chunks reuse the buffer: it is cleared between chunks, its
+    // capacity is retained, and no further allocations are made.
+    let allocations_before = ALLOCATIONS.load(Ordering::SeqCst);
+
+    assert_eq!(stream.as_mut().poll_next_into(&mut cx, &mut buf), Poll::Ready(Some(10)));
+    assert!(buf.iter().copied().eq(10..20));
+    assert_eq!(buf.capacity(), cap);
+
+    assert_eq!(stream.as_mut().poll_next_into(&mut cx, &mut buf), Poll::Ready(Some(10)));
+    assert!(buf.iter().copied().eq(20..30));
+    assert_eq!(buf.capacity(), cap);
+
+    // The final partial chunk is flushed at end-of-stream.
+    assert_eq!(stream.as_mut().poll_next_into(&mut cx, &mut buf), Poll::Ready(Some(5)));
+    assert!(buf.iter().copied().eq(30..35));
+    assert_eq!(buf.capacity(), cap);
+
+    assert_eq!(stream.as_mut().poll_next_into(&mut cx, &mut buf), Poll::Ready(None));
+
+    assert_eq!(ALLOCATIONS.load(Ordering::SeqCst), allocations_before);
+}
@@ -0,0 +1,27 @@
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+
+#[test]
+fn reverses_finite_stream() {
+    let stream = stream::iter(vec![1, 2, 3, 4, 5]).reverse();
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![5, 4, 3, 2, 1]);
+}
+
+#[test]
+fn empty_stream_yields_empty() {
+    let stream = stream::iter(Vec::<i32>::new()).reverse();
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), Vec::<i32>::new());
+}
+
+#[test]
+fn works_on_non_double_ended_sources() {
+    // `unfold` streams have no back end; `reverse` still works by buffering.
+    let stream = stream::unfold(0, |n| async move {
+        if n < 4 {
+            Some((n, n + 1))
+        } else {
+            None
+        }
+    });
+    assert_eq!(block_on(stream.reverse().collect::<Vec<_>>()), vec![3, 2, 1, 0]);
+}
@@ -0,0 +1,132 @@
+use futures::channel::mpsc;
+use futures::future::Future;
+use futures::stream::{FusedStream, StreamExt};
+use futures::task::{Context, Poll};
+use futures_test::task::noop_context;
+use std::cell::{Cell, RefCell};
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::Waker;
+use std::time::Duration;
+
+/// A manually advanced clock whose sleeps resolve once enough time has been
+/// added with [`advance`](MockClock::advance).
+#[derive(Clone, Default)]
+struct MockClock {
+    now: Rc<Cell<Duration>>,
+    wakers: Rc<RefCell<Vec<Waker>>>,
+}
+
+impl MockClock {
+    fn advance(&self, duration: Duration) {
+        self.now.set(self.now.get() + duration);
+        for waker in self.wakers.borrow_mut().drain(..) {
+            waker.wake();
+        }
+    }
+
+    fn timer(&self) -> impl FnMut(Duration) -> MockSleep {
+        let clock = self.clone();
+        move |duration| MockSleep { deadline: clock.now.get() + duration, clock: clock.clone() }
+    }
+}
+
+struct MockSleep {
+    deadline: Duration,
+    clock: MockClock,
+}
+
+impl Future for MockSleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.clock.now.get() >= self.deadline {
+            Poll::Ready(())
+        } else {
+            self.clock.wakers.borrow_mut().push(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+const SECOND: Duration = Duration::from_secs(1);
+
+#[test]
+fn fast_items_coalesce_to_latest() {
+    let clock = MockClock::default();
+    let (mut tx, rx) = mpsc::unbounded();
+    let mut stream = rx.sample(SECOND, clock.timer());
+    let mut cx = noop_context();
+
+    tx.start_send(1).unwrap();
+    tx.start_send(2).unwrap();
+    tx.start_send(3).unwrap();
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+
+    clock.advance(SECOND);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(3)));
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+}
+
+#[test]
+fn empty_tick_emits_nothing() {
+    let clock = MockClock::default();
+    let (mut tx, rx) = mpsc::unbounded();
+    let mut stream = rx.sample(SECOND, clock.timer());
+    let mut cx = noop_context();
+
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+    clock.advance(SECOND);
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+
+    // An item arriving during the following interval is sampled on its
+    // tick as usual.
+    tx.start_send(1).unwrap();
+    clock.advance(SECOND);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+}
+
+#[test]
+fn one_item_per_tick() {
+    let clock = MockClock::default();
+    let (mut tx, rx) = mpsc::unbounded();
+    let mut stream = rx.sample(SECOND, clock.timer());
+    let mut cx = noop_context();
+
+    for i in 0..3 {
+        tx.start_send(i).unwrap();
+        assert!(stream.poll_next_unpin(&mut cx).is_pending());
+        clock.advance(SECOND);
+        assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(i)));
+    }
+}
+
+#[test]
+fn source_end_flushes_last_item() {
+    let clock = MockClock::default();
+    let (mut tx, rx) = mpsc::unbounded();
+    let mut stream = rx.sample(SECOND, clock.timer());
+    let mut cx = noop_context();
+
+    tx.start_send(1).unwrap();
+    tx.start_send(2).unwrap();
+    drop(tx);
+
+    // No tick elapsed, but the final value is not lost.
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(stream.is_terminated());
+}
+
+#[test]
+fn source_end_without_pending_item() {
+    let clock = MockClock::default();
+    let (tx, rx) = mpsc::unbounded::<i32>();
+    let mut stream = rx.sample(SECOND, clock.timer());
+    let mut cx = noop_context();
+
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+    drop(tx);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(stream.is_terminated());
+}
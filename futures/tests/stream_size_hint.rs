@@ -0,0 +1,63 @@
+use futures::executor::block_on;
+use futures::stream::{self, Stream, StreamExt};
+
+#[test]
+fn map_preserves_size_hint() {
+    let stream = stream::iter(0..10).map(|x| x + 1);
+    assert_eq!(stream.size_hint(), (10, Some(10)));
+}
+
+#[test]
+fn filter_drops_lower_bound() {
+    let stream = stream::iter(0..10).filter(|x| futures::future::ready(x % 2 == 0));
+    assert_eq!(stream.size_hint(), (0, Some(10)));
+}
+
+#[test]
+fn take_caps_both_bounds() {
+    let stream = stream::iter(0..10).take(3);
+    assert_eq!(stream.size_hint(), (3, Some(3)));
+
+    let stream = stream::iter(0..2).take(5);
+    assert_eq!(stream.size_hint(), (2, Some(2)));
+}
+
+#[test]
+fn skip_subtracts_from_both_bounds() {
+    let stream = stream::iter(0..10).skip(4);
+    assert_eq!(stream.size_hint(), (6, Some(6)));
+
+    let stream = stream::iter(0..3).skip(5);
+    assert_eq!(stream.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn enumerate_preserves_size_hint() {
+    let stream = stream::iter(0..7).enumerate();
+    assert_eq!(stream.size_hint(), (7, Some(7)));
+}
+
+#[test]
+fn chain_adds_bounds() {
+    let stream = stream::iter(0..3).chain(stream::iter(0..4));
+    assert_eq!(stream.size_hint(), (7, Some(7)));
+}
+
+#[test]
+fn zip_takes_the_minimum() {
+    let stream = stream::iter(0..3).zip(stream::iter(0..10));
+    assert_eq!(stream.size_hint(), (3, Some(3)));
+}
+
+#[test]
+fn collect_with_capacity_preallocates() {
+    block_on(async {
+        let n = 10_000usize;
+        let output = stream::iter(0..n).map(|x| x * 2).collect_with_capacity().await;
+
+        assert_eq!(output.len(), n);
+        // The lower bound was exact, so the initial allocation was never
+        // grown.
+        assert_eq!(output.capacity(), n);
+    });
+}
@@ -0,0 +1,36 @@
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+
+#[test]
+fn sorted_yields_ascending_order() {
+    block_on(async {
+        let out = stream::iter(vec![5, 1, 4, 2, 3]).sorted().collect::<Vec<_>>().await;
+        assert_eq!(out, vec![1, 2, 3, 4, 5]);
+    })
+}
+
+#[test]
+fn sorted_empty() {
+    block_on(async {
+        let out = stream::empty::<i32>().sorted().collect::<Vec<_>>().await;
+        assert_eq!(out, Vec::<i32>::new());
+    })
+}
+
+#[test]
+fn sorted_by_comparator() {
+    block_on(async {
+        let out = stream::iter(vec![1, 3, 2]).sorted_by(|a, b| b.cmp(a)).collect::<Vec<_>>().await;
+        assert_eq!(out, vec![3, 2, 1]);
+    })
+}
+
+#[test]
+fn sorted_by_is_stable() {
+    block_on(async {
+        // Items with equal keys must keep their arrival order.
+        let items = vec![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')];
+        let out = stream::iter(items).sorted_by(|a, b| a.0.cmp(&b.0)).collect::<Vec<_>>().await;
+        assert_eq!(out, vec![(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]);
+    })
+}
@@ -0,0 +1,110 @@
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::future::join;
+use futures::stream::{self, StreamExt};
+use futures_test::task::{new_count_waker, noop_context};
+use std::task::{Context, Poll};
+
+#[test]
+fn distributes_round_robin() {
+    block_on(async {
+        let mut parts = stream::iter(0..9).split_round_robin(3);
+        let c = parts.pop().unwrap();
+        let b = parts.pop().unwrap();
+        let a = parts.pop().unwrap();
+
+        // Drive the three outputs in lockstep.
+        let mut a = a;
+        let mut b = b;
+        let mut c = c;
+        for round in 0..3 {
+            assert_eq!(a.next().await, Some(round * 3));
+            assert_eq!(b.next().await, Some(round * 3 + 1));
+            assert_eq!(c.next().await, Some(round * 3 + 2));
+        }
+        assert_eq!(a.next().await, None);
+        assert_eq!(b.next().await, None);
+        assert_eq!(c.next().await, None);
+    });
+}
+
+#[test]
+fn concurrent_consumers_collect_their_share() {
+    block_on(async {
+        let mut parts = stream::iter(0..6).split_round_robin(2);
+        let odd = parts.pop().unwrap();
+        let even = parts.pop().unwrap();
+
+        let (even, odd) = join(even.collect::<Vec<_>>(), odd.collect::<Vec<_>>()).await;
+        assert_eq!(even, vec![0, 2, 4]);
+        assert_eq!(odd, vec![1, 3, 5]);
+    });
+}
+
+#[test]
+fn dropped_output_leaves_the_rotation() {
+    block_on(async {
+        let mut parts = stream::iter(0..6).split_round_robin(2);
+        let odd = parts.pop().unwrap();
+        let even = parts.pop().unwrap();
+
+        drop(odd);
+
+        // With the second output gone, the remaining one receives everything.
+        assert_eq!(even.collect::<Vec<_>>().await, vec![0, 1, 2, 3, 4, 5]);
+    });
+}
+
+#[test]
+fn lagging_consumer_applies_backpressure() {
+    let mut cx = noop_context();
+
+    let mut parts = stream::iter(0..100).split_round_robin(2);
+    let mut odd = parts.pop().unwrap();
+    let mut even = parts.pop().unwrap();
+
+    // The fast consumer can set one item aside for its lagging sibling, but
+    // cannot run further ahead until the sibling consumes it.
+    assert_eq!(even.poll_next_unpin(&mut cx), Poll::Ready(Some(0)));
+    assert_eq!(even.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    assert_eq!(even.poll_next_unpin(&mut cx), Poll::Pending);
+
+    assert_eq!(odd.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(even.poll_next_unpin(&mut cx), Poll::Ready(Some(4)));
+    assert_eq!(even.poll_next_unpin(&mut cx), Poll::Pending);
+}
+
+#[test]
+fn taking_own_item_wakes_the_next_turn_holder() {
+    let (tx, rx) = mpsc::unbounded();
+    let mut parts = rx.split_round_robin(2);
+    let mut second = parts.pop().unwrap();
+    let mut first = parts.pop().unwrap();
+
+    let (first_waker, _first_count) = new_count_waker();
+    let (second_waker, second_count) = new_count_waker();
+
+    // The second output parks while the source is empty; the first output
+    // polls the source afterwards, clobbering the waker the second output
+    // had registered with it.
+    assert_eq!(second.poll_next_unpin(&mut Context::from_waker(&second_waker)), Poll::Pending);
+    assert_eq!(first.poll_next_unpin(&mut Context::from_waker(&first_waker)), Poll::Pending);
+
+    tx.unbounded_send(0).unwrap();
+    assert_eq!(first.poll_next_unpin(&mut Context::from_waker(&first_waker)), Poll::Ready(Some(0)));
+
+    // Taking its own item passed the turn on; the second output must be
+    // woken explicitly, or it stays parked with no one left to wake it.
+    assert_eq!(second_count, 1);
+    tx.unbounded_send(1).unwrap();
+    assert_eq!(
+        second.poll_next_unpin(&mut Context::from_waker(&second_waker)),
+        Poll::Ready(Some(1))
+    );
+}
+
+#[test]
+fn zero_outputs() {
+    let parts = stream::iter(0..3).split_round_robin(0);
+    assert!(parts.is_empty());
+}
@@ -0,0 +1,32 @@
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+
+#[test]
+fn step_by_one_is_identity() {
+    block_on(async {
+        let stream = stream::iter(0..5).step_by(1);
+        assert_eq!(vec![0, 1, 2, 3, 4], stream.collect::<Vec<_>>().await);
+    });
+}
+
+#[test]
+fn step_by_two() {
+    block_on(async {
+        let stream = stream::iter(0..6).step_by(2);
+        assert_eq!(vec![0, 2, 4], stream.collect::<Vec<_>>().await);
+    });
+}
+
+#[test]
+fn step_by_larger_than_stream() {
+    block_on(async {
+        let stream = stream::iter(0..3).step_by(10);
+        assert_eq!(vec![0], stream.collect::<Vec<_>>().await);
+    });
+}
+
+#[test]
+#[should_panic]
+fn step_by_zero_panics() {
+    let _ = stream::iter(0..3).step_by(0);
+}
@@ -0,0 +1,41 @@
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+
+#[test]
+fn sum_known_sequence() {
+    block_on(async {
+        assert_eq!(stream::iter(1..=10).sum::<i32>().await, 55);
+        assert_eq!(stream::iter(vec![1.5f64, 2.5]).sum::<f64>().await, 4.0);
+    })
+}
+
+#[test]
+fn sum_empty_is_zero() {
+    block_on(async {
+        assert_eq!(stream::empty::<i32>().sum::<i32>().await, 0);
+    })
+}
+
+#[test]
+fn product_known_sequence() {
+    block_on(async {
+        assert_eq!(stream::iter(1..=5).product::<i32>().await, 120);
+    })
+}
+
+#[test]
+fn product_empty_is_one() {
+    block_on(async {
+        assert_eq!(stream::empty::<i32>().product::<i32>().await, 1);
+    })
+}
+
+#[test]
+fn sum_wrapping_follows_item_type() {
+    block_on(async {
+        use std::num::Wrapping;
+        let total =
+            stream::iter(vec![Wrapping(u8::max_value()), Wrapping(2)]).sum::<Wrapping<u8>>().await;
+        assert_eq!(total, Wrapping(1));
+    })
+}
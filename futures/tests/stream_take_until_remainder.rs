@@ -0,0 +1,71 @@
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use futures::future;
+use futures::stream::{self, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+
+#[test]
+fn recover_and_continue() {
+    block_on(async {
+        let stream = stream::iter(1..=10);
+
+        let mut i = 0;
+        let stop_fut = future::poll_fn(|_cx| {
+            i += 1;
+            if i <= 5 {
+                Poll::Pending
+            } else {
+                Poll::Ready("reason")
+            }
+        });
+
+        let mut stream = stream.take_until_remainder(stop_fut);
+        assert_eq!(vec![1, 2, 3, 4, 5], stream.by_ref().collect::<Vec<_>>().await);
+        assert!(stream.is_stopped());
+        assert_eq!(stream.take_result(), Some("reason"));
+
+        let remainder = stream.into_inner().unwrap();
+        assert_eq!(vec![6, 7, 8, 9, 10], remainder.collect::<Vec<_>>().await);
+    });
+}
+
+#[test]
+fn into_inner_before_signal_returns_none() {
+    block_on(async {
+        let (_tx, rx) = oneshot::channel::<()>();
+        let mut stream = stream::iter(1..=3).take_until_remainder(rx);
+
+        assert_eq!(stream.next().await, Some(1));
+        assert!(!stream.is_stopped());
+        assert!(stream.into_inner().is_none());
+    });
+}
+
+#[test]
+fn signal_wins_over_ready_item() {
+    let mut cx = noop_context();
+
+    // Both the signal and the next item are ready on the same poll: the
+    // stream must stop and leave the item in the remainder.
+    let mut stream = stream::iter(1..=3).take_until_remainder(future::ready(()));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(stream.is_stopped());
+
+    let remainder = stream.into_inner().unwrap();
+    assert_eq!(block_on(remainder.collect::<Vec<_>>()), vec![1, 2, 3]);
+}
+
+#[test]
+fn inner_stream_ending_does_not_stop() {
+    block_on(async {
+        let (_tx, rx) = oneshot::channel::<()>();
+        let mut stream = stream::iter(1..=2).take_until_remainder(rx);
+
+        assert_eq!(stream.by_ref().collect::<Vec<_>>().await, vec![1, 2]);
+        // The inner stream ended on its own, so the signal never fired and
+        // there is no remainder to recover.
+        assert!(!stream.is_stopped());
+        assert!(stream.into_inner().is_none());
+    });
+}
@@ -0,0 +1,81 @@
+use std::cell::Cell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::stream::StreamExt;
+use futures_test::task::noop_context;
+
+/// A manually driven delay: completes once the shared flag has been set.
+#[derive(Clone)]
+struct MockDelay {
+    fired: Rc<Cell<bool>>,
+}
+
+impl Future for MockDelay {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+        if self.fired.get() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+#[test]
+fn throttle_forwards_first_and_coalesces_rest() {
+    block_on(async {
+        let stream = futures::stream::iter(1..=5)
+            .throttle(Duration::from_millis(10), |_| futures::future::ready(()));
+        assert_eq!(vec![1, 5], stream.collect::<Vec<_>>().await);
+    });
+}
+
+#[test]
+fn throttle_spaces_out_emissions() {
+    let fired = Rc::new(Cell::new(false));
+    let delay = MockDelay { fired: fired.clone() };
+
+    let (tx, rx) = mpsc::unbounded::<u32>();
+    let mut throttled = rx.throttle(Duration::from_millis(10), move |_| {
+        delay.fired.set(false);
+        delay.clone()
+    });
+
+    let mut cx = noop_context();
+
+    // The first item passes through immediately and closes the window.
+    tx.unbounded_send(1).unwrap();
+    assert_eq!(throttled.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+
+    // Items arriving inside the window are suppressed down to the latest.
+    tx.unbounded_send(2).unwrap();
+    tx.unbounded_send(3).unwrap();
+    assert!(throttled.poll_next_unpin(&mut cx).is_pending());
+
+    // When the window reopens the suppressed item is emitted and a new
+    // window begins.
+    fired.set(true);
+    assert_eq!(throttled.poll_next_unpin(&mut cx), Poll::Ready(Some(3)));
+    assert!(throttled.poll_next_unpin(&mut cx).is_pending());
+
+    // With an open window and no suppressed item, the next item passes
+    // through immediately again.
+    fired.set(true);
+    assert!(throttled.poll_next_unpin(&mut cx).is_pending());
+    tx.unbounded_send(4).unwrap();
+    assert_eq!(throttled.poll_next_unpin(&mut cx), Poll::Ready(Some(4)));
+
+    // A trailing suppressed item is flushed when the stream ends.
+    tx.unbounded_send(5).unwrap();
+    assert!(throttled.poll_next_unpin(&mut cx).is_pending());
+    drop(tx);
+    assert_eq!(throttled.poll_next_unpin(&mut cx), Poll::Ready(Some(5)));
+    assert_eq!(throttled.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
@@ -0,0 +1,131 @@
+use futures::channel::mpsc;
+use futures::future::{Elapsed, Future};
+use futures::stream::{FusedStream, StreamExt};
+use futures::task::{Context, Poll};
+use futures_test::task::noop_context;
+use std::cell::{Cell, RefCell};
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::Waker;
+use std::time::Duration;
+
+/// A manually advanced clock whose sleeps resolve once enough time has been
+/// added with [`advance`](MockClock::advance).
+#[derive(Clone, Default)]
+struct MockClock {
+    now: Rc<Cell<Duration>>,
+    wakers: Rc<RefCell<Vec<Waker>>>,
+}
+
+impl MockClock {
+    fn advance(&self, duration: Duration) {
+        self.now.set(self.now.get() + duration);
+        for waker in self.wakers.borrow_mut().drain(..) {
+            waker.wake();
+        }
+    }
+
+    fn timer(&self) -> impl FnMut(Duration) -> MockSleep {
+        let clock = self.clone();
+        move |duration| MockSleep { deadline: clock.now.get() + duration, clock: clock.clone() }
+    }
+}
+
+struct MockSleep {
+    deadline: Duration,
+    clock: MockClock,
+}
+
+impl Future for MockSleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.clock.now.get() >= self.deadline {
+            Poll::Ready(())
+        } else {
+            self.clock.wakers.borrow_mut().push(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+const SECOND: Duration = Duration::from_secs(1);
+
+#[test]
+fn items_within_deadline_pass_through() {
+    let clock = MockClock::default();
+    let (mut tx, rx) = mpsc::unbounded();
+    let mut stream = rx.timeout(SECOND, clock.timer());
+    let mut cx = noop_context();
+
+    tx.start_send(1).unwrap();
+    tx.start_send(2).unwrap();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(1))));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(2))));
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+}
+
+#[test]
+fn missing_item_yields_error_and_keeps_going() {
+    let clock = MockClock::default();
+    let (mut tx, rx) = mpsc::unbounded();
+    let mut stream = rx.timeout(SECOND, clock.timer());
+    let mut cx = noop_context();
+
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+    clock.advance(SECOND);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(Err(Elapsed))));
+
+    // The stream stays alive: a later item comes through as usual.
+    tx.start_send(7).unwrap();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(7))));
+}
+
+#[test]
+fn deadline_resets_after_each_item() {
+    let clock = MockClock::default();
+    let (mut tx, rx) = mpsc::unbounded();
+    let mut stream = rx.timeout(SECOND, clock.timer());
+    let mut cx = noop_context();
+
+    // Items spaced three quarters apart never time out, even though the
+    // total elapsed time exceeds the deadline.
+    for i in 0..3 {
+        assert!(stream.poll_next_unpin(&mut cx).is_pending());
+        clock.advance(3 * SECOND / 4);
+        tx.start_send(i).unwrap();
+        assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(i))));
+    }
+}
+
+#[test]
+fn deadline_resets_after_timeout_error() {
+    let clock = MockClock::default();
+    let (_tx, rx) = mpsc::unbounded::<i32>();
+    let mut stream = rx.timeout(SECOND, clock.timer());
+    let mut cx = noop_context();
+
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+    clock.advance(SECOND);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(Err(Elapsed))));
+
+    // A fresh full deadline applies to the next wait.
+    clock.advance(SECOND / 2);
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+    clock.advance(SECOND / 2);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(Err(Elapsed))));
+}
+
+#[test]
+fn stream_end_cancels_timer() {
+    let clock = MockClock::default();
+    let (mut tx, rx) = mpsc::unbounded();
+    let mut stream = rx.timeout(SECOND, clock.timer());
+    let mut cx = noop_context();
+
+    tx.start_send(1).unwrap();
+    drop(tx);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(1))));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(stream.is_terminated());
+}
@@ -0,0 +1,72 @@
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt, TryStreamExt};
+use futures_test::task::noop_context;
+use std::task::Poll;
+
+#[test]
+fn staggered_completion_preserves_order() {
+    let mut cx = noop_context();
+
+    let (tx1, rx1) = oneshot::channel::<i32>();
+    let (tx2, rx2) = oneshot::channel::<i32>();
+    let (tx3, rx3) = oneshot::channel::<i32>();
+
+    let stream_of_futures = stream::iter(vec![Ok(rx1), Ok(rx2), Ok(rx3)]);
+    let mut buffered = stream_of_futures.try_buffered(10);
+
+    // Later futures completing first must wait for earlier ones.
+    tx3.send(3).unwrap();
+    tx2.send(2).unwrap();
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Pending);
+
+    tx1.send(1).unwrap();
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(1))));
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(2))));
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(3))));
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
+
+#[test]
+fn error_in_the_middle_cancels_later_futures() {
+    block_on(async {
+        let (tx1, rx1) = oneshot::channel::<i32>();
+        let (tx2, rx2) = oneshot::channel::<i32>();
+        let (tx3, rx3) = oneshot::channel::<i32>();
+
+        let stream_of_futures = stream::iter(vec![Ok(rx1), Ok(rx2), Ok(rx3)]);
+        let mut buffered = stream_of_futures.try_buffered(10);
+
+        tx1.send(1).unwrap();
+        assert_eq!(buffered.next().await, Some(Ok(1)));
+
+        // The second future fails; the third is dropped when the error is
+        // yielded, which its sender observes as cancellation.
+        drop(tx2);
+        assert!(buffered.next().await.unwrap().is_err());
+        assert!(tx3.is_canceled());
+
+        assert!(buffered.next().await.is_none());
+    });
+}
+
+#[test]
+fn error_does_not_jump_ahead_of_earlier_results() {
+    let mut cx = noop_context();
+
+    let (tx1, rx1) = oneshot::channel::<i32>();
+    let (_tx2, rx2) = oneshot::channel::<i32>();
+
+    let stream_of_futures = stream::iter(vec![Ok(rx1), Ok(rx2)]);
+    let mut buffered = stream_of_futures.try_buffered(10);
+
+    // The second future has already failed, but the first one is still
+    // pending, so nothing is yielded yet.
+    drop(_tx2);
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Pending);
+
+    tx1.send(1).unwrap();
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(1))));
+    assert!(matches!(buffered.poll_next_unpin(&mut cx), Poll::Ready(Some(Err(_)))));
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
@@ -0,0 +1,92 @@
+use std::cell::Cell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::stream::{StreamExt, TryChunksTimeoutError, TryStreamExt};
+use futures_test::task::noop_context;
+
+/// A manually driven delay: completes once the shared flag has been set.
+#[derive(Clone)]
+struct MockDelay {
+    fired: Rc<Cell<bool>>,
+}
+
+impl Future for MockDelay {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+        if self.fired.get() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+#[test]
+fn try_chunks_timeout_count_path() {
+    block_on(async {
+        let stream = futures::stream::iter(vec![Ok::<i32, i32>(1), Ok(2), Ok(3), Ok(4), Ok(5)])
+            .try_chunks_timeout(2, Duration::from_millis(10), |_| futures::future::pending());
+        assert_eq!(
+            vec![vec![1, 2], vec![3, 4], vec![5]],
+            stream.try_collect::<Vec<_>>().await.unwrap()
+        );
+    });
+}
+
+#[test]
+fn try_chunks_timeout_error_flushes_batch_into_payload() {
+    block_on(async {
+        let stream = futures::stream::iter(vec![Ok::<i32, i32>(1), Ok(2), Ok(3), Err(4), Ok(5)]);
+        let mut stream =
+            stream.try_chunks_timeout(2, Duration::from_millis(10), |_| futures::future::pending());
+
+        assert_eq!(stream.try_next().await, Ok(Some(vec![1, 2])));
+        assert_eq!(stream.try_next().await, Err(TryChunksTimeoutError(vec![3], 4)));
+        assert_eq!(stream.try_next().await, Ok(Some(vec![5])));
+        assert_eq!(stream.try_next().await, Ok(None));
+    });
+}
+
+#[test]
+fn try_chunks_timeout_time_path() {
+    let fired = Rc::new(Cell::new(false));
+    let delay = MockDelay { fired: fired.clone() };
+
+    let (tx, rx) = mpsc::unbounded::<Result<u32, u32>>();
+    let mut chunked = rx.try_chunks_timeout(3, Duration::from_millis(10), move |_| {
+        delay.fired.set(false);
+        delay.clone()
+    });
+
+    let mut cx = noop_context();
+
+    // A partial chunk is held while the timer is running...
+    tx.unbounded_send(Ok(1)).unwrap();
+    tx.unbounded_send(Ok(2)).unwrap();
+    assert!(chunked.poll_next_unpin(&mut cx).is_pending());
+
+    // ...and flushed once the timeout elapses.
+    fired.set(true);
+    assert_eq!(chunked.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(vec![1, 2]))));
+
+    // An error mid-batch carries the buffered items in the payload.
+    tx.unbounded_send(Ok(3)).unwrap();
+    tx.unbounded_send(Err(4)).unwrap();
+    assert_eq!(
+        chunked.poll_next_unpin(&mut cx),
+        Poll::Ready(Some(Err(TryChunksTimeoutError(vec![3], 4))))
+    );
+
+    // A partial chunk is flushed when the source ends mid-window.
+    tx.unbounded_send(Ok(5)).unwrap();
+    drop(tx);
+    assert_eq!(chunked.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(vec![5]))));
+    assert_eq!(chunked.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
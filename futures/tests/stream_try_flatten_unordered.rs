@@ -0,0 +1,96 @@
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt, TryStreamExt};
+use futures_test::task::noop_context;
+use std::task::Poll;
+
+#[test]
+fn interleaves_inner_streams() {
+    block_on(async {
+        let (tx1, rx1) = mpsc::unbounded::<Result<i32, i32>>();
+        let (tx2, rx2) = mpsc::unbounded();
+
+        let outer = stream::iter(vec![Ok::<_, i32>(rx1), Ok(rx2)]);
+        let mut flattened = outer.try_flatten_unordered(None);
+
+        // Items become ready on the inner streams in alternating order; the
+        // flattened stream must not wait for the first inner stream to
+        // finish before yielding from the second.
+        tx2.unbounded_send(Ok(1)).unwrap();
+        assert_eq!(flattened.next().await, Some(Ok(1)));
+        tx1.unbounded_send(Ok(2)).unwrap();
+        assert_eq!(flattened.next().await, Some(Ok(2)));
+        tx2.unbounded_send(Ok(3)).unwrap();
+        assert_eq!(flattened.next().await, Some(Ok(3)));
+
+        drop((tx1, tx2));
+        assert_eq!(flattened.next().await, None);
+    });
+}
+
+#[test]
+fn limit_holds_back_inner_streams() {
+    let mut cx = noop_context();
+
+    let (tx1, rx1) = mpsc::unbounded::<Result<i32, i32>>();
+    let (tx2, rx2) = mpsc::unbounded();
+
+    let outer = stream::iter(vec![Ok::<_, i32>(rx1), Ok(rx2)]);
+    let mut flattened = outer.try_flatten_unordered(1);
+
+    // Only the first inner stream is being polled, so an item on the second
+    // one is not seen yet.
+    tx2.unbounded_send(Ok(2)).unwrap();
+    assert_eq!(flattened.poll_next_unpin(&mut cx), Poll::Pending);
+
+    // Once the first inner stream finishes, its slot goes to the second.
+    tx1.unbounded_send(Ok(1)).unwrap();
+    assert_eq!(flattened.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(1))));
+    drop(tx1);
+    assert_eq!(flattened.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(2))));
+
+    drop(tx2);
+    assert_eq!(flattened.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
+
+#[test]
+fn inner_error_cancels_other_inner_streams() {
+    block_on(async {
+        let (tx1, rx1) = mpsc::unbounded::<Result<i32, i32>>();
+        let (tx2, rx2) = mpsc::unbounded();
+
+        let outer = stream::iter(vec![Ok::<_, i32>(rx1), Ok(rx2)]);
+        let mut flattened = outer.try_flatten_unordered(None);
+
+        tx2.unbounded_send(Err(7)).unwrap();
+        assert_eq!(flattened.next().await, Some(Err(7)));
+
+        // The error dropped the other in-flight inner stream, which the
+        // sender observes as a disconnect.
+        assert!(tx1.is_closed());
+        assert_eq!(flattened.next().await, None);
+    });
+}
+
+#[test]
+fn outer_error_cancels_inner_streams() {
+    block_on(async {
+        let (tx1, rx1) = mpsc::unbounded::<Result<i32, i32>>();
+
+        let outer = stream::iter(vec![Ok::<_, i32>(rx1), Err(9)]);
+        let mut flattened = outer.try_flatten_unordered(None);
+
+        assert_eq!(flattened.next().await, Some(Err(9)));
+        assert!(tx1.is_closed());
+    });
+}
+
+#[test]
+fn empty_outer_stream() {
+    block_on(async {
+        let outer =
+            stream::iter(Vec::<Result<mpsc::UnboundedReceiver<Result<i32, i32>>, i32>>::new());
+        let mut flattened = outer.try_flatten_unordered(None);
+        assert_eq!(flattened.next().await, None);
+    });
+}
@@ -0,0 +1,105 @@
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use futures::future::{self, FutureExt};
+use futures::stream::{self, StreamExt};
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+#[test]
+fn resolves_ok_when_all_succeed() {
+    let stream = stream::iter(vec![1, 2, 3]);
+    let res: Result<(), i32> =
+        block_on(stream.try_for_each_concurrent(2, |_| future::ready(Ok(()))));
+    assert_eq!(res, Ok(()));
+}
+
+#[test]
+fn first_error_is_returned() {
+    let stream = stream::iter(vec![1, 2, 3]);
+    let res = block_on(stream.try_for_each_concurrent(None, |n| async move {
+        if n == 2 {
+            Err(n)
+        } else {
+            Ok(())
+        }
+    }));
+    assert_eq!(res, Err(2));
+}
+
+#[test]
+fn outstanding_futures_are_dropped_on_error() {
+    // Three tasks run concurrently; the second fails while the others are
+    // still pending. Guards observe the in-flight tasks being dropped.
+    let drops = Rc::new(Cell::new(0));
+
+    struct DropGuard(Rc<Cell<usize>>);
+    impl Drop for DropGuard {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    // The failing task blocks on a channel until all three tasks are in
+    // flight.
+    let (tx, rx) = oneshot::channel::<()>();
+    let mut rx = Some(rx);
+
+    let fut = stream::iter(vec![1, 2, 3]).try_for_each_concurrent(3, |n| {
+        let guard = DropGuard(drops.clone());
+        let rx = if n == 2 { rx.take() } else { None };
+        async move {
+            let _guard = guard;
+            if let Some(rx) = rx {
+                let _ = rx.await;
+                return Err(n);
+            }
+            // Never resolves; must be cancelled rather than awaited.
+            future::pending::<()>().await;
+            Ok(())
+        }
+    });
+    futures::pin_mut!(fut);
+
+    assert!(fut.as_mut().now_or_never().is_none());
+    assert_eq!(drops.get(), 0);
+
+    tx.send(()).unwrap();
+    assert_eq!(block_on(fut), Err(2));
+    // All three task futures were dropped: the failing one on completion,
+    // the two pending ones by cancellation.
+    assert_eq!(drops.get(), 3);
+}
+
+#[test]
+fn no_task_continues_after_error() {
+    // The in-flight tasks would increment the counter if they were polled to
+    // completion; cancelling them on error must prevent that.
+    let completions = Arc::new(AtomicUsize::new(0));
+
+    let (tx, rx) = oneshot::channel::<()>();
+    let mut rx = Some(rx);
+
+    let completions2 = completions.clone();
+    let fut = stream::iter(vec![1, 2, 3]).try_for_each_concurrent(3, move |n| {
+        let completions = completions2.clone();
+        let rx = if n == 2 { rx.take() } else { None };
+        async move {
+            if let Some(rx) = rx {
+                let _ = rx.await;
+                return Err(n);
+            }
+            future::pending::<()>().await;
+            completions.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    });
+    futures::pin_mut!(fut);
+
+    // All three tasks are in flight until the failing one is released.
+    assert!(fut.as_mut().now_or_never().is_none());
+    tx.send(()).unwrap();
+    assert_eq!(block_on(fut), Err(2));
+    assert_eq!(completions.load(Ordering::SeqCst), 0);
+}
@@ -0,0 +1,55 @@
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::stream::{self, Stream, StreamExt, TryReadyChunksError, TryStreamExt};
+use futures_test::task::noop_context;
+use std::pin::Pin;
+use std::task::Poll;
+
+#[test]
+fn ready_items_are_batched_up_to_capacity() {
+    let stream = stream::iter(vec![Ok::<i32, i32>(1), Ok(2), Ok(3), Ok(4), Ok(5)]);
+    let chunks: Vec<_> = block_on(stream.try_ready_chunks(2).collect());
+    assert_eq!(chunks, vec![Ok(vec![1, 2]), Ok(vec![3, 4]), Ok(vec![5])]);
+}
+
+#[test]
+fn error_mid_burst_carries_partial_batch() {
+    let stream = stream::iter(vec![Ok::<i32, i32>(1), Ok(2), Ok(3), Err(4), Ok(5)]);
+    let mut stream = stream.try_ready_chunks(3);
+
+    assert_eq!(block_on(stream.try_next()), Ok(Some(vec![1, 2, 3])));
+    assert_eq!(block_on(stream.try_next()), Err(TryReadyChunksError(vec![], 4)));
+    assert_eq!(block_on(stream.try_next()), Ok(Some(vec![5])));
+}
+
+#[test]
+fn error_after_some_items_preserves_them() {
+    let stream = stream::iter(vec![Ok::<i32, i32>(1), Ok(2), Err(3)]);
+    let mut stream = stream.try_ready_chunks(5);
+
+    assert_eq!(block_on(stream.try_next()), Err(TryReadyChunksError(vec![1, 2], 3)));
+    assert_eq!(block_on(stream.try_next()), Ok(None));
+}
+
+#[test]
+fn pending_flushes_partial_batch() {
+    let (tx, rx) = mpsc::unbounded::<Result<i32, i32>>();
+    let mut stream = rx.try_ready_chunks(4);
+    let mut cx = noop_context();
+
+    // No items ready yet: the combinator awaits at least one.
+    assert!(Pin::new(&mut stream).poll_next(&mut cx).is_pending());
+
+    tx.unbounded_send(Ok(1)).unwrap();
+    tx.unbounded_send(Ok(2)).unwrap();
+    assert_eq!(Pin::new(&mut stream).poll_next(&mut cx), Poll::Ready(Some(Ok(vec![1, 2]))));
+
+    // Back to waiting once the burst has been drained.
+    assert!(Pin::new(&mut stream).poll_next(&mut cx).is_pending());
+}
+
+#[test]
+#[should_panic]
+fn try_ready_chunks_panic_on_cap_zero() {
+    let _ = stream::iter(vec![Ok::<i32, i32>(1)]).try_ready_chunks(0);
+}
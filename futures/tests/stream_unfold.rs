@@ -30,3 +30,53 @@ fn unfold1() {
     assert_stream_pending!(stream);
     assert_stream_done!(stream);
 }
+
+#[test]
+fn unfold_with_state_after_completion() {
+    let mut stream = stream::unfold_with_state(0, |state| {
+        if state <= 2 {
+            future::ready((Some(state * 2), state + 1)).pending_once()
+        } else {
+            future::ready((None, state)).pending_once()
+        }
+    });
+
+    assert_stream_pending!(stream);
+    assert_stream_next!(stream, 0);
+    assert_stream_pending!(stream);
+    assert_stream_next!(stream, 2);
+    assert_stream_pending!(stream);
+    assert_stream_next!(stream, 4);
+    assert_stream_pending!(stream);
+    assert_stream_done!(stream);
+
+    // The final state survives natural completion.
+    assert_eq!(stream.into_state(), Some(3));
+}
+
+#[test]
+fn unfold_with_state_after_partial_consumption() {
+    let mut stream = stream::unfold_with_state(0, |state| {
+        future::ready((Some(state * 2), state + 1)).pending_once()
+    });
+
+    assert_stream_pending!(stream);
+    assert_stream_next!(stream, 0);
+    assert_stream_pending!(stream);
+    assert_stream_next!(stream, 2);
+
+    // Between steps the state is available for resumption.
+    assert_eq!(stream.into_state(), Some(2));
+}
+
+#[test]
+fn unfold_with_state_in_flight_step() {
+    let mut stream = stream::unfold_with_state(0, |state| {
+        future::ready((Some(state), state + 1)).pending_once()
+    });
+
+    // The first poll starts a step future that is still pending; the state is
+    // owned by that future and cannot be recovered.
+    assert_stream_pending!(stream);
+    assert_eq!(stream.into_state(), None);
+}
@@ -0,0 +1,39 @@
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+
+#[test]
+fn unique_suppresses_interleaved_duplicates() {
+    block_on(async {
+        let out = stream::iter(vec![1, 2, 1, 3, 2, 4, 1]).unique().collect::<Vec<_>>().await;
+        assert_eq!(out, vec![1, 2, 3, 4]);
+    })
+}
+
+#[test]
+fn unique_empty() {
+    block_on(async {
+        let out = stream::empty::<i32>().unique().collect::<Vec<_>>().await;
+        assert_eq!(out, Vec::<i32>::new());
+    })
+}
+
+#[test]
+fn unique_is_lazy() {
+    block_on(async {
+        // Items flow through as they arrive; a duplicate later on doesn't
+        // change what was already yielded.
+        let mut stream = stream::iter(vec![1, 1, 2, 1]).unique();
+        assert_eq!(stream.next().await, Some(1));
+        assert_eq!(stream.next().await, Some(2));
+        assert_eq!(stream.next().await, None);
+    })
+}
+
+#[test]
+fn unique_by_key_function() {
+    block_on(async {
+        let words = vec!["apple", "avocado", "banana", "cherry", "blueberry"];
+        let out = stream::iter(words).unique_by(|s| s.chars().next()).collect::<Vec<_>>().await;
+        assert_eq!(out, vec!["apple", "banana", "cherry"]);
+    })
+}
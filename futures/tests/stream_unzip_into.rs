@@ -0,0 +1,62 @@
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::future::FutureExt;
+use futures::stream::{self, StreamExt};
+use futures_test::task::noop_context;
+
+#[test]
+fn routes_pairs_to_both_sinks() {
+    let (tx_a, rx_a) = mpsc::unbounded();
+    let (tx_b, rx_b) = mpsc::unbounded();
+
+    block_on(stream::iter(vec![(1, 'a'), (2, 'b'), (3, 'c')]).unzip_into(tx_a, tx_b)).unwrap();
+
+    assert_eq!(block_on(rx_a.collect::<Vec<_>>()), vec![1, 2, 3]);
+    assert_eq!(block_on(rx_b.collect::<Vec<_>>()), vec!['a', 'b', 'c']);
+}
+
+#[test]
+fn empty_stream_closes_both_sinks() {
+    let (tx_a, rx_a) = mpsc::unbounded::<i32>();
+    let (tx_b, rx_b) = mpsc::unbounded::<char>();
+
+    block_on(stream::iter(Vec::new()).unzip_into(tx_a, tx_b)).unwrap();
+
+    assert_eq!(block_on(rx_a.collect::<Vec<_>>()), Vec::<i32>::new());
+    assert_eq!(block_on(rx_b.collect::<Vec<_>>()), Vec::<char>::new());
+}
+
+#[test]
+fn full_sink_pauses_the_stream() {
+    // A bounded channel of capacity zero accepts one item per sender before
+    // exerting backpressure.
+    let (tx_a, mut rx_a) = mpsc::channel(0);
+    let (tx_b, rx_b) = mpsc::unbounded();
+
+    let mut fut = stream::iter(vec![(1, 'a'), (2, 'b'), (3, 'c')]).unzip_into(tx_a, tx_b);
+    let mut cx = noop_context();
+
+    // The first pair goes through, then sink A is full: the second pair's
+    // left half stays buffered and the stream is not pulled any further.
+    assert!(fut.poll_unpin(&mut cx).is_pending());
+    assert_eq!(rx_a.try_next().unwrap(), Some(1));
+
+    // Draining sink A lets one more pair through at a time.
+    assert!(fut.poll_unpin(&mut cx).is_pending());
+    assert_eq!(rx_a.try_next().unwrap(), Some(2));
+    assert!(fut.poll_unpin(&mut cx).is_ready());
+    assert_eq!(rx_a.try_next().unwrap(), Some(3));
+
+    assert_eq!(block_on(rx_b.collect::<Vec<_>>()), vec!['a', 'b', 'c']);
+}
+
+#[test]
+fn receiver_drop_aborts_with_error() {
+    let (tx_a, rx_a) = mpsc::channel::<i32>(1);
+    let (tx_b, rx_b) = mpsc::unbounded::<char>();
+    drop(rx_a);
+
+    let result = block_on(stream::iter(vec![(1, 'a'), (2, 'b')]).unzip_into(tx_a, tx_b));
+    assert!(result.is_err());
+    drop(rx_b);
+}
@@ -0,0 +1,29 @@
+use futures::executor::block_on;
+use futures::stream::{self, Position, StreamExt};
+
+fn positions(len: usize) -> Vec<(Position, usize)> {
+    block_on(stream::iter(0..len).with_position().collect())
+}
+
+#[test]
+fn with_position_empty() {
+    assert_eq!(positions(0), vec![]);
+}
+
+#[test]
+fn with_position_single() {
+    assert_eq!(positions(1), vec![(Position::Only, 0)]);
+}
+
+#[test]
+fn with_position_two() {
+    assert_eq!(positions(2), vec![(Position::First, 0), (Position::Last, 1)]);
+}
+
+#[test]
+fn with_position_three() {
+    assert_eq!(
+        positions(3),
+        vec![(Position::First, 0), (Position::Middle, 1), (Position::Last, 2)]
+    );
+}
@@ -0,0 +1,63 @@
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::stream::{self, EitherOrBoth, FusedStream, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+
+#[test]
+fn equal_length() {
+    let result: Vec<_> = block_on(stream::iter(1..=3).zip_longest(stream::iter(4..=6)).collect());
+    assert_eq!(
+        result,
+        vec![EitherOrBoth::Both(1, 4), EitherOrBoth::Both(2, 5), EitherOrBoth::Both(3, 6)]
+    );
+}
+
+#[test]
+fn left_longer() {
+    let result: Vec<_> = block_on(stream::iter(1..=4).zip_longest(stream::iter(7..=8)).collect());
+    assert_eq!(
+        result,
+        vec![
+            EitherOrBoth::Both(1, 7),
+            EitherOrBoth::Both(2, 8),
+            EitherOrBoth::Left(3),
+            EitherOrBoth::Left(4),
+        ]
+    );
+}
+
+#[test]
+fn right_longer() {
+    let result: Vec<_> = block_on(stream::iter(1..=1).zip_longest(stream::iter(7..=9)).collect());
+    assert_eq!(
+        result,
+        vec![EitherOrBoth::Both(1, 7), EitherOrBoth::Right(8), EitherOrBoth::Right(9)]
+    );
+}
+
+#[test]
+fn both_empty() {
+    let mut stream = stream::iter(Vec::<i32>::new()).zip_longest(stream::empty::<u8>());
+    assert_eq!(block_on(stream.next()), None);
+    assert!(stream.is_terminated());
+}
+
+#[test]
+fn waits_for_slow_side_before_yielding_alone() {
+    let (mut tx, rx) = mpsc::unbounded::<i32>();
+    let mut stream = stream::iter(1..=2).zip_longest(rx);
+    let mut cx = noop_context();
+
+    // The left side is ready, but the right side has neither produced an
+    // item nor ended, so nothing can be yielded yet.
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+
+    tx.start_send(7).unwrap();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(EitherOrBoth::Both(1, 7))));
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+
+    drop(tx);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(EitherOrBoth::Left(2))));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
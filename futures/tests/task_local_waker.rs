@@ -0,0 +1,98 @@
+use futures::executor::LocalPool;
+use futures::future::{self, Future};
+use futures::task::{self, Context, LocalSpawnExt, LocalWake, LocalWaker, Poll};
+use std::cell::Cell;
+use std::rc::Rc;
+
+struct CountingWaker {
+    nr_wake: Cell<i32>,
+}
+
+impl CountingWaker {
+    fn new() -> Self {
+        Self { nr_wake: Cell::new(0) }
+    }
+
+    fn wakes(&self) -> i32 {
+        self.nr_wake.get()
+    }
+}
+
+impl LocalWake for CountingWaker {
+    fn wake_by_ref(rc_self: &Rc<Self>) {
+        rc_self.nr_wake.set(rc_self.nr_wake.get() + 1);
+    }
+}
+
+#[test]
+fn create_from_rc() {
+    let some_w = Rc::new(CountingWaker::new());
+
+    let w1: LocalWaker = task::local_waker(some_w.clone());
+    assert_eq!(2, Rc::strong_count(&some_w));
+    w1.wake_by_ref();
+    assert_eq!(1, some_w.wakes());
+
+    let w2 = w1.clone();
+    assert_eq!(3, Rc::strong_count(&some_w));
+
+    w2.wake_by_ref();
+    assert_eq!(2, some_w.wakes());
+
+    drop(w2);
+    assert_eq!(2, Rc::strong_count(&some_w));
+    w1.wake();
+    assert_eq!(3, some_w.wakes());
+    assert_eq!(1, Rc::strong_count(&some_w));
+}
+
+#[test]
+fn drives_a_future_through_context() {
+    let some_w = Rc::new(CountingWaker::new());
+    let waker: LocalWaker = task::local_waker(some_w.clone());
+    let mut cx = Context::from_waker(&waker);
+
+    let mut polls = 0;
+    let mut fut = Box::pin(future::poll_fn(|cx| {
+        polls += 1;
+        if polls < 3 {
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        } else {
+            Poll::Ready(())
+        }
+    }));
+
+    assert!(fut.as_mut().poll(&mut cx).is_pending());
+    assert_eq!(1, some_w.wakes());
+    assert!(fut.as_mut().poll(&mut cx).is_pending());
+    assert_eq!(2, some_w.wakes());
+    assert!(fut.as_mut().poll(&mut cx).is_ready());
+    assert_eq!(2, some_w.wakes());
+    assert_eq!(3, polls);
+}
+
+#[test]
+fn wakes_on_local_executor() {
+    // `Rc` makes the spawned future `!Send`; the waker driving it on the
+    // local pool must still re-poll it after a self-wake.
+    let mut pool = LocalPool::new();
+    let spawner = pool.spawner();
+
+    let polls = Rc::new(Cell::new(0));
+    let polls2 = polls.clone();
+    spawner
+        .spawn_local(future::poll_fn(move |cx| {
+            polls2.set(polls2.get() + 1);
+            if polls2.get() < 3 {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            } else {
+                Poll::Ready(())
+            }
+        }))
+        .unwrap();
+
+    pool.run();
+    assert_eq!(3, polls.get());
+}
@@ -0,0 +1,66 @@
+use futures::future::{self, FutureExt};
+use futures::task::{noop_context, noop_waker, noop_waker_ref, Poll};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn noop_context_polls_futures() {
+    let mut cx = noop_context();
+
+    let mut polls = 0;
+    let mut fut = future::poll_fn(|cx| {
+        polls += 1;
+        if polls < 3 {
+            // Waking the noop waker is itself a no-op; the manual poll loop
+            // below drives the future to completion.
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        } else {
+            Poll::Ready(42)
+        }
+    });
+
+    // The context is freely reusable across polls.
+    assert!(fut.poll_unpin(&mut cx).is_pending());
+    assert!(fut.poll_unpin(&mut cx).is_pending());
+    assert_eq!(fut.poll_unpin(&mut cx), Poll::Ready(42));
+}
+
+#[test]
+fn noop_waker_does_not_allocate() {
+    // Warm up anything lazily initialized by the test harness.
+    noop_waker().wake();
+
+    let before = ALLOCATIONS.load(Ordering::SeqCst);
+
+    let waker = noop_waker();
+    let clone = waker.clone();
+    clone.wake_by_ref();
+    clone.wake();
+    waker.wake();
+
+    let static_waker = noop_waker_ref();
+    static_waker.wake_by_ref();
+    let mut cx = noop_context();
+    assert!(future::pending::<()>().poll_unpin(&mut cx).is_pending());
+
+    assert_eq!(ALLOCATIONS.load(Ordering::SeqCst), before);
+}